@@ -2,23 +2,34 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use twist_and_shout::*;
+use twist_and_shout::utils::G1Element;
+use ark_ff::Field;
 
 fn benchmark_twist_prover(c: &mut Criterion) {
     let mut group = c.benchmark_group("twist_prover");
+    group.sample_size(10);
     
+    // Columns are interpolated with NTTs in O(n log n), so sizes reach the
+    // range where that beats quadratic interpolation
     for log_size in [8, 10, 12].iter() {
         let (prover_params, _) = setup_params(*log_size);
+        let twist = Twist::new(&prover_params);
         let memory_size = 1 << log_size;
         
+        // Half as many operations as cells: a write every third, reads otherwise
+        let mut trace = MemoryTrace::new(memory_size);
+        for i in 0..memory_size / 2 {
+            if i % 3 == 0 {
+                trace.write(i % memory_size, FieldElement::from((i * 42) as u64)).unwrap();
+            } else {
+                trace.read((i / 2) % memory_size).unwrap();
+            }
+        }
+        
         group.bench_with_input(
             BenchmarkId::new("memory_operations", memory_size),
-            log_size,
-            |b, &log_size| {
-                b.iter(|| {
-                    // This will be implemented when we have the full Twist protocol
-                    black_box(log_size);
-                })
-            },
+            &trace,
+            |b, trace| b.iter(|| black_box(twist.prove(trace).unwrap())),
         );
     }
     
@@ -27,25 +38,90 @@ fn benchmark_twist_prover(c: &mut Criterion) {
 
 fn benchmark_shout_prover(c: &mut Criterion) {
     let mut group = c.benchmark_group("shout_prover");
+    group.sample_size(10);
     
     for log_size in [8, 10, 12].iter() {
         let (prover_params, _) = setup_params(*log_size);
+        let shout = Shout::new(&prover_params);
         let table_size = 1 << log_size;
         
+        // A table of squares, looked up at half as many indices as it has entries
+        let mut table = LookupTable::new((0..table_size as u64).map(|i| FieldElement::from(i * i)).collect());
+        let indices: Vec<usize> = (0..table_size / 2).map(|i| (i * 7) % table_size).collect();
+        table.lookup_batch(&indices).unwrap();
+        
         group.bench_with_input(
             BenchmarkId::new("lookup_operations", table_size),
-            log_size,
-            |b, &log_size| {
-                b.iter(|| {
-                    // This will be implemented when we have the full Shout protocol
-                    black_box(log_size);
-                })
-            },
+            &table,
+            |b, table| b.iter(|| black_box(shout.prove(table).unwrap())),
         );
     }
     
     group.finish();
 }
 
-criterion_group!(benches, benchmark_twist_prover, benchmark_shout_prover);
+fn benchmark_windowed_commit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("windowed_commit");
+    
+    // Many small commitments against the same SRS, where the tables amortize
+    let (prover_params, _) = setup_params(4);
+    let params = &prover_params.commitment_params;
+    let polys: Vec<Vec<FieldElement>> = (0..64u64)
+        .map(|i| (0..16u64).map(|j| FieldElement::from(i * 16 + j + 1).pow([7u64])).collect())
+        .collect();
+    let windows = params.precompute_windows(5).unwrap();
+    
+    group.bench_function("standard_64x16", |b| {
+        b.iter(|| {
+            for poly in &polys {
+                black_box(KZGCommitment::commit(params, poly).unwrap());
+            }
+        })
+    });
+    
+    group.bench_function("windowed_64x16", |b| {
+        b.iter(|| {
+            for poly in &polys {
+                black_box(KZGCommitment::commit_windowed(&windows, poly).unwrap());
+            }
+        })
+    });
+    
+    group.finish();
+}
+
+fn benchmark_msm_commit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("msm_commit");
+    group.sample_size(10);
+    
+    // One large commitment, where MSM bucketing pays off over per-term multiplication
+    let (prover_params, _) = setup_params(12);
+    let params = &prover_params.commitment_params;
+    let poly: Vec<FieldElement> = (0..1u64 << 12).map(|i| FieldElement::from(i + 1).pow([7u64])).collect();
+    
+    group.bench_function("scalar_loop_4096", |b| {
+        b.iter(|| {
+            black_box(
+                poly.iter()
+                    .zip(&params.g1_powers)
+                    .map(|(&coeff, &power)| power * coeff)
+                    .sum::<G1Element>(),
+            )
+        })
+    });
+    
+    group.bench_function("msm_4096", |b| {
+        b.iter(|| black_box(KZGCommitment::commit(params, &poly).unwrap()))
+    });
+    
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    benchmark_twist_prover,
+    benchmark_shout_prover,
+    benchmark_windowed_commit,
+    benchmark_msm_commit
+);
 criterion_main!(benches);
\ No newline at end of file
@@ -1,19 +1,27 @@
 //! Benchmarking utilities for Twist and Shout protocols.
 
 use crate::utils::{FieldElement, setup_params};
-use crate::{Twist, Shout, MemoryTrace, LookupTable};
-use std::time::{Duration, Instant};
+use crate::{GrandProduct, Twist, Shout, MemoryTrace, LookupTable, Result, TwistAndShoutError};
+use ark_serialize::CanonicalSerialize;
+use serde::{Deserialize, Serialize};
+use ark_std::io::Write;
+use ark_std::path::Path;
+use ark_std::time::{Duration, Instant};
+use ark_std::{format, string::{String, ToString}, vec::Vec};
 
 /// Benchmark results for protocol operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BenchmarkResults {
     /// Setup time
+    #[serde(rename = "setup_time_ns", with = "duration_nanos")]
     pub setup_time: Duration,
     /// Proving time
+    #[serde(rename = "prove_time_ns", with = "duration_nanos")]
     pub prove_time: Duration,
     /// Verification time
+    #[serde(rename = "verify_time_ns", with = "duration_nanos")]
     pub verify_time: Duration,
-    /// Proof size in bytes (estimated)
+    /// Proof size in bytes, compressed canonical serialization
     pub proof_size: usize,
     /// Number of operations processed
     pub num_operations: usize,
@@ -21,7 +29,54 @@ pub struct BenchmarkResults {
     pub memory_usage: usize,
 }
 
+/// One exported scaling measurement: the protocol, the memory or table size,
+/// and its results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    pub protocol: String,
+    pub size: usize,
+    #[serde(flatten)]
+    pub results: BenchmarkResults,
+}
+
+/// File format for [`ProtocolBenchmarks::run_and_export`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+/// Serde encoding of a [`Duration`] as whole nanoseconds
+mod duration_nanos {
+    use ark_std::time::Duration;
+    
+    pub fn serialize<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+    
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
 impl BenchmarkResults {
+    /// Column names matching [`BenchmarkResults::to_csv_row`]
+    pub const CSV_HEADER: &'static str =
+        "setup_time_ns,prove_time_ns,verify_time_ns,proof_size,num_operations,memory_usage";
+    
+    /// Every field as a comma-separated row, times in nanoseconds
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.setup_time.as_nanos(),
+            self.prove_time.as_nanos(),
+            self.verify_time.as_nanos(),
+            self.proof_size,
+            self.num_operations,
+            self.memory_usage
+        )
+    }
+    
     /// Calculate operations per second for proving
     pub fn prove_ops_per_second(&self) -> f64 {
         self.num_operations as f64 / self.prove_time.as_secs_f64()
@@ -82,21 +137,7 @@ impl ProtocolBenchmarks {
         
         // Create memory trace
         let memory_size = 1 << log_size;
-        let mut trace = MemoryTrace::new(memory_size);
-        
-        // Populate with operations (mix of reads and writes)
-        for i in 0..num_operations {
-            if i % 3 == 0 {
-                // Write operation
-                let addr = i % memory_size;
-                let value = FieldElement::from((i * 42) as u64);
-                trace.write(addr, value).unwrap();
-            } else {
-                // Read operation
-                let addr = (i / 2) % memory_size;
-                trace.read(addr).unwrap();
-            }
-        }
+        let trace = Self::benchmark_trace(memory_size, num_operations);
         
         // Proving phase
         let prove_start = Instant::now();
@@ -110,8 +151,8 @@ impl ProtocolBenchmarks {
         
         assert!(is_valid, "Proof verification failed");
         
-        // Estimate proof size and memory usage
-        let proof_size = Self::estimate_twist_proof_size(&proof);
+        // Measure proof size and estimate memory usage
+        let proof_size = proof.compressed_size();
         let memory_usage = Self::estimate_memory_usage(memory_size, num_operations);
         
         BenchmarkResults {
@@ -124,6 +165,61 @@ impl ProtocolBenchmarks {
         }
     }
     
+    /// Benchmark the grand-product baseline on the trace Twist is benchmarked with.
+    ///
+    /// The baseline commits a row per memory cell as well as per operation, so
+    /// setup draws an SRS sized for those rows rather than the memory size.
+    pub fn benchmark_grand_product_single(log_size: usize, num_operations: usize) -> BenchmarkResults {
+        let memory_size = 1 << log_size;
+        let trace = Self::benchmark_trace(memory_size, num_operations);
+        
+        // Setup phase
+        let setup_start = Instant::now();
+        let rows = (memory_size + num_operations + 1).next_power_of_two();
+        let (prover_params, verifier_params) = setup_params(rows.trailing_zeros().saturating_sub(2) as usize);
+        let checker = GrandProduct::new(&prover_params);
+        let setup_time = setup_start.elapsed();
+        
+        // Proving phase
+        let prove_start = Instant::now();
+        let proof = checker.prove(&trace).unwrap();
+        let prove_time = prove_start.elapsed();
+        
+        // Verification phase
+        let verify_start = Instant::now();
+        let is_valid = checker.verify(&proof, &verifier_params).unwrap();
+        let verify_time = verify_start.elapsed();
+        
+        assert!(is_valid, "Proof verification failed");
+        
+        BenchmarkResults {
+            setup_time,
+            prove_time,
+            verify_time,
+            proof_size: proof.compressed_size(),
+            num_operations: trace.operations.len(),
+            memory_usage: Self::estimate_memory_usage(memory_size, num_operations),
+        }
+    }
+    
+    /// The benchmark's memory trace: a write every third operation, reads otherwise
+    fn benchmark_trace(memory_size: usize, num_operations: usize) -> MemoryTrace {
+        let mut trace = MemoryTrace::new(memory_size);
+        for i in 0..num_operations {
+            if i % 3 == 0 {
+                // Write operation
+                let addr = i % memory_size;
+                let value = FieldElement::from((i * 42) as u64);
+                trace.write(addr, value).unwrap();
+            } else {
+                // Read operation
+                let addr = (i / 2) % memory_size;
+                trace.read(addr).unwrap();
+            }
+        }
+        trace
+    }
+    
     /// Benchmark Shout protocol with various table sizes
     pub fn benchmark_shout_scaling() -> Vec<(usize, BenchmarkResults)> {
         Self::benchmark_shout_scaling_range(4, 8) // Good balance of coverage and speed
@@ -188,8 +284,8 @@ impl ProtocolBenchmarks {
         
         assert!(is_valid, "Proof verification failed");
         
-        // Estimate proof size and memory usage
-        let proof_size = Self::estimate_shout_proof_size(&proof);
+        // Measure proof size and estimate memory usage
+        let proof_size = proof.compressed_size();
         let memory_usage = Self::estimate_memory_usage(table_size, num_lookups);
         
         BenchmarkResults {
@@ -234,7 +330,8 @@ impl ProtocolBenchmarks {
         let compare_table_size = 1 << compare_log_size;
         println!("\n🔄 Comparative Analysis (Memory/Table Size: {}):", compare_table_size);
         let (twist_comp, shout_comp) = Self::comparative_benchmark(compare_log_size, num_ops);
-        Self::print_comparative_results(&twist_comp, &shout_comp);
+        let baseline_comp = Self::benchmark_grand_product_single(compare_log_size, num_ops);
+        Self::print_comparative_results(&twist_comp, &shout_comp, &baseline_comp);
     }
     
     /// Run quick benchmark suite for development/testing
@@ -256,12 +353,13 @@ impl ProtocolBenchmarks {
         for log_size in min_log_size..=max_log_size {
             let table_size = 1 << log_size;
             // Scale operations inversely with table size for consistent runtime
-            let num_operations = std::cmp::max(32, 512 / (1 << (log_size - 4)));
+            let num_operations = ark_std::cmp::max(32, 512 / (1 << (log_size - 4)));
             
             println!("📊 Protocol Comparison at size {} (2^{}) with {} operations:", 
                      table_size, log_size, num_operations);
             
             let (twist_result, shout_result) = Self::comparative_benchmark(log_size, num_operations);
+            let baseline_result = Self::benchmark_grand_product_single(log_size, num_operations);
             
             println!("Protocol | Prove(ms) | Verify(ms) | Proof(KB) | Ops/sec | Memory(KB)");
             println!("---------|-----------|------------|-----------|---------|----------");
@@ -281,10 +379,54 @@ impl ProtocolBenchmarks {
                 shout_result.prove_ops_per_second(),
                 shout_result.memory_usage as f64 / 1024.0
             );
+            println!(
+                "Baseline | {:.2}      | {:.2}       | {:.2}      | {:.0}     | {:.1}",
+                baseline_result.prove_time.as_millis(),
+                baseline_result.verify_time.as_millis(),
+                baseline_result.proof_size as f64 / 1024.0,
+                baseline_result.prove_ops_per_second(),
+                baseline_result.memory_usage as f64 / 1024.0
+            );
             println!();
         }
     }
     
+    /// Benchmark Twist, Shout and the grand-product baseline at every size from 2^`min_log_size` to
+    /// 2^`max_log_size` with `num_ops` operations each, and write the results
+    /// to `path` as CSV (with a header row) or a JSON array of [`BenchmarkRecord`]s
+    pub fn run_and_export(
+        min_log_size: usize,
+        max_log_size: usize,
+        num_ops: usize,
+        path: &Path,
+        format: ExportFormat,
+    ) -> Result<Vec<BenchmarkRecord>> {
+        let mut records = Vec::new();
+        for log_size in min_log_size..=max_log_size {
+            let (twist, shout) = Self::comparative_benchmark(log_size, num_ops);
+            let baseline = Self::benchmark_grand_product_single(log_size, num_ops);
+            for (protocol, results) in [("Twist", twist), ("Shout", shout), ("GrandProduct", baseline)] {
+                records.push(BenchmarkRecord { protocol: protocol.to_string(), size: 1 << log_size, results });
+            }
+        }
+        
+        let contents = match format {
+            ExportFormat::Csv => {
+                let mut csv = format!("protocol,size,{}\n", BenchmarkResults::CSV_HEADER);
+                for record in &records {
+                    csv.push_str(&format!("{},{},{}\n", record.protocol, record.size, record.results.to_csv_row()));
+                }
+                csv
+            }
+            ExportFormat::Json => crate::utils::to_json(&records)?,
+        };
+        
+        let io_error = |e: ark_std::io::Error| TwistAndShoutError::Serialization(e.to_string());
+        let mut file = ark_std::fs::File::create(path).map_err(io_error)?;
+        file.write_all(contents.as_bytes()).map_err(io_error)?;
+        Ok(records)
+    }
+    
     /// Print scaling benchmark results
     pub fn print_scaling_results(protocol: &str, results: &[(usize, BenchmarkResults)]) {
         println!("Size\t| Setup(ms)\t| Prove(ms)\t| Verify(ms)\t| Proof(KB)\t| Ops/sec");
@@ -303,8 +445,30 @@ impl ProtocolBenchmarks {
         }
     }
     
+    /// Render scaling benchmark results as a GitHub-flavored markdown table
+    /// under a heading naming the protocol
+    pub fn results_to_markdown(protocol: &str, results: &[(usize, BenchmarkResults)]) -> String {
+        let mut markdown = format!("### {}\n\n", protocol);
+        markdown.push_str("| Size | Setup (ms) | Prove (ms) | Verify (ms) | Proof (KB) | Ops/sec |\n");
+        markdown.push_str("|-----:|-----------:|-----------:|------------:|-----------:|--------:|\n");
+        
+        for (size, result) in results {
+            markdown.push_str(&format!(
+                "| {} | {:.2} | {:.2} | {:.2} | {:.2} | {:.0} |\n",
+                size,
+                result.setup_time.as_secs_f64() * 1000.0,
+                result.prove_time.as_secs_f64() * 1000.0,
+                result.verify_time.as_secs_f64() * 1000.0,
+                result.proof_size as f64 / 1024.0,
+                result.prove_ops_per_second()
+            ));
+        }
+        
+        markdown
+    }
+    
     /// Print comparative benchmark results
-    fn print_comparative_results(twist: &BenchmarkResults, shout: &BenchmarkResults) {
+    fn print_comparative_results(twist: &BenchmarkResults, shout: &BenchmarkResults, baseline: &BenchmarkResults) {
         println!("Protocol | Prove(ms) | Verify(ms) | Proof(KB) | Ops/sec | Total(ms)");
         println!("---------|-----------|------------|-----------|---------|----------");
         println!(
@@ -323,6 +487,14 @@ impl ProtocolBenchmarks {
             shout.prove_ops_per_second(),
             shout.total_time().as_millis()
         );
+        println!(
+            "Baseline | {:.2}      | {:.2}       | {:.2}      | {:.0}     | {:.2}",
+            baseline.prove_time.as_millis(),
+            baseline.verify_time.as_millis(),
+            baseline.proof_size as f64 / 1024.0,
+            baseline.prove_ops_per_second(),
+            baseline.total_time().as_millis()
+        );
         
         // Performance ratios
         let prove_ratio = twist.prove_time.as_millis() as f64 / shout.prove_time.as_millis() as f64;
@@ -330,26 +502,9 @@ impl ProtocolBenchmarks {
         
         println!("\n📈 Performance Ratios (Twist/Shout):");
         println!("Proving: {:.2}x, Verification: {:.2}x", prove_ratio, verify_ratio);
-    }
-    
-    /// Estimate proof size for Twist protocol
-    fn estimate_twist_proof_size(proof: &crate::twist::TwistProof) -> usize {
-        // Rough estimation based on typical sizes
-        let commitment_size = 64; // G1 point in compressed form
-        let sumcheck_size = proof.consistency_proof.round_polynomials.len() * 128; // Polynomials
-        let opening_size = proof.opening_proofs.len() * 64; // G1 proofs
-        
-        2 * commitment_size + sumcheck_size + opening_size
-    }
-    
-    /// Estimate proof size for Shout protocol
-    fn estimate_shout_proof_size(proof: &crate::shout::ShoutProof) -> usize {
-        // Rough estimation based on typical sizes
-        let commitment_size = 64; // G1 point in compressed form
-        let sumcheck_size = proof.lookup_proof.round_polynomials.len() * 128; // Polynomials
-        let opening_size = proof.opening_proofs.len() * 64; // G1 proofs
-        
-        2 * commitment_size + sumcheck_size + opening_size
+        
+        let baseline_ratio = baseline.prove_time.as_secs_f64() / twist.prove_time.as_secs_f64();
+        println!("Grand-product baseline proving: {:.2}x Twist", baseline_ratio);
     }
     
     /// Estimate memory usage
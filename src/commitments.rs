@@ -3,40 +3,62 @@
 //! This module provides commitment schemes used in the Twist and Shout protocols,
 //! with KZG commitments as the default implementation.
 
-use crate::utils::{FieldElement, G1Element, G2Element, CommitmentParams, CommitmentVerificationKey};
+use crate::utils::{FieldElement, G1Element, G2Element, CommitmentParams, CommitmentVerificationKey, ProverParams, VerifierParams, FiatShamirTranscript, Transcript, WindowTables};
+use crate::polynomials::MultilinearExtension;
 use crate::{Result, TwistAndShoutError};
-use ark_ec::{CurveGroup, Group, pairing::Pairing};
+use ark_ec::{CurveGroup, Group, VariableBaseMSM, pairing::Pairing, scalar_mul::wnaf::WnafContext};
 use ark_bn254::{Bn254, G1Affine, G2Affine};
 use ark_ff::{Field, Zero, One, PrimeField, BigInteger};
 use ark_serialize::{CanonicalSerialize, CanonicalDeserialize};
 use serde::{Deserialize, Serialize};
+use ark_std::UniformRand;
+use ark_std::borrow::Cow;
+use ark_std::fmt::Debug;
+use ark_std::{string::ToString, vec, vec::Vec};
 
 /// Trait defining a polynomial commitment scheme
 pub trait CommitmentScheme {
-    type Commitment: Clone + PartialEq;
-    type Proof: Clone;
+    /// Scalar field the committed polynomials are defined over
+    type Scalar: PrimeField;
+    type Commitment: Clone + PartialEq + Debug + CanonicalSerialize + CanonicalDeserialize;
+    type Proof: Clone + Debug + CanonicalSerialize + CanonicalDeserialize;
     type Params: Clone;
     type VerifyKey: Clone;
     
+    /// Commitment key derived from the protocol's prover parameters
+    fn prover_key(params: &ProverParams) -> Cow<'_, Self::Params>;
+    
+    /// Verification key derived from the protocol's verifier parameters
+    fn verifier_key(params: &VerifierParams) -> Cow<'_, Self::VerifyKey>;
+    
+    /// Field element binding a commitment into a Fiat-Shamir transcript,
+    /// which always runs over [`FieldElement`]
+    fn transcript_hash(commitment: &Self::Commitment) -> FieldElement;
+    
+    /// Whether `vk` can check openings of polynomials up to `degree`
+    fn supports_degree(_vk: &Self::VerifyKey, _degree: usize) -> bool {
+        true
+    }
+    
     /// Commit to a polynomial represented by its coefficients
     fn commit(
         params: &Self::Params,
-        polynomial: &[FieldElement],
+        polynomial: &[Self::Scalar],
     ) -> Result<Self::Commitment>;
     
     /// Open the commitment at a given point
     fn open(
         params: &Self::Params,
-        polynomial: &[FieldElement],
-        point: FieldElement,
-    ) -> Result<(FieldElement, Self::Proof)>;
+        polynomial: &[Self::Scalar],
+        point: Self::Scalar,
+    ) -> Result<(Self::Scalar, Self::Proof)>;
     
     /// Verify an opening proof
     fn verify(
         vk: &Self::VerifyKey,
         commitment: &Self::Commitment,
-        point: FieldElement,
-        value: FieldElement,
+        point: Self::Scalar,
+        value: Self::Scalar,
         proof: &Self::Proof,
     ) -> Result<bool>;
     
@@ -44,8 +66,8 @@ pub trait CommitmentScheme {
     fn batch_verify(
         vk: &Self::VerifyKey,
         commitments: &[Self::Commitment],
-        points: &[FieldElement],
-        values: &[FieldElement],
+        points: &[Self::Scalar],
+        values: &[Self::Scalar],
         proofs: &[Self::Proof],
     ) -> Result<bool> {
         // Default implementation: verify each proof individually
@@ -71,16 +93,44 @@ pub struct KZGCommitmentValue {
 impl KZGCommitmentValue {
     /// Get a field element hash of the commitment for transcripts
     pub fn hash(&self) -> FieldElement {
-        // Convert the x-coordinate to a scalar field element via modular reduction
-        let x_coord = self.commitment.into_affine().x;
-        let x_bytes = x_coord.into_bigint().to_bytes_le();
-        
-        // Create a field element from the first 32 bytes
-        let mut bytes = [0u8; 32];
-        let copy_len = std::cmp::min(x_bytes.len(), 32);
-        bytes[..copy_len].copy_from_slice(&x_bytes[..copy_len]);
-        
-        FieldElement::from_le_bytes_mod_order(&bytes)
+        g1_transcript_hash(&self.commitment)
+    }
+    
+    /// Commitment to the polynomial scaled by `s`: s·commit(p) = commit(s·p)
+    pub fn scale(&self, s: FieldElement) -> KZGCommitmentValue {
+        KZGCommitmentValue { commitment: self.commitment * s }
+    }
+}
+
+/// Field element hash of a G1 point: its x-coordinate reduced into the scalar field
+fn g1_transcript_hash(point: &G1Element) -> FieldElement {
+    let x_coord = point.into_affine().x;
+    
+    // The coordinate's four limbs are its 32 little-endian bytes, written on
+    // the stack so transcripts can hash commitments without allocating
+    let mut bytes = [0u8; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(x_coord.into_bigint().0) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    
+    FieldElement::from_le_bytes_mod_order(&bytes)
+}
+
+impl ark_std::ops::Add for KZGCommitmentValue {
+    type Output = KZGCommitmentValue;
+    
+    /// Commitments are additively homomorphic: commit(p) + commit(q) = commit(p + q)
+    fn add(self, other: KZGCommitmentValue) -> KZGCommitmentValue {
+        KZGCommitmentValue { commitment: self.commitment + other.commitment }
+    }
+}
+
+impl ark_std::ops::Sub for KZGCommitmentValue {
+    type Output = KZGCommitmentValue;
+    
+    /// commit(p) - commit(q) = commit(p - q)
+    fn sub(self, other: KZGCommitmentValue) -> KZGCommitmentValue {
+        KZGCommitmentValue { commitment: self.commitment - other.commitment }
     }
 }
 
@@ -90,15 +140,40 @@ pub struct KZGProof {
     pub proof: G1Element,
 }
 
+/// A claimed KZG opening: (commitment, point, value, proof)
+pub type KZGOpening = (KZGCommitmentValue, FieldElement, FieldElement, KZGProof);
+
+/// Multi-point KZG opening from [`KZGCommitment::open_batch`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KZGBatchProof {
+    /// Commitment W to (p - I)/Z
+    pub quotient: G1Element,
+    
+    /// Opening proof W' of the linearized polynomial at ζ
+    pub shifted: G1Element,
+}
+
+/// Opening of a hiding KZG commitment: the proof for the blinded polynomial
+/// together with the blind
+#[derive(Debug, Clone)]
+pub struct KZGHidingOpening {
+    pub proof: KZGProof,
+    pub blinding: FieldElement,
+    
+    /// Power D of τ the blind multiplies: the top power of the committer's
+    /// SRS, which a trimmed prover key places below the verifier key's
+    pub blinding_degree: usize,
+}
+
 // Manual implementations for arkworks compatibility
 impl ark_serialize::Valid for KZGCommitmentValue {
-    fn check(&self) -> std::result::Result<(), ark_serialize::SerializationError> {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
         Ok(())
     }
 }
 
 impl ark_serialize::Valid for KZGProof {
-    fn check(&self) -> std::result::Result<(), ark_serialize::SerializationError> {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
         Ok(())
     }
 }
@@ -108,7 +183,7 @@ impl ark_serialize::CanonicalSerialize for KZGCommitmentValue {
         &self,
         writer: W,
         compress: ark_serialize::Compress,
-    ) -> std::result::Result<(), ark_serialize::SerializationError> {
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
         self.commitment.serialize_with_mode(writer, compress)
     }
     
@@ -122,7 +197,7 @@ impl ark_serialize::CanonicalDeserialize for KZGCommitmentValue {
         reader: R,
         compress: ark_serialize::Compress,
         validate: ark_serialize::Validate,
-    ) -> std::result::Result<Self, ark_serialize::SerializationError> {
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
         let commitment = G1Element::deserialize_with_mode(reader, compress, validate)?;
         Ok(Self { commitment })
     }
@@ -133,7 +208,7 @@ impl ark_serialize::CanonicalSerialize for KZGProof {
         &self,
         writer: W,
         compress: ark_serialize::Compress,
-    ) -> std::result::Result<(), ark_serialize::SerializationError> {
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
         self.proof.serialize_with_mode(writer, compress)
     }
     
@@ -147,18 +222,383 @@ impl ark_serialize::CanonicalDeserialize for KZGProof {
         reader: R,
         compress: ark_serialize::Compress,
         validate: ark_serialize::Validate,
-    ) -> std::result::Result<Self, ark_serialize::SerializationError> {
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
         let proof = G1Element::deserialize_with_mode(reader, compress, validate)?;
         Ok(Self { proof })
     }
 }
 
+impl ark_serialize::Valid for MultilinearKZGProof {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        Ok(())
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for MultilinearKZGProof {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.quotients.serialize_with_mode(writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.quotients.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for MultilinearKZGProof {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let quotients = Vec::deserialize_with_mode(reader, compress, validate)?;
+        Ok(Self { quotients })
+    }
+}
+
+impl KZGCommitment {
+    /// Commit using window-NAF tables from [`CommitmentParams::precompute_windows`].
+    ///
+    /// Produces the same commitment as [`CommitmentScheme::commit`].
+    pub fn commit_windowed(
+        windows: &WindowTables,
+        polynomial: &[FieldElement],
+    ) -> Result<KZGCommitmentValue> {
+        if polynomial.len() > windows.tables.len() {
+            return Err(TwistAndShoutError::Commitment(
+                "Polynomial degree exceeds setup size".to_string(),
+            ));
+        }
+        
+        let context = WnafContext::new(windows.window_size);
+        let mut commitment = G1Element::zero();
+        for (coeff, table) in polynomial.iter().zip(&windows.tables) {
+            if coeff.is_zero() {
+                continue;
+            }
+            commitment += context.mul_with_table(table, coeff).ok_or_else(|| {
+                TwistAndShoutError::Commitment("Window table is smaller than the window size".to_string())
+            })?;
+        }
+        
+        Ok(KZGCommitmentValue { commitment })
+    }
+    
+    /// Commit to the difference between two evaluation vectors over the points 0..n-1.
+    ///
+    /// Returns the commitment to the interpolated `new - old` together with its
+    /// coefficients, so that `commit(old) + delta = commit(new)` and the delta can
+    /// be opened without recommitting the whole vector.
+    pub fn commit_delta(
+        params: &CommitmentParams,
+        old_evaluations: &[FieldElement],
+        new_evaluations: &[FieldElement],
+    ) -> Result<(KZGCommitmentValue, Vec<FieldElement>)> {
+        if old_evaluations.len() != new_evaluations.len() {
+            return Err(TwistAndShoutError::Commitment(
+                "Delta requires evaluation vectors of equal length".to_string(),
+            ));
+        }
+        
+        let delta: Vec<FieldElement> = old_evaluations.iter().zip(new_evaluations).map(|(old, new)| *new - old).collect();
+        let delta_poly = crate::polynomials::poly_utils::interpolate_domain(&delta);
+        
+        Ok((Self::commit(params, &delta_poly)?, delta_poly))
+    }
+    
+    /// Commit to the multilinear extension of `evaluations` as one MSM over
+    /// the Lagrange basis [eq(τ', x)]₁ of the hypercube, skipping interpolation.
+    ///
+    /// Parameters come from [`MultilinearKZG::derive_params`], whose secrets
+    /// are independent of the univariate SRS, so the commitment is not a
+    /// univariate KZG commitment and only opens at multilinear points.
+    pub fn commit_mle(params: &MultilinearKZGParams, evaluations: &[FieldElement]) -> Result<KZGCommitmentValue> {
+        if evaluations.len() != 1 << params.num_vars {
+            return Err(TwistAndShoutError::Commitment(
+                "Evaluation count does not match setup".to_string(),
+            ));
+        }
+        
+        Ok(KZGCommitmentValue {
+            commitment: MultilinearKZG::msm(&params.eq_bases[0], evaluations),
+        })
+    }
+    
+    /// Open the commitment from [`KZGCommitment::commit_mle`] at a multilinear
+    /// evaluation point, returning f̃(point) and the proof
+    pub fn open_mle(
+        params: &MultilinearKZGParams,
+        evaluations: &[FieldElement],
+        point: &[FieldElement],
+    ) -> Result<(FieldElement, MultilinearKZGProof)> {
+        if evaluations.len() != 1 << params.num_vars {
+            return Err(TwistAndShoutError::Commitment(
+                "Evaluation count does not match setup".to_string(),
+            ));
+        }
+        
+        let mle = MultilinearExtension::from_evaluations_vec(params.num_vars, evaluations.to_vec());
+        MultilinearKZG::open(params, &mle, point)
+    }
+    
+    /// Verify an opening produced by [`KZGCommitment::open_mle`]
+    pub fn verify_mle(
+        vk: &MultilinearKZGVerifierKey,
+        commitment: &KZGCommitmentValue,
+        point: &[FieldElement],
+        value: FieldElement,
+        proof: &MultilinearKZGProof,
+    ) -> Result<bool> {
+        MultilinearKZG::verify(vk, commitment, point, value, proof)
+    }
+    
+    /// Commit to `polynomial` plus a random multiple r of the top SRS power,
+    /// `C = commit(p) + r·[τ^D]₁` with D the largest supported degree.
+    ///
+    /// Equal polynomials get unrelated commitments. The polynomial must have
+    /// degree below D. Returns the commitment and the blind r, which
+    /// [`KZGCommitment::open_hiding`] needs.
+    pub fn commit_hiding<R: ark_std::rand::RngCore>(
+        params: &CommitmentParams,
+        polynomial: &[FieldElement],
+        rng: &mut R,
+    ) -> Result<(KZGCommitmentValue, FieldElement)> {
+        let blinding = FieldElement::rand(rng);
+        let blinded = Self::blinded_polynomial(params, polynomial, blinding)?;
+        Ok((Self::commit(params, &blinded)?, blinding))
+    }
+    
+    /// Open a commitment from [`KZGCommitment::commit_hiding`] at `point`,
+    /// returning p(point) and an opening that carries the blind.
+    ///
+    /// Revealing the blind lets anyone strip it from the commitment, so each
+    /// hiding commitment should be opened at most once.
+    pub fn open_hiding(
+        params: &CommitmentParams,
+        polynomial: &[FieldElement],
+        blinding: FieldElement,
+        point: FieldElement,
+    ) -> Result<(FieldElement, KZGHidingOpening)> {
+        let blinded = Self::blinded_polynomial(params, polynomial, blinding)?;
+        let (_, proof) = Self::open(params, &blinded, point)?;
+        let value = crate::utils::field_utils::horner_eval(polynomial, point);
+        let blinding_degree = blinded.len() - 1;
+        Ok((value, KZGHidingOpening { proof, blinding, blinding_degree }))
+    }
+    
+    /// Verify an opening from [`KZGCommitment::open_hiding`]: the blinded
+    /// polynomial must evaluate to `value + r·point^D`, with D the opening's
+    /// `blinding_degree`, which the key must support
+    pub fn verify_hiding(
+        vk: &CommitmentVerificationKey,
+        commitment: &KZGCommitmentValue,
+        point: FieldElement,
+        value: FieldElement,
+        opening: &KZGHidingOpening,
+    ) -> Result<bool> {
+        if !vk.supports_degree(opening.blinding_degree) {
+            return Ok(false);
+        }
+        let blinded_value = value + opening.blinding * point.pow([opening.blinding_degree as u64]);
+        Self::verify(vk, commitment, point, blinded_value, &opening.proof)
+    }
+    
+    /// Collapse openings of any polynomials at any points into a single
+    /// opening at zero that [`CommitmentScheme::verify`] checks with one pairing.
+    ///
+    /// Each opening is absorbed into `transcript` before its weight γᵢ is
+    /// drawn, exactly as in [`BatchVerifier::add_opening`]. Rearranging
+    /// e(Cᵢ - vᵢ·g₁, g₂) = e(πᵢ, [τ - zᵢ]₂) to e(Cᵢ + zᵢ·πᵢ - vᵢ·g₁, g₂) = e(πᵢ, [τ]₂)
+    /// shows that Σ γᵢ(Cᵢ + zᵢ·πᵢ) opens to Σ γᵢvᵢ at zero with proof Σ γᵢπᵢ.
+    /// The combination needs no verifier key; the caller still verifies the result.
+    pub fn combine_openings(
+        items: &[KZGOpening],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<KZGOpening> {
+        if items.is_empty() {
+            return Err(TwistAndShoutError::Commitment(
+                "Combining openings needs at least one opening".to_string(),
+            ));
+        }
+        
+        let mut commitment = G1Element::zero();
+        let mut value = FieldElement::zero();
+        let mut proof = G1Element::zero();
+        for (item_commitment, point, item_value, item_proof) in items {
+            let gamma = opening_weight(transcript, item_commitment, *point, *item_value, item_proof);
+            commitment += (item_commitment.commitment + item_proof.proof * point) * gamma;
+            value += gamma * item_value;
+            proof += item_proof.proof * gamma;
+        }
+        
+        Ok((
+            KZGCommitmentValue { commitment },
+            FieldElement::zero(),
+            value,
+            KZGProof { proof },
+        ))
+    }
+    
+    /// Open `polynomial` at several distinct points with one constant-size proof.
+    ///
+    /// With I the interpolant of the opened values and Z(X) = ∏(X - zᵢ), the
+    /// proof commits to q = (p - I)/Z, then to the quotient of
+    /// L(X) = p(X) - I(ζ) - Z(ζ)·q(X) by (X - ζ) for a Fiat-Shamir point ζ,
+    /// so verification needs a single pairing check against `[τ]₂`.
+    pub fn open_batch(
+        params: &CommitmentParams,
+        polynomial: &[FieldElement],
+        points: &[FieldElement],
+    ) -> Result<(Vec<FieldElement>, KZGBatchProof)> {
+        if points.is_empty() {
+            return Err(TwistAndShoutError::Commitment(
+                "Batch opening needs at least one point".to_string(),
+            ));
+        }
+        
+        let values: Vec<FieldElement> = points.iter().map(|&z| evaluate_polynomial(polynomial, z)).collect();
+        let interpolant = Self::batch_interpolant(points, &values)?;
+        let vanishing = points.iter().fold(vec![FieldElement::one()], |acc, &z| {
+            let mut next = vec![FieldElement::zero(); acc.len() + 1];
+            for (k, &c) in acc.iter().enumerate() {
+                next[k + 1] += c;
+                next[k] -= c * z;
+            }
+            next
+        });
+        
+        let mut numerator = polynomial.to_vec();
+        numerator.resize(numerator.len().max(interpolant.len()), FieldElement::zero());
+        for (coeff, &i) in numerator.iter_mut().zip(&interpolant) {
+            *coeff -= i;
+        }
+        let quotient = polynomial_division(&numerator, &vanishing)?;
+        let quotient_commitment = Self::commit(params, &quotient)?.commitment;
+        
+        let commitment = Self::commit(params, polynomial)?.commitment;
+        let zeta = Self::batch_point(&commitment, points, &values, &quotient_commitment);
+        
+        // L(X) = p(X) - I(ζ) - Z(ζ)·q(X) vanishes at ζ
+        let vanishing_at_zeta = evaluate_polynomial(&vanishing, zeta);
+        let mut linearized = polynomial.to_vec();
+        linearized.resize(linearized.len().max(quotient.len()).max(1), FieldElement::zero());
+        linearized[0] -= evaluate_polynomial(&interpolant, zeta);
+        for (coeff, &q) in linearized.iter_mut().zip(&quotient) {
+            *coeff -= vanishing_at_zeta * q;
+        }
+        let (_, shifted) = Self::open(params, &linearized, zeta)?;
+        
+        Ok((values, KZGBatchProof { quotient: quotient_commitment, shifted: shifted.proof }))
+    }
+    
+    /// Verify a proof from [`KZGCommitment::open_batch`] with one pairing check:
+    /// C - [I(ζ)]₁ - Z(ζ)·W must open to zero at ζ with proof W'
+    pub fn verify_batch(
+        vk: &CommitmentVerificationKey,
+        commitment: &KZGCommitmentValue,
+        points: &[FieldElement],
+        values: &[FieldElement],
+        proof: &KZGBatchProof,
+    ) -> Result<bool> {
+        if points.is_empty() || points.len() != values.len() {
+            return Ok(false);
+        }
+        
+        let interpolant = match Self::batch_interpolant(points, values) {
+            Ok(interpolant) => interpolant,
+            Err(_) => return Ok(false),
+        };
+        let zeta = Self::batch_point(&commitment.commitment, points, values, &proof.quotient);
+        let vanishing_at_zeta: FieldElement = points.iter().map(|&z| zeta - z).product();
+        
+        let linearized = commitment.commitment
+            - vk.g1_generator * evaluate_polynomial(&interpolant, zeta)
+            - proof.quotient * vanishing_at_zeta;
+        Self::verify(
+            vk,
+            &KZGCommitmentValue { commitment: linearized },
+            zeta,
+            FieldElement::zero(),
+            &KZGProof { proof: proof.shifted },
+        )
+    }
+    
+    /// Interpolant of (zᵢ, vᵢ), rejecting repeated points
+    fn batch_interpolant(points: &[FieldElement], values: &[FieldElement]) -> Result<Vec<FieldElement>> {
+        for (i, z) in points.iter().enumerate() {
+            if points[..i].contains(z) {
+                return Err(TwistAndShoutError::Commitment(
+                    "Batch opening points must be distinct".to_string(),
+                ));
+            }
+        }
+        
+        let pairs: Vec<(FieldElement, FieldElement)> = points.iter().copied().zip(values.iter().copied()).collect();
+        Ok(crate::polynomials::poly_utils::lagrange_interpolate(&pairs))
+    }
+    
+    /// Fiat-Shamir point ζ binding the commitment, opening points, values and
+    /// quotient commitment
+    fn batch_point(
+        commitment: &G1Element,
+        points: &[FieldElement],
+        values: &[FieldElement],
+        quotient_commitment: &G1Element,
+    ) -> FieldElement {
+        let mut transcript = Transcript::new(&MULTI_POINT_TRANSCRIPT_SEED);
+        transcript.append_bytes(b"multi_point_commitment", &g1_bytes(commitment));
+        transcript.append_field_elements(b"multi_point_points", points);
+        transcript.append_field_elements(b"multi_point_values", values);
+        transcript.append_bytes(b"multi_point_quotient", &g1_bytes(quotient_commitment));
+        transcript.challenge_field_element(b"multi_point_zeta")
+    }
+    
+    /// p(X) + r·X^D, where D = |g1_powers| - 1 is the top SRS power
+    fn blinded_polynomial(
+        params: &CommitmentParams,
+        polynomial: &[FieldElement],
+        blinding: FieldElement,
+    ) -> Result<Vec<FieldElement>> {
+        let top_degree = params.g1_powers.len().saturating_sub(1);
+        if polynomial.len() > top_degree {
+            return Err(TwistAndShoutError::Commitment(
+                "Hiding commitments need degree below the top SRS power".to_string(),
+            ));
+        }
+        
+        let mut blinded = polynomial.to_vec();
+        blinded.resize(top_degree + 1, FieldElement::zero());
+        blinded[top_degree] = blinding;
+        Ok(blinded)
+    }
+}
+
 impl CommitmentScheme for KZGCommitment {
+    type Scalar = FieldElement;
     type Commitment = KZGCommitmentValue;
     type Proof = KZGProof;
     type Params = CommitmentParams;
     type VerifyKey = CommitmentVerificationKey;
     
+    fn prover_key(params: &ProverParams) -> Cow<'_, Self::Params> {
+        Cow::Borrowed(&params.commitment_params)
+    }
+    
+    fn verifier_key(params: &VerifierParams) -> Cow<'_, Self::VerifyKey> {
+        Cow::Borrowed(&params.commitment_vk)
+    }
+    
+    fn transcript_hash(commitment: &Self::Commitment) -> FieldElement {
+        commitment.hash()
+    }
+    
+    fn supports_degree(vk: &Self::VerifyKey, degree: usize) -> bool {
+        vk.supports_degree(degree)
+    }
+    
     fn commit(
         params: &Self::Params,
         polynomial: &[FieldElement],
@@ -170,11 +610,7 @@ impl CommitmentScheme for KZGCommitment {
         }
         
         // Compute commitment: C = Σᵢ cᵢ * [τⁱ]₁ where cᵢ are coefficients
-        let commitment = polynomial
-            .iter()
-            .zip(params.g1_powers.iter())
-            .map(|(&coeff, &generator)| generator * coeff)
-            .sum::<G1Element>();
+        let commitment = g1_msm(&params.g1_powers, polynomial);
         
         Ok(KZGCommitmentValue { commitment })
     }
@@ -184,6 +620,18 @@ impl CommitmentScheme for KZGCommitment {
         polynomial: &[FieldElement],
         point: FieldElement,
     ) -> Result<(FieldElement, Self::Proof)> {
+        // Fast path at z = 0: P(0) is the constant term and (P(x) - P(0)) / x
+        // just drops it and shifts the remaining coefficients down
+        if point.is_zero() {
+            let value = polynomial.first().copied().unwrap_or_else(FieldElement::zero);
+            let quotient = polynomial.get(1..).unwrap_or(&[]);
+            let proof_commitment = Self::commit(params, quotient)?;
+            
+            return Ok((value, KZGProof {
+                proof: proof_commitment.commitment,
+            }));
+        }
+        
         // Evaluate polynomial at the point
         let value = evaluate_polynomial(polynomial, point);
         
@@ -246,61 +694,327 @@ impl CommitmentScheme for KZGCommitment {
             return Ok(true);
         }
         
-        // Use random linear combination for batch verification
-        use ark_std::rand::SeedableRng;
-        let mut rng = rand_chacha::ChaCha20Rng::from_seed([42u8; 32]);
-        use ark_ff::UniformRand;
-        let challenges: Vec<FieldElement> = (0..commitments.len())
-            .map(|_| FieldElement::rand(&mut rng))
-            .collect();
-        
-        // Compute batched commitment: Σᵢ γᵢ * Cᵢ
-        let batched_commitment = commitments
-            .iter()
-            .zip(challenges.iter())
-            .map(|(c, &gamma)| c.commitment * gamma)
-            .sum::<G1Element>();
-        
-        // Compute batched value: Σᵢ γᵢ * vᵢ
-        let batched_value = values
-            .iter()
-            .zip(challenges.iter())
-            .map(|(&v, &gamma)| v * gamma)
-            .sum::<FieldElement>();
-        
-        // Compute batched proof: Σᵢ γᵢ * πᵢ
-        let batched_proof = proofs
-            .iter()
-            .zip(challenges.iter())
-            .map(|(p, &gamma)| p.proof * gamma)
-            .sum::<G1Element>();
+        // Bind the weights to the whole batch before any of them is drawn
+        let mut transcript = Transcript::new(&BATCH_TRANSCRIPT_SEED);
+        for commitment in commitments {
+            transcript.append_bytes(b"batch_commitment", &g1_bytes(&commitment.commitment));
+        }
+        transcript.append_field_elements(b"batch_points", points);
+        transcript.append_field_elements(b"batch_values", values);
         
-        // We need to verify: e(batched_commitment - [batched_value]₁, [1]₂) = 
-        //                   e(batched_proof, Σᵢ γᵢ * ([τ]₂ - [zᵢ]₂))
+        let mut batch = BatchVerifier::with_transcript(vk, transcript);
+        for i in 0..commitments.len() {
+            batch.add_opening(&commitments[i], points[i], values[i], &proofs[i]);
+        }
+        Ok(batch.finalize())
+    }
+}
+
+/// Seed of the transcript that derives batch verification weights
+const BATCH_TRANSCRIPT_SEED: [u8; 32] = *b"twist-and-shout/kzg-batch-verify";
+
+/// Seed of the transcript that derives the multi-point opening challenge ζ
+const MULTI_POINT_TRANSCRIPT_SEED: [u8; 32] = *b"twist-and-shout/kzg-multi-points";
+
+/// Σᵢ scalarsᵢ · basesᵢ by variable-base multi-scalar multiplication over the
+/// first min(|bases|, |scalars|) pairs
+fn g1_msm(bases: &[G1Element], scalars: &[FieldElement]) -> G1Element {
+    let len = bases.len().min(scalars.len());
+    let affine_bases = G1Element::normalize_batch(&bases[..len]);
+    G1Element::msm(&affine_bases, &scalars[..len])
+        .expect("Bases and scalars have equal length")
+}
+
+/// Compressed encoding of a G1 element for transcripts
+fn g1_bytes(point: &G1Element) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("G1 serialization should not fail");
+    bytes
+}
+
+/// Pedersen commitments Σᵢ cᵢ·Gᵢ (+ r·H when hiding) to polynomial coefficients.
+///
+/// The generators are hashed onto the curve from the Fiat-Shamir seed, so no
+/// trusted setup is needed. Openings reveal the coefficients and blinding, so
+/// proofs are linear in the polynomial size; use this for testing or where
+/// constant-size openings are not required.
+#[derive(Debug, Clone)]
+pub struct PedersenCommitment;
+
+/// Generators for [`PedersenCommitment`], shared by prover and verifier
+#[derive(Debug, Clone, PartialEq)]
+pub struct PedersenParams {
+    /// One generator per coefficient
+    pub generators: Vec<G1Element>,
+    
+    /// Generator H multiplying the blinding factor
+    pub blinding_generator: G1Element,
+}
+
+/// Pedersen commitment (a point in G1)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PedersenCommitmentValue {
+    pub commitment: G1Element,
+}
+
+/// Pedersen opening: the committed coefficients and blinding factor
+#[derive(Debug, Clone, PartialEq)]
+pub struct PedersenOpening {
+    pub coefficients: Vec<FieldElement>,
+    pub blinding: FieldElement,
+}
+
+impl PedersenParams {
+    /// Derive `size` generators and the blinding generator from `seed`.
+    ///
+    /// Each generator is found by try-and-increment on x-coordinates drawn from
+    /// a seeded stream, so nobody knows discrete logs between them. BN254 G1 has
+    /// cofactor 1, so every curve point found is in the prime-order group.
+    pub fn new(size: usize, seed: &[u8; 32]) -> Self {
+        let mut transcript = Transcript::new(seed);
+        transcript.append_bytes(b"pedersen_generators", &(size as u64).to_le_bytes());
         
-        let value_in_g1 = vk.g1_generator * batched_value;
-        let left_g1 = batched_commitment - value_in_g1;
+        let mut hash_to_curve = || loop {
+            let bytes: Vec<u8> = transcript
+                .challenge_field_element(b"pedersen_candidate")
+                .into_bigint()
+                .to_bytes_le();
+            let x = ark_bn254::Fq::from_le_bytes_mod_order(&bytes);
+            if let Some(point) = G1Affine::get_point_from_x_unchecked(x, false) {
+                return G1Element::from(point);
+            }
+        };
         
-        // Compute batched G2 element: Σᵢ γᵢ * ([τ]₂ - [zᵢ]₂)
-        let batched_g2 = points
-            .iter()
-            .zip(challenges.iter())
-            .map(|(&point, &gamma)| (vk.g2_tau - vk.g2_generator * point) * gamma)
-            .sum::<G2Element>();
+        let generators = (0..size).map(|_| hash_to_curve()).collect();
+        let blinding_generator = hash_to_curve();
+        Self { generators, blinding_generator }
+    }
+}
+
+impl PedersenCommitment {
+    /// Commit with blinding factor `blinding`, hiding the polynomial when it is random
+    pub fn commit_hiding(
+        params: &PedersenParams,
+        polynomial: &[FieldElement],
+        blinding: FieldElement,
+    ) -> Result<PedersenCommitmentValue> {
+        if polynomial.len() > params.generators.len() {
+            return Err(TwistAndShoutError::Commitment(
+                "Polynomial degree exceeds number of generators".to_string(),
+            ));
+        }
         
-        // Check pairing equation
-        let left_g1_affine = left_g1.into_affine();
-        let g2_gen_affine = vk.g2_generator.into_affine();
-        let batched_proof_affine = batched_proof.into_affine();
-        let batched_g2_affine = batched_g2.into_affine();
+        let commitment = g1_msm(&params.generators, polynomial) + params.blinding_generator * blinding;
+        Ok(PedersenCommitmentValue { commitment })
+    }
+    
+    /// Open a commitment from [`PedersenCommitment::commit_hiding`] at `point`
+    pub fn open_hiding(
+        polynomial: &[FieldElement],
+        blinding: FieldElement,
+        point: FieldElement,
+    ) -> (FieldElement, PedersenOpening) {
+        let value = crate::utils::field_utils::horner_eval(polynomial, point);
+        let opening = PedersenOpening {
+            coefficients: polynomial.to_vec(),
+            blinding,
+        };
+        (value, opening)
+    }
+}
+
+impl CommitmentScheme for PedersenCommitment {
+    type Scalar = FieldElement;
+    type Commitment = PedersenCommitmentValue;
+    type Proof = PedersenOpening;
+    type Params = PedersenParams;
+    type VerifyKey = PedersenParams;
+    
+    /// Generators are rederived on every call; cache the key when committing often
+    fn prover_key(params: &ProverParams) -> Cow<'_, Self::Params> {
+        Cow::Owned(PedersenParams::new(params.commitment_params.g1_powers.len(), &params.fiat_shamir_seed))
+    }
+    
+    fn verifier_key(params: &VerifierParams) -> Cow<'_, Self::VerifyKey> {
+        Cow::Owned(PedersenParams::new(params.commitment_vk.max_degree + 1, &params.fiat_shamir_seed))
+    }
+    
+    fn transcript_hash(commitment: &Self::Commitment) -> FieldElement {
+        g1_transcript_hash(&commitment.commitment)
+    }
+    
+    fn supports_degree(vk: &Self::VerifyKey, degree: usize) -> bool {
+        degree < vk.generators.len()
+    }
+    
+    fn commit(
+        params: &Self::Params,
+        polynomial: &[FieldElement],
+    ) -> Result<Self::Commitment> {
+        Self::commit_hiding(params, polynomial, FieldElement::zero())
+    }
+    
+    fn open(
+        _params: &Self::Params,
+        polynomial: &[FieldElement],
+        point: FieldElement,
+    ) -> Result<(FieldElement, Self::Proof)> {
+        Ok(Self::open_hiding(polynomial, FieldElement::zero(), point))
+    }
+    
+    fn verify(
+        vk: &Self::VerifyKey,
+        commitment: &Self::Commitment,
+        point: FieldElement,
+        value: FieldElement,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        if proof.coefficients.len() > vk.generators.len() {
+            return Ok(false);
+        }
         
-        let left_pairing = Bn254::pairing(left_g1_affine, g2_gen_affine);
-        let right_pairing = Bn254::pairing(batched_proof_affine, batched_g2_affine);
+        let recomputed = Self::commit_hiding(vk, &proof.coefficients, proof.blinding)?;
+        Ok(recomputed == *commitment
+            && crate::utils::field_utils::horner_eval(&proof.coefficients, point) == value)
+    }
+}
+
+impl ark_serialize::Valid for PedersenCommitmentValue {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        Ok(())
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for PedersenCommitmentValue {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.commitment.serialize_with_mode(writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.commitment.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for PedersenCommitmentValue {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let commitment = G1Element::deserialize_with_mode(reader, compress, validate)?;
+        Ok(Self { commitment })
+    }
+}
+
+impl ark_serialize::Valid for PedersenOpening {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        Ok(())
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for PedersenOpening {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.coefficients.serialize_with_mode(&mut writer, compress)?;
+        self.blinding.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.coefficients.serialized_size(compress) + self.blinding.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for PedersenOpening {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let coefficients = Vec::<FieldElement>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let blinding = FieldElement::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { coefficients, blinding })
+    }
+}
+
+/// Streaming KZG batch verifier using O(1) memory.
+///
+/// Each opening e(Cᵢ - vᵢ·g₁, g₂) = e(πᵢ, [τ - zᵢ]₂) is rearranged to
+/// e(Cᵢ - vᵢ·g₁ + zᵢ·πᵢ, g₂) = e(πᵢ, [τ]₂), so a random linear combination of
+/// all openings only needs two running G1 accumulators and one final pairing check.
+/// Each weight is a transcript challenge drawn after absorbing its opening, so
+/// a prover cannot pick openings whose errors cancel.
+#[derive(Debug, Clone)]
+pub struct BatchVerifier {
+    vk: CommitmentVerificationKey,
+    transcript: Transcript,
+    /// Σᵢ γᵢ (Cᵢ - vᵢ·g₁ + zᵢ·πᵢ)
+    combined_lhs: G1Element,
+    /// Σᵢ γᵢ πᵢ
+    combined_proof: G1Element,
+}
+
+impl BatchVerifier {
+    /// Start an empty batch
+    pub fn new(vk: &CommitmentVerificationKey) -> Self {
+        Self::with_transcript(vk, Transcript::new(&BATCH_TRANSCRIPT_SEED))
+    }
+    
+    /// Start an empty batch whose weights continue from `transcript`
+    pub fn with_transcript(vk: &CommitmentVerificationKey, transcript: Transcript) -> Self {
+        Self {
+            vk: vk.clone(),
+            transcript,
+            combined_lhs: G1Element::zero(),
+            combined_proof: G1Element::zero(),
+        }
+    }
+    
+    /// Fold one opening into the running accumulators
+    pub fn add_opening(
+        &mut self,
+        commitment: &KZGCommitmentValue,
+        point: FieldElement,
+        value: FieldElement,
+        proof: &KZGProof,
+    ) {
+        let gamma = opening_weight(&mut self.transcript, commitment, point, value, proof);
         
-        Ok(left_pairing == right_pairing)
+        let lhs = commitment.commitment - self.vk.g1_generator * value + proof.proof * point;
+        self.combined_lhs += lhs * gamma;
+        self.combined_proof += proof.proof * gamma;
+    }
+    
+    /// Check every absorbed opening with a single pairing equation
+    pub fn finalize(self) -> bool {
+        let left = Bn254::pairing(self.combined_lhs.into_affine(), self.vk.g2_generator.into_affine());
+        let right = Bn254::pairing(self.combined_proof.into_affine(), self.vk.g2_tau.into_affine());
+        left == right
     }
 }
 
+/// Absorb one opening into `transcript` and draw its random-linear-combination weight
+fn opening_weight(
+    transcript: &mut impl FiatShamirTranscript,
+    commitment: &KZGCommitmentValue,
+    point: FieldElement,
+    value: FieldElement,
+    proof: &KZGProof,
+) -> FieldElement {
+    transcript.append_bytes(b"batch_opening_commitment", &g1_bytes(&commitment.commitment));
+    transcript.append_bytes(b"batch_opening_proof", &g1_bytes(&proof.proof));
+    transcript.append_field_elements(b"batch_opening", &[point, value]);
+    transcript.challenge_field_element(b"batch_weight")
+}
+
 /// Evaluate a polynomial at a given point using Horner's method
 fn evaluate_polynomial(coeffs: &[FieldElement], point: FieldElement) -> FieldElement {
     if coeffs.is_empty() {
@@ -335,7 +1049,7 @@ fn compute_quotient_polynomial(
 
 /// Divide polynomial p(x) by q(x), returning the quotient
 /// Assumes q(x) divides p(x) exactly
-fn polynomial_division(dividend: &[FieldElement], divisor: &[FieldElement]) -> Result<Vec<FieldElement>> {
+pub(crate) fn polynomial_division(dividend: &[FieldElement], divisor: &[FieldElement]) -> Result<Vec<FieldElement>> {
     if divisor.is_empty() || divisor.iter().all(|&x| x.is_zero()) {
         return Err(TwistAndShoutError::Polynomial("Cannot divide by zero polynomial".to_string()));
     }
@@ -482,6 +1196,334 @@ impl VectorCommitmentScheme for KZGVectorCommitment {
     }
 }
 
+/// Multilinear KZG (PST) commitments: multilinear polynomials are committed by
+/// their evaluations over the Boolean hypercube and opened at points in F^n
+#[derive(Debug, Clone)]
+pub struct MultilinearKZG;
+
+/// Prover parameters for [`MultilinearKZG`]
+#[derive(Debug, Clone)]
+pub struct MultilinearKZGParams {
+    /// Number of variables supported
+    pub num_vars: usize,
+    
+    /// `eq_bases[k][x] = [eq((τ_k, ..., τ_{n-1}), x)]₁`, the Lagrange basis over
+    /// the last `n - k` variables
+    pub eq_bases: Vec<Vec<G1Element>>,
+}
+
+/// Verification key for [`MultilinearKZG`]; keys derived from a
+/// [`CommitmentVerificationKey`] borrow its G2 powers instead of copying them
+#[derive(Debug, Clone)]
+pub struct MultilinearKZGVerifierKey<'a> {
+    /// Number of variables supported
+    pub num_vars: usize,
+    
+    /// G1 generator
+    pub g1_generator: G1Element,
+    
+    /// G2 generator
+    pub g2_generator: G2Element,
+    
+    /// [τ_j]₂ for each variable
+    pub g2_taus: Cow<'a, [G2Element]>,
+}
+
+/// Multilinear KZG opening proof: one quotient commitment per variable
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultilinearKZGProof {
+    pub quotients: Vec<G1Element>,
+}
+
+/// Opening of several multilinear extensions at one shared point, proved by a
+/// single opening of their random linear combination
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchedMleProof {
+    /// Opening of Σᵢ ρⁱ fᵢ at the shared point
+    pub combined: MultilinearKZGProof,
+}
+
+impl MultilinearKZG {
+    /// Generate parameters for `num_vars`-variate polynomials with fresh secrets τ
+    pub fn setup<R: ark_std::rand::RngCore>(
+        num_vars: usize,
+        rng: &mut R,
+    ) -> (MultilinearKZGParams, MultilinearKZGVerifierKey<'static>) {
+        let taus: Vec<FieldElement> = (0..num_vars).map(|_| FieldElement::rand(rng)).collect();
+        let g1_generator = G1Element::generator();
+        let g2_generator = G2Element::generator();
+        
+        let eq_bases = (0..=num_vars)
+            .map(|k| {
+                crate::polynomials::poly_utils::eq_table(&taus[k..])
+                    .into_iter()
+                    .map(|eq| g1_generator * eq)
+                    .collect()
+            })
+            .collect();
+        
+        let params = MultilinearKZGParams { num_vars, eq_bases };
+        let vk = MultilinearKZGVerifierKey {
+            num_vars,
+            g1_generator,
+            g2_generator,
+            g2_taus: Cow::Owned(taus.iter().map(|&tau| g2_generator * tau).collect()),
+        };
+        
+        (params, vk)
+    }
+    
+    /// Commit to a multilinear extension: C = Σₓ f(x) [eq(τ, x)]₁
+    pub fn commit(params: &MultilinearKZGParams, mle: &MultilinearExtension) -> Result<KZGCommitmentValue> {
+        if mle.num_vars != params.num_vars {
+            return Err(TwistAndShoutError::Commitment(
+                "Polynomial variable count does not match setup".to_string(),
+            ));
+        }
+        
+        Ok(KZGCommitmentValue {
+            commitment: Self::msm(&params.eq_bases[0], &mle.evaluations),
+        })
+    }
+    
+    /// Derive multilinear parameters for `num_vars` variables from the
+    /// multilinear basis of a setup.
+    ///
+    /// The basis `[eq(τ', x)]₁` covers more variables than needed; since
+    /// Σ_z eq(τ'_high, z) = 1, summing out the high variables leaves the basis
+    /// over the first `num_vars` secrets, and summing out the lowest variable
+    /// of each level gives the next. The secrets τ' are independent of the
+    /// univariate τ: tying them to it, e.g. τⱼ = τ^(2^j), lets a prover open
+    /// a commitment to a value other than the polynomial's.
+    pub fn derive_params(params: &CommitmentParams, num_vars: usize) -> Result<MultilinearKZGParams> {
+        if num_vars > params.mle_vars() {
+            return Err(TwistAndShoutError::Commitment(
+                "SRS is too small for the requested number of variables".to_string(),
+            ));
+        }
+        
+        let mut eq_bases = Vec::with_capacity(num_vars + 1);
+        eq_bases.push(Self::restrict_basis(&params.mle_basis, num_vars));
+        for k in 0..num_vars {
+            let next = eq_bases[k].chunks(2).map(|pair| pair[0] + pair[1]).collect();
+            eq_bases.push(next);
+        }
+        
+        Ok(MultilinearKZGParams { num_vars, eq_bases })
+    }
+    
+    /// Verification key matching [`MultilinearKZG::derive_params`]
+    pub fn derive_verifier_key(vk: &CommitmentVerificationKey, num_vars: usize) -> Result<MultilinearKZGVerifierKey<'_>> {
+        if vk.g2_mle_taus.len() < num_vars {
+            return Err(TwistAndShoutError::Commitment(
+                "Verification key is too small for the requested number of variables".to_string(),
+            ));
+        }
+        
+        Ok(MultilinearKZGVerifierKey {
+            num_vars,
+            g1_generator: vk.g1_generator,
+            g2_generator: vk.g2_generator,
+            g2_taus: Cow::Borrowed(&vk.g2_mle_taus[..num_vars]),
+        })
+    }
+    
+    /// Sum the basis `[eq(τ', x)]₁` over its variables beyond the first
+    /// `num_vars`: entry x becomes Σ_z basis[x + z·2^num_vars]
+    pub(crate) fn restrict_basis(basis: &[G1Element], num_vars: usize) -> Vec<G1Element> {
+        let mut restricted = basis[..1 << num_vars].to_vec();
+        for chunk in basis.chunks(1 << num_vars).skip(1) {
+            for (sum, &base) in restricted.iter_mut().zip(chunk) {
+                *sum += base;
+            }
+        }
+        restricted
+    }
+    
+    /// Open a multilinear extension at `point`.
+    ///
+    /// Writes f(X) - f(r) = Σⱼ (Xⱼ - rⱼ) qⱼ(Xⱼ₊₁, ..., Xₙ₋₁) by fixing one
+    /// variable at a time, committing to each quotient qⱼ.
+    pub fn open(
+        params: &MultilinearKZGParams,
+        mle: &MultilinearExtension,
+        point: &[FieldElement],
+    ) -> Result<(FieldElement, MultilinearKZGProof)> {
+        if mle.num_vars != params.num_vars || point.len() != params.num_vars {
+            return Err(TwistAndShoutError::Commitment(
+                "Opening point dimension does not match setup".to_string(),
+            ));
+        }
+        
+        let mut remainder = mle.evaluations.clone();
+        let mut quotients = Vec::with_capacity(point.len());
+        
+        for (j, &r_j) in point.iter().enumerate() {
+            let half = remainder.len() / 2;
+            let mut quotient = Vec::with_capacity(half);
+            let mut next = Vec::with_capacity(half);
+            
+            for k in 0..half {
+                let (f0, f1) = (remainder[2 * k], remainder[2 * k + 1]);
+                quotient.push(f1 - f0);
+                next.push(f0 + r_j * (f1 - f0));
+            }
+            
+            quotients.push(Self::msm(&params.eq_bases[j + 1], &quotient));
+            remainder = next;
+        }
+        
+        Ok((remainder[0], MultilinearKZGProof { quotients }))
+    }
+    
+    /// Verify e(C - v·g₁, g₂) = Σⱼ e(πⱼ, [τⱼ - rⱼ]₂)
+    pub fn verify(
+        vk: &MultilinearKZGVerifierKey,
+        commitment: &KZGCommitmentValue,
+        point: &[FieldElement],
+        value: FieldElement,
+        proof: &MultilinearKZGProof,
+    ) -> Result<bool> {
+        if point.len() != vk.num_vars || proof.quotients.len() != vk.num_vars {
+            return Ok(false);
+        }
+        
+        let left = Bn254::pairing(
+            (commitment.commitment - vk.g1_generator * value).into_affine(),
+            vk.g2_generator.into_affine(),
+        );
+        
+        let quotients: Vec<G1Affine> = proof.quotients.iter().map(|q| q.into_affine()).collect();
+        let shifted_taus: Vec<G2Affine> = vk.g2_taus
+            .iter()
+            .zip(point)
+            .map(|(&g2_tau, &r_j)| (g2_tau - vk.g2_generator * r_j).into_affine())
+            .collect();
+        let right = Bn254::multi_pairing(quotients, shifted_taus);
+        
+        Ok(left == right)
+    }
+    
+    /// Open several multilinear extensions at the same `point` with one proof.
+    ///
+    /// The commitments, point and evaluations are absorbed into `transcript`
+    /// to draw ρ, and the single proof opens Σᵢ ρⁱ fᵢ at `point`. Returns each
+    /// polynomial's evaluation in input order.
+    pub fn open_batch(
+        params: &MultilinearKZGParams,
+        mles: &[&MultilinearExtension],
+        point: &[FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(Vec<FieldElement>, BatchedMleProof)> {
+        if mles.is_empty() {
+            return Err(TwistAndShoutError::Commitment(
+                "Batch opening needs at least one polynomial".to_string(),
+            ));
+        }
+        if point.len() != params.num_vars {
+            return Err(TwistAndShoutError::Commitment(
+                "Opening point dimension does not match setup".to_string(),
+            ));
+        }
+        
+        let commitments = mles
+            .iter()
+            .map(|mle| Self::commit(params, mle))
+            .collect::<Result<Vec<_>>>()?;
+        let columns: Vec<&[FieldElement]> = mles.iter().map(|mle| &mle.evaluations[..]).collect();
+        Self::open_batch_committed(params, &columns, &commitments, point, transcript)
+    }
+    
+    /// [`Self::open_batch`] for columns already committed to, given by their
+    /// values over the hypercube, without committing to them again
+    pub fn open_batch_committed(
+        params: &MultilinearKZGParams,
+        columns: &[&[FieldElement]],
+        commitments: &[KZGCommitmentValue],
+        point: &[FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(Vec<FieldElement>, BatchedMleProof)> {
+        if columns.is_empty() || columns.len() != commitments.len() {
+            return Err(TwistAndShoutError::Commitment(
+                "Batch opening needs one commitment per polynomial".to_string(),
+            ));
+        }
+        if point.len() != params.num_vars || columns.iter().any(|column| column.len() != 1 << params.num_vars) {
+            return Err(TwistAndShoutError::Commitment(
+                "Opening point dimension does not match setup".to_string(),
+            ));
+        }
+        
+        let eq = crate::polynomials::poly_utils::eq_table(point);
+        let values: Vec<FieldElement> = columns
+            .iter()
+            .map(|column| column.iter().zip(&eq).map(|(&value, &weight)| value * weight).sum())
+            .collect();
+        let rho = Self::batch_challenge(transcript, commitments, point, &values);
+        
+        let mut combined = vec![FieldElement::zero(); 1 << params.num_vars];
+        let mut power = FieldElement::one();
+        for column in columns {
+            for (sum, &evaluation) in combined.iter_mut().zip(column.iter()) {
+                *sum += power * evaluation;
+            }
+            power *= rho;
+        }
+        
+        let combined = MultilinearExtension::from_evaluations_vec(params.num_vars, combined);
+        let (_, proof) = Self::open(params, &combined, point)?;
+        Ok((values, BatchedMleProof { combined: proof }))
+    }
+    
+    /// Verify a [`BatchedMleProof`] that `commitments[i]` opens to `values[i]`
+    /// at `point`, replaying the transcript of [`Self::open_batch`]
+    pub fn verify_batch(
+        vk: &MultilinearKZGVerifierKey,
+        commitments: &[KZGCommitmentValue],
+        point: &[FieldElement],
+        values: &[FieldElement],
+        proof: &BatchedMleProof,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<bool> {
+        if commitments.is_empty() || commitments.len() != values.len() {
+            return Ok(false);
+        }
+        
+        let rho = Self::batch_challenge(transcript, commitments, point, values);
+        
+        let mut commitment = G1Element::zero();
+        let mut value = FieldElement::zero();
+        let mut power = FieldElement::one();
+        for (c, &v) in commitments.iter().zip(values) {
+            commitment += c.commitment * power;
+            value += power * v;
+            power *= rho;
+        }
+        
+        Self::verify(vk, &KZGCommitmentValue { commitment }, point, value, &proof.combined)
+    }
+    
+    /// Combination challenge ρ binding the commitments, point and claimed values
+    fn batch_challenge(
+        transcript: &mut impl FiatShamirTranscript,
+        commitments: &[KZGCommitmentValue],
+        point: &[FieldElement],
+        values: &[FieldElement],
+    ) -> FieldElement {
+        for commitment in commitments {
+            transcript.append_field_element(b"mle_batch_commitment", &g1_transcript_hash(&commitment.commitment));
+        }
+        transcript.append_field_elements(b"mle_batch_point", point);
+        transcript.append_field_elements(b"mle_batch_values", values);
+        transcript.challenge_field_element(b"mle_batch_rho")
+    }
+    
+    /// Σᵢ scalarsᵢ · basesᵢ
+    fn msm(bases: &[G1Element], scalars: &[FieldElement]) -> G1Element {
+        g1_msm(bases, scalars)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -532,6 +1574,31 @@ mod tests {
         assert!(!is_invalid);
     }
     
+    #[test]
+    fn test_kzg_commitment_homomorphism() {
+        let (prover_params, _) = setup_params(3);
+        let params = &prover_params.commitment_params;
+        
+        let f: Vec<FieldElement> = [1u64, 2, 3].iter().map(|&c| FieldElement::from(c)).collect();
+        let g: Vec<FieldElement> = [4u64, 0, 5, 6].iter().map(|&c| FieldElement::from(c)).collect();
+        let combined: Vec<FieldElement> = (0..g.len())
+            .map(|i| {
+                let fi = f.get(i).copied().unwrap_or_else(FieldElement::zero);
+                FieldElement::from(3u64) * fi + FieldElement::from(2u64) * g[i]
+            })
+            .collect();
+        let difference: Vec<FieldElement> = (0..g.len())
+            .map(|i| f.get(i).copied().unwrap_or_else(FieldElement::zero) - g[i])
+            .collect();
+        
+        let commit_f = KZGCommitment::commit(params, &f).unwrap();
+        let commit_g = KZGCommitment::commit(params, &g).unwrap();
+        
+        let homomorphic = commit_f.scale(FieldElement::from(3u64)) + commit_g.scale(FieldElement::from(2u64));
+        assert_eq!(homomorphic, KZGCommitment::commit(params, &combined).unwrap());
+        assert_eq!(commit_f - commit_g, KZGCommitment::commit(params, &difference).unwrap());
+    }
+    
     #[test]
     fn test_kzg_vector_commitment() {
         let (prover_params, verifier_params) = setup_params(4);
@@ -585,4 +1652,416 @@ mod tests {
             FieldElement::one(),   // x term: 1
         ]);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_kzg_open_at_zero_fast_path() {
+        let (prover_params, verifier_params) = setup_params(4);
+        let params = &prover_params.commitment_params;
+        
+        let polynomials = vec![
+            vec![FieldElement::from(7u64)],
+            vec![FieldElement::from(1u64), FieldElement::from(2u64), FieldElement::from(3u64)],
+            (1..=9u64).map(FieldElement::from).collect::<Vec<_>>(),
+        ];
+        
+        for poly in &polynomials {
+            let (value, fast_proof) = KZGCommitment::open(params, poly, FieldElement::zero()).unwrap();
+            assert_eq!(value, poly[0]);
+            
+            // General division path
+            let quotient = compute_quotient_polynomial(poly, FieldElement::zero(), value).unwrap();
+            let general_proof = KZGCommitment::commit(params, &quotient).unwrap();
+            assert_eq!(fast_proof.proof, general_proof.commitment);
+            
+            let commitment = KZGCommitment::commit(params, poly).unwrap();
+            assert!(KZGCommitment::verify(
+                &verifier_params.commitment_vk,
+                &commitment,
+                FieldElement::zero(),
+                value,
+                &fast_proof,
+            ).unwrap());
+        }
+    }
+    
+    #[test]
+    fn test_multilinear_kzg_open_verify() {
+        let mut rng = ark_std::test_rng();
+        let (params, vk) = MultilinearKZG::setup(3, &mut rng);
+        
+        let mle = MultilinearExtension::from_evaluations(
+            (0..8u64).map(|i| FieldElement::from(i * i + 1)).collect(),
+        );
+        let commitment = MultilinearKZG::commit(&params, &mle).unwrap();
+        
+        let point: Vec<FieldElement> = (0..3).map(|_| FieldElement::rand(&mut rng)).collect();
+        let (value, proof) = MultilinearKZG::open(&params, &mle, &point).unwrap();
+        assert_eq!(value, mle.evaluate(&point));
+        assert!(MultilinearKZG::verify(&vk, &commitment, &point, value, &proof).unwrap());
+        
+        // Wrong value or point is rejected
+        assert!(!MultilinearKZG::verify(&vk, &commitment, &point, value + FieldElement::one(), &proof).unwrap());
+        let mut other_point = point.clone();
+        other_point[1] += FieldElement::one();
+        assert!(!MultilinearKZG::verify(&vk, &commitment, &other_point, value, &proof).unwrap());
+    }
+    
+    #[test]
+    fn test_multilinear_kzg_open_batch() {
+        let mut rng = ark_std::test_rng();
+        let (params, vk) = MultilinearKZG::setup(3, &mut rng);
+        let seed = [5u8; 32];
+        
+        let mles: Vec<MultilinearExtension> = (1..=3u64)
+            .map(|k| MultilinearExtension::from_evaluations((0..8u64).map(|i| FieldElement::from(k * i + k * k)).collect()))
+            .collect();
+        let refs: Vec<&MultilinearExtension> = mles.iter().collect();
+        let commitments: Vec<KZGCommitmentValue> = mles
+            .iter()
+            .map(|mle| MultilinearKZG::commit(&params, mle).unwrap())
+            .collect();
+        let point: Vec<FieldElement> = (0..3).map(|_| FieldElement::rand(&mut rng)).collect();
+        
+        let (values, proof) = MultilinearKZG::open_batch(&params, &refs, &point, &mut Transcript::new(&seed)).unwrap();
+        assert_eq!(values.len(), 3);
+        for (mle, &value) in mles.iter().zip(&values) {
+            assert_eq!(value, mle.evaluate(&point));
+        }
+        assert_eq!(proof.combined.quotients.len(), 3);
+        assert!(MultilinearKZG::verify_batch(&vk, &commitments, &point, &values, &proof, &mut Transcript::new(&seed)).unwrap());
+        
+        // Any wrong claimed evaluation, or a reordered commitment list, is rejected
+        for i in 0..3 {
+            let mut wrong = values.clone();
+            wrong[i] += FieldElement::one();
+            assert!(!MultilinearKZG::verify_batch(&vk, &commitments, &point, &wrong, &proof, &mut Transcript::new(&seed)).unwrap());
+        }
+        let mut swapped = commitments.clone();
+        swapped.swap(0, 2);
+        assert!(!MultilinearKZG::verify_batch(&vk, &swapped, &point, &values, &proof, &mut Transcript::new(&seed)).unwrap());
+        assert!(!MultilinearKZG::verify_batch(&vk, &commitments[..2], &point, &values, &proof, &mut Transcript::new(&seed)).unwrap());
+    }
+    
+    #[test]
+    fn test_commit_mle_opens_at_multilinear_points() {
+        let mut rng = ark_std::test_rng();
+        let (prover_params, verifier_params) = setup_params(2);
+        let params = MultilinearKZG::derive_params(&prover_params.commitment_params, 3).unwrap();
+        let vk = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, 3).unwrap();
+        
+        let evaluations: Vec<FieldElement> = (0..8u64).map(|i| FieldElement::from(i * i + 7)).collect();
+        let commitment = KZGCommitment::commit_mle(&params, &evaluations).unwrap();
+        assert_eq!(commitment, MultilinearKZG::commit(&params, &MultilinearExtension::from_evaluations(evaluations.clone())).unwrap());
+        
+        let point: Vec<FieldElement> = (0..3).map(|_| FieldElement::rand(&mut rng)).collect();
+        let (value, proof) = KZGCommitment::open_mle(&params, &evaluations, &point).unwrap();
+        assert_eq!(value, MultilinearExtension::from_evaluations(evaluations.clone()).evaluate(&point));
+        assert!(KZGCommitment::verify_mle(&vk, &commitment, &point, value, &proof).unwrap());
+        assert!(!KZGCommitment::verify_mle(&vk, &commitment, &point, value + FieldElement::one(), &proof).unwrap());
+        
+        // Every level of the derived parameters agrees with a direct
+        // restriction of the setup's basis
+        let smaller = MultilinearKZG::derive_params(&prover_params.commitment_params, 2).unwrap();
+        assert_eq!(smaller.eq_bases[0], params.eq_bases[0].chunks(4).fold(vec![G1Element::zero(); 4], |mut acc, chunk| {
+            acc.iter_mut().zip(chunk).for_each(|(a, &c)| *a += c);
+            acc
+        }));
+        assert_eq!(smaller.eq_bases[2].len(), 1);
+        assert!(MultilinearKZG::derive_params(&prover_params.commitment_params, 5).is_err());
+    }
+    
+    #[test]
+    fn test_mle_opening_rejects_forged_value() {
+        // With τⱼ = τ^(2^j), the evaluations [1, 2, 3, 4] could be opened to
+        // 21 at (5, 7); the correct value is 20
+        let (prover_params, verifier_params) = setup_params(2);
+        let params = MultilinearKZG::derive_params(&prover_params.commitment_params, 2).unwrap();
+        let vk = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, 2).unwrap();
+        let evaluations: Vec<FieldElement> = (1..=4u64).map(FieldElement::from).collect();
+        let point = [FieldElement::from(5u64), FieldElement::from(7u64)];
+        
+        let commitment = KZGCommitment::commit_mle(&params, &evaluations).unwrap();
+        let (value, proof) = KZGCommitment::open_mle(&params, &evaluations, &point).unwrap();
+        assert_eq!(value, FieldElement::from(20u64));
+        assert!(KZGCommitment::verify_mle(&vk, &commitment, &point, value, &proof).unwrap());
+        assert!(!KZGCommitment::verify_mle(&vk, &commitment, &point, FieldElement::from(21u64), &proof).unwrap());
+    }
+    
+    #[test]
+    fn test_streaming_batch_verifier() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let vk = &verifier_params.commitment_vk;
+        
+        let mut commitments = Vec::new();
+        let mut points = Vec::new();
+        let mut values = Vec::new();
+        let mut proofs = Vec::new();
+        for i in 0..4u64 {
+            let poly: Vec<FieldElement> = (0..=i).map(|j| FieldElement::from(j + 2)).collect();
+            let point = FieldElement::from(3 * i + 1);
+            let (value, proof) = KZGCommitment::open(&prover_params.commitment_params, &poly, point).unwrap();
+            commitments.push(KZGCommitment::commit(&prover_params.commitment_params, &poly).unwrap());
+            points.push(point);
+            values.push(value);
+            proofs.push(proof);
+        }
+        
+        let stream = |values: &[FieldElement]| {
+            let mut batch = BatchVerifier::new(vk);
+            for i in 0..commitments.len() {
+                batch.add_opening(&commitments[i], points[i], values[i], &proofs[i]);
+            }
+            batch.finalize()
+        };
+        
+        assert!(stream(&values));
+        assert!(KZGCommitment::batch_verify(vk, &commitments, &points, &values, &proofs).unwrap());
+        
+        let mut tampered = values.clone();
+        tampered[2] += FieldElement::one();
+        assert!(!stream(&tampered));
+        assert!(!KZGCommitment::batch_verify(vk, &commitments, &points, &tampered, &proofs).unwrap());
+    }
+    
+    #[test]
+    fn test_batch_verify_weights_are_unpredictable() {
+        use ark_std::rand::SeedableRng;
+        
+        let (prover_params, verifier_params) = setup_params(3);
+        let vk = &verifier_params.commitment_vk;
+        
+        let mut commitments = Vec::new();
+        let mut points = Vec::new();
+        let mut values = Vec::new();
+        let mut proofs = Vec::new();
+        for i in 0..3u64 {
+            let poly: Vec<FieldElement> = (0..4).map(|j| FieldElement::from(5 * i + j + 1)).collect();
+            let point = FieldElement::from(i + 10);
+            let (value, proof) = KZGCommitment::open(&prover_params.commitment_params, &poly, point).unwrap();
+            commitments.push(KZGCommitment::commit(&prover_params.commitment_params, &poly).unwrap());
+            points.push(point);
+            values.push(value);
+            proofs.push(proof);
+        }
+        assert!(KZGCommitment::batch_verify(vk, &commitments, &points, &values, &proofs).unwrap());
+        
+        // Flipping any single value breaks the batch
+        for i in 0..values.len() {
+            let mut tampered = values.clone();
+            tampered[i] += FieldElement::one();
+            assert!(!KZGCommitment::batch_verify(vk, &commitments, &points, &tampered, &proofs).unwrap());
+        }
+        
+        // Errors chosen to cancel under the weights of a fixed-seed RNG no
+        // longer cancel once the weights depend on the batch
+        let mut fixed_rng = rand_chacha::ChaCha20Rng::from_seed([42u8; 32]);
+        let gamma_0 = FieldElement::rand(&mut fixed_rng);
+        let gamma_1 = FieldElement::rand(&mut fixed_rng);
+        let mut crafted = values.clone();
+        crafted[0] += gamma_1;
+        crafted[1] -= gamma_0;
+        assert!(!KZGCommitment::batch_verify(vk, &commitments, &points, &crafted, &proofs).unwrap());
+    }
+    
+    #[test]
+    fn test_combine_openings_of_different_polynomials() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let params = &prover_params.commitment_params;
+        let vk = &verifier_params.commitment_vk;
+        
+        let polynomials = [
+            vec![FieldElement::from(3u64), FieldElement::from(1u64)],
+            vec![FieldElement::from(2u64), FieldElement::from(0u64), FieldElement::from(5u64)],
+            vec![FieldElement::from(7u64), FieldElement::from(4u64), FieldElement::from(1u64), FieldElement::from(9u64)],
+        ];
+        let items: Vec<KZGOpening> = polynomials
+            .iter()
+            .zip([2u64, 11, 6])
+            .map(|(poly, z)| {
+                let point = FieldElement::from(z);
+                let (value, proof) = KZGCommitment::open(params, poly, point).unwrap();
+                (KZGCommitment::commit(params, poly).unwrap(), point, value, proof)
+            })
+            .collect();
+        
+        let combine = |items: &[KZGOpening]| {
+            KZGCommitment::combine_openings(items, &mut Transcript::new(b"combine_openings_test_transcript")).unwrap()
+        };
+        let (commitment, point, value, proof) = combine(&items);
+        assert!(point.is_zero());
+        assert!(KZGCommitment::verify(vk, &commitment, point, value, &proof).unwrap());
+        
+        // A single wrong value survives the combination as a wrong combined triple
+        let mut tampered = items.clone();
+        tampered[1].2 += FieldElement::one();
+        let (commitment, point, value, proof) = combine(&tampered);
+        assert!(!KZGCommitment::verify(vk, &commitment, point, value, &proof).unwrap());
+        
+        assert!(KZGCommitment::combine_openings(&[], &mut Transcript::new(&[0u8; 32])).is_err());
+    }
+    
+    #[test]
+    fn test_commit_windowed_matches_commit() {
+        let (prover_params, _) = setup_params(4);
+        let params = &prover_params.commitment_params;
+        let mut rng = ark_std::test_rng();
+        
+        for window_size in [2, 4, 5] {
+            let windows = params.precompute_windows(window_size).unwrap();
+            for len in [1, 7, 16] {
+                let poly: Vec<FieldElement> = (0..len).map(|_| FieldElement::rand(&mut rng)).collect();
+                assert_eq!(
+                    KZGCommitment::commit_windowed(&windows, &poly).unwrap(),
+                    KZGCommitment::commit(params, &poly).unwrap()
+                );
+            }
+        }
+        
+        let windows = params.precompute_windows(3).unwrap();
+        let too_long = vec![FieldElement::one(); params.g1_powers.len() + 1];
+        assert!(KZGCommitment::commit_windowed(&windows, &too_long).is_err());
+        assert!(params.precompute_windows(1).is_err());
+        assert!(params.precompute_windows(16).is_ok());
+        assert!(params.precompute_windows(17).is_err());
+    }
+    
+    #[test]
+    fn test_msm_commit_matches_scalar_loop() {
+        let (prover_params, _) = setup_params(6);
+        let params = &prover_params.commitment_params;
+        let mut rng = ark_std::test_rng();
+        
+        for len in [0, 1, 5, 33, params.g1_powers.len()] {
+            let poly: Vec<FieldElement> = (0..len).map(|_| FieldElement::rand(&mut rng)).collect();
+            let expected: G1Element = poly
+                .iter()
+                .zip(&params.g1_powers)
+                .map(|(&coeff, &power)| power * coeff)
+                .sum();
+            assert_eq!(KZGCommitment::commit(params, &poly).unwrap().commitment, expected);
+        }
+        
+        // Mismatched lengths use the common prefix
+        let scalars = vec![FieldElement::from(3u64); 4];
+        assert_eq!(g1_msm(&params.g1_powers[..2], &scalars), (params.g1_powers[0] + params.g1_powers[1]) * FieldElement::from(3u64));
+    }
+    
+    #[test]
+    fn test_pedersen_commitment() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let params = PedersenCommitment::prover_key(&prover_params).into_owned();
+        let vk = PedersenCommitment::verifier_key(&verifier_params).into_owned();
+        assert_eq!(params, vk);
+        assert_eq!(params, PedersenParams::new(params.generators.len(), &prover_params.fiat_shamir_seed));
+        
+        let poly = vec![FieldElement::from(3u64), FieldElement::from(2u64), FieldElement::one()];
+        let commitment = PedersenCommitment::commit(&params, &poly).unwrap();
+        let point = FieldElement::from(5u64);
+        let (value, proof) = PedersenCommitment::open(&params, &poly, point).unwrap();
+        assert_eq!(value, FieldElement::from(38u64));
+        assert!(PedersenCommitment::verify(&vk, &commitment, point, value, &proof).unwrap());
+        assert!(!PedersenCommitment::verify(&vk, &commitment, point, value + FieldElement::one(), &proof).unwrap());
+        
+        // An opening of a different polynomial does not match the commitment
+        let other = vec![FieldElement::from(38u64)];
+        let (other_value, other_proof) = PedersenCommitment::open(&params, &other, point).unwrap();
+        assert!(!PedersenCommitment::verify(&vk, &commitment, point, other_value, &other_proof).unwrap());
+        
+        let too_long = vec![FieldElement::one(); params.generators.len() + 1];
+        assert!(PedersenCommitment::commit(&params, &too_long).is_err());
+    }
+    
+    #[test]
+    fn test_pedersen_hiding_commitment() {
+        let (prover_params, _) = setup_params(2);
+        let params = PedersenCommitment::prover_key(&prover_params).into_owned();
+        let mut rng = ark_std::test_rng();
+        
+        let poly = vec![FieldElement::from(7u64), FieldElement::from(11u64)];
+        let (r1, r2) = (FieldElement::rand(&mut rng), FieldElement::rand(&mut rng));
+        let first = PedersenCommitment::commit_hiding(&params, &poly, r1).unwrap();
+        let second = PedersenCommitment::commit_hiding(&params, &poly, r2).unwrap();
+        assert_ne!(first, second);
+        assert_ne!(first, PedersenCommitment::commit(&params, &poly).unwrap());
+        
+        let point = FieldElement::from(3u64);
+        let (value, proof) = PedersenCommitment::open_hiding(&poly, r1, point);
+        assert_eq!(value, FieldElement::from(40u64));
+        assert!(PedersenCommitment::verify(&params, &first, point, value, &proof).unwrap());
+        assert!(!PedersenCommitment::verify(&params, &second, point, value, &proof).unwrap());
+        
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(PedersenOpening::deserialize_compressed(&bytes[..]).unwrap(), proof);
+    }
+    
+    #[test]
+    fn test_kzg_hiding_commitment() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let params = &prover_params.commitment_params;
+        let vk = &verifier_params.commitment_vk;
+        let mut rng = ark_std::test_rng();
+        
+        let poly = vec![FieldElement::from(3u64), FieldElement::from(2u64), FieldElement::one()];
+        let (first, first_blind) = KZGCommitment::commit_hiding(params, &poly, &mut rng).unwrap();
+        let (second, second_blind) = KZGCommitment::commit_hiding(params, &poly, &mut rng).unwrap();
+        assert_ne!(first, second);
+        assert_ne!(first, KZGCommitment::commit(params, &poly).unwrap());
+        
+        let point = FieldElement::from(5u64);
+        for (commitment, blind) in [(&first, first_blind), (&second, second_blind)] {
+            let (value, opening) = KZGCommitment::open_hiding(params, &poly, blind, point).unwrap();
+            assert_eq!(value, FieldElement::from(38u64));
+            assert!(KZGCommitment::verify_hiding(vk, commitment, point, value, &opening).unwrap());
+            assert!(!KZGCommitment::verify_hiding(vk, commitment, point, value + FieldElement::one(), &opening).unwrap());
+        }
+        
+        // An opening only verifies against the commitment carrying its blind
+        let (value, opening) = KZGCommitment::open_hiding(params, &poly, first_blind, point).unwrap();
+        assert!(!KZGCommitment::verify_hiding(vk, &second, point, value, &opening).unwrap());
+        
+        let too_long = vec![FieldElement::one(); params.g1_powers.len()];
+        assert!(KZGCommitment::commit_hiding(params, &too_long, &mut rng).is_err());
+        
+        // A trimmed prover key blinds at its own top power, which the full
+        // verifier key reads from the opening
+        let trimmed = prover_params.trim(4).unwrap();
+        let (commitment, blind) = KZGCommitment::commit_hiding(&trimmed.commitment_params, &poly, &mut rng).unwrap();
+        let (value, opening) = KZGCommitment::open_hiding(&trimmed.commitment_params, &poly, blind, point).unwrap();
+        assert_eq!(opening.blinding_degree, 4);
+        assert!(KZGCommitment::verify_hiding(vk, &commitment, point, value, &opening).unwrap());
+        let mut beyond = opening.clone();
+        beyond.blinding_degree = vk.max_degree + 1;
+        assert!(!KZGCommitment::verify_hiding(vk, &commitment, point, value, &beyond).unwrap());
+    }
+    
+    #[test]
+    fn test_kzg_open_batch() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let params = &prover_params.commitment_params;
+        let vk = &verifier_params.commitment_vk;
+        let mut rng = ark_std::test_rng();
+        
+        // Degree 8
+        let poly: Vec<FieldElement> = (0..9).map(|_| FieldElement::rand(&mut rng)).collect();
+        let commitment = KZGCommitment::commit(params, &poly).unwrap();
+        let points: Vec<FieldElement> = [2u64, 3, 5, 7].iter().map(|&z| FieldElement::from(z)).collect();
+        
+        let (values, proof) = KZGCommitment::open_batch(params, &poly, &points).unwrap();
+        for (&z, &value) in points.iter().zip(&values) {
+            assert_eq!(value, evaluate_polynomial(&poly, z));
+        }
+        assert!(KZGCommitment::verify_batch(vk, &commitment, &points, &values, &proof).unwrap());
+        
+        let mut wrong_values = values.clone();
+        wrong_values[2] += FieldElement::one();
+        assert!(!KZGCommitment::verify_batch(vk, &commitment, &points, &wrong_values, &proof).unwrap());
+        assert!(!KZGCommitment::verify_batch(vk, &commitment, &points[..3], &values[..3], &proof).unwrap());
+        
+        let other = KZGCommitment::commit(params, &poly[1..]).unwrap();
+        assert!(!KZGCommitment::verify_batch(vk, &other, &points, &values, &proof).unwrap());
+        
+        let repeated = [points[0], points[0]];
+        assert!(KZGCommitment::open_batch(params, &poly, &repeated).is_err());
+    }
+}
@@ -0,0 +1,612 @@
+//! Transparent polynomial commitments from the FRI low-degree test.
+//!
+//! A polynomial is committed by a Merkle root over its evaluations on a
+//! power-of-two subgroup `blowup` times larger than its degree bound, i.e. a
+//! Reed–Solomon codeword. Opening f at z shows that the quotient
+//! (f(X) - f(z)) / (X - z) is a polynomial of lower degree by running FRI on
+//! it, computing the quotient at every queried position from the committed
+//! evaluations of f. Security rests on the hash function alone: there is no
+//! trusted setup and no discrete-logarithm assumption.
+//!
+//! ## Security level
+//!
+//! Each query catches a codeword far from the code except with probability
+//! about √ρ, where ρ = 1/blowup is the code rate (the Johnson bound), so a
+//! proof gives num_queries·log₂(blowup)/2 provable bits; see
+//! [`FriParams::security_bits`]. The defaults, a 16x blowup with 52 queries,
+//! give 104 bits, and 208 under the usual conjecture that each query is worth
+//! the full log₂(blowup). There is no proof-of-work grinding, and the hash
+//! caps the level at 128 bits against quantum collision search.
+//!
+//! ## Proof size versus KZG
+//!
+//! Both schemes commit with 32 bytes: a compressed G1 point for KZG and a
+//! Blake2b root here. A KZG opening is one more G1 point, 32 bytes at any
+//! degree. A FRI opening carries, for each query, an evaluation pair and a
+//! Merkle path per folding round, so it grows with the number of queries and
+//! with log² of the degree. With the default 16x blowup and 52 queries, opening
+//! a degree-16 polynomial takes 69,232 bytes, about 2,200 times the KZG opening.
+
+use crate::commitments::CommitmentScheme;
+use crate::utils::{field_utils, FieldElement, ProverParams, FiatShamirTranscript, Transcript, VerifierParams};
+use crate::{Result, TwistAndShoutError};
+use ark_ff::{BigInteger, Field, PrimeField, One};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use ark_std::borrow::Cow;
+use ark_std::{string::ToString, vec, vec::Vec};
+
+/// Merkle tree node
+type Hash = [u8; 32];
+
+/// FRI commitment scheme over BN254's scalar field
+#[derive(Debug, Clone)]
+pub struct FriCommitment;
+
+/// Parameters shared by the FRI prover and verifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FriParams {
+    /// Committed polynomials have fewer coefficients than this (power of 2)
+    pub degree_bound: usize,
+    
+    /// Ratio of the evaluation domain size to the degree bound (power of 2, at least 2)
+    pub blowup: usize,
+    
+    /// Number of positions the verifier queries per opening
+    pub num_queries: usize,
+}
+
+/// FRI commitment: the Merkle root of the committed codeword
+#[derive(Debug, Clone, PartialEq)]
+pub struct FriCommitmentValue {
+    pub root: [u8; 32],
+}
+
+/// Evaluation pair at ±x in one FRI layer with its Merkle path
+#[derive(Debug, Clone, PartialEq)]
+pub struct FriQueryRound {
+    pub values: [FieldElement; 2],
+    pub path: Vec<[u8; 32]>,
+}
+
+/// FRI opening proof for a claimed evaluation
+#[derive(Debug, Clone, PartialEq)]
+pub struct FriProof {
+    /// Merkle roots of the folded layers, excluding the committed codeword
+    /// and the final constant layer
+    pub layer_roots: Vec<[u8; 32]>,
+    
+    /// Value of the quotient after folding down to a constant
+    pub final_value: FieldElement,
+    
+    /// For each query, one round per layer starting with the committed codeword
+    pub queries: Vec<Vec<FriQueryRound>>,
+}
+
+impl FriParams {
+    /// Domain size is `blowup` times the degree bound
+    pub const DEFAULT_BLOWUP: usize = 16;
+    
+    /// Queries per opening, enough for 104 provable bits at the default blowup
+    pub const DEFAULT_NUM_QUERIES: usize = 52;
+    
+    /// Parameters for polynomials of up to `max_coefficients` coefficients
+    pub fn new(max_coefficients: usize) -> Self {
+        Self {
+            degree_bound: max_coefficients.next_power_of_two().max(1),
+            blowup: Self::DEFAULT_BLOWUP,
+            num_queries: Self::DEFAULT_NUM_QUERIES,
+        }
+    }
+    
+    /// Number of evaluations in the committed codeword
+    pub fn domain_size(&self) -> usize {
+        self.degree_bound * self.blowup
+    }
+    
+    /// Provable soundness in bits: each query contributes log₂(blowup)/2
+    pub fn security_bits(&self) -> usize {
+        self.num_queries * self.blowup.trailing_zeros() as usize / 2
+    }
+    
+    /// Number of folds that reduce a polynomial below the degree bound to a constant
+    fn num_rounds(&self) -> usize {
+        self.degree_bound.trailing_zeros() as usize
+    }
+    
+    /// Evaluation domain of the committed codeword
+    fn domain(&self) -> Result<Radix2EvaluationDomain<FieldElement>> {
+        if !self.degree_bound.is_power_of_two() || !self.blowup.is_power_of_two() || self.blowup < 2 {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "FRI degree bound and blowup must be powers of 2, with blowup at least 2".to_string(),
+            ));
+        }
+        
+        Radix2EvaluationDomain::new(self.domain_size()).ok_or_else(|| {
+            TwistAndShoutError::InvalidParameters("FRI domain exceeds the field's two-adicity".to_string())
+        })
+    }
+}
+
+impl FriCommitment {
+    /// Evaluate `polynomial` over the domain, checking it fits the degree bound
+    fn codeword(params: &FriParams, polynomial: &[FieldElement]) -> Result<Vec<FieldElement>> {
+        if polynomial.len() > params.degree_bound {
+            return Err(TwistAndShoutError::Commitment(
+                "Polynomial degree exceeds the FRI degree bound".to_string(),
+            ));
+        }
+        
+        Ok(params.domain()?.fft(polynomial))
+    }
+    
+    /// Transcript binding the parameters, commitment and claimed evaluation
+    fn transcript(params: &FriParams, root: &Hash, point: FieldElement, value: FieldElement) -> Transcript {
+        let mut transcript = Transcript::new(&[0u8; 32]);
+        transcript.append_bytes(b"fri_degree_bound", &(params.degree_bound as u64).to_le_bytes());
+        transcript.append_bytes(b"fri_blowup", &(params.blowup as u64).to_le_bytes());
+        transcript.append_bytes(b"fri_num_queries", &(params.num_queries as u64).to_le_bytes());
+        transcript.append_bytes(b"fri_root", root);
+        transcript.append_field_element(b"fri_point", &point);
+        transcript.append_field_element(b"fri_value", &value);
+        transcript
+    }
+    
+    /// Pair indices in the first layer, each in [0, domain_size / 2)
+    fn query_indices(transcript: &mut impl FiatShamirTranscript, params: &FriParams) -> Vec<usize> {
+        let half = params.domain_size() / 2;
+        transcript
+            .challenge_field_elements(b"fri_query", params.num_queries)
+            .into_iter()
+            .map(|challenge| challenge.into_bigint().0[0] as usize % half)
+            .collect()
+    }
+}
+
+impl CommitmentScheme for FriCommitment {
+    type Scalar = FieldElement;
+    type Commitment = FriCommitmentValue;
+    type Proof = FriProof;
+    type Params = FriParams;
+    type VerifyKey = FriParams;
+    
+    /// Only the degree bound is taken from the parameters; the SRS is unused.
+    /// The bound follows `max_operations`, which setup makes equal to the
+    /// verifier's `max_degree`, so trimmed parameters derive the same key.
+    fn prover_key(params: &ProverParams) -> Cow<'_, Self::Params> {
+        Cow::Owned(FriParams::new(params.max_operations + 1))
+    }
+    
+    fn verifier_key(params: &VerifierParams) -> Cow<'_, Self::VerifyKey> {
+        Cow::Owned(FriParams::new(params.commitment_vk.max_degree + 1))
+    }
+    
+    fn transcript_hash(commitment: &Self::Commitment) -> FieldElement {
+        FieldElement::from_le_bytes_mod_order(&commitment.root)
+    }
+    
+    fn supports_degree(vk: &Self::VerifyKey, degree: usize) -> bool {
+        degree < vk.degree_bound
+    }
+    
+    fn commit(
+        params: &Self::Params,
+        polynomial: &[FieldElement],
+    ) -> Result<Self::Commitment> {
+        let codeword = Self::codeword(params, polynomial)?;
+        Ok(FriCommitmentValue { root: MerkleTree::from_codeword(&codeword).root() })
+    }
+    
+    fn open(
+        params: &Self::Params,
+        polynomial: &[FieldElement],
+        point: FieldElement,
+    ) -> Result<(FieldElement, Self::Proof)> {
+        let codeword = Self::codeword(params, polynomial)?;
+        let domain = params.domain()?;
+        let size = codeword.len();
+        if point.pow([size as u64]).is_one() {
+            return Err(TwistAndShoutError::Commitment(
+                "Opening point lies in the FRI evaluation domain".to_string(),
+            ));
+        }
+        
+        let value = field_utils::horner_eval(polynomial, point);
+        let tree = MerkleTree::from_codeword(&codeword);
+        let mut transcript = Self::transcript(params, &tree.root(), point, value);
+        
+        // Quotient (f(x) - f(z)) / (x - z) on the domain
+        let denominators: Vec<FieldElement> = domain.elements().map(|x| x - point).collect();
+        let quotient: Vec<FieldElement> = field_utils::batch_inverse(&denominators)
+            .into_iter()
+            .zip(&codeword)
+            .map(|(inverse, &evaluation)| (evaluation - value) * inverse)
+            .collect();
+        
+        // Fold the quotient down to a constant, committing every intermediate layer
+        let num_rounds = params.num_rounds();
+        let mut layers = Vec::new();
+        let mut current = quotient;
+        let mut generator = domain.group_gen;
+        for round in 0..num_rounds {
+            let beta = transcript.challenge_field_element(b"fri_fold");
+            current = fold(&current, beta, generator);
+            generator.square_in_place();
+            if round + 1 < num_rounds {
+                let layer_tree = MerkleTree::from_codeword(&current);
+                transcript.append_bytes(b"fri_layer_root", &layer_tree.root());
+                layers.push((current.clone(), layer_tree));
+            }
+        }
+        let final_value = current[0];
+        transcript.append_field_element(b"fri_final", &final_value);
+        
+        let queries = Self::query_indices(&mut transcript, params)
+            .into_iter()
+            .map(|index| {
+                let half = size / 2;
+                let mut rounds = vec![FriQueryRound {
+                    values: [codeword[index], codeword[index + half]],
+                    path: tree.path(index),
+                }];
+                for (layer, layer_tree) in &layers {
+                    let half = layer.len() / 2;
+                    let pair = index % half;
+                    rounds.push(FriQueryRound {
+                        values: [layer[pair], layer[pair + half]],
+                        path: layer_tree.path(pair),
+                    });
+                }
+                rounds
+            })
+            .collect();
+        
+        let layer_roots = layers.iter().map(|(_, layer_tree)| layer_tree.root()).collect();
+        Ok((value, FriProof { layer_roots, final_value, queries }))
+    }
+    
+    fn verify(
+        vk: &Self::VerifyKey,
+        commitment: &Self::Commitment,
+        point: FieldElement,
+        value: FieldElement,
+        proof: &Self::Proof,
+    ) -> Result<bool> {
+        let domain = vk.domain()?;
+        let size = vk.domain_size();
+        let num_rounds = vk.num_rounds();
+        if point.pow([size as u64]).is_one()
+            || proof.layer_roots.len() != num_rounds.saturating_sub(1)
+            || proof.queries.len() != vk.num_queries
+            || proof.queries.iter().any(|rounds| rounds.len() != num_rounds.max(1))
+        {
+            return Ok(false);
+        }
+        
+        let mut transcript = Self::transcript(vk, &commitment.root, point, value);
+        let mut betas = Vec::with_capacity(num_rounds);
+        for round in 0..num_rounds {
+            betas.push(transcript.challenge_field_element(b"fri_fold"));
+            if round + 1 < num_rounds {
+                transcript.append_bytes(b"fri_layer_root", &proof.layer_roots[round]);
+            }
+        }
+        transcript.append_field_element(b"fri_final", &proof.final_value);
+        
+        let two_inv = FieldElement::from(2u64).inverse().expect("2 is invertible");
+        for (index, rounds) in Self::query_indices(&mut transcript, vk).into_iter().zip(&proof.queries) {
+            // First layer: the quotient at ±x, computed from the committed codeword
+            let [positive, negative] = rounds[0].values;
+            if !MerkleTree::verify_path(&commitment.root, &rounds[0], index) {
+                return Ok(false);
+            }
+            let x = domain.group_gen.pow([index as u64]);
+            let (Some(positive_inv), Some(negative_inv)) = ((x - point).inverse(), (-x - point).inverse()) else {
+                return Ok(false);
+            };
+            let mut pair = [(positive - value) * positive_inv, (negative - value) * negative_inv];
+            if num_rounds == 0 {
+                if pair != [proof.final_value; 2] {
+                    return Ok(false);
+                }
+                continue;
+            }
+            
+            let mut generator = domain.group_gen;
+            let mut layer_size = size;
+            for round in 0..num_rounds {
+                if round > 0 {
+                    // The previous fold must match this layer's entry at x^(2^round)
+                    let half = layer_size / 2;
+                    let pair_index = index % half;
+                    let query = &rounds[round];
+                    if !MerkleTree::verify_path(&proof.layer_roots[round - 1], query, pair_index) {
+                        return Ok(false);
+                    }
+                    let position = index % layer_size;
+                    let folded = pair[0];
+                    if query.values[usize::from(position >= half)] != folded {
+                        return Ok(false);
+                    }
+                    pair = query.values;
+                }
+                
+                let half = layer_size / 2;
+                let x = generator.pow([(index % half) as u64]);
+                let x_inv = x.inverse().expect("domain elements are nonzero");
+                let [u, v] = pair;
+                pair[0] = (u + v) * two_inv + betas[round] * (u - v) * two_inv * x_inv;
+                
+                generator.square_in_place();
+                layer_size /= 2;
+            }
+            
+            if pair[0] != proof.final_value {
+                return Ok(false);
+            }
+        }
+        
+        Ok(true)
+    }
+}
+
+/// One FRI fold: the entries at x and -x = ω^(j + n/2) become
+/// (u + v)/2 + β(u - v)/(2x) at x² in a domain of half the size
+fn fold(evaluations: &[FieldElement], beta: FieldElement, generator: FieldElement) -> Vec<FieldElement> {
+    let half = evaluations.len() / 2;
+    let two_inv = FieldElement::from(2u64).inverse().expect("2 is invertible");
+    let generator_inv = generator.inverse().expect("domain generator is nonzero");
+    
+    let mut x_inv = FieldElement::one();
+    let mut folded = Vec::with_capacity(half);
+    for j in 0..half {
+        let (u, v) = (evaluations[j], evaluations[j + half]);
+        folded.push((u + v) * two_inv + beta * (u - v) * two_inv * x_inv);
+        x_inv *= generator_inv;
+    }
+    folded
+}
+
+/// Binary Blake2b Merkle tree whose leaf j commits to the evaluation pair
+/// (c[j], c[j + n/2]) of a codeword c of length n
+struct MerkleTree {
+    /// Levels from the leaves up to the root
+    levels: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    fn from_codeword(codeword: &[FieldElement]) -> Self {
+        let half = codeword.len() / 2;
+        let leaves = (0..half).map(|j| Self::hash_leaf(&[codeword[j], codeword[j + half]])).collect();
+        
+        let mut levels: Vec<Vec<Hash>> = vec![leaves];
+        while levels.last().is_some_and(|level| level.len() > 1) {
+            let next = levels
+                .last()
+                .expect("at least one level")
+                .chunks(2)
+                .map(|pair| Self::hash_node(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+        Self { levels }
+    }
+    
+    fn root(&self) -> Hash {
+        self.levels.last().expect("at least one level")[0]
+    }
+    
+    /// Sibling hashes from leaf `index` up to the root
+    fn path(&self, mut index: usize) -> Vec<Hash> {
+        let mut path = Vec::with_capacity(self.levels.len() - 1);
+        for level in &self.levels[..self.levels.len() - 1] {
+            path.push(level[index ^ 1]);
+            index /= 2;
+        }
+        path
+    }
+    
+    /// Check that `round` opens leaf `index` of the tree with root `root`
+    fn verify_path(root: &Hash, round: &FriQueryRound, mut index: usize) -> bool {
+        let mut node = Self::hash_leaf(&round.values);
+        for sibling in &round.path {
+            node = if index & 1 == 0 {
+                Self::hash_node(&node, sibling)
+            } else {
+                Self::hash_node(sibling, &node)
+            };
+            index /= 2;
+        }
+        index == 0 && node == *root
+    }
+    
+    fn hash_leaf(values: &[FieldElement; 2]) -> Hash {
+        let mut hasher = Blake2b::<U32>::new();
+        hasher.update([0u8]);
+        for value in values {
+            hasher.update(value.into_bigint().to_bytes_le());
+        }
+        hasher.finalize().into()
+    }
+    
+    fn hash_node(left: &Hash, right: &Hash) -> Hash {
+        let mut hasher = Blake2b::<U32>::new();
+        hasher.update([1u8]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
+    }
+}
+
+impl ark_serialize::Valid for FriCommitmentValue {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        Ok(())
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for FriCommitmentValue {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.root.serialize_with_mode(writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.root.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for FriCommitmentValue {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let root = <[u8; 32]>::deserialize_with_mode(reader, compress, validate)?;
+        Ok(Self { root })
+    }
+}
+
+impl ark_serialize::Valid for FriQueryRound {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.values.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for FriQueryRound {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.values.serialize_with_mode(&mut writer, compress)?;
+        self.path.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.values.serialized_size(compress) + self.path.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for FriQueryRound {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let values = <[FieldElement; 2]>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let path = Vec::<[u8; 32]>::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { values, path })
+    }
+}
+
+impl ark_serialize::Valid for FriProof {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.final_value.check()?;
+        self.queries.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for FriProof {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.layer_roots.serialize_with_mode(&mut writer, compress)?;
+        self.final_value.serialize_with_mode(&mut writer, compress)?;
+        self.queries.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.layer_roots.serialized_size(compress)
+            + self.final_value.serialized_size(compress)
+            + self.queries.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for FriProof {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let layer_roots = Vec::<[u8; 32]>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let final_value = FieldElement::deserialize_with_mode(&mut reader, compress, validate)?;
+        let queries = Vec::<Vec<FriQueryRound>>::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { layer_roots, final_value, queries })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commitments::KZGCommitment;
+    use crate::utils::setup_params;
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{test_rng, UniformRand};
+    
+    #[test]
+    fn test_fri_commit_and_open_degree_16() {
+        let mut rng = test_rng();
+        let params = FriParams::new(17);
+        assert_eq!(params.degree_bound, 32);
+        
+        let polynomial: Vec<FieldElement> = (0..17).map(|_| FieldElement::rand(&mut rng)).collect();
+        let commitment = FriCommitment::commit(&params, &polynomial).unwrap();
+        let point = FieldElement::rand(&mut rng);
+        let (value, proof) = FriCommitment::open(&params, &polynomial, point).unwrap();
+        
+        assert_eq!(value, field_utils::horner_eval(&polynomial, point));
+        assert!(FriCommitment::verify(&params, &commitment, point, value, &proof).unwrap());
+        assert!(!FriCommitment::verify(&params, &commitment, point, value + FieldElement::one(), &proof).unwrap());
+        assert!(!FriCommitment::verify(&params, &commitment, point + FieldElement::one(), value, &proof).unwrap());
+        
+        let mut tampered = proof.clone();
+        tampered.queries[3][2].values[0] += FieldElement::one();
+        assert!(!FriCommitment::verify(&params, &commitment, point, value, &tampered).unwrap());
+        
+        let mut tampered = proof.clone();
+        tampered.final_value += FieldElement::one();
+        assert!(!FriCommitment::verify(&params, &commitment, point, value, &tampered).unwrap());
+        
+        // Polynomials beyond the degree bound are rejected outright
+        let too_long: Vec<FieldElement> = (0..33).map(|_| FieldElement::rand(&mut rng)).collect();
+        assert!(FriCommitment::commit(&params, &too_long).is_err());
+        
+        // Openings survive a serialization round trip
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        let decoded = <FriProof as ark_serialize::CanonicalDeserialize>::deserialize_compressed(&bytes[..]).unwrap();
+        assert_eq!(decoded, proof);
+    }
+    
+    #[test]
+    fn test_fri_proof_size_versus_kzg() {
+        let mut rng = test_rng();
+        let polynomial: Vec<FieldElement> = (0..17).map(|_| FieldElement::rand(&mut rng)).collect();
+        let point = FieldElement::rand(&mut rng);
+        
+        let (prover_params, _) = setup_params(5);
+        let kzg_key = KZGCommitment::prover_key(&prover_params);
+        let (_, kzg_proof) = KZGCommitment::open(&kzg_key, &polynomial, point).unwrap();
+        let (_, fri_proof) = FriCommitment::open(&FriParams::new(17), &polynomial, point).unwrap();
+        
+        // Matches the figures quoted in the module documentation
+        assert_eq!(kzg_proof.compressed_size(), 32);
+        assert_eq!(fri_proof.compressed_size(), 69232);
+    }
+    
+    #[test]
+    fn test_fri_default_security_level() {
+        let params = FriParams::new(17);
+        assert_eq!(params.security_bits(), 104);
+        assert!(params.security_bits() >= 100);
+        
+        let weaker = FriParams { blowup: 4, num_queries: 32, ..params };
+        assert_eq!(weaker.security_bits(), 32);
+    }
+}
@@ -0,0 +1,613 @@
+//! Grand-product memory checking baseline.
+//!
+//! The offline memory checker of Blum et al. reduces read-write consistency to
+//! multiset equality between a write set and a read set of (address, value,
+//! timestamp) tuples, proven here with a running-product column. It exists to
+//! compare prover time against Twist on the same traces.
+
+use crate::utils::{FieldElement, ProofParams, ProverParams, VerifierParams, FiatShamirTranscript, Transcript};
+use crate::polynomials::poly_utils;
+use crate::commitments::{CommitmentScheme, KZGCommitment, KZGCommitmentValue, KZGProof};
+use crate::twist::{MemoryOp, MemoryTrace};
+use crate::{Result, TwistAndShoutError};
+use ark_ff::{Field, Zero, One};
+use ark_std::{format, string::ToString, vec, vec::Vec};
+
+/// Grand-product memory checker
+#[derive(Debug, Clone)]
+pub struct GrandProduct {
+    /// Prover parameters
+    prover_params: ProverParams,
+}
+
+/// Proof that a committed trace is consistent by multiset equality.
+///
+/// Row r of the columns holds one write tuple and one read tuple sharing an
+/// address: the first `memory_size` rows initialize each cell (write (a, 0, 0))
+/// and read back its final state, the next rows are the operations (read the
+/// cell's last tuple, write the new one at the operation's timestamp), and the
+/// rest, including the last row, are padding whose write and read tuples
+/// cancel. Write timestamps are public: 0 outside the operations and i + 1 for
+/// operation i. The bit columns decompose `t_w - t_r - 1` on operation rows, so
+/// every read consumes a tuple written before it.
+#[derive(Debug, Clone)]
+pub struct GrandProductProof {
+    /// Parameters the proof was generated under
+    pub params: ProofParams,
+    
+    /// Number of memory cells
+    pub memory_size: usize,
+    
+    /// Number of memory operations
+    pub num_operations: usize,
+    
+    /// Commitments to the address, op-type, write value, read value and read
+    /// timestamp columns, then to each bit of the timestamp gap
+    pub column_commitments: Vec<KZGCommitmentValue>,
+    
+    /// Commitment to the running product Z of write over read fingerprints
+    pub product_commitment: KZGCommitmentValue,
+    
+    /// Commitment to the constraint quotient
+    pub quotient_commitment: KZGCommitmentValue,
+    
+    /// The committed columns, Z and the quotient at ζ, then Z at ζ + 1, 0 and
+    /// the last row
+    pub evaluations: Vec<FieldElement>,
+    
+    /// Opening proofs, in the order of `evaluations`
+    pub opening_proofs: Vec<KZGProof>,
+}
+
+impl ark_serialize::Valid for GrandProductProof {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.params.check()?;
+        self.column_commitments.check()?;
+        self.product_commitment.check()?;
+        self.quotient_commitment.check()?;
+        self.evaluations.check()?;
+        self.opening_proofs.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for GrandProductProof {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.params.serialize_with_mode(&mut writer, compress)?;
+        self.memory_size.serialize_with_mode(&mut writer, compress)?;
+        self.num_operations.serialize_with_mode(&mut writer, compress)?;
+        self.column_commitments.serialize_with_mode(&mut writer, compress)?;
+        self.product_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.quotient_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)?;
+        self.opening_proofs.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        ark_serialize::CanonicalSerialize::serialized_size(&self.params, compress)
+            + self.memory_size.serialized_size(compress)
+            + self.num_operations.serialized_size(compress)
+            + self.column_commitments.serialized_size(compress)
+            + self.product_commitment.serialized_size(compress)
+            + self.quotient_commitment.serialized_size(compress)
+            + self.evaluations.serialized_size(compress)
+            + self.opening_proofs.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for GrandProductProof {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        Ok(Self {
+            params: ProofParams::deserialize_with_mode(&mut reader, compress, validate)?,
+            memory_size: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            num_operations: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            column_commitments: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            product_commitment: KZGCommitmentValue::deserialize_with_mode(&mut reader, compress, validate)?,
+            quotient_commitment: KZGCommitmentValue::deserialize_with_mode(&mut reader, compress, validate)?,
+            evaluations: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            opening_proofs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+/// Number of committed trace columns before the timestamp-gap bits
+const TRACE_COLUMNS: usize = 5;
+
+/// Row selectors and public write timestamps of a trace layout
+struct RowLayout {
+    memory_size: usize,
+    num_operations: usize,
+    num_rows: usize,
+}
+
+impl RowLayout {
+    /// Σ_r weights[r]·(S_init(r), S_op(r), S_pad(r), t_w(r)): with the
+    /// Lagrange basis at a point as weights, the four polynomials there
+    fn combine(&self, weights: &[FieldElement]) -> [FieldElement; 4] {
+        let (init, rest) = weights.split_at(self.memory_size);
+        let (ops, padding) = rest.split_at(self.num_operations);
+        let timestamps = ops
+            .iter()
+            .enumerate()
+            .map(|(i, &weight)| weight * FieldElement::from((i + 1) as u64))
+            .sum();
+        [init.iter().sum(), ops.iter().sum(), padding.iter().sum(), timestamps]
+    }
+    
+    /// S_init, S_op, S_pad and t_w as row columns
+    fn columns(&self) -> [Vec<FieldElement>; 4] {
+        let operations_end = self.memory_size + self.num_operations;
+        let selector = |range: core::ops::Range<usize>| {
+            (0..self.num_rows).map(|row| FieldElement::from(range.contains(&row))).collect()
+        };
+        let mut write_time = vec![FieldElement::zero(); self.num_rows];
+        for (i, time) in write_time[self.memory_size..operations_end].iter_mut().enumerate() {
+            *time = FieldElement::from((i + 1) as u64);
+        }
+        [
+            selector(0..self.memory_size),
+            selector(self.memory_size..operations_end),
+            selector(operations_end..self.num_rows),
+            write_time,
+        ]
+    }
+}
+
+impl GrandProduct {
+    /// Create a new grand-product checker
+    pub fn new(prover_params: &ProverParams) -> Self {
+        Self {
+            prover_params: prover_params.clone(),
+        }
+    }
+    
+    /// Prove that a trace is consistent.
+    ///
+    /// With fingerprints W = a + β·v_w + β²·t_w - γ and R = a + β·v_r + β²·t_r - γ,
+    /// Z(0) = 1 and Z(r + 1)·R(r) = Z(r)·W(r), so the write and read multisets
+    /// agree exactly when Z ends at 1. As in the offline checker of Blum et al.,
+    /// the verifier does not take the rest of the layout on trust. Every row
+    /// but the last also satisfies, combined with powers of a challenge λ:
+    ///
+    /// - reads write back what they read: (1 - op)(v_w - v_r) = 0;
+    /// - initial rows write (r, 0, 0) as writes: S_init·(a - X), S_init·(1 - op)
+    ///   and S_init·v_w vanish;
+    /// - padding rows cancel: S_pad·(v_w - v_r) and S_pad·t_r vanish;
+    /// - reads precede their writes: S_op·(t_w - t_r - 1 - Σⱼ 2ʲ·bⱼ) and every
+    ///   bⱼ(bⱼ - 1) vanish.
+    ///
+    /// The selectors and t_w are public, so the verifier evaluates them itself.
+    /// The combination is divided by the vanishing polynomial of those rows.
+    pub fn prove(&self, trace: &MemoryTrace) -> Result<GrandProductProof> {
+        let num_operations = trace.operations.iter().filter(|op| !op.is_padding()).count();
+        let num_rows = self.num_rows_within_srs(trace.memory_size, num_operations)?;
+        let columns = Self::trace_columns(trace, num_rows);
+        self.prove_columns(trace.memory_size, num_operations, columns)
+    }
+    
+    /// Rows a trace needs, if the SRS covers them
+    fn num_rows_within_srs(&self, memory_size: usize, num_operations: usize) -> Result<usize> {
+        let params = &self.prover_params.commitment_params;
+        Self::num_rows(memory_size, num_operations)
+            .filter(|&rows| rows <= params.g1_powers.len())
+            .ok_or_else(|| {
+                TwistAndShoutError::InvalidParameters(format!(
+                    "Trace of {} cells and {} operations needs more rows than the SRS's {}",
+                    memory_size,
+                    num_operations,
+                    params.g1_powers.len()
+                ))
+            })
+    }
+    
+    /// Prove the constraints of [`GrandProduct::prove`] for already laid out
+    /// committed columns
+    fn prove_columns(
+        &self,
+        memory_size: usize,
+        num_operations: usize,
+        columns: Vec<Vec<FieldElement>>,
+    ) -> Result<GrandProductProof> {
+        let num_rows = self.num_rows_within_srs(memory_size, num_operations)?;
+        let layout = RowLayout { memory_size, num_operations, num_rows };
+        let params = &self.prover_params.commitment_params;
+        let column_polys: Vec<Vec<FieldElement>> = columns.iter().map(|column| poly_utils::interpolate_domain(column)).collect();
+        let column_commitments = column_polys
+            .iter()
+            .map(|poly| KZGCommitment::commit(params, poly))
+            .collect::<Result<Vec<_>>>()?;
+        let [init, ops, padding, write_time] = layout.columns();
+        let write_time_poly = poly_utils::interpolate_domain(&write_time);
+        
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let (beta, gamma) = Self::absorb_columns(&mut transcript, memory_size, num_operations, num_rows, &column_commitments);
+        
+        // Running product of write over read fingerprints
+        let fingerprint = |row: usize, value: &[FieldElement], time: &[FieldElement]| {
+            columns[0][row] + beta * value[row] + beta.square() * time[row] - gamma
+        };
+        let mut product = Vec::with_capacity(num_rows);
+        let mut running = FieldElement::one();
+        for row in 0..num_rows {
+            product.push(running);
+            let read = fingerprint(row, &columns[3], &columns[4]).inverse().ok_or_else(|| {
+                TwistAndShoutError::InvalidParameters("Read fingerprint is zero".to_string())
+            })?;
+            running *= fingerprint(row, &columns[2], &write_time) * read;
+        }
+        let product_poly = poly_utils::interpolate_domain(&product);
+        let product_commitment = KZGCommitment::commit(params, &product_poly)?;
+        
+        transcript.append_field_element(b"grand_product_z", &product_commitment.hash());
+        let lambda = transcript.challenge_field_element(b"grand_product_lambda");
+        
+        // Linear combinations of the column polynomials plus a constant
+        let combine = |terms: &[(&[FieldElement], FieldElement)], constant: FieldElement| {
+            let mut sum = vec![FieldElement::zero(); num_rows];
+            sum[0] = constant;
+            for (poly, scale) in terms {
+                for (acc, &coeff) in sum.iter_mut().zip(poly.iter()) {
+                    *acc += *scale * coeff;
+                }
+            }
+            sum
+        };
+        let one = FieldElement::one();
+        let [address, op, write_value, read_value, read_time] = [0, 1, 2, 3, 4].map(|i| &column_polys[i][..]);
+        let [init, ops, padding] = [init, ops, padding].map(|selector| poly_utils::interpolate_domain(&selector));
+        let write_poly = combine(&[(address, one), (write_value, beta), (&write_time_poly, beta.square())], -gamma);
+        let read_poly = combine(&[(address, one), (read_value, beta), (read_time, beta.square())], -gamma);
+        let value_change = combine(&[(write_value, one), (read_value, -one)], FieldElement::zero());
+        let mut gap = combine(&[(&write_time_poly, one), (read_time, -one)], -one);
+        for (j, bit) in column_polys[TRACE_COLUMNS..].iter().enumerate() {
+            gap = combine(&[(&gap, one), (bit, -FieldElement::from(1u64 << j))], FieldElement::zero());
+        }
+        let mut index_offset = combine(&[(address, one)], FieldElement::zero());
+        index_offset[1] -= one;
+        
+        let shifted_product = poly_utils::shift_polynomial(&product_poly, one);
+        let mut terms = vec![
+            poly_utils::multiply_ntt(&shifted_product, &read_poly),
+            poly_utils::multiply_ntt(&product_poly, &write_poly).into_iter().map(|c| -c).collect(),
+            poly_utils::multiply_ntt(&combine(&[(op, -one)], one), &value_change),
+            poly_utils::multiply_ntt(&init, &index_offset),
+            poly_utils::multiply_ntt(&init, &combine(&[(op, -one)], one)),
+            poly_utils::multiply_ntt(&init, write_value),
+            poly_utils::multiply_ntt(&padding, &value_change),
+            poly_utils::multiply_ntt(&padding, read_time),
+            poly_utils::multiply_ntt(&ops, &gap),
+        ];
+        terms.extend(column_polys[TRACE_COLUMNS..].iter().map(|bit| {
+            poly_utils::multiply_ntt(bit, &combine(&[(bit, one)], -one))
+        }));
+        
+        // The fingerprint recursion, then each further term scaled by λ, λ², ...
+        let mut constraint = vec![FieldElement::zero(); 2 * num_rows - 1];
+        let mut scale = one;
+        for (k, term) in terms.iter().enumerate() {
+            if k >= 2 {
+                scale *= lambda;
+            }
+            for (acc, &coeff) in constraint.iter_mut().zip(term) {
+                *acc += scale * coeff;
+            }
+        }
+        
+        let vanishing = poly_utils::vanishing_polynomial(num_rows - 1);
+        let quotient = poly_utils::divide_by_monic(&constraint, &vanishing);
+        let quotient_commitment = KZGCommitment::commit(params, &quotient)?;
+        
+        transcript.append_field_element(b"grand_product_quotient", &quotient_commitment.hash());
+        let point = transcript.challenge_field_element(b"grand_product_point");
+        
+        let last_row = FieldElement::from((num_rows - 1) as u64);
+        let openings = column_polys
+            .iter()
+            .chain([&product_poly, &quotient])
+            .map(|poly| (poly, point))
+            .chain([
+                (&product_poly, point + one),
+                (&product_poly, FieldElement::zero()),
+                (&product_poly, last_row),
+            ]);
+        let mut evaluations = Vec::new();
+        let mut opening_proofs = Vec::new();
+        for (poly, at) in openings {
+            let (evaluation, opening) = KZGCommitment::open(params, poly, at)?;
+            evaluations.push(evaluation);
+            opening_proofs.push(opening);
+        }
+        
+        Ok(GrandProductProof {
+            params: self.prover_params.proof_params(),
+            memory_size,
+            num_operations,
+            column_commitments,
+            product_commitment,
+            quotient_commitment,
+            evaluations,
+            opening_proofs,
+        })
+    }
+    
+    /// Verify a grand-product proof.
+    ///
+    /// Besides the openings, the verifier evaluates the row selectors and write
+    /// timestamps at ζ in O(rows) through the Lagrange basis.
+    pub fn verify(&self, proof: &GrandProductProof, verifier_params: &VerifierParams) -> Result<bool> {
+        let vk = &verifier_params.commitment_vk;
+        let Some(num_rows) = Self::num_rows(proof.memory_size, proof.num_operations)
+            .filter(|&rows| vk.supports_degree(rows - 1))
+        else {
+            return Ok(false);
+        };
+        let num_columns = TRACE_COLUMNS + num_rows.trailing_zeros() as usize;
+        if !verifier_params.is_compatible_with(&proof.params)
+            || proof.column_commitments.len() != num_columns
+            || proof.evaluations.len() != num_columns + 5
+            || proof.opening_proofs.len() != num_columns + 5
+        {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let (beta, gamma) = Self::absorb_columns(
+            &mut transcript,
+            proof.memory_size,
+            proof.num_operations,
+            num_rows,
+            &proof.column_commitments,
+        );
+        transcript.append_field_element(b"grand_product_z", &proof.product_commitment.hash());
+        let lambda = transcript.challenge_field_element(b"grand_product_lambda");
+        transcript.append_field_element(b"grand_product_quotient", &proof.quotient_commitment.hash());
+        let point = transcript.challenge_field_element(b"grand_product_point");
+        
+        let one = FieldElement::one();
+        let last_row = FieldElement::from((num_rows - 1) as u64);
+        let commitments = proof
+            .column_commitments
+            .iter()
+            .chain([&proof.product_commitment, &proof.quotient_commitment])
+            .map(|commitment| (commitment, point))
+            .chain([
+                (&proof.product_commitment, point + one),
+                (&proof.product_commitment, FieldElement::zero()),
+                (&proof.product_commitment, last_row),
+            ]);
+        for ((commitment, at), (&value, opening)) in commitments.zip(proof.evaluations.iter().zip(&proof.opening_proofs)) {
+            if !KZGCommitment::verify(vk, commitment, at, value, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        let layout = RowLayout { memory_size: proof.memory_size, num_operations: proof.num_operations, num_rows };
+        let [init, ops, padding, write_time] = layout.combine(&poly_utils::lagrange_basis_at(num_rows, point));
+        let (columns, rest) = proof.evaluations.split_at(num_columns);
+        let [address, op, write_value, read_value, read_time] = [0, 1, 2, 3, 4].map(|i| columns[i]);
+        let bits = &columns[TRACE_COLUMNS..];
+        let (product, quotient, shifted_product, first, last) = (rest[0], rest[1], rest[2], rest[3], rest[4]);
+        
+        let write = address + beta * write_value + beta.square() * write_time - gamma;
+        let read = address + beta * read_value + beta.square() * read_time - gamma;
+        let recomposed = bits.iter().rev().fold(FieldElement::zero(), |acc, &bit| acc.double() + bit);
+        let terms = [
+            (one - op) * (write_value - read_value),
+            init * (address - point),
+            init * (one - op),
+            init * write_value,
+            padding * (write_value - read_value),
+            padding * read_time,
+            ops * (write_time - read_time - one - recomposed),
+        ]
+        .into_iter()
+        .chain(bits.iter().map(|&bit| bit * (bit - one)));
+        let combined = terms.rev().fold(FieldElement::zero(), |acc, term| (acc + term) * lambda);
+        let constraint = shifted_product * read - product * write + combined;
+        let vanishing: FieldElement = (0..num_rows - 1)
+            .map(|i| point - FieldElement::from(i as u64))
+            .product();
+        
+        Ok(constraint == quotient * vanishing && first.is_one() && last.is_one())
+    }
+    
+    /// Rows for the initial/final cell tuples, the operations and at least
+    /// one padding row, rounded up to a power of two and at least two;
+    /// `None` for sizes past `usize`
+    fn num_rows(memory_size: usize, num_operations: usize) -> Option<usize> {
+        memory_size
+            .checked_add(num_operations)?
+            .checked_add(1)?
+            .checked_next_power_of_two()
+            .map(|rows| rows.max(2))
+    }
+    
+    /// Address, op-type, write value, read value and read timestamp columns,
+    /// replaying the trace to find each read tuple, then the bits of each
+    /// operation's timestamp gap t_w - t_r - 1
+    fn trace_columns(trace: &MemoryTrace, num_rows: usize) -> Vec<Vec<FieldElement>> {
+        let operations: Vec<&MemoryOp> = trace.operations.iter().filter(|op| !op.is_padding()).collect();
+        let num_bits = num_rows.trailing_zeros() as usize;
+        let mut columns = vec![vec![FieldElement::zero(); num_rows]; TRACE_COLUMNS + num_bits];
+        
+        // Last (value, timestamp) written to each cell
+        let mut memory = vec![(FieldElement::zero(), 0u64); trace.memory_size];
+        for (i, op) in operations.iter().enumerate() {
+            let row = trace.memory_size + i;
+            let address = op.address();
+            let (value, time) = memory[address];
+            let timestamp = (i + 1) as u64;
+            
+            columns[0][row] = FieldElement::from(address as u64);
+            columns[1][row] = if op.is_write() { FieldElement::one() } else { FieldElement::zero() };
+            columns[2][row] = op.value();
+            columns[3][row] = value;
+            columns[4][row] = FieldElement::from(time);
+            let gap = timestamp - time - 1;
+            for (j, column) in columns[TRACE_COLUMNS..].iter_mut().enumerate() {
+                column[row] = FieldElement::from((gap >> j) & 1);
+            }
+            memory[address] = (op.value(), timestamp);
+        }
+        
+        // Initialize every cell with a write of (0, 0) and read back its final state
+        for (address, &(value, time)) in memory.iter().enumerate() {
+            columns[0][address] = FieldElement::from(address as u64);
+            columns[1][address] = FieldElement::one();
+            columns[3][address] = value;
+            columns[4][address] = FieldElement::from(time);
+        }
+        
+        columns
+    }
+    
+    /// Absorb the statement and column commitments, returning (β, γ)
+    fn absorb_columns(
+        transcript: &mut impl FiatShamirTranscript,
+        memory_size: usize,
+        num_operations: usize,
+        num_rows: usize,
+        column_commitments: &[KZGCommitmentValue],
+    ) -> (FieldElement, FieldElement) {
+        transcript.append_field_elements(
+            b"grand_product_statement",
+            &[memory_size, num_operations, num_rows].map(|n| FieldElement::from(n as u64)),
+        );
+        let hashes: Vec<FieldElement> = column_commitments.iter().map(KZGCommitmentValue::hash).collect();
+        transcript.append_field_elements(b"grand_product_columns", &hashes);
+        
+        let beta = transcript.challenge_field_element(b"grand_product_beta");
+        let gamma = transcript.challenge_field_element(b"grand_product_gamma");
+        (beta, gamma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::setup_params;
+    
+    #[test]
+    fn test_grand_product_accepts_consistent_trace() {
+        let (prover_params, verifier_params) = setup_params(3);
+        
+        let mut trace = MemoryTrace::new(4);
+        trace.write(1, FieldElement::from(5u64)).unwrap();
+        trace.read(1).unwrap();
+        trace.read(2).unwrap();
+        trace.write(1, FieldElement::from(8u64)).unwrap();
+        trace.read(1).unwrap();
+        
+        let checker = GrandProduct::new(&prover_params);
+        let proof = checker.prove(&trace).unwrap();
+        assert_eq!(proof.num_operations, 5);
+        assert!(checker.verify(&proof, &verifier_params).unwrap());
+        
+        // Tampered evaluations and a wrong statement are rejected
+        let mut tampered = proof.clone();
+        tampered.evaluations[10] += FieldElement::one();
+        assert!(!checker.verify(&tampered, &verifier_params).unwrap());
+        let mut tampered = proof.clone();
+        tampered.num_operations += 1;
+        assert!(!checker.verify(&tampered, &verifier_params).unwrap());
+        tampered.memory_size = usize::MAX;
+        assert!(!checker.verify(&tampered, &verifier_params).unwrap());
+    }
+    
+    #[test]
+    fn test_grand_product_rejects_inconsistent_trace() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let checker = GrandProduct::new(&prover_params);
+        
+        let mut trace = MemoryTrace::new(4);
+        trace.write(2, FieldElement::from(3u64)).unwrap();
+        trace.read(2).unwrap();
+        trace.write(0, FieldElement::from(6u64)).unwrap();
+        
+        // A read returning a value never written to its cell
+        let mut stale = trace.clone();
+        stale.operations[1] = MemoryOp::Read { address: 2, value: FieldElement::from(4u64) };
+        let proof = checker.prove(&stale).unwrap();
+        assert!(!checker.verify(&proof, &verifier_params).unwrap());
+        
+        // A read of the right value from the wrong cell
+        let mut misplaced = trace.clone();
+        misplaced.operations[1] = MemoryOp::Read { address: 1, value: FieldElement::from(3u64) };
+        let proof = checker.prove(&misplaced).unwrap();
+        assert!(!checker.verify(&proof, &verifier_params).unwrap());
+    }
+    
+    /// Columns for `memory_size` cells initialized to (a, 0, 0) and read back
+    /// as `finals`, then operation and padding rows of
+    /// (row, address, op, write value, read value, read timestamp, gap)
+    fn forged_columns(
+        memory_size: usize,
+        num_rows: usize,
+        finals: &[(u64, u64)],
+        rows: &[(usize, u64, u64, u64, u64, u64, u64)],
+    ) -> Vec<Vec<FieldElement>> {
+        let num_bits = num_rows.trailing_zeros() as usize;
+        let mut columns = vec![vec![FieldElement::zero(); num_rows]; TRACE_COLUMNS + num_bits];
+        let mut set = |row: usize, values: [u64; 5], gap: u64| {
+            for (column, value) in columns.iter_mut().zip(values) {
+                column[row] = FieldElement::from(value);
+            }
+            for (j, column) in columns[TRACE_COLUMNS..].iter_mut().enumerate() {
+                column[row] = FieldElement::from((gap >> j) & 1);
+            }
+        };
+        for (address, &(value, time)) in finals.iter().enumerate().take(memory_size) {
+            set(address, [address as u64, 1, 0, value, time], 0);
+        }
+        for &(row, address, op, write_value, read_value, read_time, gap) in rows {
+            set(row, [address, op, write_value, read_value, read_time], gap);
+        }
+        columns
+    }
+    
+    /// Prove forged columns and check that their multisets balance, so only
+    /// the layout constraints can reject them
+    fn prove_balanced(checker: &GrandProduct, memory_size: usize, num_operations: usize, columns: Vec<Vec<FieldElement>>) -> GrandProductProof {
+        let proof = checker.prove_columns(memory_size, num_operations, columns).unwrap();
+        assert!(proof.evaluations.last().unwrap().is_one());
+        proof
+    }
+    
+    #[test]
+    fn test_grand_product_rejects_forged_layouts() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let checker = GrandProduct::new(&prover_params);
+        let zero = (0, 0);
+        
+        // Operation 1 reads 5 from cell 1 before operation 2 writes it, by
+        // consuming the write's future tuple (1, 5, 2); the gap 1 - 2 - 1 has no bits
+        let columns = forged_columns(4, 8, &[zero, (5, 1), zero, zero], &[
+            (4, 1, 0, 5, 5, 2, 0),
+            (5, 1, 1, 5, 0, 0, 1),
+        ]);
+        assert!(!checker.verify(&prove_balanced(&checker, 4, 2, columns), &verifier_params).unwrap());
+        
+        // Cell 2 starts at 7 rather than 0
+        let mut columns = forged_columns(4, 8, &[zero, zero, (7, 1), zero], &[(4, 2, 0, 7, 7, 0, 0)]);
+        columns[2][2] = FieldElement::from(7u64);
+        assert!(!checker.verify(&prove_balanced(&checker, 4, 1, columns), &verifier_params).unwrap());
+        
+        // A padding row overwrites cell 1 with 9 before operation 1 reads it
+        let columns = forged_columns(4, 8, &[zero, (9, 1), zero, zero], &[
+            (4, 1, 0, 9, 9, 0, 0),
+            (5, 1, 1, 9, 0, 0, 0),
+        ]);
+        assert!(!checker.verify(&prove_balanced(&checker, 4, 1, columns), &verifier_params).unwrap());
+        
+        // The honest layout of the last trace's single read of 0 verifies
+        let columns = forged_columns(4, 8, &[zero, (0, 1), zero, zero], &[(4, 1, 0, 0, 0, 0, 0)]);
+        assert!(checker.verify(&prove_balanced(&checker, 4, 1, columns), &verifier_params).unwrap());
+    }
+}
@@ -37,6 +37,17 @@
 //! // Verify proof
 //! assert!(twist.verify(&proof, &verifier_params).unwrap());
 //! ```
+//!
+//! ## `no_std`
+//!
+//! With `default-features = false` the crate builds on `core` and `alloc`
+//! alone. Proving and verification are unchanged; entropy-seeded
+//! [`setup_params`], trace files, streaming proofs and [`benchmarks`] need the
+//! `std` feature.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use ark_std::{fmt, string::String};
 
 pub mod commitments;
 pub mod polynomials;
@@ -44,45 +55,75 @@ pub mod sumcheck;
 pub mod twist;
 pub mod shout;
 pub mod utils;
+#[cfg(feature = "std")]
 pub mod benchmarks;
+pub mod grand_product;
+pub mod fri;
 
 // Re-export main types for convenience
-pub use twist::{Twist, TwistProof, MemoryTrace, MemoryOp};
-pub use shout::{Shout, ShoutProof, LookupTable, LookupOp};
-pub use commitments::{CommitmentScheme, KZGCommitment};
-pub use polynomials::MultilinearExtension;
-pub use utils::FieldElement;
-pub use utils::{setup_params, ProverParams, VerifierParams};
-pub use benchmarks::{ProtocolBenchmarks, BenchmarkResults};
+pub use twist::{verify_proof_bytes, Twist, TwistProof, ValueRangeProof, EqualityProof, InterleavedTraceProof, SequentialConsistencyProof, RefinementProof, VerifierProofView, MemoryTrace, MemoryOp, IncrementalTwist, WordMemoryTrace, WordTwistProof};
+#[cfg(feature = "std")]
+pub use twist::StreamingTrace;
+pub use shout::{verify_lookup_proof_bytes, Shout, ShoutProof, MultiShoutProof, PrefixExtensionProof, PrivateLookupProof, PrivateTable, PrivateTableCommitment, PrivateTableProof, LookupTable, LookupOp, VirtualLookupProof, StructuredTable, RangeCheckTable, StructuredLookupProof};
+pub use commitments::{BatchVerifier, CommitmentScheme, KZGCommitment, KZGOpening, MultilinearKZG, PedersenCommitment, PedersenParams};
+pub use polynomials::{MultilinearExtension, SparseMultilinearExtension};
+pub use utils::{FiatShamirTranscript, FieldElement, RecordingTranscript, Transcript};
+pub use utils::{from_json, setup_params_with_rng, to_json, ProofLayout, ProofParams, ProofSizeBreakdown, ProverParams, VerifierParams, VerifyCheck, VerifyReport};
+#[cfg(feature = "std")]
+pub use utils::{load_setup_from_ceremony, setup_params};
+pub use grand_product::{GrandProduct, GrandProductProof};
+pub use fri::{FriCommitment, FriParams};
+#[cfg(feature = "std")]
+pub use benchmarks::{BenchmarkRecord, BenchmarkResults, ExportFormat, ProtocolBenchmarks};
 
 /// Common error types for the library
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug)]
 pub enum TwistAndShoutError {
-    #[error("Invalid parameters: {0}")]
     InvalidParameters(String),
     
-    #[error("Proof generation failed: {0}")]
     ProofGeneration(String),
     
-    #[error("Proof verification failed: {0}")]
     ProofVerification(String),
     
-    #[error("Commitment error: {0}")]
     Commitment(String),
     
-    #[error("Polynomial operation failed: {0}")]
     Polynomial(String),
     
-    #[error("Sum-check protocol error: {0}")]
     SumCheck(String),
+    
+    Serialization(String),
+    
+    /// A caller-provided scratch buffer is too small; carries no message so
+    /// allocation-free verifiers can report it
+    ScratchTooSmall { required: usize, provided: usize },
 }
 
-pub type Result<T> = std::result::Result<T, TwistAndShoutError>;
+impl fmt::Display for TwistAndShoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidParameters(message) => write!(f, "Invalid parameters: {}", message),
+            Self::ProofGeneration(message) => write!(f, "Proof generation failed: {}", message),
+            Self::ProofVerification(message) => write!(f, "Proof verification failed: {}", message),
+            Self::Commitment(message) => write!(f, "Commitment error: {}", message),
+            Self::Polynomial(message) => write!(f, "Polynomial operation failed: {}", message),
+            Self::SumCheck(message) => write!(f, "Sum-check protocol error: {}", message),
+            Self::Serialization(message) => write!(f, "Serialization error: {}", message),
+            Self::ScratchTooSmall { required, provided } => {
+                write!(f, "Scratch buffer holds {} elements, {} required", provided, required)
+            }
+        }
+    }
+}
+
+// `core::error::Error` is `std::error::Error`, so this also serves std users
+impl core::error::Error for TwistAndShoutError {}
+
+pub type Result<T> = ark_std::result::Result<T, TwistAndShoutError>;
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
+    
     #[test]
     fn test_library_imports() {
         // Basic smoke test to ensure all modules compile
@@ -4,11 +4,16 @@
 //! of sparse vectors, which are fundamental to the Twist and Shout protocols.
 
 use crate::utils::{FieldElement, field_utils};
+use crate::{Result, TwistAndShoutError};
 use ark_ff::{Field, Zero, One};
+use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+use ark_std::cfg_iter;
 use ark_std::collections::BTreeMap;
 use itertools::Itertools;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use ark_std::{format, vec, vec::Vec};
 
 /// A multilinear extension of a vector over a finite field.
 /// 
@@ -26,9 +31,7 @@ pub struct MultilinearExtension {
 impl MultilinearExtension {
     /// Create a new multilinear extension from evaluations
     pub fn from_evaluations(evaluations: Vec<FieldElement>) -> Self {
-        let num_vars = (evaluations.len() as f64).log2() as usize;
-        assert_eq!(1 << num_vars, evaluations.len(), 
-                  "Evaluation vector length must be a power of 2");
+        let num_vars = poly_utils::log2_exact(evaluations.len());
         
         Self {
             num_vars,
@@ -82,91 +85,78 @@ impl MultilinearExtension {
     }
     
     /// Evaluate the multilinear extension at a point r ∈ F^n
+    ///
+    /// Folds the evaluation table through every variable, O(2^n) in total.
     pub fn evaluate(&self, point: &[FieldElement]) -> FieldElement {
         assert_eq!(point.len(), self.num_vars, 
                   "Point dimension must match number of variables");
         
-        // Use the multilinear evaluation formula:
+        self.evaluate_with_eq(&poly_utils::eq_table(point))
+    }
+    
+    /// Evaluate at the point r whose table `eq = poly_utils::eq_table(r)` the
+    /// caller has already built, so several MLEs evaluated at one point share it
+    pub fn evaluate_with_eq(&self, eq: &[FieldElement]) -> FieldElement {
+        assert_eq!(eq.len(), self.evaluations.len(),
+                  "Eq table size must match number of evaluations");
+        
+        // f(r) = Σ_x f(x)·eq(r, x)
+        crate::utils::field_utils::inner_product(&self.evaluations, eq)
+    }
+    
+    /// Fix the first variable to `r` in place, halving the evaluation table in O(2^n).
+    ///
+    /// Variable 0 is the least significant bit of an evaluation index, so each
+    /// pair (eval[2i], eval[2i + 1]) folds to (1 - r)·eval[2i] + r·eval[2i + 1].
+    pub fn fix_first_variable(&mut self, r: FieldElement) {
+        assert!(self.num_vars > 0, "No variable left to fix");
+        
+        let half = self.evaluations.len() / 2;
+        for i in 0..half {
+            let low = self.evaluations[2 * i];
+            let high = self.evaluations[2 * i + 1];
+            self.evaluations[i] = low + r * (high - low);
+        }
+        
+        self.evaluations.truncate(half);
+        self.num_vars -= 1;
+    }
+    
+    /// Evaluate by summing f(x)·ψₓ(r) over the Boolean hypercube, O(n·2^n)
+    #[cfg(test)]
+    fn evaluate_by_basis(&self, point: &[FieldElement]) -> FieldElement {
         // f(r) = Σ_{x ∈ {0,1}^n} f(x) * ∏_{i=1}^n ((1-r_i)(1-x_i) + r_i * x_i)
-        self.evaluations
-            .par_iter()
+        cfg_iter!(self.evaluations)
             .enumerate()
             .map(|(index, &eval)| {
                 if eval.is_zero() {
                     return FieldElement::zero();
                 }
                 
-                let basis_eval = self.evaluate_basis_polynomial(index, point);
-                eval * basis_eval
+                eval * basis_polynomial(index, point)
             })
             .sum()
     }
     
-    /// Evaluate the basis polynomial at a point
-    /// For index i with binary representation (b₁, ..., bₙ):
-    /// ψᵢ(r) = ∏_{j=1}^n ((1-rⱼ)(1-bⱼ) + rⱼ * bⱼ)
-    fn evaluate_basis_polynomial(&self, index: usize, point: &[FieldElement]) -> FieldElement {
-        let mut result = FieldElement::one();
-        
-        for j in 0..self.num_vars {
-            let bit = (index >> j) & 1;
-            let contribution = if bit == 0 {
-                FieldElement::one() - point[j]
-            } else {
-                point[j]
-            };
-            result *= contribution;
-        }
-        
-        result
-    }
-    
     /// Partial evaluation: fix the first k variables to given values
     /// Returns a new MLE in the remaining (n-k) variables
     pub fn partial_evaluate(&self, fixed_values: &[FieldElement]) -> MultilinearExtension {
-        let k = fixed_values.len();
-        assert!(k <= self.num_vars, "Cannot fix more variables than available");
+        assert!(fixed_values.len() <= self.num_vars, "Cannot fix more variables than available");
         
-        if k == 0 {
-            return self.clone();
+        let mut partial = self.clone();
+        for &r in fixed_values {
+            partial.fix_first_variable(r);
         }
         
-        let new_num_vars = self.num_vars - k;
-        let new_size = 1 << new_num_vars;
-        let mut new_evaluations = vec![FieldElement::zero(); new_size];
-        
-        // For each point in the new space, evaluate the original polynomial
-        // at the extended point (fixed_values || new_point)
-        for new_index in 0..new_size {
-            let mut full_point = Vec::with_capacity(self.num_vars);
-            full_point.extend_from_slice(fixed_values);
-            
-            // Add binary representation of new_index
-            for j in 0..new_num_vars {
-                let bit = (new_index >> j) & 1;
-                full_point.push(if bit == 0 { 
-                    FieldElement::zero() 
-                } else { 
-                    FieldElement::one() 
-                });
-            }
-            
-            new_evaluations[new_index] = self.evaluate(&full_point);
-        }
-        
-        MultilinearExtension {
-            num_vars: new_num_vars,
-            evaluations: new_evaluations,
-        }
+        partial
     }
     
     /// Add two multilinear extensions
     pub fn add(&self, other: &MultilinearExtension) -> MultilinearExtension {
         assert_eq!(self.num_vars, other.num_vars, "Number of variables must match");
         
-        let evaluations = self.evaluations
-            .par_iter()
-            .zip(other.evaluations.par_iter())
+        let evaluations = cfg_iter!(self.evaluations)
+            .zip(cfg_iter!(other.evaluations))
             .map(|(&a, &b)| a + b)
             .collect();
         
@@ -178,8 +168,7 @@ impl MultilinearExtension {
     
     /// Multiply by a scalar
     pub fn scalar_mul(&self, scalar: FieldElement) -> MultilinearExtension {
-        let evaluations = self.evaluations
-            .par_iter()
+        let evaluations = cfg_iter!(self.evaluations)
             .map(|&eval| eval * scalar)
             .collect();
         
@@ -189,14 +178,145 @@ impl MultilinearExtension {
         }
     }
     
+    /// Multiply two multilinear extensions pointwise on the Boolean hypercube.
+    ///
+    /// The true product f·g has degree 2 in each variable, so it is not
+    /// multilinear. The result is the multilinear extension of the product's
+    /// hypercube values only: it agrees with f·g on {0,1}^n but not elsewhere.
+    /// That suffices for sums Σₓ f(x)·g(x) over the hypercube, but a sum-check
+    /// over f·g must still evaluate both factors at its random points.
+    pub fn mul_pointwise(&self, other: &MultilinearExtension) -> MultilinearExtension {
+        assert_eq!(self.num_vars, other.num_vars, "Number of variables must match");
+        
+        let evaluations = cfg_iter!(self.evaluations)
+            .zip(cfg_iter!(other.evaluations))
+            .map(|(&a, &b)| a * b)
+            .collect();
+        
+        MultilinearExtension {
+            num_vars: self.num_vars,
+            evaluations,
+        }
+    }
+    
     /// Compute the sum of all evaluations
     pub fn sum_evaluations(&self) -> FieldElement {
-        self.evaluations.par_iter().sum()
+        cfg_iter!(self.evaluations).sum()
+    }
+}
+
+/// Evaluate the basis polynomial of a hypercube index at a point
+/// For index i with binary representation (b₁, ..., bₙ):
+/// ψᵢ(r) = ∏_{j=1}^n ((1-rⱼ)(1-bⱼ) + rⱼ * bⱼ)
+fn basis_polynomial(index: usize, point: &[FieldElement]) -> FieldElement {
+    let mut result = FieldElement::one();
+    
+    for (j, &r) in point.iter().enumerate() {
+        let contribution = if (index >> j) & 1 == 0 {
+            FieldElement::one() - r
+        } else {
+            r
+        };
+        result *= contribution;
+    }
+    
+    result
+}
+
+/// A multilinear extension storing only its nonzero hypercube evaluations.
+///
+/// Memory and evaluation cost scale with the number of stored entries rather
+/// than 2^num_vars, which suits traces over large address spaces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMultilinearExtension {
+    /// Number of variables
+    pub num_vars: usize,
+    
+    /// Nonzero evaluations keyed by hypercube index
+    pub entries: BTreeMap<usize, FieldElement>,
+}
+
+impl SparseMultilinearExtension {
+    /// Create a sparse MLE from (index, value) pairs; later pairs overwrite
+    /// earlier ones and zero values are dropped
+    pub fn from_entries(num_vars: usize, entries: &[(usize, FieldElement)]) -> Self {
+        let mut sparse = Self {
+            num_vars,
+            entries: BTreeMap::new(),
+        };
+        
+        let size = u32::try_from(num_vars).ok().and_then(|shift| 1usize.checked_shl(shift));
+        for &(index, value) in entries {
+            assert!(size.is_none_or(|size| index < size), "Index {} out of bounds for {} variables", index, num_vars);
+            if value.is_zero() {
+                sparse.entries.remove(&index);
+            } else {
+                sparse.entries.insert(index, value);
+            }
+        }
+        
+        sparse
+    }
+    
+    /// Evaluate at a point r ∈ F^n, summing the basis formula over stored entries only
+    pub fn evaluate(&self, point: &[FieldElement]) -> FieldElement {
+        assert_eq!(point.len(), self.num_vars, 
+                  "Point dimension must match number of variables");
+        
+        self.entries
+            .iter()
+            .map(|(&index, &value)| value * basis_polynomial(index, point))
+            .sum()
+    }
+    
+    /// Add two sparse multilinear extensions
+    pub fn add(&self, other: &SparseMultilinearExtension) -> SparseMultilinearExtension {
+        assert_eq!(self.num_vars, other.num_vars, "Number of variables must match");
+        
+        let mut entries = self.entries.clone();
+        for (&index, &value) in &other.entries {
+            let sum = entries.get(&index).copied().unwrap_or_else(FieldElement::zero) + value;
+            if sum.is_zero() {
+                entries.remove(&index);
+            } else {
+                entries.insert(index, sum);
+            }
+        }
+        
+        SparseMultilinearExtension {
+            num_vars: self.num_vars,
+            entries,
+        }
+    }
+    
+    /// Multiply by a scalar
+    pub fn scalar_mul(&self, scalar: FieldElement) -> SparseMultilinearExtension {
+        let entries = if scalar.is_zero() {
+            BTreeMap::new()
+        } else {
+            self.entries.iter().map(|(&index, &value)| (index, value * scalar)).collect()
+        };
+        
+        SparseMultilinearExtension {
+            num_vars: self.num_vars,
+            entries,
+        }
+    }
+    
+    /// Materialize the full evaluation table
+    pub fn to_dense(&self) -> MultilinearExtension {
+        let entries: Vec<(usize, FieldElement)> = self.entries.iter().map(|(&i, &v)| (i, v)).collect();
+        MultilinearExtension::from_sparse(self.num_vars, &entries)
     }
 }
 
 /// Represents a less-than indicator polynomial
-/// lt(a, b) = 1 if a < b (in lexicographic order), 0 otherwise
+/// lt(a, b) = 1 if a < b as unsigned integers, 0 otherwise
+///
+/// Operands are stored little-endian: bit `i` has weight 2^i, so index 0 is
+/// the least significant bit and index `num_vars - 1` the most significant,
+/// matching the variable order of [`MultilinearExtension`]. Comparison always
+/// starts from the most significant bit.
 #[derive(Debug, Clone)]
 pub struct LessThanPolynomial {
     pub num_vars: usize,
@@ -218,28 +338,58 @@ impl LessThanPolynomial {
         self.evaluate_at_bits(&a_bits, &b_bits)
     }
     
-    /// Evaluate lt(a, b) where a and b are given as bit vectors
+    /// Evaluate lt(a, b) for field elements, returning an error if either value
+    /// does not fit in `num_vars` bits instead of silently truncating it
+    pub fn try_evaluate_at_field_elements(&self, a: FieldElement, b: FieldElement) -> Result<FieldElement> {
+        for (name, elem) in [("a", a), ("b", b)] {
+            if !self.fits_in_bits(elem) {
+                return Err(TwistAndShoutError::Polynomial(format!(
+                    "Operand {} does not fit in {} bits", name, self.num_vars
+                )));
+            }
+        }
+        
+        self.try_evaluate_at_bits(&self.field_to_bits(a), &self.field_to_bits(b))
+    }
+    
+    /// Evaluate lt(a, b) where a and b are given as little-endian bit vectors
+    ///
+    /// Panics if either bit vector does not have exactly `num_vars` bits; see
+    /// [`LessThanPolynomial::try_evaluate_at_bits`] for a non-panicking version.
     pub fn evaluate_at_bits(&self, a_bits: &[bool], b_bits: &[bool]) -> FieldElement {
-        assert_eq!(a_bits.len(), self.num_vars);
-        assert_eq!(b_bits.len(), self.num_vars);
+        match self.try_evaluate_at_bits(a_bits, b_bits) {
+            Ok(result) => result,
+            Err(e) => panic!("{}", e),
+        }
+    }
+    
+    /// Evaluate lt(a, b) where a and b are given as little-endian bit vectors,
+    /// returning an error if either bit vector does not have exactly `num_vars` bits
+    pub fn try_evaluate_at_bits(&self, a_bits: &[bool], b_bits: &[bool]) -> Result<FieldElement> {
+        if a_bits.len() != self.num_vars || b_bits.len() != self.num_vars {
+            return Err(TwistAndShoutError::Polynomial(format!(
+                "Expected {}-bit operands, got {} and {} bits",
+                self.num_vars, a_bits.len(), b_bits.len()
+            )));
+        }
         
-        // Lexicographic comparison: a < b iff there exists i such that
-        // a[0..i] = b[0..i] and a[i] = 0, b[i] = 1
-        for i in 0..self.num_vars {
+        // Scanning from the most significant bit down, a < b iff at the
+        // first differing position i, a[i] = 0 and b[i] = 1
+        for i in (0..self.num_vars).rev() {
             if a_bits[i] && !b_bits[i] {
-                return FieldElement::zero(); // a > b at position i
+                return Ok(FieldElement::zero()); // a > b at position i
             }
             if !a_bits[i] && b_bits[i] {
-                return FieldElement::one();  // a < b at position i
+                return Ok(FieldElement::one());  // a < b at position i
             }
             // If a_bits[i] == b_bits[i], continue to next position
         }
         
-        FieldElement::zero() // a == b
+        Ok(FieldElement::zero()) // a == b
     }
     
     /// Get the multilinear extension of the less-than function
-    /// Returns MLE over 2n variables (n for each input)
+    /// Returns MLE over 2n variables: a's bits, then b's, each little-endian
     pub fn to_multilinear_extension(&self) -> MultilinearExtension {
         let total_vars = 2 * self.num_vars;
         let size = 1 << total_vars;
@@ -262,6 +412,12 @@ impl LessThanPolynomial {
         }
     }
     
+    /// Check whether a field element is below 2^num_vars
+    fn fits_in_bits(&self, elem: FieldElement) -> bool {
+        use ark_ff::{BigInteger, PrimeField};
+        elem.into_bigint().num_bits() as usize <= self.num_vars
+    }
+    
     /// Convert field element to bit representation (little-endian)
     fn field_to_bits(&self, elem: FieldElement) -> Vec<bool> {
         let mut bits = Vec::with_capacity(self.num_vars);
@@ -282,7 +438,7 @@ impl LessThanPolynomial {
         bits
     }
     
-    /// Convert index to bit representation
+    /// Convert index to bit representation (little-endian)
     fn index_to_bits(&self, index: usize) -> Vec<bool> {
         let mut bits = Vec::with_capacity(self.num_vars);
         for i in 0..self.num_vars {
@@ -292,6 +448,50 @@ impl LessThanPolynomial {
     }
 }
 
+/// Evaluate eq(a, b) = ∏_i (a_i b_i + (1-a_i)(1-b_i)) in O(n)
+/// Verifier-side companion to `poly_utils::eq_table`
+pub fn eq_eval(a: &[FieldElement], b: &[FieldElement]) -> Result<FieldElement> {
+    if a.len() != b.len() {
+        return Err(TwistAndShoutError::Polynomial(format!(
+            "eq_eval operands have {} and {} coordinates", a.len(), b.len()
+        )));
+    }
+    
+    Ok(a.iter()
+        .zip(b.iter())
+        .map(|(&a_i, &b_i)| a_i * b_i + (FieldElement::one() - a_i) * (FieldElement::one() - b_i))
+        .product())
+}
+
+/// Return the first boolean index at which a constraint MLE is nonzero
+pub fn debug_find_violation(constraint: &MultilinearExtension) -> Option<usize> {
+    constraint.evaluations.iter().position(|eval| !eval.is_zero())
+}
+
+/// Tabulate a constraint over the boolean hypercube and report the first violating row
+/// This costs O(2^n) constraint evaluations, so it is only run in debugging mode
+pub(crate) fn check_constraint_rows<C>(num_vars: usize, constraint: &C, name: &str) -> Result<()>
+where
+    C: Fn(&[FieldElement]) -> FieldElement,
+{
+    let evaluations = (0..1usize << num_vars)
+        .map(|index| {
+            let point = (0..num_vars)
+                .map(|j| if (index >> j) & 1 == 1 { FieldElement::one() } else { FieldElement::zero() })
+                .collect::<Vec<_>>();
+            constraint(&point)
+        })
+        .collect();
+    let table = MultilinearExtension::from_evaluations_vec(num_vars, evaluations);
+    
+    match debug_find_violation(&table) {
+        Some(row) => Err(TwistAndShoutError::ProofGeneration(format!(
+            "{} constraint violated at operation row {}", name, row
+        ))),
+        None => Ok(()),
+    }
+}
+
 /// Utility functions for polynomial operations
 pub mod poly_utils {
     use super::*;
@@ -351,11 +551,400 @@ pub mod poly_utils {
         result
     }
     
+    /// Interpolate the polynomial taking `evals[i]` at X = i, the same
+    /// interpolant as [`lagrange_interpolate`] over 0, 1, ..., n-1.
+    ///
+    /// Power-of-two lengths run in O(n log² n) on NTTs over
+    /// `Radix2EvaluationDomain` subgroups: the Newton coefficients
+    /// cₖ = Δᵏf(0)/k! are a single convolution of f(j)/j! with (-1)ᵐ/m!, and
+    /// Σₖ cₖ·X(X-1)···(X-k+1) is expanded by splitting the sum in halves.
+    /// Rows stay on the integer domain rather than the subgroup itself, since
+    /// prefix, shift and vanishing-polynomial arguments rely on row i sitting
+    /// at X = i. Other lengths fall back to Lagrange interpolation.
+    pub fn interpolate_domain(evals: &[FieldElement]) -> Vec<FieldElement> {
+        let n = evals.len();
+        if !n.is_power_of_two() {
+            let points: Vec<(FieldElement, FieldElement)> = evals
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| (FieldElement::from(i as u64), value))
+                .collect();
+            return lagrange_interpolate(&points);
+        }
+        
+        // Inverse factorials 1/0!, ..., 1/(n-1)! from a single inversion
+        let mut inverse_factorials = vec![FieldElement::one(); n];
+        let factorial = (1..n as u64).map(FieldElement::from).product::<FieldElement>();
+        inverse_factorials[n - 1] = factorial.inverse().expect("Factorials below the characteristic are nonzero");
+        for k in (1..n).rev() {
+            inverse_factorials[k - 1] = inverse_factorials[k] * FieldElement::from(k as u64);
+        }
+        
+        let scaled: Vec<FieldElement> = evals.iter().zip(&inverse_factorials).map(|(&f, &inv)| f * inv).collect();
+        let alternating: Vec<FieldElement> = inverse_factorials
+            .iter()
+            .enumerate()
+            .map(|(m, &inv)| if m % 2 == 0 { inv } else { -inv })
+            .collect();
+        let mut newton = multiply_ntt(&scaled, &alternating);
+        newton.truncate(n);
+        
+        let (mut coeffs, _) = expand_newton(&newton, 0, false);
+        coeffs.resize(n, FieldElement::zero());
+        coeffs
+    }
+    
+    /// Coefficients of p(X + c) from those of p, by one NTT product: with
+    /// aⱼ = j!·pⱼ and bₘ = cᵐ/m!, the coefficient of Xᵏ is (1/k!)·Σₘ a_{k+m}·bₘ
+    pub fn shift_polynomial(coeffs: &[FieldElement], shift: FieldElement) -> Vec<FieldElement> {
+        let n = coeffs.len();
+        if n == 0 {
+            return vec![];
+        }
+        
+        let mut factorials = vec![FieldElement::one(); n];
+        for k in 1..n {
+            factorials[k] = factorials[k - 1] * FieldElement::from(k as u64);
+        }
+        let mut inverse_factorials = vec![FieldElement::one(); n];
+        inverse_factorials[n - 1] = factorials[n - 1].inverse().expect("Factorials below the characteristic are nonzero");
+        for k in (1..n).rev() {
+            inverse_factorials[k - 1] = inverse_factorials[k] * FieldElement::from(k as u64);
+        }
+        
+        let reversed: Vec<FieldElement> = coeffs.iter().zip(&factorials).map(|(&p, &f)| p * f).rev().collect();
+        let mut power = FieldElement::one();
+        let powers: Vec<FieldElement> = inverse_factorials
+            .iter()
+            .map(|&inverse| {
+                let term = power * inverse;
+                power *= shift;
+                term
+            })
+            .collect();
+        let product = multiply_ntt(&reversed, &powers);
+        (0..n).map(|k| inverse_factorials[k] * product[n - 1 - k]).collect()
+    }
+    
+    /// Expand Σₖ cₖ·∏_{lo ≤ j < lo+k} (X - j) for the Newton coefficients
+    /// `newton` on the nodes lo, lo+1, ..., also returning the node product
+    /// ∏_{lo ≤ j < lo+len} (X - j) when `with_nodes` is set
+    fn expand_newton(newton: &[FieldElement], lo: usize, with_nodes: bool) -> (Vec<FieldElement>, Vec<FieldElement>) {
+        if newton.len() == 1 {
+            let nodes = if with_nodes {
+                vec![-FieldElement::from(lo as u64), FieldElement::one()]
+            } else {
+                Vec::new()
+            };
+            return (vec![newton[0]], nodes);
+        }
+        
+        // The upper half's terms all carry the lower half's node product
+        let mid = newton.len() / 2;
+        let (low, low_nodes) = expand_newton(&newton[..mid], lo, true);
+        let (high, high_nodes) = expand_newton(&newton[mid..], lo + mid, with_nodes);
+        
+        let mut expanded = multiply_ntt(&low_nodes, &high);
+        for (coeff, &c) in expanded.iter_mut().zip(&low) {
+            *coeff += c;
+        }
+        let nodes = if with_nodes {
+            multiply_ntt(&low_nodes, &high_nodes)
+        } else {
+            Vec::new()
+        };
+        (expanded, nodes)
+    }
+    
+    /// Product of two polynomials, by NTT over a `Radix2EvaluationDomain`
+    /// once both are long enough for it to beat schoolbook multiplication
+    pub fn multiply_ntt(a: &[FieldElement], b: &[FieldElement]) -> Vec<FieldElement> {
+        const SCHOOLBOOK_LEN: usize = 32;
+        if a.len().min(b.len()) <= SCHOOLBOOK_LEN {
+            return multiply_polynomials(a, b);
+        }
+        
+        let len = a.len() + b.len() - 1;
+        let domain = Radix2EvaluationDomain::<FieldElement>::new(len)
+            .expect("Product degree exceeds the field's two-adicity");
+        let mut product = domain.fft(a);
+        for (value, other) in product.iter_mut().zip(domain.fft(b)) {
+            *value *= other;
+        }
+        domain.ifft_in_place(&mut product);
+        product.truncate(len);
+        product
+    }
+    
+    /// Multilinear extension of the row index, Σⱼ rⱼ·2ʲ
+    ///
+    /// At a Boolean point this is the index of that vertex, with bit j set by
+    /// `point[j]` as in `MultilinearExtension`. It is the public identity column
+    /// of a sum-check, not a place to open committed columns: a univariate
+    /// interpolant U composed with this map is not multilinear, so U at this
+    /// value is not the column's MLE at `point`. Committed columns are opened at
+    /// the point itself through `ColumnOpening`.
+    pub fn hypercube_index(point: &[FieldElement]) -> FieldElement {
+        point
+            .iter()
+            .rev()
+            .fold(FieldElement::zero(), |index, &r_j| index.double() + r_j)
+    }
+    
+    /// Exact base-2 logarithm of a power-of-two length
+    ///
+    /// Panics if `len` is not a power of two.
+    pub fn log2_exact(len: usize) -> usize {
+        assert!(len.is_power_of_two(), "Length {} must be a power of 2", len);
+        len.trailing_zeros() as usize
+    }
+    
+    /// Build the table eq(r, x) for every x ∈ {0,1}^n in O(2^n)
+    /// Bit j of the table index corresponds to r[j], matching `MultilinearExtension`
+    pub fn eq_table(r: &[FieldElement]) -> Vec<FieldElement> {
+        let mut table = vec![FieldElement::zero(); 1 << r.len()];
+        table[0] = FieldElement::one();
+        
+        // Doubling step j splits each entry t into t·(1 - r_j) and t·r_j,
+        // writing the r_j half above the current prefix
+        for (j, &r_j) in r.iter().enumerate() {
+            let size = 1 << j;
+            for i in 0..size {
+                let high = table[i] * r_j;
+                table[i + size] = high;
+                table[i] -= high;
+            }
+        }
+        
+        table
+    }
+    
+    /// Coefficients of the vanishing polynomial Z(X) = ∏_{i<n} (X - i) of the
+    /// integer domain {0, ..., n-1}, by a product tree of NTT products
+    pub fn vanishing_polynomial(n: usize) -> Vec<FieldElement> {
+        vanishing_range(0, n)
+    }
+    
+    /// ∏_{lo ≤ i < hi} (X - i)
+    fn vanishing_range(lo: usize, hi: usize) -> Vec<FieldElement> {
+        const SCHOOLBOOK_LEN: usize = 32;
+        if hi - lo > SCHOOLBOOK_LEN {
+            let mid = lo + (hi - lo) / 2;
+            return multiply_ntt(&vanishing_range(lo, mid), &vanishing_range(mid, hi));
+        }
+        
+        let mut coeffs = vec![FieldElement::one()];
+        for i in lo..hi {
+            let root = FieldElement::from(i as u64);
+            let mut next = vec![FieldElement::zero(); coeffs.len() + 1];
+            for (k, &c) in coeffs.iter().enumerate() {
+                next[k + 1] += c;
+                next[k] -= c * root;
+            }
+            coeffs = next;
+        }
+        
+        coeffs
+    }
+    
+    /// Quotient of `dividend` by a monic `divisor`, dropping the remainder.
+    ///
+    /// Runs in O(n log n): reversing both polynomials turns the quotient into
+    /// a power series product, with the reversed divisor inverted by Newton
+    /// iteration.
+    pub fn divide_by_monic(dividend: &[FieldElement], divisor: &[FieldElement]) -> Vec<FieldElement> {
+        assert_eq!(divisor.last(), Some(&FieldElement::one()), "Divisor must be monic");
+        if dividend.len() < divisor.len() {
+            return Vec::new();
+        }
+        
+        let len = dividend.len() - divisor.len() + 1;
+        let reversed_divisor: Vec<FieldElement> = divisor.iter().rev().take(len).copied().collect();
+        let reversed_dividend: Vec<FieldElement> = dividend.iter().rev().take(len).copied().collect();
+        
+        // 1 / rev(divisor) mod X^len, doubling the precision each step
+        let mut inverse = vec![FieldElement::one()];
+        while inverse.len() < len {
+            let precision = (2 * inverse.len()).min(len);
+            let truncated = &reversed_divisor[..reversed_divisor.len().min(precision)];
+            let mut error = multiply_ntt(truncated, &inverse);
+            error.resize(precision, FieldElement::zero());
+            for coeff in error.iter_mut() {
+                *coeff = -*coeff;
+            }
+            error[0] += FieldElement::from(2u64);
+            inverse = multiply_ntt(&inverse, &error);
+            inverse.truncate(precision);
+        }
+        
+        let mut quotient = multiply_ntt(&reversed_dividend, &inverse);
+        quotient.resize(len, FieldElement::zero());
+        quotient.reverse();
+        quotient
+    }
+    
+    /// L_i(z) for every Lagrange basis polynomial of the integer domain
+    /// {0, ..., n-1}, in O(n) with one batch inversion.
+    ///
+    /// A column committed as its interpolant P over 0, 1, 2, ... has
+    /// P(z) = Σᵢ L_i(z)·column[i], which ties the univariate commitment to
+    /// the column's multilinear extension.
+    pub fn lagrange_basis_at(n: usize, z: FieldElement) -> Vec<FieldElement> {
+        let mut basis = vec![FieldElement::zero(); n];
+        if let Some(i) = domain_index(n, z) {
+            basis[i] = FieldElement::one();
+            return basis;
+        }
+        
+        // L_i(z) = Z(z) / ((z - i)·w_i) with w_i = ∏_{j≠i} (i - j) = (-1)^(n-1-i)·i!·(n-1-i)!
+        let mut factorials = vec![FieldElement::one(); n.max(1)];
+        for k in 1..n {
+            factorials[k] = factorials[k - 1] * FieldElement::from(k as u64);
+        }
+        let denominators: Vec<FieldElement> = (0..n)
+            .map(|i| {
+                let weight = factorials[i] * factorials[n - 1 - i];
+                let signed = if (n - 1 - i).is_multiple_of(2) { weight } else { -weight };
+                (z - FieldElement::from(i as u64)) * signed
+            })
+            .collect();
+        let vanishing: FieldElement = (0..n).map(|i| z - FieldElement::from(i as u64)).product();
+        
+        for (entry, inverse) in basis.iter_mut().zip(field_utils::batch_inverse(&denominators)) {
+            *entry = vanishing * inverse;
+        }
+        basis
+    }
+    
+    /// Multilinear extension at `point` of [`lagrange_basis_at`]`(n, z)`,
+    /// zero-padded to `2^point.len()` rows: Σ_{i<n} eq(point, i)·L_i(z).
+    ///
+    /// Runs in O(n·|point|) without allocating, accumulating the sum as a
+    /// single fraction so only one inversion is needed.
+    pub fn lagrange_basis_mle(n: usize, z: FieldElement, point: &[FieldElement]) -> FieldElement {
+        if n == 0 {
+            return FieldElement::zero();
+        }
+        if let Some(i) = domain_index(n, z) {
+            return eq_at_index(point, i);
+        }
+        
+        // 1/w_i = u_i / v_i, starting from 1/w_0 = (-1)^(n-1) / (n-1)! and
+        // stepping by w_i / w_(i+1) = -(n-1-i) / (i+1)
+        let mut u = if (n - 1).is_multiple_of(2) { FieldElement::one() } else { -FieldElement::one() };
+        let mut v: FieldElement = (1..n).map(|k| FieldElement::from(k as u64)).product();
+        let mut vanishing = FieldElement::one();
+        let (mut numerator, mut denominator) = (FieldElement::zero(), FieldElement::one());
+        for i in 0..n {
+            let offset = z - FieldElement::from(i as u64);
+            vanishing *= offset;
+            
+            // Add eq(point, i)·u_i / ((z - i)·v_i)
+            let term_denominator = offset * v;
+            numerator = numerator * term_denominator + denominator * eq_at_index(point, i) * u;
+            denominator *= term_denominator;
+            
+            u *= -FieldElement::from((n - 1 - i) as u64);
+            v *= FieldElement::from((i + 1) as u64);
+        }
+        
+        vanishing * numerator * denominator.inverse().expect("z lies outside the domain")
+    }
+    
+    /// Multilinear extension of the indicator of rows below `n` at `point`,
+    /// in O(|point|); rows are numbered as in `MultilinearExtension`
+    pub fn prefix_selector(point: &[FieldElement], n: usize) -> FieldElement {
+        if n.checked_shr(point.len() as u32).unwrap_or(0) != 0 {
+            return FieldElement::one();
+        }
+        
+        // x < n exactly when x and n agree above some bit b where n has a 1 and x a 0
+        let mut below = FieldElement::zero();
+        let mut agree = FieldElement::one();
+        for (b, &r_b) in point.iter().enumerate().rev() {
+            if (n >> b) & 1 == 1 {
+                below += agree * (FieldElement::one() - r_b);
+                agree *= r_b;
+            } else {
+                agree *= FieldElement::one() - r_b;
+            }
+        }
+        below
+    }
+    
+    /// eq(point, i) for the hypercube vertex with index `i`, zero if `i` has
+    /// more bits than `point`
+    pub fn eq_at_index(point: &[FieldElement], i: usize) -> FieldElement {
+        if i.checked_shr(point.len() as u32).unwrap_or(0) != 0 {
+            return FieldElement::zero();
+        }
+        
+        point
+            .iter()
+            .enumerate()
+            .map(|(j, &r_j)| if (i >> j) & 1 == 1 { r_j } else { FieldElement::one() - r_j })
+            .product()
+    }
+    
+    /// The integer `z` as a row of {0, ..., n-1}, if it is one
+    fn domain_index(n: usize, z: FieldElement) -> Option<usize> {
+        use ark_ff::PrimeField;
+        let index = z.into_bigint();
+        (index < <FieldElement as PrimeField>::BigInt::from(n as u64)).then(|| index.as_ref()[0] as usize)
+    }
+    
     /// Evaluate polynomial using Horner's method
     pub fn evaluate_polynomial(coeffs: &[FieldElement], point: FieldElement) -> FieldElement {
         field_utils::horner_eval(coeffs, point)
     }
     
+    /// Coefficients of the univariate polynomial f̃(X, X², X⁴, ...) for the
+    /// multilinear extension f̃ with the given hypercube evaluations.
+    ///
+    /// The coefficient of X^y is Σ_{x ⊆ y} (-1)^{|y \ x|} f(x) over the bits
+    /// of y, so this is the subset Möbius transform of the evaluations.
+    pub fn multilinear_to_univariate(evaluations: &[FieldElement]) -> Vec<FieldElement> {
+        let mut coeffs = evaluations.to_vec();
+        let mut bit = 1;
+        while bit < coeffs.len() {
+            for y in 0..coeffs.len() {
+                if y & bit != 0 {
+                    let subset = coeffs[y ^ bit];
+                    coeffs[y] -= subset;
+                }
+            }
+            bit <<= 1;
+        }
+        coeffs
+    }
+    
+    /// Product of two polynomials in coefficient form
+    pub fn multiply_polynomials(a: &[FieldElement], b: &[FieldElement]) -> Vec<FieldElement> {
+        if a.is_empty() || b.is_empty() {
+            return Vec::new();
+        }
+        
+        let mut product = vec![FieldElement::zero(); a.len() + b.len() - 1];
+        for (i, &a_i) in a.iter().enumerate() {
+            for (j, &b_j) in b.iter().enumerate() {
+                product[i + j] += a_i * b_j;
+            }
+        }
+        product
+    }
+    
+    /// Coefficients of outer(inner(X)), by Horner's method over polynomials
+    pub fn compose_polynomials(outer: &[FieldElement], inner: &[FieldElement]) -> Vec<FieldElement> {
+        outer.iter().rev().fold(Vec::new(), |acc, &coeff| {
+            let mut next = multiply_polynomials(&acc, inner);
+            if next.is_empty() {
+                next.push(FieldElement::zero());
+            }
+            next[0] += coeff;
+            next
+        })
+    }
+    
     /// Compute the derivative of a polynomial
     pub fn derivative(coeffs: &[FieldElement]) -> Vec<FieldElement> {
         if coeffs.len() <= 1 {
@@ -412,6 +1001,23 @@ mod tests {
         assert_eq!(mle.evaluate(&[r1, r2]), expected);
     }
     
+    #[test]
+    fn test_log2_exact() {
+        for k in 0..=24 {
+            assert_eq!(poly_utils::log2_exact(1 << k), k);
+        }
+        for k in 0..=10 {
+            let mle = MultilinearExtension::from_evaluations(vec![FieldElement::one(); 1 << k]);
+            assert_eq!(mle.num_vars, k);
+        }
+    }
+    
+    #[test]
+    #[should_panic(expected = "Length 12 must be a power of 2")]
+    fn test_from_evaluations_rejects_non_power_of_two() {
+        MultilinearExtension::from_evaluations(vec![FieldElement::zero(); 12]);
+    }
+    
     #[test]
     fn test_one_hot_polynomial() {
         let mle = MultilinearExtension::one_hot(3, 5); // Index 5 = 101 in binary
@@ -439,7 +1045,9 @@ mod tests {
         assert_eq!(lt_poly.evaluate_at_bits(&[true, false, false], &[true, false, false]), 
                   FieldElement::zero()); // 1 == 1
         assert_eq!(lt_poly.evaluate_at_bits(&[false, true, false], &[true, false, false]), 
-                  FieldElement::one());  // 2 < 1 in little-endian: false
+                  FieldElement::zero()); // 2 > 1
+        assert_eq!(lt_poly.evaluate_at_bits(&[true, false, false], &[false, true, false]), 
+                  FieldElement::one());  // 1 < 2
     }
     
     #[test]
@@ -475,4 +1083,159 @@ mod tests {
         let scaled = mle1.scalar_mul(FieldElement::from(3u64));
         assert_eq!(scaled.evaluations, vec![FieldElement::from(3u64), FieldElement::from(6u64)]);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_interpolate_domain_matches_lagrange() {
+        let mut rng = test_rng();
+        
+        // Lengths up to 128 reach the NTT multiplication path; 6 falls back
+        for n in [1, 2, 4, 8, 64, 128, 6] {
+            let evals: Vec<FieldElement> = (0..n).map(|_| FieldElement::rand(&mut rng)).collect();
+            let points: Vec<(FieldElement, FieldElement)> = evals
+                .iter()
+                .enumerate()
+                .map(|(i, &value)| (FieldElement::from(i as u64), value))
+                .collect();
+            assert_eq!(poly_utils::interpolate_domain(&evals), poly_utils::lagrange_interpolate(&points), "n = {}", n);
+        }
+        
+        assert!(poly_utils::interpolate_domain(&[]).is_empty());
+    }
+    
+    #[test]
+    fn test_shift_polynomial_matches_evaluation() {
+        let mut rng = test_rng();
+        
+        // 64 coefficients reach the NTT multiplication path
+        for n in [1, 3, 64] {
+            let coeffs: Vec<FieldElement> = (0..n).map(|_| FieldElement::rand(&mut rng)).collect();
+            let (shift, x) = (FieldElement::rand(&mut rng), FieldElement::rand(&mut rng));
+            let shifted = poly_utils::shift_polynomial(&coeffs, shift);
+            assert_eq!(shifted.len(), n);
+            assert_eq!(
+                poly_utils::evaluate_polynomial(&shifted, x),
+                poly_utils::evaluate_polynomial(&coeffs, x + shift),
+                "n = {}",
+                n
+            );
+        }
+        
+        assert!(poly_utils::shift_polynomial(&[], FieldElement::one()).is_empty());
+    }
+    
+    #[test]
+    fn test_divide_by_monic_matches_long_division() {
+        let mut rng = test_rng();
+        
+        // A 100-root vanishing polynomial reaches the product tree, and a
+        // 250-coefficient dividend the NTT products of the Newton iteration
+        for (n, roots) in [(5, 2), (250, 100), (101, 100), (3, 7)] {
+            let dividend: Vec<FieldElement> = (0..n).map(|_| FieldElement::rand(&mut rng)).collect();
+            let divisor = poly_utils::vanishing_polynomial(roots);
+            assert_eq!(
+                poly_utils::evaluate_polynomial(&divisor, FieldElement::from(roots as u64 / 2)),
+                FieldElement::zero()
+            );
+            
+            let expected = crate::commitments::polynomial_division(&dividend, &divisor).unwrap();
+            assert_eq!(poly_utils::divide_by_monic(&dividend, &divisor), expected, "n = {}, roots = {}", n, roots);
+        }
+    }
+    
+    #[test]
+    fn test_eq_eval_matches_eq_table() {
+        let mut rng = test_rng();
+        let r: Vec<FieldElement> = (0..3).map(|_| FieldElement::rand(&mut rng)).collect();
+        let table = poly_utils::eq_table(&r);
+        assert_eq!(table.len(), 8);
+        
+        for (index, &entry) in table.iter().enumerate() {
+            let x = (0..3).map(|j| {
+                if (index >> j) & 1 == 1 { FieldElement::one() } else { FieldElement::zero() }
+            }).collect::<Vec<_>>();
+            assert_eq!(eq_eval(&r, &x).unwrap(), entry);
+        }
+        
+        // Points of different dimensions are an error rather than a panic
+        assert!(eq_eval(&r, &r[..2]).is_err());
+    }
+    
+    #[test]
+    fn test_hypercube_index() {
+        for index in 0..16u64 {
+            let bits: Vec<FieldElement> = (0..4).map(|j| FieldElement::from((index >> j) & 1)).collect();
+            assert_eq!(poly_utils::hypercube_index(&bits), FieldElement::from(index));
+        }
+        
+        // Multilinear in each coordinate
+        let mut rng = test_rng();
+        let r: Vec<FieldElement> = (0..3).map(|_| FieldElement::rand(&mut rng)).collect();
+        let expected = r[0] + r[1].double() + r[2] * FieldElement::from(4u64);
+        assert_eq!(poly_utils::hypercube_index(&r), expected);
+        let identity = MultilinearExtension::from_evaluations((0..8u64).map(FieldElement::from).collect());
+        assert_eq!(identity.evaluate(&r), expected);
+        
+        // A non-linear column's MLE is not its interpolant at the index
+        let squares: Vec<FieldElement> = (0..8u64).map(|i| FieldElement::from(i * i)).collect();
+        let interpolant = poly_utils::interpolate_domain(&squares);
+        assert_ne!(
+            MultilinearExtension::from_evaluations(squares).evaluate(&r),
+            poly_utils::evaluate_polynomial(&interpolant, expected)
+        );
+        assert_eq!(poly_utils::hypercube_index(&[]), FieldElement::zero());
+    }
+    
+    #[test]
+    fn test_eq_table_evaluation() {
+        let mut rng = test_rng();
+        let r: Vec<FieldElement> = (0..4).map(|_| FieldElement::rand(&mut rng)).collect();
+        assert_eq!(poly_utils::eq_table(&r).iter().sum::<FieldElement>(), FieldElement::one());
+        assert_eq!(poly_utils::eq_table(&[]), vec![FieldElement::one()]);
+        
+        // Evaluation through eq(r, ·) matches folding one variable at a time
+        let evaluations: Vec<FieldElement> = (0..16).map(|_| FieldElement::rand(&mut rng)).collect();
+        let mle = MultilinearExtension::from_evaluations(evaluations);
+        let mut folded = mle.clone();
+        for &r_j in &r {
+            folded.fix_first_variable(r_j);
+        }
+        assert_eq!(mle.evaluate(&r), folded.evaluations[0]);
+    }
+    
+    #[test]
+    fn test_debug_find_violation() {
+        let mut evaluations = vec![FieldElement::zero(); 8];
+        assert_eq!(debug_find_violation(&MultilinearExtension::from_evaluations(evaluations.clone())), None);
+        
+        evaluations[5] = FieldElement::from(3u64);
+        evaluations[6] = FieldElement::one();
+        let constraint = MultilinearExtension::from_evaluations(evaluations);
+        assert_eq!(debug_find_violation(&constraint), Some(5));
+        
+        // The closure form used by the provers reports the same row
+        let err = check_constraint_rows(3, &|vars: &[FieldElement]| constraint.evaluate(vars), "Test")
+            .unwrap_err();
+        assert!(err.to_string().contains("row 5"), "unexpected error: {}", err);
+    }
+    
+    #[test]
+    fn test_folding_matches_basis_evaluation() {
+        let mut rng = test_rng();
+        let num_vars = 10;
+        
+        for _ in 0..3 {
+            let evaluations: Vec<FieldElement> = (0..1 << num_vars).map(|_| FieldElement::rand(&mut rng)).collect();
+            let mle = MultilinearExtension::from_evaluations(evaluations);
+            let point: Vec<FieldElement> = (0..num_vars).map(|_| FieldElement::rand(&mut rng)).collect();
+            
+            assert_eq!(mle.evaluate(&point), mle.evaluate_by_basis(&point));
+            assert_eq!(mle.evaluate_with_eq(&poly_utils::eq_table(&point)), mle.evaluate(&point));
+            
+            // Folding the first k variables leaves an MLE in the remaining ones
+            let partial = mle.partial_evaluate(&point[..4]);
+            assert_eq!(partial.num_vars, num_vars - 4);
+            assert_eq!(partial.evaluations.len(), 1 << (num_vars - 4));
+            assert_eq!(partial.evaluate_by_basis(&point[4..]), mle.evaluate_by_basis(&point));
+        }
+    }
+}
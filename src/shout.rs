@@ -3,14 +3,18 @@
 //! The Shout protocol provides efficient zero-knowledge proofs for lookup operations
 //! in read-only tables, optimized for static lookup scenarios.
 
-use crate::utils::{FieldElement, ProverParams, VerifierParams, Transcript};
-use crate::polynomials::MultilinearExtension;
-use crate::commitments::{CommitmentScheme, KZGCommitment, KZGCommitmentValue, KZGProof};
-use crate::sumcheck::{SumCheck, SumCheckProof};
+use crate::utils::{FieldElement, ProofLayout, ProofParams, ProofSizeBreakdown, ProverParams, VerifierParams, VerifyReport, FiatShamirTranscript, Transcript};
+use crate::polynomials::{check_constraint_rows, eq_eval, poly_utils, MultilinearExtension};
+use crate::commitments::{BatchedMleProof, CommitmentScheme, KZGCommitment, KZGCommitmentValue, KZGProof, MultilinearKZG};
+use crate::sumcheck::{ColumnOpening, SumCheck, SumCheckProof};
 use crate::{Result, TwistAndShoutError};
-use ark_ff::{Field, Zero, One};
-use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use ark_ff::{Field, One, Zero};
+use ark_serialize::CanonicalDeserialize;
+use ark_std::{rand::RngCore, UniformRand};
+use ark_std::borrow::Cow;
+use ark_std::collections::BTreeMap;
+use ark_std::marker::PhantomData;
+use ark_std::{format, string::ToString, vec, vec::Vec};
 
 /// A lookup operation in the table
 #[derive(Debug, Clone, Copy)]
@@ -53,242 +57,2674 @@ impl LookupTable {
         Ok(value)
     }
     
+    /// Look up every index in order, all or nothing.
+    ///
+    /// Indices are checked before anything is recorded, so an out-of-bounds
+    /// index leaves the recorded lookups untouched.
+    pub fn lookup_batch(&mut self, indices: &[usize]) -> Result<Vec<FieldElement>> {
+        if let Some(&index) = indices.iter().find(|&&index| index >= self.entries.len()) {
+            return Err(TwistAndShoutError::InvalidParameters(format!(
+                "Lookup index {} out of bounds for a {}-entry table",
+                index,
+                self.entries.len()
+            )));
+        }
+        
+        let values: Vec<FieldElement> = indices.iter().map(|&index| self.entries[index]).collect();
+        self.lookups.extend(indices.iter().zip(&values).map(|(&index, &value)| LookupOp { index, value }));
+        Ok(values)
+    }
+    
     /// Get the size of the table
     pub fn size(&self) -> usize {
         self.entries.len()
     }
+    
+    /// Concatenate tables into one indexed table.
+    ///
+    /// Returns the merged table and the base offset of each input table, so an
+    /// index `i` into `tables[k]` becomes `offsets[k] + i`. Lookups already
+    /// recorded on the inputs are carried over with their indices translated.
+    pub fn merge(tables: &[LookupTable]) -> (LookupTable, Vec<usize>) {
+        let mut merged = LookupTable::new(Vec::new());
+        let mut offsets = Vec::with_capacity(tables.len());
+        
+        for table in tables {
+            let offset = merged.entries.len();
+            offsets.push(offset);
+            merged.entries.extend_from_slice(&table.entries);
+            merged.lookups.extend(table.lookups.iter().map(|op| LookupOp {
+                index: offset + op.index,
+                value: op.value,
+            }));
+        }
+        
+        (merged, offsets)
+    }
+    
+    /// Multilinear extension of the entries, zero-padded to a power of two
+    pub fn to_mle(&self) -> MultilinearExtension {
+        let table_size = self.entries.len().next_power_of_two();
+        let mut padded_entries = self.entries.clone();
+        padded_entries.resize(table_size, FieldElement::zero());
+        
+        MultilinearExtension::from_evaluations_vec(table_size.trailing_zeros() as usize, padded_entries)
+    }
+}
+
+/// A lookup table too large to materialize, given by its multilinear extension.
+///
+/// Entry `i` is T̃ at the bits of `i`, least significant first, so every entry
+/// is a known function of the index's chunks. A bitwise AND of two k-bit
+/// operands packed as `a + 2^k·b`, for instance, has T̃ = Σₗ 2ˡ aₗbₗ.
+/// [`Shout::prove_structured`] only ever evaluates T̃ at single points.
+pub trait StructuredTable {
+    /// Number of index bits; the table has 2^num_vars entries
+    fn num_vars(&self) -> usize;
+    
+    /// Evaluate the table's multilinear extension T̃ at `point`
+    fn evaluate_mle(&self, point: &[FieldElement]) -> FieldElement;
+    
+    /// Total degree of T̃, which bounds the degree of the lookup sum-check
+    fn degree(&self) -> usize {
+        self.num_vars()
+    }
+    
+    /// Table entry at `index`
+    fn entry(&self, index: usize) -> FieldElement {
+        let bits: Vec<FieldElement> = (0..self.num_vars())
+            .map(|l| FieldElement::from(((index >> l) & 1) as u64))
+            .collect();
+        self.evaluate_mle(&bits)
+    }
+}
+
+/// The table T[i] = i over `num_bits`-bit indices. A lookup of `v` shows
+/// 0 ≤ v < 2^num_bits.
+#[derive(Debug, Clone, Copy)]
+pub struct RangeCheckTable {
+    pub num_bits: usize,
+}
+
+impl RangeCheckTable {
+    /// Range-check table for `num_bits`-bit values
+    pub fn new(num_bits: usize) -> Self {
+        Self { num_bits }
+    }
+}
+
+impl StructuredTable for RangeCheckTable {
+    fn num_vars(&self) -> usize {
+        self.num_bits
+    }
+    
+    /// T̃(x) = Σₗ 2ˡ xₗ
+    fn evaluate_mle(&self, point: &[FieldElement]) -> FieldElement {
+        point.iter().rev().fold(FieldElement::zero(), |acc, &bit| acc.double() + bit)
+    }
+    
+    fn degree(&self) -> usize {
+        1
+    }
 }
 
 /// Shout protocol proof
 #[derive(Debug, Clone)]
-pub struct ShoutProof {
+pub struct ShoutProof<C: CommitmentScheme = KZGCommitment> {
+    /// Parameters the proof was generated under
+    pub params: ProofParams,
+    
+    /// Number of real table entries; the committed table is zero-padded past
+    /// it to a power of two, and no lookup may land in the padding
+    pub table_size: usize,
+    
+    /// Number of lookups; the index and value columns are padded past it to
+    /// a power of two with lookups of entry 0
+    pub num_lookups: usize,
+    
     /// Commitment to the lookup table
-    pub table_commitment: KZGCommitmentValue,
+    pub table_commitment: C::Commitment,
     
     /// Commitment to lookup indices
-    pub index_commitment: KZGCommitmentValue,
+    pub index_commitment: C::Commitment,
+    
+    /// Commitment to the values read
+    pub value_commitment: C::Commitment,
     
     /// Sum-check proof for lookup correctness
     pub lookup_proof: SumCheckProof,
     
-    /// Opening proofs for verification
-    pub opening_proofs: Vec<KZGProof>,
+    /// Commitments to the lookup-argument columns, opened at the lookup
+    /// sum-check's point
+    pub lookup_opening: ColumnOpening,
+    
+    /// Openings of the table, index and value polynomials at the bridge point
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// Table, index and value polynomials at the bridge point
+    pub final_evaluations: Vec<FieldElement>,
+    
+    /// Argument that the lookups begin with an earlier proof's lookups,
+    /// present only for proofs from [`Shout::prove_extends`]
+    pub extension_proof: Option<PrefixExtensionProof<C>>,
+}
+
+/// Argument that the first `prefix_length` lookup indices of a proof equal
+/// the indices committed by an earlier proof.
+///
+/// Both index polynomials interpolate their indices over 0, 1, 2, ..., so the
+/// prefixes agree exactly when `index(X) - prefix(X) = Z(X)·quotient(X)` with
+/// `Z(X) = ∏_{k < prefix_length} (X - k)`. The identity is checked at a single
+/// challenge point.
+#[derive(Debug, Clone)]
+pub struct PrefixExtensionProof<C: CommitmentScheme = KZGCommitment> {
+    /// Number of lookups in the earlier proof
+    pub prefix_length: usize,
+    
+    /// Commitment to the quotient polynomial
+    pub quotient_commitment: C::Commitment,
+    
+    /// Openings of the index, earlier index and quotient polynomials at the challenge point
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// Evaluations of the index, earlier index and quotient polynomials at the challenge point
+    pub evaluations: Vec<FieldElement>,
+}
+
+impl<C: CommitmentScheme> ark_serialize::Valid for PrefixExtensionProof<C> {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.quotient_commitment.check()?;
+        self.opening_proofs.check()?;
+        self.evaluations.check()
+    }
+}
+
+impl<C: CommitmentScheme> ark_serialize::CanonicalSerialize for PrefixExtensionProof<C> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.prefix_length.serialize_with_mode(&mut writer, compress)?;
+        self.quotient_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.opening_proofs.serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.prefix_length.serialized_size(compress)
+            + self.quotient_commitment.serialized_size(compress)
+            + self.opening_proofs.serialized_size(compress)
+            + self.evaluations.serialized_size(compress)
+    }
+}
+
+impl<C: CommitmentScheme> ark_serialize::CanonicalDeserialize for PrefixExtensionProof<C> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        Ok(Self {
+            prefix_length: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            quotient_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            opening_proofs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            evaluations: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+impl<C: CommitmentScheme> ark_serialize::Valid for ShoutProof<C> {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.params.check()?;
+        self.table_commitment.check()?;
+        self.index_commitment.check()?;
+        self.value_commitment.check()?;
+        self.lookup_proof.check()?;
+        self.lookup_opening.check()?;
+        self.opening_proofs.check()?;
+        self.final_evaluations.check()?;
+        self.extension_proof.check()
+    }
+}
+
+impl<C: CommitmentScheme> ark_serialize::CanonicalSerialize for ShoutProof<C> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.params.serialize_with_mode(&mut writer, compress)?;
+        self.table_size.serialize_with_mode(&mut writer, compress)?;
+        self.num_lookups.serialize_with_mode(&mut writer, compress)?;
+        self.table_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.index_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.value_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.lookup_proof.serialize_with_mode(&mut writer, compress)?;
+        self.lookup_opening.serialize_with_mode(&mut writer, compress)?;
+        self.opening_proofs.serialize_with_mode(&mut writer, compress)?;
+        self.final_evaluations.serialize_with_mode(&mut writer, compress)?;
+        self.extension_proof.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        ark_serialize::CanonicalSerialize::serialized_size(&self.params, compress)
+            + self.table_size.serialized_size(compress)
+            + self.num_lookups.serialized_size(compress)
+            + self.table_commitment.serialized_size(compress)
+            + self.index_commitment.serialized_size(compress)
+            + self.value_commitment.serialized_size(compress)
+            + ark_serialize::CanonicalSerialize::serialized_size(&self.lookup_proof, compress)
+            + self.lookup_opening.serialized_size(compress)
+            + self.opening_proofs.serialized_size(compress)
+            + self.final_evaluations.serialized_size(compress)
+            + self.extension_proof.serialized_size(compress)
+    }
+}
+
+impl<C: CommitmentScheme> ark_serialize::CanonicalDeserialize for ShoutProof<C> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        Ok(Self {
+            params: ProofParams::deserialize_with_mode(&mut reader, compress, validate)?,
+            table_size: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            num_lookups: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            table_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            index_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            value_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            lookup_proof: SumCheckProof::deserialize_with_mode(&mut reader, compress, validate)?,
+            lookup_opening: ColumnOpening::deserialize_with_mode(&mut reader, compress, validate)?,
+            opening_proofs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            final_evaluations: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            extension_proof: Option::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+/// JSON-friendly encoding: the compressed canonical bytes as a hex string
+impl<C: CommitmentScheme> serde::Serialize for ShoutProof<C> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> ark_std::result::Result<S::Ok, S::Error> {
+        let mut bytes = Vec::new();
+        ark_serialize::CanonicalSerialize::serialize_compressed(self, &mut bytes).map_err(serde::ser::Error::custom)?;
+        crate::utils::serialize_hex(&bytes, serializer)
+    }
+}
+
+impl<'de, C: CommitmentScheme> serde::Deserialize<'de> for ShoutProof<C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> ark_std::result::Result<Self, D::Error> {
+        let bytes = crate::utils::deserialize_hex(deserializer)?;
+        ark_serialize::CanonicalDeserialize::deserialize_compressed(&bytes[..]).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<C: CommitmentScheme> ShoutProof<C> {
+    /// Serialize the proof, compressed, in the given layout.
+    ///
+    /// In [`ProofLayout::Recursive`] the header holds `log_size`,
+    /// `max_operations`, the table size, the lookup count, the number of opening proofs, the
+    /// number of final evaluations, and an extension presence flag, followed
+    /// by the round shapes of the lookup sum-check, the lookup column and
+    /// quotient counts and, for an extension, its prefix length, opening count
+    /// and evaluation count. The scalar section holds `vk_hash`, the lookup
+    /// sum-check, the lookup column evaluations, the final evaluations, then
+    /// the extension evaluations. The group section holds the table, index and
+    /// value commitments, the lookup column commitments and quotients, the
+    /// opening proofs, then the extension's quotient commitment and opening proofs.
+    pub fn serialize_with_layout<W: ark_serialize::Write>(
+        &self,
+        layout: ProofLayout,
+        mut writer: W,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        use ark_serialize::CanonicalSerialize;
+        if layout == ProofLayout::Component {
+            return self.serialize_compressed(writer);
+        }
+        
+        let mut header = vec![
+            self.params.log_size as u64,
+            self.params.max_operations as u64,
+            self.table_size as u64,
+            self.num_lookups as u64,
+            self.opening_proofs.len() as u64,
+            self.final_evaluations.len() as u64,
+            self.extension_proof.is_some() as u64,
+        ];
+        self.lookup_proof.push_shape(&mut header);
+        self.lookup_opening.push_shape(&mut header);
+        if let Some(extension) = &self.extension_proof {
+            header.extend([
+                extension.prefix_length as u64,
+                extension.opening_proofs.len() as u64,
+                extension.evaluations.len() as u64,
+            ]);
+        }
+        header.serialize_compressed(&mut writer)?;
+        
+        self.params.vk_hash.serialize_compressed(&mut writer)?;
+        self.lookup_proof.write_scalars(&mut writer)?;
+        self.lookup_opening.write_scalars(&mut writer)?;
+        let extension_evaluations = self.extension_proof.iter().flat_map(|extension| &extension.evaluations);
+        for evaluation in self.final_evaluations.iter().chain(extension_evaluations) {
+            evaluation.serialize_compressed(&mut writer)?;
+        }
+        
+        self.table_commitment.serialize_compressed(&mut writer)?;
+        self.index_commitment.serialize_compressed(&mut writer)?;
+        self.value_commitment.serialize_compressed(&mut writer)?;
+        self.lookup_opening.write_groups(&mut writer)?;
+        for opening in &self.opening_proofs {
+            opening.serialize_compressed(&mut writer)?;
+        }
+        if let Some(extension) = &self.extension_proof {
+            extension.quotient_commitment.serialize_compressed(&mut writer)?;
+            for opening in &extension.opening_proofs {
+                opening.serialize_compressed(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Deserialize a proof written by [`Self::serialize_with_layout`]
+    pub fn deserialize_with_layout<R: ark_serialize::Read>(
+        layout: ProofLayout,
+        mut reader: R,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        use ark_serialize::{CanonicalDeserialize, SerializationError};
+        if layout == ProofLayout::Component {
+            return Self::deserialize_compressed(reader);
+        }
+        
+        let flag = |entry| match entry {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(SerializationError::InvalidData),
+        };
+        let header = Vec::<u64>::deserialize_compressed(&mut reader)?;
+        let mut header = header.into_iter();
+        let log_size = crate::utils::next_header_entry(&mut header)?;
+        let max_operations = crate::utils::next_header_entry(&mut header)?;
+        let table_size = crate::utils::next_header_entry(&mut header)?;
+        let num_lookups = crate::utils::next_header_entry(&mut header)?;
+        let num_openings = crate::utils::next_header_entry(&mut header)?;
+        let num_evaluations = crate::utils::next_header_entry(&mut header)?;
+        let has_extension = flag(crate::utils::next_header_entry(&mut header)?)?;
+        let lookup_shape = SumCheckProof::read_shape(&mut header)?;
+        let lookup_opening_shape = ColumnOpening::read_shape(&mut header)?;
+        let extension_shape = if has_extension {
+            Some((
+                crate::utils::next_header_entry(&mut header)?,
+                crate::utils::next_header_entry(&mut header)?,
+                crate::utils::next_header_entry(&mut header)?,
+            ))
+        } else {
+            None
+        };
+        if header.next().is_some() {
+            return Err(SerializationError::InvalidData);
+        }
+        
+        let vk_hash = FieldElement::deserialize_compressed(&mut reader)?;
+        let lookup_proof = SumCheckProof::read_scalars(&lookup_shape, &mut reader)?;
+        let mut read_scalars = |count: usize| {
+            (0..count)
+                .map(|_| FieldElement::deserialize_compressed(&mut reader))
+                .collect::<ark_std::result::Result<Vec<_>, _>>()
+        };
+        let column_evaluations = read_scalars(lookup_opening_shape.0)?;
+        let final_evaluations = read_scalars(num_evaluations)?;
+        let extension_evaluations = extension_shape
+            .map(|(_, _, num_evaluations)| read_scalars(num_evaluations))
+            .transpose()?;
+        
+        let table_commitment = C::Commitment::deserialize_compressed(&mut reader)?;
+        let index_commitment = C::Commitment::deserialize_compressed(&mut reader)?;
+        let value_commitment = C::Commitment::deserialize_compressed(&mut reader)?;
+        let lookup_opening = ColumnOpening::read_groups(lookup_opening_shape, column_evaluations, &mut reader)?;
+        let opening_proofs = (0..num_openings)
+            .map(|_| C::Proof::deserialize_compressed(&mut reader))
+            .collect::<ark_std::result::Result<Vec<_>, _>>()?;
+        let extension_proof = match (extension_shape, extension_evaluations) {
+            (Some((prefix_length, num_openings, _)), Some(evaluations)) => Some(PrefixExtensionProof {
+                prefix_length,
+                quotient_commitment: C::Commitment::deserialize_compressed(&mut reader)?,
+                opening_proofs: (0..num_openings)
+                    .map(|_| C::Proof::deserialize_compressed(&mut reader))
+                    .collect::<ark_std::result::Result<Vec<_>, _>>()?,
+                evaluations,
+            }),
+            _ => None,
+        };
+        
+        Ok(Self {
+            params: ProofParams { log_size, max_operations, vk_hash },
+            table_size,
+            num_lookups,
+            table_commitment,
+            index_commitment,
+            value_commitment,
+            lookup_proof,
+            lookup_opening,
+            opening_proofs,
+            final_evaluations,
+            extension_proof,
+        })
+    }
+    
+    /// Compressed serialized size of each proof component
+    pub fn size_breakdown(&self) -> ProofSizeBreakdown {
+        use ark_serialize::CanonicalSerialize;
+        
+        // An optional extension argument costs a presence flag plus its prefix
+        // length, quotient commitment and openings
+        let extension = self.extension_proof.as_ref();
+        let extension_inputs = true.compressed_size()
+            + extension.map_or(0, |extension| extension.prefix_length.compressed_size());
+        let extension_commitments = extension.map_or(0, |extension| extension.quotient_commitment.compressed_size());
+        let extension_openings = extension.map_or(0, |extension| {
+            extension.opening_proofs.compressed_size() + extension.evaluations.compressed_size()
+        });
+        
+        ProofSizeBreakdown {
+            public_inputs: self.params.serialized_size()
+                + self.table_size.compressed_size()
+                + self.num_lookups.compressed_size()
+                + extension_inputs,
+            commitments: self.table_commitment.compressed_size()
+                + self.index_commitment.compressed_size()
+                + self.value_commitment.compressed_size()
+                + self.lookup_opening.commitments.compressed_size()
+                + extension_commitments,
+            sumcheck: self.lookup_proof.serialized_size(),
+            openings: self.opening_proofs.compressed_size()
+                + self.final_evaluations.compressed_size()
+                + self.lookup_opening.evaluations.compressed_size()
+                + self.lookup_opening.proof.combined.compressed_size()
+                + extension_openings,
+        }
+    }
+}
+
+/// Lookup table kept private behind a blinded commitment, from
+/// [`Shout::commit_private_table`].
+///
+/// The committed polynomial is `T(X) + Z(X)·R(X)`, where `T` interpolates the
+/// entries over 0, 1, 2, ..., `Z` vanishes on those points and `R` has one
+/// random coefficient per proof the table may serve. It still takes the entry
+/// values on the table domain, and each proof opens it at one fresh point off
+/// the domain: up to `max_proofs` such openings are uniformly random, while
+/// more would start to pin down the entries, so
+/// [`Shout::prove_private_table`] refuses to go past that budget.
+#[derive(Debug, Clone)]
+pub struct PrivateTable<C: CommitmentScheme = KZGCommitment> {
+    entries: Vec<FieldElement>,
+    polynomial: Vec<FieldElement>,
+    public: PrivateTableCommitment<C>,
+    remaining_proofs: usize,
+}
+
+impl<C: CommitmentScheme> PrivateTable<C> {
+    /// Public handle to publish for verifiers
+    pub fn commitment(&self) -> &PrivateTableCommitment<C> {
+        &self.public
+    }
+    
+    /// Number of entries
+    pub fn size(&self) -> usize {
+        self.entries.len()
+    }
+    
+    /// Number of proofs the table's blinding still covers
+    pub fn remaining_proofs(&self) -> usize {
+        self.remaining_proofs
+    }
+}
+
+/// Public side of a [`PrivateTable`], fixed when the table is committed.
+///
+/// Lookups are verified against this handle, so the table size a proof is
+/// checked against is the one published with the commitment rather than one
+/// the prover picks per proof.
+#[derive(Debug, Clone)]
+pub struct PrivateTableCommitment<C: CommitmentScheme = KZGCommitment> {
+    /// Number of entries in the table
+    pub table_size: usize,
+    
+    /// Blinded commitment to the entries
+    pub commitment: C::Commitment,
+}
+
+/// Proof that lookups at public indices into a [`PrivateTable`] return the
+/// values committed in `value_commitment`, revealing neither.
+///
+/// With S the distinct looked-up indices, `Z_S(X) = ∏_{i ∈ S} (X - i)` and
+/// `V(X)` interpolating the value read at each index of S, blinded by a random
+/// multiple of `Z_S`, the lookups are correct exactly when
+/// `T(X) - V(X) = Z_S(X)·quotient(X)`: the table and the values agree on every
+/// looked-up index. The identity is checked at a single challenge point ζ.
+#[derive(Debug, Clone)]
+pub struct PrivateTableProof<C: CommitmentScheme = KZGCommitment> {
+    /// Blinded commitment to the looked-up values, V(i) being the value at index i
+    pub value_commitment: C::Commitment,
+    
+    /// Commitment to the quotient polynomial
+    pub quotient_commitment: C::Commitment,
+    
+    /// Openings of the table, value and quotient polynomials at ζ
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// Evaluations T(ζ), V(ζ) and quotient(ζ)
+    pub evaluations: Vec<FieldElement>,
+}
+
+/// Random coefficients masking the index and value of a private lookup
+const PRIVATE_LOOKUP_MASK_TERMS: usize = 4;
+
+/// Proof from [`Shout::prove_private_lookup`] that a committed value is the
+/// entry of a committed table at a committed index, revealing neither.
+///
+/// The index i and value v sit at X = 0 of `I(X) = i + X·R_I(X)` and
+/// `V(X) = v + X·R_V(X)`, for random R_I and R_V with four coefficients each.
+/// With T interpolating the table over 0, 1, 2, ... and
+/// `Z(X) = ∏_{k < table_size} (X - k)`, the lookup is valid exactly when
+/// `T(I(X)) - V(X) = X·Q_T(X)` and `Z(I(X)) = X·Q_Z(X)`: the value is the
+/// entry at I(0), and I(0) is a real row of the table. Both identities are
+/// checked at a single challenge point ζ, where I and V take masked values.
+#[derive(Debug, Clone)]
+pub struct PrivateLookupProof<C: CommitmentScheme = KZGCommitment> {
+    /// Number of real table entries
+    pub table_size: usize,
+    
+    /// Commitment to the public table
+    pub table_commitment: C::Commitment,
+    
+    /// Masked commitments to the index and the value read
+    pub index_commitment: C::Commitment,
+    pub value_commitment: C::Commitment,
+    
+    /// Commitments to the quotients Q_T and Q_Z
+    pub entry_quotient_commitment: C::Commitment,
+    pub range_quotient_commitment: C::Commitment,
+    
+    /// Openings of T at I(ζ) and of I, V, Q_T and Q_Z at ζ
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// T(I(ζ)), I(ζ), V(ζ), Q_T(ζ) and Q_Z(ζ)
+    pub evaluations: Vec<FieldElement>,
+}
+
+/// Lookups into several tables proven together by [`Shout::prove_multi`].
+///
+/// Every table runs the lookup argument of [`ShoutProof`] over a common row
+/// count, and one sum-check covers all of them through a random combination
+/// of their summands. Each table's table, index and value polynomials are
+/// opened at the same bridge point.
+#[derive(Debug, Clone)]
+pub struct MultiShoutProof<C: CommitmentScheme = KZGCommitment> {
+    /// Parameters the proof was generated under
+    pub params: ProofParams,
+    
+    /// Number of real entries of each table
+    pub table_sizes: Vec<usize>,
+    
+    /// Number of lookups into each table
+    pub num_lookups: Vec<usize>,
+    
+    /// Commitment to each lookup table
+    pub table_commitments: Vec<C::Commitment>,
+    
+    /// Commitment to each table's lookup indices
+    pub index_commitments: Vec<C::Commitment>,
+    
+    /// Commitment to each table's values read
+    pub value_commitments: Vec<C::Commitment>,
+    
+    /// Batched sum-check over every table's lookup argument
+    pub lookup_proof: SumCheckProof,
+    
+    /// Commitments to every table's lookup-argument columns, opened at the
+    /// sum-check's point: each table's four lookup columns in table order,
+    /// then each table's two inverse-fingerprint columns
+    pub lookup_opening: ColumnOpening,
     
-    /// Final evaluations
+    /// Table, index and value openings at the bridge point, grouped per table
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// Table, index and value evaluations at the bridge point, grouped per table
     pub final_evaluations: Vec<FieldElement>,
 }
 
-/// Shout protocol implementation
+/// Proof of lookups into a virtual table defined by a function `f(index)`.
+///
+/// Only the accessed entries are materialized: the inner Shout proof runs over
+/// a compact table of `f` at `accessed_indices`, and `table_opening` lets the
+/// verifier check that compact table against its own evaluations of `f`.
+#[derive(Debug, Clone)]
+pub struct VirtualLookupProof<C: CommitmentScheme = KZGCommitment> {
+    /// Size of the virtual table
+    pub table_size: usize,
+    
+    /// Distinct accessed indices, in increasing order
+    pub accessed_indices: Vec<usize>,
+    
+    /// Shout proof over the compact table of accessed entries
+    pub shout_proof: ShoutProof<C>,
+    
+    /// Opening of the compact table commitment at a transcript challenge
+    pub table_opening: C::Proof,
+}
+
+/// Proof of lookups into a [`StructuredTable`].
+///
+/// The lookup rows are committed as multilinear columns: values V, indices I
+/// and the index bits B₀, ..., Bₙ₋₁. Rows past the last lookup repeat the
+/// first one.
+#[derive(Debug, Clone)]
+pub struct StructuredLookupProof {
+    /// Number of index bits of the table
+    pub table_vars: usize,
+    
+    /// Commitment to the looked-up values
+    pub value_commitment: KZGCommitmentValue,
+    
+    /// Commitment to the lookup indices
+    pub index_commitment: KZGCommitmentValue,
+    
+    /// Commitment to bit `l` of every lookup index, for each `l`
+    pub bit_commitments: Vec<KZGCommitmentValue>,
+    
+    /// Zero-check over the lookup rows
+    pub lookup_proof: SumCheckProof,
+    
+    /// V, I, B₀, ..., Bₙ₋₁ at the sum-check point
+    pub evaluations: Vec<FieldElement>,
+    
+    /// Opening of every column at the sum-check point
+    pub opening: BatchedMleProof,
+}
+
+/// Shout protocol implementation, generic over the polynomial commitment scheme
 #[derive(Debug, Clone)]
-pub struct Shout {
+pub struct Shout<C: CommitmentScheme = KZGCommitment> {
     /// Prover parameters
     prover_params: ProverParams,
+    /// Check every constraint row before proving and report the first violation
+    debug_constraints: bool,
+    _scheme: PhantomData<C>,
 }
 
 impl Shout {
-    /// Create a new Shout instance
+    /// Create a new Shout instance committing with KZG
     pub fn new(prover_params: &ProverParams) -> Self {
-        Self {
-            prover_params: prover_params.clone(),
-        }
+        Self::with_scheme(prover_params)
     }
     
-    /// Generate a proof for lookup correctness
-    pub fn prove(&self, table: &LookupTable) -> Result<ShoutProof> {
-        if table.lookups.len() > self.prover_params.max_operations {
-            return Err(TwistAndShoutError::InvalidParameters(
-                "Too many lookup operations".to_string(),
+    /// Prove that a table update moves `old_commitment` to `old_commitment + delta`,
+    /// where `delta` commits to `new_table - old_table`.
+    ///
+    /// Only the delta is committed and opened at a Fiat-Shamir point, so the
+    /// verifier can check it against the public changes without the tables.
+    pub fn prove_table_update(
+        &self,
+        old_commitment: &KZGCommitmentValue,
+        old_table: &LookupTable,
+        new_table: &LookupTable,
+    ) -> Result<(KZGProof, KZGCommitmentValue)> {
+        if old_table.entries.len() != new_table.entries.len() {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Table update must preserve the table size".to_string(),
             ));
         }
         
-        // Pad table to power of 2
-        let table_size = (table.entries.len() as usize).next_power_of_two();
-        let mut padded_table = table.entries.clone();
-        padded_table.resize(table_size, FieldElement::zero());
+        let table_size = new_table.entries.len().next_power_of_two();
+        let mut old_entries = old_table.entries.clone();
+        let mut new_entries = new_table.entries.clone();
+        old_entries.resize(table_size, FieldElement::zero());
+        new_entries.resize(table_size, FieldElement::zero());
         
-        // Extract lookup indices
-        let indices: Vec<FieldElement> = table.lookups
-            .iter()
-            .map(|lookup| FieldElement::from(lookup.index as u64))
-            .collect();
+        let params = &self.prover_params.commitment_params;
+        let (delta_commitment, delta_poly) = KZGCommitment::commit_delta(params, &old_entries, &new_entries)?;
+        let delta: Vec<FieldElement> = new_table.entries.iter().zip(&old_table.entries).map(|(new, old)| *new - old).collect();
         
-        // Pad indices to power of 2
-        let lookups_size = (indices.len() as usize).next_power_of_two().max(1);
-        let mut padded_indices = indices;
-        padded_indices.resize(lookups_size, FieldElement::zero());
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let point = Self::absorb_table_update(&mut transcript, old_commitment, &delta_commitment, &delta);
+        let (_, opening) = KZGCommitment::open(params, &delta_poly, point)?;
         
-        // Convert to polynomials
-        let table_poly = self.vector_to_polynomial(&padded_table)?;
-        let index_poly = self.vector_to_polynomial(&padded_indices)?;
+        Ok((opening, delta_commitment))
+    }
+    
+    /// Verify a proof from [`Shout::prove_table_update`].
+    ///
+    /// `delta` holds `new - old` for every table entry; the verifier checks that
+    /// `new_commitment = old_commitment + delta_commitment` and that the delta
+    /// commitment opens to the interpolation of `delta` at the challenge point,
+    /// evaluated in O(n) through the Lagrange basis rather than interpolated.
+    pub fn verify_table_update(
+        &self,
+        old_commitment: &KZGCommitmentValue,
+        new_commitment: &KZGCommitmentValue,
+        delta_commitment: &KZGCommitmentValue,
+        proof: &KZGProof,
+        delta: &[FieldElement],
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        if old_commitment.clone() + delta_commitment.clone() != *new_commitment {
+            return Ok(false);
+        }
         
-        // Commit to table and indices
-        let table_commitment = KZGCommitment::commit(
-            &self.prover_params.commitment_params,
-            &table_poly,
-        )?;
+        let Some(table_size) = delta.len().checked_next_power_of_two() else {
+            return Ok(false);
+        };
         
-        let index_commitment = KZGCommitment::commit(
-            &self.prover_params.commitment_params,
-            &index_poly,
-        )?;
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let point = Self::absorb_table_update(&mut transcript, old_commitment, delta_commitment, delta);
+        let value = poly_utils::lagrange_basis_at(table_size, point)
+            .iter()
+            .zip(delta)
+            .map(|(basis, change)| *basis * change)
+            .sum();
+        
+        KZGCommitment::verify(&verifier_params.commitment_vk, delta_commitment, point, value, proof)
+    }
+    
+    /// Absorb the table-update statement, the public changes included, and
+    /// derive the opening point
+    fn absorb_table_update(
+        transcript: &mut impl FiatShamirTranscript,
+        old_commitment: &KZGCommitmentValue,
+        delta_commitment: &KZGCommitmentValue,
+        delta: &[FieldElement],
+    ) -> FieldElement {
+        transcript.append_field_element(b"update_old_table", &old_commitment.hash());
+        transcript.append_field_element(b"update_delta", &delta_commitment.hash());
+        transcript.append_field_element(b"update_table_size", &FieldElement::from(delta.len() as u64));
+        transcript.append_field_elements(b"update_changes", delta);
+        transcript.challenge_field_element(b"update_point")
+    }
+    
+    /// Prove lookups into a [`StructuredTable`] without materializing it.
+    ///
+    /// A zero-check over the lookup rows shows that each row satisfies
+    ///
+    /// `V - T̃(B₀, ..., Bₙ₋₁) + λ·(I - Σₗ 2ˡ Bₗ + λ·Σₗ γˡ Bₗ(Bₗ - 1)) = 0`.
+    ///
+    /// Every Bₗ is then a bit, the bits recompose I, and V is the entry at I.
+    /// The verifier evaluates T̃ once, at the opened bits, so neither side
+    /// touches the table's 2^n entries.
+    pub fn prove_structured<T: StructuredTable>(
+        &self,
+        table: &T,
+        lookups: &[LookupOp],
+    ) -> Result<StructuredLookupProof> {
+        if lookups.is_empty() || lookups.len() > self.prover_params.max_operations {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Structured lookups need between one and max_operations operations".to_string(),
+            ));
+        }
         
-        // Create sum-check proof for lookup correctness
-        // For now, use a simple polynomial that evaluates to zero everywhere
-        // indicating perfect lookup correctness
-        let log_lookups = (lookups_size as f64).log2() as usize;
-        let sumcheck = SumCheck::new(log_lookups, FieldElement::zero());
+        let table_vars = table.num_vars();
+        for op in lookups {
+            let in_range = op.index.checked_shr(table_vars as u32).unwrap_or(0) == 0;
+            if !in_range || table.entry(op.index) != op.value {
+                return Err(TwistAndShoutError::ProofGeneration(format!(
+                    "Lookup at index {} does not match the structured table", op.index
+                )));
+            }
+        }
         
-        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        // Columns V, I, B₀, ..., Bₙ₋₁ over the padded rows
+        let rows = lookups.len().next_power_of_two();
+        let log_rows = poly_utils::log2_exact(rows);
+        let padded: Vec<&LookupOp> = lookups.iter().chain(ark_std::iter::repeat(&lookups[0])).take(rows).collect();
+        let mut columns = vec![
+            padded.iter().map(|op| op.value).collect(),
+            Self::index_values(&padded.iter().map(|op| op.index).collect::<Vec<_>>()),
+        ];
+        columns.extend((0..table_vars).map(|l| {
+            padded.iter().map(|op| FieldElement::from(((op.index >> l) & 1) as u64)).collect()
+        }));
+        let columns: Vec<MultilinearExtension> = columns
+            .into_iter()
+            .map(|column| MultilinearExtension::from_evaluations_vec(log_rows, column))
+            .collect();
         
-        // Add commitments to transcript
-        transcript.append_field_element(b"table_commitment", &table_commitment.hash());
-        transcript.append_field_element(b"index_commitment", &index_commitment.hash());
+        let params = MultilinearKZG::derive_params(&self.prover_params.commitment_params, log_rows)?;
+        let commitments = columns
+            .iter()
+            .map(|column| KZGCommitment::commit_mle(&params, &column.evaluations))
+            .collect::<Result<Vec<_>>>()?;
         
-        // Create multilinear extensions for lookup correctness checking
-        let table_mle = MultilinearExtension::from_evaluations_vec(
-            (table_size as f64).log2() as usize, 
-            padded_table.clone()
-        );
-        let index_mle = MultilinearExtension::from_evaluations_vec(
-            log_lookups, 
-            padded_indices.clone()
-        );
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let (tau, lambda, gamma) = Self::absorb_structured_statement(&mut transcript, table_vars, &commitments, log_rows);
         
-        // Define the lookup correctness polynomial
-        // This polynomial encodes the constraint that each lookup index corresponds to
-        // the correct value in the lookup table
-        let lookup_polynomial = {
-            let t_mle = table_mle.clone();
-            let i_mle = index_mle.clone();
-            let table_entries = padded_table.clone();
-            let indices = padded_indices.clone();
-            
-            move |vars: &[FieldElement]| -> FieldElement {
-                if vars.len() != log_lookups {
-                    return FieldElement::zero();
-                }
-                
-                // For each lookup operation, verify that the indexed value equals the expected value
-                // This is a simplified constraint that checks basic lookup correctness
-                
-                // Evaluate the multilinear extensions at the given point
-                let lookup_index = i_mle.evaluate(vars);
-                
-                // In a production implementation, this would involve more complex constraints
-                // to ensure that lookup_index correctly indexes into the table
-                // For now, we implement a simplified version that assumes correctness
-                
-                // The polynomial should be zero when lookups are correct
-                FieldElement::zero()
-            }
+        let sumcheck = SumCheck::new(log_rows, FieldElement::zero()).with_max_degree(1 + table.degree().max(2));
+        let zero_check = |vars: &[FieldElement]| {
+            let eq = poly_utils::eq_table(vars);
+            let at_vars: Vec<FieldElement> = columns.iter().map(|column| column.evaluate_with_eq(&eq)).collect();
+            eq_eval(&tau, vars).expect("τ has one coordinate per sum-check variable") * Self::structured_row(table, &at_vars, lambda, gamma)
         };
+        let (lookup_proof, point) = sumcheck.prove_with_challenges(zero_check, &mut transcript)?;
         
-        let lookup_proof = sumcheck.prove(lookup_polynomial, &mut transcript)?;
-        
-        // Generate opening proofs at challenge points from the sum-check
-        let challenges = transcript.challenge_field_elements(b"opening_challenges", log_lookups);
-        
-        let mut opening_proofs = Vec::new();
-        let mut final_evaluations = Vec::new();
-        
-        // Create opening proofs for table and index polynomials at the challenge point
-        // Only if we have at least one challenge
-        if !challenges.is_empty() {
-            let (table_eval, table_opening) = KZGCommitment::open(
-                &self.prover_params.commitment_params,
-                &table_poly,
-                challenges[0], // Use first challenge as evaluation point
-            )?;
-            
-            let (index_eval, index_opening) = KZGCommitment::open(
-                &self.prover_params.commitment_params,
-                &index_poly,
-                challenges[0], // Use first challenge as evaluation point
-            )?;
-            
-            opening_proofs.push(table_opening);
-            opening_proofs.push(index_opening);
-            final_evaluations.push(table_eval);
-            final_evaluations.push(index_eval);
-        }
+        let column_refs: Vec<&MultilinearExtension> = columns.iter().collect();
+        let (evaluations, opening) = MultilinearKZG::open_batch(&params, &column_refs, &point, &mut transcript)?;
         
-        Ok(ShoutProof {
-            table_commitment,
-            index_commitment,
+        let mut commitments = commitments.into_iter();
+        Ok(StructuredLookupProof {
+            table_vars,
+            value_commitment: commitments.next().expect("value column is committed"),
+            index_commitment: commitments.next().expect("index column is committed"),
+            bit_commitments: commitments.collect(),
             lookup_proof,
-            opening_proofs,
-            final_evaluations,
+            evaluations,
+            opening,
         })
     }
     
-    /// Verify a Shout proof
-    pub fn verify(&self, proof: &ShoutProof, verifier_params: &VerifierParams) -> Result<bool> {
-        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+    /// Verify a proof from [`Shout::prove_structured`] against `table`
+    pub fn verify_structured<T: StructuredTable>(
+        &self,
+        table: &T,
+        proof: &StructuredLookupProof,
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        let table_vars = table.num_vars();
+        if proof.table_vars != table_vars
+            || proof.bit_commitments.len() != table_vars
+            || proof.evaluations.len() != table_vars + 2
+        {
+            return Ok(false);
+        }
+        
+        let log_rows = proof.lookup_proof.round_polynomials.len();
+        let vk = match MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, log_rows) {
+            Ok(vk) => vk,
+            Err(_) => return Ok(false),
+        };
         
-        // Add commitments to transcript
-        transcript.append_field_element(b"table_commitment", &proof.table_commitment.hash());
-        transcript.append_field_element(b"index_commitment", &proof.index_commitment.hash());
+        let mut commitments = vec![proof.value_commitment.clone(), proof.index_commitment.clone()];
+        commitments.extend(proof.bit_commitments.iter().cloned());
         
-        // Verify sum-check proof - use the same number of variables as in the proof
-        let num_vars = proof.lookup_proof.round_polynomials.len();
-        let sumcheck = SumCheck::new(num_vars, FieldElement::zero());
-        let (sumcheck_valid, _challenges) = sumcheck.verify(&proof.lookup_proof, &mut transcript)?;
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let (tau, lambda, gamma) = Self::absorb_structured_statement(&mut transcript, table_vars, &commitments, log_rows);
         
+        let sumcheck = SumCheck::new(log_rows, FieldElement::zero()).with_max_degree(1 + table.degree().max(2));
+        let (sumcheck_valid, point) = sumcheck.verify(&proof.lookup_proof, &mut transcript)?;
         if !sumcheck_valid {
             return Ok(false);
         }
         
-        // Generate the same challenge points used in proof generation
-        let opening_challenges = transcript.challenge_field_elements(b"opening_challenges", num_vars);
-        
-        // Verify opening proofs if they exist
-        if !opening_challenges.is_empty() && proof.opening_proofs.len() >= 2 && proof.final_evaluations.len() >= 2 {
-            // Verify table polynomial opening
-            let table_valid = KZGCommitment::verify(
-                &verifier_params.commitment_vk,
-                &proof.table_commitment,
-                opening_challenges[0],
-                proof.final_evaluations[0],
-                &proof.opening_proofs[0],
-            )?;
-            
-            if !table_valid {
-                return Ok(false);
-            }
-            
-            // Verify index polynomial opening
-            let index_valid = KZGCommitment::verify(
-                &verifier_params.commitment_vk,
-                &proof.index_commitment,
-                opening_challenges[0],
-                proof.final_evaluations[1],
-                &proof.opening_proofs[1],
-            )?;
-            
-            if !index_valid {
-                return Ok(false);
-            }
+        if !MultilinearKZG::verify_batch(&vk, &commitments, &point, &proof.evaluations, &proof.opening, &mut transcript)? {
+            return Ok(false);
         }
         
-        Ok(true)
+        let expected = eq_eval(&tau, &point)? * Self::structured_row(table, &proof.evaluations, lambda, gamma);
+        Ok(expected == proof.lookup_proof.final_evaluation)
     }
     
-    /// Convert a vector to polynomial coefficients via interpolation
-    fn vector_to_polynomial(&self, vector: &[FieldElement]) -> Result<Vec<FieldElement>> {
-        let points: Vec<(FieldElement, FieldElement)> = vector
-            .iter()
-            .enumerate()
-            .map(|(i, &val)| (FieldElement::from(i as u64), val))
-            .collect();
+    /// Absorb the structured-lookup statement and derive the zero-check
+    /// weights τ and the combiners λ and γ
+    fn absorb_structured_statement(
+        transcript: &mut impl FiatShamirTranscript,
+        table_vars: usize,
+        commitments: &[KZGCommitmentValue],
+        log_rows: usize,
+    ) -> (Vec<FieldElement>, FieldElement, FieldElement) {
+        transcript.append_field_element(b"structured_table_vars", &FieldElement::from(table_vars as u64));
+        let hashes: Vec<FieldElement> = commitments.iter().map(|commitment| commitment.hash()).collect();
+        transcript.append_field_elements(b"structured_columns", &hashes);
+        
+        let tau = transcript.challenge_field_elements(b"structured_tau", log_rows);
+        let lambda = transcript.challenge_field_element(b"structured_lambda");
+        let gamma = transcript.challenge_field_element(b"structured_gamma");
+        (tau, lambda, gamma)
+    }
+    
+    /// Constraint of one structured-lookup row from its columns V, I, B₀, ..., Bₙ₋₁
+    fn structured_row<T: StructuredTable>(
+        table: &T,
+        columns: &[FieldElement],
+        lambda: FieldElement,
+        gamma: FieldElement,
+    ) -> FieldElement {
+        let (value, index, bits) = (columns[0], columns[1], &columns[2..]);
         
-        Ok(crate::polynomials::poly_utils::lagrange_interpolate(&points))
+        let mut recomposed = FieldElement::zero();
+        let mut booleanity = FieldElement::zero();
+        for &bit in bits.iter().rev() {
+            recomposed = recomposed.double() + bit;
+            booleanity = booleanity * gamma + bit * (bit - FieldElement::from(1u64));
+        }
+        
+        value - table.evaluate_mle(bits) + lambda * (index - recomposed + lambda * booleanity)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::utils::setup_params;
+/// Verify a KZG Shout proof given only serialized bytes.
+///
+/// The Shout counterpart of [`crate::verify_proof_bytes`]: `vk_bytes` is a
+/// [`VerifierParams::write`] encoding and `proof_bytes` a compressed
+/// [`ShoutProof`], and a parse failure or trailing bytes count as a rejection.
+pub fn verify_lookup_proof_bytes(vk_bytes: &[u8], proof_bytes: &[u8]) -> bool {
+    let mut vk_reader = vk_bytes;
+    let Ok(verifier_params) = VerifierParams::read(&mut vk_reader) else {
+        return false;
+    };
+    let mut proof_reader = proof_bytes;
+    let Ok(proof) = ShoutProof::<KZGCommitment>::deserialize_compressed(&mut proof_reader) else {
+        return false;
+    };
+    if !vk_reader.is_empty() || !proof_reader.is_empty() {
+        return false;
+    }
+    
+    let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+    Shout::<KZGCommitment>::verify_report(&proof, &verifier_params, &mut transcript, None)
+        .map(|report| report.is_valid())
+        .unwrap_or(false)
+}
+
+impl<C: CommitmentScheme<Scalar = FieldElement>> Shout<C> {
+    /// Create a new Shout instance committing with the scheme `C`
+    pub fn with_scheme(prover_params: &ProverParams) -> Self {
+        Self {
+            prover_params: prover_params.clone(),
+            debug_constraints: false,
+            _scheme: PhantomData,
+        }
+    }
+    
+    /// Commitment key for `C`
+    fn commitment_key(&self) -> Cow<'_, C::Params> {
+        C::prover_key(&self.prover_params)
+    }
+    
+    /// Enable constraint debugging, turning opaque sum-check failures into
+    /// errors that name the first violating row
+    pub fn with_constraint_debugging(mut self, enabled: bool) -> Self {
+        self.debug_constraints = enabled;
+        self
+    }
+    
+    /// Generate a proof for lookup correctness
+    pub fn prove(&self, table: &LookupTable) -> Result<ShoutProof<C>> {
+        self.prove_with_transcript(table, &mut Transcript::new(&self.prover_params.fiat_shamir_seed))
+    }
+    
+    /// Generate a proof against a caller-managed transcript, so the proof can be
+    /// composed with other arguments sharing one Fiat-Shamir transcript.
+    ///
+    /// The proof's messages are appended to whatever `transcript` already holds;
+    /// verify with [`Shout::verify_with_transcript`] from the same transcript state.
+    pub fn prove_with_transcript(&self, table: &LookupTable, transcript: &mut impl FiatShamirTranscript) -> Result<ShoutProof<C>> {
+        self.prove_lookups(table, transcript)
+    }
+    
+    /// Prove a single lookup at a secret index, revealing neither the index nor
+    /// the value read.
+    ///
+    /// The index and value are committed with random masks and shown to be a
+    /// table entry by the identities of [`PrivateLookupProof`], so the verifier
+    /// learns only that some entry of the committed table was accessed.
+    pub fn prove_private_lookup<R: RngCore>(
+        &self,
+        table: &LookupTable,
+        index: usize,
+        rng: &mut R,
+    ) -> Result<PrivateLookupProof<C>> {
+        let value = table.entries.get(index).copied().ok_or_else(|| {
+            TwistAndShoutError::InvalidParameters("Lookup index out of bounds".to_string())
+        })?;
+        
+        let params = self.commitment_key();
+        let mut padded_table = table.entries.clone();
+        padded_table.resize(table.entries.len().next_power_of_two(), FieldElement::zero());
+        let table_poly = self.vector_to_polynomial(&padded_table)?;
+        let index_poly = Self::masked_constant(FieldElement::from(index as u64), rng);
+        let value_poly = Self::masked_constant(value, rng);
+        
+        // T(I(X)) - V(X) and Z(I(X)) vanish at X = 0, so both divide by X
+        let mut entry_difference = poly_utils::compose_polynomials(&table_poly, &index_poly);
+        entry_difference.resize(entry_difference.len().max(value_poly.len()), FieldElement::zero());
+        for (coeff, value_coeff) in entry_difference.iter_mut().zip(&value_poly) {
+            *coeff -= value_coeff;
+        }
+        let vanishing = poly_utils::vanishing_polynomial(table.entries.len());
+        let range_difference = poly_utils::compose_polynomials(&vanishing, &index_poly);
+        let entry_quotient = Self::divide_by_x(&entry_difference)?;
+        let range_quotient = Self::divide_by_x(&range_difference)?;
+        
+        let mut proof = PrivateLookupProof {
+            table_size: table.entries.len(),
+            table_commitment: C::commit(&params, &table_poly)?,
+            index_commitment: C::commit(&params, &index_poly)?,
+            value_commitment: C::commit(&params, &value_poly)?,
+            entry_quotient_commitment: C::commit(&params, &entry_quotient)?,
+            range_quotient_commitment: C::commit(&params, &range_quotient)?,
+            opening_proofs: Vec::with_capacity(5),
+            evaluations: Vec::with_capacity(5),
+        };
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let point = Self::absorb_private_lookup_statement(&mut transcript, &proof);
+        let index_point = poly_utils::evaluate_polynomial(&index_poly, point);
+        
+        let openings = [
+            (&table_poly, index_point),
+            (&index_poly, point),
+            (&value_poly, point),
+            (&entry_quotient, point),
+            (&range_quotient, point),
+        ];
+        for (poly, at) in openings {
+            let (evaluation, opening) = C::open(&params, poly, at)?;
+            proof.evaluations.push(evaluation);
+            proof.opening_proofs.push(opening);
+        }
+        Ok(proof)
+    }
+    
+    /// Verify a proof from [`Shout::prove_private_lookup`] against the table
+    /// commitment it carries
+    pub fn verify_private_lookup(&self, proof: &PrivateLookupProof<C>, verifier_params: &VerifierParams) -> Result<bool> {
+        let vk = C::verifier_key(verifier_params);
+        let composed_degree = proof.table_size.checked_mul(PRIVATE_LOOKUP_MASK_TERMS);
+        if proof.table_size == 0
+            || !composed_degree.is_some_and(|degree| C::supports_degree(&vk, degree))
+            || proof.opening_proofs.len() != 5
+            || proof.evaluations.len() != 5
+        {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let point = Self::absorb_private_lookup_statement(&mut transcript, proof);
+        let index_point = proof.evaluations[1];
+        
+        let openings = [
+            (&proof.table_commitment, index_point),
+            (&proof.index_commitment, point),
+            (&proof.value_commitment, point),
+            (&proof.entry_quotient_commitment, point),
+            (&proof.range_quotient_commitment, point),
+        ];
+        for (((commitment, at), &evaluation), opening) in openings.into_iter().zip(&proof.evaluations).zip(&proof.opening_proofs) {
+            if !C::verify(&vk, commitment, at, evaluation, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        let [table_at_index, _, value, entry_quotient, range_quotient] = proof.evaluations[..] else {
+            return Ok(false);
+        };
+        let vanishing: FieldElement = (0..proof.table_size)
+            .map(|k| index_point - FieldElement::from(k as u64))
+            .product();
+        Ok(table_at_index - value == point * entry_quotient && vanishing == point * range_quotient)
+    }
+    
+    /// Absorb the private-lookup statement and derive the evaluation point
+    fn absorb_private_lookup_statement(transcript: &mut impl FiatShamirTranscript, proof: &PrivateLookupProof<C>) -> FieldElement {
+        transcript.append_field_element(b"private_lookup_table_size", &FieldElement::from(proof.table_size as u64));
+        let commitments = [
+            &proof.table_commitment,
+            &proof.index_commitment,
+            &proof.value_commitment,
+            &proof.entry_quotient_commitment,
+            &proof.range_quotient_commitment,
+        ];
+        let hashes: Vec<FieldElement> = commitments.into_iter().map(C::transcript_hash).collect();
+        transcript.append_field_elements(b"private_lookup_commitments", &hashes);
+        
+        transcript.challenge_field_element(b"private_lookup_point")
+    }
+    
+    /// The polynomial c + X·R(X) for a random R with [`PRIVATE_LOOKUP_MASK_TERMS`]
+    /// coefficients
+    fn masked_constant<R: RngCore>(constant: FieldElement, rng: &mut R) -> Vec<FieldElement> {
+        ark_std::iter::once(constant)
+            .chain((0..PRIVATE_LOOKUP_MASK_TERMS).map(|_| FieldElement::rand(rng)))
+            .collect()
+    }
+    
+    /// p(X) / X for a polynomial vanishing at 0
+    fn divide_by_x(poly: &[FieldElement]) -> Result<Vec<FieldElement>> {
+        match poly.split_first() {
+            Some((constant, quotient)) if constant.is_zero() => Ok(quotient.to_vec()),
+            _ => Err(TwistAndShoutError::ProofGeneration(
+                "Private lookup identity does not vanish at the committed index".to_string(),
+            )),
+        }
+    }
+    
+    /// Commit to a table whose entries stay private, for up to `max_proofs`
+    /// lookups with [`Shout::prove_private_table`]
+    pub fn commit_private_table<R: RngCore>(
+        &self,
+        entries: Vec<FieldElement>,
+        max_proofs: usize,
+        rng: &mut R,
+    ) -> Result<PrivateTable<C>> {
+        if entries.is_empty() || max_proofs == 0 {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Private table needs at least one entry and one proof".to_string(),
+            ));
+        }
+        
+        let interpolant = self.vector_to_polynomial(&entries)?;
+        let polynomial = Self::blind(interpolant, &poly_utils::vanishing_polynomial(entries.len()), max_proofs, rng);
+        let commitment = C::commit(&self.commitment_key(), &polynomial)?;
+        let public = PrivateTableCommitment { table_size: entries.len(), commitment };
+        Ok(PrivateTable { entries, polynomial, public, remaining_proofs: max_proofs })
+    }
+    
+    /// Prove lookups at public indices into a private table, revealing neither
+    /// the table entries nor the values returned.
+    ///
+    /// The looked-up values are committed, blinded, as the proof's
+    /// `value_commitment`, for use by later arguments. Each proof spends one of
+    /// the proofs the table was committed for.
+    pub fn prove_private_table<R: RngCore>(
+        &self,
+        table: &mut PrivateTable<C>,
+        public_indices: &[usize],
+        rng: &mut R,
+    ) -> Result<PrivateTableProof<C>> {
+        let values = public_indices
+            .iter()
+            .map(|&index| {
+                table.entries.get(index).copied().ok_or_else(|| {
+                    TwistAndShoutError::InvalidParameters("Lookup index out of bounds".to_string())
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        
+        self.prove_private_table_values(table, public_indices, &values, rng)
+    }
+    
+    /// Prove that `values` are the entries of `table` at `public_indices`
+    fn prove_private_table_values<R: RngCore>(
+        &self,
+        table: &mut PrivateTable<C>,
+        public_indices: &[usize],
+        values: &[FieldElement],
+        rng: &mut R,
+    ) -> Result<PrivateTableProof<C>> {
+        if public_indices.is_empty() || public_indices.len() > self.prover_params.max_operations {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Private table lookups need between one and max_operations indices".to_string(),
+            ));
+        }
+        if table.remaining_proofs == 0 {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Private table has served every proof its blinding covers".to_string(),
+            ));
+        }
+        
+        // One value per distinct index, so V interpolates over S
+        let mut reads: Vec<(usize, FieldElement)> = public_indices.iter().copied().zip(values.iter().copied()).collect();
+        reads.sort_by_key(|&(index, _)| index);
+        if reads.windows(2).any(|pair| pair[0].0 == pair[1].0 && pair[0].1 != pair[1].1) {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Lookups at the same index must return the same value".to_string(),
+            ));
+        }
+        reads.dedup_by_key(|&mut (index, _)| index);
+        let points: Vec<(FieldElement, FieldElement)> = reads
+            .iter()
+            .map(|&(index, value)| (FieldElement::from(index as u64), value))
+            .collect();
+        let vanishing = Self::index_vanishing_polynomial(&Self::distinct_indices(public_indices));
+        
+        let params = self.commitment_key();
+        let value_poly = Self::blind(poly_utils::lagrange_interpolate(&points), &vanishing, 1, rng);
+        let value_commitment = C::commit(&params, &value_poly)?;
+        
+        // T(X) - V(X) vanishes on S
+        let mut difference = table.polynomial.clone();
+        difference.resize(difference.len().max(value_poly.len()), FieldElement::zero());
+        for (coeff, value_coeff) in difference.iter_mut().zip(&value_poly) {
+            *coeff -= value_coeff;
+        }
+        let quotient = crate::commitments::polynomial_division(&difference, &vanishing)?;
+        let quotient_commitment = C::commit(&params, &quotient)?;
+        
+        let mut proof = PrivateTableProof {
+            value_commitment,
+            quotient_commitment,
+            opening_proofs: Vec::with_capacity(3),
+            evaluations: Vec::with_capacity(3),
+        };
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let point = Self::absorb_private_table_statement(&mut transcript, &table.public, public_indices, &proof);
+        
+        for poly in [&table.polynomial, &value_poly, &quotient] {
+            let (evaluation, opening) = C::open(&params, poly, point)?;
+            proof.evaluations.push(evaluation);
+            proof.opening_proofs.push(opening);
+        }
+        table.remaining_proofs -= 1;
+        Ok(proof)
+    }
+    
+    /// Verify a proof from [`Shout::prove_private_table`] against the table's
+    /// public handle and the public lookup indices
+    pub fn verify_private_table(
+        &self,
+        table: &PrivateTableCommitment<C>,
+        public_indices: &[usize],
+        proof: &PrivateTableProof<C>,
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        if public_indices.is_empty()
+            || public_indices.iter().any(|&index| index >= table.table_size)
+            || proof.opening_proofs.len() != 3
+            || proof.evaluations.len() != 3
+        {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let point = Self::absorb_private_table_statement(&mut transcript, table, public_indices, proof);
+        
+        let vk = C::verifier_key(verifier_params);
+        let commitments = [&table.commitment, &proof.value_commitment, &proof.quotient_commitment];
+        for ((commitment, &evaluation), opening) in commitments.into_iter().zip(&proof.evaluations).zip(&proof.opening_proofs) {
+            if !C::verify(&vk, commitment, point, evaluation, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        let vanishing: FieldElement = Self::distinct_indices(public_indices)
+            .into_iter()
+            .map(|index| point - index)
+            .product();
+        Ok(proof.evaluations[0] - proof.evaluations[1] == vanishing * proof.evaluations[2])
+    }
+    
+    /// Absorb the private-table statement and derive the evaluation point
+    fn absorb_private_table_statement(
+        transcript: &mut impl FiatShamirTranscript,
+        table: &PrivateTableCommitment<C>,
+        public_indices: &[usize],
+        proof: &PrivateTableProof<C>,
+    ) -> FieldElement {
+        transcript.append_field_element(b"private_table", &C::transcript_hash(&table.commitment));
+        transcript.append_field_element(b"private_table_size", &FieldElement::from(table.table_size as u64));
+        transcript.append_field_elements(b"private_table_indices", &Self::index_values(public_indices));
+        transcript.append_field_element(b"private_table_values", &C::transcript_hash(&proof.value_commitment));
+        transcript.append_field_element(b"private_table_quotient", &C::transcript_hash(&proof.quotient_commitment));
+        
+        transcript.challenge_field_element(b"private_table_point")
+    }
+    
+    /// `polynomial` plus `vanishing` times a random polynomial with `terms`
+    /// coefficients
+    fn blind<R: RngCore>(
+        mut polynomial: Vec<FieldElement>,
+        vanishing: &[FieldElement],
+        terms: usize,
+        rng: &mut R,
+    ) -> Vec<FieldElement> {
+        let mask: Vec<FieldElement> = (0..terms).map(|_| FieldElement::rand(rng)).collect();
+        let masking = poly_utils::multiply_polynomials(vanishing, &mask);
+        polynomial.resize(polynomial.len().max(masking.len()), FieldElement::zero());
+        for (coeff, mask_coeff) in polynomial.iter_mut().zip(&masking) {
+            *coeff += mask_coeff;
+        }
+        polynomial
+    }
+    
+    /// Distinct lookup indices as field elements, in increasing order
+    fn distinct_indices(indices: &[usize]) -> Vec<FieldElement> {
+        let mut distinct = indices.to_vec();
+        distinct.sort_unstable();
+        distinct.dedup();
+        Self::index_values(&distinct)
+    }
+    
+    /// ∏_{i ∈ indices} (X - i)
+    fn index_vanishing_polynomial(indices: &[FieldElement]) -> Vec<FieldElement> {
+        indices.iter().fold(vec![FieldElement::one()], |product, &index| {
+            poly_utils::multiply_polynomials(&product, &[-index, FieldElement::one()])
+        })
+    }
+    
+    /// Lookup indices as field elements
+    fn index_values(indices: &[usize]) -> Vec<FieldElement> {
+        indices.iter().map(|&index| FieldElement::from(index as u64)).collect()
+    }
+    
+    /// Generate a proof for the lookups recorded on `table`
+    fn prove_lookups(&self, table: &LookupTable, transcript: &mut impl FiatShamirTranscript) -> Result<ShoutProof<C>> {
+        if table.lookups.len() > self.prover_params.max_operations {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Too many lookup operations".to_string(),
+            ));
+        }
+        
+        // The padding entries are zeros the table never had, so a lookup there
+        // must be refused rather than proven against a virtual 0
+        if let Some(op) = table.lookups.iter().find(|op| op.index >= table.entries.len()) {
+            return Err(TwistAndShoutError::InvalidParameters(format!(
+                "Lookup index {} is outside the {}-entry table", op.index, table.entries.len()
+            )));
+        }
+        
+        let num_lookups = table.lookups.len();
+        let table_size = table.entries.len().next_power_of_two();
+        let lookups_size = num_lookups.next_power_of_two().max(1);
+        let rows = table_size.max(lookups_size);
+        let num_vars = poly_utils::log2_exact(rows);
+        
+        // Lookup correctness: row i is v_i - T[idx_i], zero exactly when the
+        // recorded value is the table entry at the recorded index
+        if self.debug_constraints {
+            let mut lookup_rows = vec![FieldElement::zero(); lookups_size];
+            for (row, op) in lookup_rows.iter_mut().zip(&table.lookups) {
+                *row = op.value - table.entries[op.index];
+            }
+            let log_lookups = poly_utils::log2_exact(lookups_size);
+            let constraint_mle = MultilinearExtension::from_evaluations_vec(log_lookups, lookup_rows);
+            let constraint = |vars: &[FieldElement]| constraint_mle.evaluate(vars);
+            check_constraint_rows(log_lookups, &constraint, "Lookup correctness")?;
+        }
+        let mut lookup_columns = Self::lookup_check_columns(table, rows)?;
+        
+        // Commit to the table, index and value columns as univariate
+        // polynomials over the rows 0, 1, 2, ...
+        let table_poly = self.vector_to_polynomial(&lookup_columns[2][..table_size])?;
+        let index_poly = self.vector_to_polynomial(&Self::padded_indices(&table.lookups))?;
+        let value_poly = self.vector_to_polynomial(&lookup_columns[1][..lookups_size])?;
+        let table_commitment = C::commit(&self.commitment_key(), &table_poly)?;
+        let index_commitment = C::commit(&self.commitment_key(), &index_poly)?;
+        let value_commitment = C::commit(&self.commitment_key(), &value_poly)?;
+        Self::absorb_public_inputs(
+            transcript,
+            table.entries.len(),
+            num_lookups,
+            [&table_commitment, &index_commitment, &value_commitment],
+        );
+        
+        // Commit to the lookup-argument columns before drawing the
+        // fingerprint challenges
+        let column_params = MultilinearKZG::derive_params(&self.prover_params.commitment_params, num_vars)?;
+        let column_slices: Vec<&[FieldElement]> = lookup_columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &column_slices)?;
+        let (beta, gamma) = Self::absorb_lookup_columns(transcript, &column_commitments);
+        
+        let fingerprint_columns = lookup_fingerprint_columns(&lookup_columns, table.entries.len(), beta, gamma)?;
+        let fingerprint_slices: Vec<&[FieldElement]> = fingerprint_columns.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &fingerprint_slices)?;
+        let bridge_point = Self::absorb_lookup_fingerprints(transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        lookup_columns.extend(fingerprint_columns);
+        
+        // Open the table, index and value commitments at the bridge point
+        let mut opening_proofs = Vec::with_capacity(3);
+        let mut final_evaluations = Vec::with_capacity(3);
+        for polynomial in [&table_poly, &index_poly, &value_poly] {
+            let (evaluation, opening) = C::open(&self.commitment_key(), polynomial, bridge_point)?;
+            opening_proofs.push(opening);
+            final_evaluations.push(evaluation);
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_lookup_bridge(transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claimed_sum = lookup_check_claim(&final_evaluations, &challenges);
+        
+        // The summand reads the public tables ahead of the committed columns
+        let mut tables = vec![
+            poly_utils::eq_table(&tau),
+            (0..rows).map(|i| FieldElement::from(i as u64)).collect(),
+            (0..rows).map(|i| FieldElement::from((i < table.entries.len()) as u64)).collect(),
+            poly_utils::lagrange_basis_at(lookups_size, bridge_point),
+            poly_utils::lagrange_basis_at(table_size, bridge_point),
+        ];
+        tables[3].resize(rows, FieldElement::zero());
+        tables[4].resize(rows, FieldElement::zero());
+        tables.extend(lookup_columns.iter().cloned());
+        let summand = |row: &[FieldElement]| {
+            lookup_check_summand([row[0], row[1], row[2], row[3], row[4]], &row[5..], &challenges)
+        };
+        let (lookup_proof, lookup_point, _) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(LOOKUP_CHECK_DEGREE)
+            .prove_tables(tables, summand, transcript)?;
+        let column_slices: Vec<&[FieldElement]> = lookup_columns.iter().map(|column| &column[..]).collect();
+        let lookup_opening = ColumnOpening::open(
+            &column_params,
+            column_commitments,
+            &column_slices,
+            &lookup_point,
+            transcript,
+        )?;
+        
+        Ok(ShoutProof {
+            params: self.prover_params.proof_params(),
+            table_size: table.entries.len(),
+            num_lookups,
+            table_commitment,
+            index_commitment,
+            value_commitment,
+            lookup_proof,
+            lookup_opening,
+            opening_proofs,
+            final_evaluations,
+            extension_proof: None,
+        })
+    }
+    
+    /// Index, value, table and multiplicity columns of the lookup argument
+    /// over `rows` rows.
+    ///
+    /// Every row past the lookups repeats a lookup of entry 0, so all rows are
+    /// lookups, and the multiplicity column counts them per table entry. The
+    /// table column is zero past the entries.
+    fn lookup_check_columns(table: &LookupTable, rows: usize) -> Result<Vec<Vec<FieldElement>>> {
+        let first_entry = *table.entries.first().ok_or_else(|| {
+            TwistAndShoutError::InvalidParameters("Lookup table has no entries".to_string())
+        })?;
+        let mut indices = vec![FieldElement::zero(); rows];
+        let mut values = vec![first_entry; rows];
+        let mut entries = table.entries.clone();
+        entries.resize(rows, FieldElement::zero());
+        let mut counts = vec![0u64; rows];
+        counts[0] = (rows - table.lookups.len()) as u64;
+        for (i, op) in table.lookups.iter().enumerate() {
+            indices[i] = FieldElement::from(op.index as u64);
+            values[i] = op.value;
+            counts[op.index] += 1;
+        }
+        let multiplicities = counts.into_iter().map(FieldElement::from).collect();
+        Ok(vec![indices, values, entries, multiplicities])
+    }
+    
+    /// Verify a Shout proof
+    pub fn verify(&self, proof: &ShoutProof<C>, verifier_params: &VerifierParams) -> Result<bool> {
+        Ok(self.verify_verbose(proof, verifier_params)?.is_valid())
+    }
+    
+    /// Verify a proof produced by [`Shout::prove_with_transcript`], replaying it
+    /// against `transcript` in the state the prover started from
+    pub fn verify_with_transcript(
+        &self,
+        proof: &ShoutProof<C>,
+        verifier_params: &VerifierParams,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<bool> {
+        Ok(Self::verify_report(proof, verifier_params, transcript, None)?.is_valid())
+    }
+    
+    /// Verify a Shout proof and report the outcome of every check
+    pub fn verify_verbose(&self, proof: &ShoutProof<C>, verifier_params: &VerifierParams) -> Result<VerifyReport> {
+        Self::verify_report(proof, verifier_params, &mut Transcript::new(&verifier_params.fiat_shamir_seed), None)
+    }
+    
+    /// Run every verifier check, stopping once the transcript can no longer be
+    /// trusted, and check the committed multiplicities against
+    /// `expected_counts` if given
+    fn verify_report(
+        proof: &ShoutProof<C>,
+        verifier_params: &VerifierParams,
+        transcript: &mut impl FiatShamirTranscript,
+        expected_counts: Option<&[usize]>,
+    ) -> Result<VerifyReport> {
+        let mut report = VerifyReport::default();
+        
+        if !report.record(
+            "parameters",
+            verifier_params.is_compatible_with(&proof.params),
+            "proof parameters are compatible with the verifier key",
+        ) {
+            return Ok(report);
+        }
+        
+        let vk = C::verifier_key(verifier_params);
+        
+        // The committed table covers the real entries padded to a power of two
+        let Some((table_rows, lookup_rows, num_vars)) = Self::lookup_shape(proof.table_size, proof.num_lookups) else {
+            report.record(
+                "table_size",
+                false,
+                format!("{}-entry table with {} lookups", proof.table_size, proof.num_lookups),
+            );
+            return Ok(report);
+        };
+        if !report.record(
+            "table_size",
+            C::supports_degree(&vk, table_rows - 1),
+            format!("verifier key supports a {}-entry table", proof.table_size),
+        ) {
+            return Ok(report);
+        }
+        
+        // The proof may come from a smaller SRS sharing the same tau; it only
+        // needs to fit within ours
+        if !report.record(
+            "degree",
+            proof.lookup_proof.round_polynomials.len() == num_vars && C::supports_degree(&vk, lookup_rows - 1),
+            format!("{} lookup sum-check rounds, expected {}", proof.lookup_proof.round_polynomials.len(), num_vars),
+        ) {
+            return Ok(report);
+        }
+        
+        // Every lookup-argument column needs its own commitment and evaluation
+        let column_vk = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, num_vars);
+        let columns = LOOKUP_COLUMNS + LOOKUP_FINGERPRINT_COLUMNS;
+        if !report.record(
+            "row_commitments",
+            proof.lookup_opening.commitments.len() == columns
+                && proof.lookup_opening.evaluations.len() == columns
+                && column_vk.is_ok(),
+            format!("{} lookup column commitments, expected {}", proof.lookup_opening.commitments.len(), columns),
+        ) {
+            return Ok(report);
+        }
+        let column_vk = column_vk?;
+        
+        Self::absorb_public_inputs(
+            transcript,
+            proof.table_size,
+            proof.num_lookups,
+            [&proof.table_commitment, &proof.index_commitment, &proof.value_commitment],
+        );
+        let commitments = &proof.lookup_opening.commitments;
+        let (beta, gamma) = Self::absorb_lookup_columns(transcript, &commitments[..LOOKUP_COLUMNS]);
+        let bridge_point = Self::absorb_lookup_fingerprints(transcript, &commitments[LOOKUP_COLUMNS..]);
+        
+        // The table, index and value commitments are opened at the bridge point
+        if !report.record(
+            "opening_count",
+            proof.opening_proofs.len() == 3 && proof.final_evaluations.len() == 3,
+            format!(
+                "{} opening proofs and {} evaluations, expected 3 of each",
+                proof.opening_proofs.len(),
+                proof.final_evaluations.len()
+            ),
+        ) {
+            return Ok(report);
+        }
+        let openings = [
+            ("table_opening", &proof.table_commitment, "table polynomial opening"),
+            ("index_opening", &proof.index_commitment, "index polynomial opening"),
+            ("value_opening", &proof.value_commitment, "value polynomial opening"),
+        ];
+        for (i, (name, commitment, description)) in openings.into_iter().enumerate() {
+            let valid = C::verify(&vk, commitment, bridge_point, proof.final_evaluations[i], &proof.opening_proofs[i])?;
+            report.record(name, valid, description);
+        }
+        
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_lookup_bridge(transcript, &proof.final_evaluations, &mut tau, beta, gamma);
+        let claimed_sum = lookup_check_claim(&proof.final_evaluations, &challenges);
+        let (sumcheck_valid, lookup_point) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(LOOKUP_CHECK_DEGREE)
+            .verify(&proof.lookup_proof, transcript)?;
+        if !report.record("lookup_sumcheck", sumcheck_valid, "lookup sum-check") {
+            return Ok(report);
+        }
+        
+        // The rounds only reduce the claim to the summand at r; the committed
+        // columns must back that value
+        let columns_valid = proof.lookup_opening.verify(&column_vk, &lookup_point, transcript)?;
+        let public = lookup_check_public_values(&tau, &lookup_point, proof.table_size, lookup_rows, table_rows, bridge_point)?;
+        report.record(
+            "lookup_binding",
+            columns_valid
+                && lookup_check_summand(public, &proof.lookup_opening.evaluations, &challenges)
+                    == proof.lookup_proof.final_evaluation,
+            "lookup columns open to values whose summand is the sum-check's final evaluation",
+        );
+        
+        // The multiplicity column counts each entry's lookups, with the rows
+        // past the lookups all looking up entry 0
+        if let Some(counts) = expected_counts {
+            let padding = table_rows.max(lookup_rows) - proof.num_lookups;
+            let expected: FieldElement = counts
+                .iter()
+                .enumerate()
+                .filter(|&(_, &count)| count > 0)
+                .map(|(i, &count)| FieldElement::from(count as u64) * poly_utils::eq_at_index(&lookup_point, i))
+                .sum::<FieldElement>()
+                + FieldElement::from(padding as u64) * poly_utils::eq_at_index(&lookup_point, 0);
+            report.record(
+                "multiplicities",
+                proof.lookup_opening.evaluations[MULTIPLICITY_COLUMN] == expected,
+                "multiplicity column opens to the expected counts",
+            );
+        }
+        
+        Ok(report)
+    }
+    
+    /// Padded table size, padded lookup count and the lookup sum-check's
+    /// variable count for the given public inputs, or `None` if they describe
+    /// no table a proof could cover
+    fn lookup_shape(table_size: usize, num_lookups: usize) -> Option<(usize, usize, usize)> {
+        if table_size == 0 {
+            return None;
+        }
+        let table_rows = table_size.checked_next_power_of_two()?;
+        let lookup_rows = num_lookups.checked_next_power_of_two()?.max(1);
+        Some((table_rows, lookup_rows, table_rows.max(lookup_rows).trailing_zeros() as usize))
+    }
+    
+    /// Absorb the table size, the lookup count and the table, index and value
+    /// commitments, in that order, before anything else
+    fn absorb_public_inputs(
+        transcript: &mut impl FiatShamirTranscript,
+        table_size: usize,
+        num_lookups: usize,
+        commitments: [&C::Commitment; 3],
+    ) {
+        transcript.append_field_element(b"table_size", &FieldElement::from(table_size as u64));
+        transcript.append_field_element(b"num_lookups", &FieldElement::from(num_lookups as u64));
+        for (label, commitment) in [&b"table_commitment"[..], b"index_commitment", b"value_commitment"]
+            .into_iter()
+            .zip(commitments)
+        {
+            transcript.append_field_element(label, &C::transcript_hash(commitment));
+        }
+    }
+    
+    /// Absorb the lookup-argument columns committed before the fingerprint
+    /// challenges, then draw the fingerprint challenges β and γ
+    fn absorb_lookup_columns(transcript: &mut impl FiatShamirTranscript, columns: &[KZGCommitmentValue]) -> (FieldElement, FieldElement) {
+        transcript.append_field_elements(b"lookup_column_commitments", &ColumnOpening::commitment_hashes(columns));
+        (
+            transcript.challenge_field_element(b"lookup_fingerprint_beta"),
+            transcript.challenge_field_element(b"lookup_fingerprint_gamma"),
+        )
+    }
+    
+    /// Absorb the inverse-fingerprint column commitments and draw the point z
+    /// the univariate commitments are opened at
+    fn absorb_lookup_fingerprints(transcript: &mut impl FiatShamirTranscript, columns: &[KZGCommitmentValue]) -> FieldElement {
+        transcript.append_field_elements(b"lookup_fingerprint_commitments", &ColumnOpening::commitment_hashes(columns));
+        transcript.challenge_field_element(b"lookup_bridge_point")
+    }
+    
+    /// Absorb the table, index and value commitments' values at z, then draw
+    /// the remaining lookup challenges, writing τ into `tau`
+    fn absorb_lookup_bridge(
+        transcript: &mut impl FiatShamirTranscript,
+        evaluations: &[FieldElement],
+        tau: &mut [FieldElement],
+        beta: FieldElement,
+        gamma: FieldElement,
+    ) -> LookupChallenges {
+        transcript.append_field_elements(b"lookup_bridge_evaluations", evaluations);
+        let alpha = transcript.challenge_field_element(b"lookup_bridge_alpha");
+        transcript.challenge_batch_into(b"lookup_tau", tau);
+        let lambda = transcript.challenge_field_element(b"lookup_lambda");
+        let mu = transcript.challenge_field_element(b"lookup_mu");
+        LookupChallenges { beta, gamma, alpha, lambda, mu }
+    }
+    
+    /// Prove the lookups recorded on every table with one batched sum-check.
+    ///
+    /// Each table gets the columns of the single-table lookup argument over
+    /// a row count shared by all tables, and the sum-check runs on
+    /// Σₖ ρᵏ·sₖ for the tables' summands sₖ. The verifier runs one sum-check
+    /// plus three openings and a constant number of field operations per table.
+    pub fn prove_multi(&self, tables: &[LookupTable]) -> Result<MultiShoutProof<C>> {
+        if tables.is_empty() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Multi-table proof needs at least one table".to_string(),
+            ));
+        }
+        if tables.iter().map(|table| table.lookups.len()).sum::<usize>() > self.prover_params.max_operations {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Too many lookup operations".to_string(),
+            ));
+        }
+        for table in tables {
+            if let Some(op) = table.lookups.iter().find(|op| op.index >= table.entries.len()) {
+                return Err(TwistAndShoutError::InvalidParameters(format!(
+                    "Lookup index {} is outside the {}-entry table", op.index, table.entries.len()
+                )));
+            }
+        }
+        
+        let table_sizes: Vec<usize> = tables.iter().map(LookupTable::size).collect();
+        let num_lookups: Vec<usize> = tables.iter().map(|table| table.lookups.len()).collect();
+        let (shapes, num_vars) = Self::multi_shape(&table_sizes, &num_lookups).ok_or_else(|| {
+            TwistAndShoutError::InvalidParameters("Every table needs at least one entry".to_string())
+        })?;
+        let rows = 1 << num_vars;
+        
+        let mut lookup_columns = Vec::with_capacity(tables.len() * (LOOKUP_COLUMNS + LOOKUP_FINGERPRINT_COLUMNS));
+        let mut polynomials = Vec::with_capacity(3 * tables.len());
+        for (table, &(table_rows, lookup_rows)) in tables.iter().zip(&shapes) {
+            let columns = Self::lookup_check_columns(table, rows)?;
+            polynomials.push(self.vector_to_polynomial(&columns[2][..table_rows])?);
+            polynomials.push(self.vector_to_polynomial(&Self::padded_indices(&table.lookups))?);
+            polynomials.push(self.vector_to_polynomial(&columns[1][..lookup_rows])?);
+            lookup_columns.extend(columns);
+        }
+        let commitments = polynomials
+            .iter()
+            .map(|polynomial| C::commit(&self.commitment_key(), polynomial))
+            .collect::<Result<Vec<_>>>()?;
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        Self::absorb_multi_statement(&mut transcript, &table_sizes, &num_lookups, &commitments);
+        
+        let column_params = MultilinearKZG::derive_params(&self.prover_params.commitment_params, num_vars)?;
+        let column_slices: Vec<&[FieldElement]> = lookup_columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &column_slices)?;
+        let (beta, gamma) = Self::absorb_lookup_columns(&mut transcript, &column_commitments);
+        
+        let mut fingerprint_columns = Vec::with_capacity(LOOKUP_FINGERPRINT_COLUMNS * tables.len());
+        for (columns, table) in lookup_columns.chunks(LOOKUP_COLUMNS).zip(tables) {
+            fingerprint_columns.extend(lookup_fingerprint_columns(columns, table.entries.len(), beta, gamma)?);
+        }
+        let fingerprint_slices: Vec<&[FieldElement]> = fingerprint_columns.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &fingerprint_slices)?;
+        let bridge_point = Self::absorb_lookup_fingerprints(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        lookup_columns.extend(fingerprint_columns);
+        
+        let mut opening_proofs = Vec::with_capacity(polynomials.len());
+        let mut final_evaluations = Vec::with_capacity(polynomials.len());
+        for polynomial in &polynomials {
+            let (evaluation, opening) = C::open(&self.commitment_key(), polynomial, bridge_point)?;
+            opening_proofs.push(opening);
+            final_evaluations.push(evaluation);
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_lookup_bridge(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let rho = transcript.challenge_field_element(b"multi_lookup_rho");
+        let claimed_sum = multi_lookup_claim(&final_evaluations, &challenges, rho);
+        
+        // The summand reads eq and the row index, each table's public
+        // tables, then the committed columns
+        let mut public_tables = vec![
+            poly_utils::eq_table(&tau),
+            (0..rows).map(|i| FieldElement::from(i as u64)).collect(),
+        ];
+        for (table, &(table_rows, lookup_rows)) in tables.iter().zip(&shapes) {
+            let mut lookup_lagrange = poly_utils::lagrange_basis_at(lookup_rows, bridge_point);
+            let mut table_lagrange = poly_utils::lagrange_basis_at(table_rows, bridge_point);
+            lookup_lagrange.resize(rows, FieldElement::zero());
+            table_lagrange.resize(rows, FieldElement::zero());
+            public_tables.push((0..rows).map(|i| FieldElement::from((i < table.entries.len()) as u64)).collect());
+            public_tables.extend([lookup_lagrange, table_lagrange]);
+        }
+        let num_public = public_tables.len();
+        public_tables.extend(lookup_columns.iter().cloned());
+        let summand = |row: &[FieldElement]| {
+            let (public, columns) = row.split_at(num_public);
+            multi_lookup_summand(public, columns, &challenges, rho)
+        };
+        let (lookup_proof, lookup_point, _) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(LOOKUP_CHECK_DEGREE)
+            .prove_tables(public_tables, summand, &mut transcript)?;
+        let column_slices: Vec<&[FieldElement]> = lookup_columns.iter().map(|column| &column[..]).collect();
+        let lookup_opening = ColumnOpening::open(&column_params, column_commitments, &column_slices, &lookup_point, &mut transcript)?;
+        
+        let mut table_commitments = Vec::with_capacity(tables.len());
+        let mut index_commitments = Vec::with_capacity(tables.len());
+        let mut value_commitments = Vec::with_capacity(tables.len());
+        let mut commitments = commitments.into_iter();
+        while let (Some(table), Some(index), Some(value)) = (commitments.next(), commitments.next(), commitments.next()) {
+            table_commitments.push(table);
+            index_commitments.push(index);
+            value_commitments.push(value);
+        }
+        Ok(MultiShoutProof {
+            params: self.prover_params.proof_params(),
+            table_sizes,
+            num_lookups,
+            table_commitments,
+            index_commitments,
+            value_commitments,
+            lookup_proof,
+            lookup_opening,
+            opening_proofs,
+            final_evaluations,
+        })
+    }
+    
+    /// Verify a [`MultiShoutProof`]
+    pub fn verify_multi(&self, proof: &MultiShoutProof<C>, verifier_params: &VerifierParams) -> Result<bool> {
+        let num_tables = proof.table_commitments.len();
+        let num_columns = num_tables * (LOOKUP_COLUMNS + LOOKUP_FINGERPRINT_COLUMNS);
+        if num_tables == 0
+            || !verifier_params.is_compatible_with(&proof.params)
+            || proof.table_sizes.len() != num_tables
+            || proof.num_lookups.len() != num_tables
+            || proof.index_commitments.len() != num_tables
+            || proof.value_commitments.len() != num_tables
+            || proof.opening_proofs.len() != 3 * num_tables
+            || proof.final_evaluations.len() != 3 * num_tables
+            || proof.lookup_opening.commitments.len() != num_columns
+            || proof.lookup_opening.evaluations.len() != num_columns
+        {
+            return Ok(false);
+        }
+        
+        // Every table and lookup stream must fit the verifier key
+        let vk = C::verifier_key(verifier_params);
+        let Some((shapes, num_vars)) = Self::multi_shape(&proof.table_sizes, &proof.num_lookups) else {
+            return Ok(false);
+        };
+        if proof.lookup_proof.round_polynomials.len() != num_vars
+            || shapes.iter().any(|&(table_rows, lookup_rows)| {
+                !C::supports_degree(&vk, table_rows - 1) || !C::supports_degree(&vk, lookup_rows - 1)
+            })
+        {
+            return Ok(false);
+        }
+        let Ok(column_vk) = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, num_vars) else {
+            return Ok(false);
+        };
+        
+        let commitments: Vec<&C::Commitment> = proof.table_commitments
+            .iter()
+            .zip(&proof.index_commitments)
+            .zip(&proof.value_commitments)
+            .flat_map(|((table, index), value)| [table, index, value])
+            .collect();
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Self::absorb_multi_statement(&mut transcript, &proof.table_sizes, &proof.num_lookups, commitments.iter().copied());
+        let column_commitments = &proof.lookup_opening.commitments;
+        let fingerprint_start = num_tables * LOOKUP_COLUMNS;
+        let (beta, gamma) = Self::absorb_lookup_columns(&mut transcript, &column_commitments[..fingerprint_start]);
+        let bridge_point = Self::absorb_lookup_fingerprints(&mut transcript, &column_commitments[fingerprint_start..]);
+        
+        for ((commitment, &evaluation), opening) in commitments.into_iter().zip(&proof.final_evaluations).zip(&proof.opening_proofs) {
+            if !C::verify(&vk, commitment, bridge_point, evaluation, opening)? {
+                return Ok(false);
+            }
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_lookup_bridge(&mut transcript, &proof.final_evaluations, &mut tau, beta, gamma);
+        let rho = transcript.challenge_field_element(b"multi_lookup_rho");
+        
+        let claimed_sum = multi_lookup_claim(&proof.final_evaluations, &challenges, rho);
+        let (sumcheck_valid, point) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(LOOKUP_CHECK_DEGREE)
+            .verify(&proof.lookup_proof, &mut transcript)?;
+        if !sumcheck_valid || !proof.lookup_opening.verify(&column_vk, &point, &mut transcript)? {
+            return Ok(false);
+        }
+        
+        // The committed columns must give the final evaluation
+        let mut public = vec![eq_eval(&tau, &point)?, poly_utils::hypercube_index(&point)];
+        for (&table_size, &(table_rows, lookup_rows)) in proof.table_sizes.iter().zip(&shapes) {
+            public.extend([
+                poly_utils::prefix_selector(&point, table_size),
+                poly_utils::lagrange_basis_mle(lookup_rows, bridge_point, &point),
+                poly_utils::lagrange_basis_mle(table_rows, bridge_point, &point),
+            ]);
+        }
+        let summand = multi_lookup_summand(&public, &proof.lookup_opening.evaluations, &challenges, rho);
+        Ok(summand == proof.lookup_proof.final_evaluation)
+    }
+    
+    /// Each table's padded table and lookup sizes and the sum-check's variable
+    /// count over the largest of them, or `None` if a table is empty or a size
+    /// overflows
+    fn multi_shape(table_sizes: &[usize], num_lookups: &[usize]) -> Option<(Vec<(usize, usize)>, usize)> {
+        let shapes = table_sizes
+            .iter()
+            .zip(num_lookups)
+            .map(|(&table_size, &lookups)| Self::lookup_shape(table_size, lookups).map(|(table_rows, lookup_rows, _)| (table_rows, lookup_rows)))
+            .collect::<Option<Vec<_>>>()?;
+        let rows = shapes.iter().map(|&(table_rows, lookup_rows)| table_rows.max(lookup_rows)).max()?;
+        Some((shapes, rows.trailing_zeros() as usize))
+    }
+    
+    /// Absorb every table's size, lookup count and table, index and value
+    /// commitments
+    fn absorb_multi_statement<'a>(
+        transcript: &mut impl FiatShamirTranscript,
+        table_sizes: &[usize],
+        num_lookups: &[usize],
+        commitments: impl IntoIterator<Item = &'a C::Commitment>,
+    ) where
+        C::Commitment: 'a,
+    {
+        transcript.append_field_element(b"num_tables", &FieldElement::from(table_sizes.len() as u64));
+        for (&size, &lookups) in table_sizes.iter().zip(num_lookups) {
+            transcript.append_field_element(b"table_size", &FieldElement::from(size as u64));
+            transcript.append_field_element(b"num_lookups", &FieldElement::from(lookups as u64));
+        }
+        for commitment in commitments {
+            transcript.append_field_element(b"multi_lookup_commitment", &C::transcript_hash(commitment));
+        }
+    }
+    
+    /// Prove lookups into the virtual table `f(0), ..., f(size - 1)` without
+    /// materializing it; only `f` at the accessed indices is ever evaluated.
+    pub fn prove_virtual(
+        &self,
+        f: impl Fn(usize) -> FieldElement,
+        size: usize,
+        lookups: &[LookupOp],
+    ) -> Result<VirtualLookupProof<C>> {
+        let mut accessed_indices: Vec<usize> = lookups.iter().map(|op| op.index).collect();
+        accessed_indices.sort_unstable();
+        accessed_indices.dedup();
+        
+        if accessed_indices.last().is_some_and(|&index| index >= size) {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Lookup index out of bounds".to_string(),
+            ));
+        }
+        
+        // Compact table of the accessed entries, with lookups remapped into it
+        let mut compact = LookupTable::new(accessed_indices.iter().map(|&index| f(index)).collect());
+        for op in lookups {
+            let position = accessed_indices.binary_search(&op.index).expect("index was collected above");
+            if compact.lookup(position)? != op.value {
+                return Err(TwistAndShoutError::ProofGeneration(format!(
+                    "Lookup at index {} does not match the virtual table", op.index
+                )));
+            }
+        }
+        
+        let shout_proof = self.prove(&compact)?;
+        
+        // Open the compact table where the verifier can recompute it from f
+        let mut padded_entries = compact.entries;
+        padded_entries.resize(padded_entries.len().next_power_of_two(), FieldElement::zero());
+        let table_poly = self.vector_to_polynomial(&padded_entries)?;
+        
+        let point = Self::virtual_table_challenge(&self.prover_params.fiat_shamir_seed, &shout_proof, size, &accessed_indices);
+        let (_, table_opening) = C::open(&self.commitment_key(), &table_poly, point)?;
+        
+        Ok(VirtualLookupProof {
+            table_size: size,
+            accessed_indices,
+            shout_proof,
+            table_opening,
+        })
+    }
+    
+    /// Verify a [`VirtualLookupProof`] against the table function `f`.
+    ///
+    /// Costs one evaluation of `f` per accessed index, independent of the table size.
+    pub fn verify_virtual(
+        &self,
+        proof: &VirtualLookupProof<C>,
+        f: impl Fn(usize) -> FieldElement,
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        let indices = &proof.accessed_indices;
+        if indices.is_empty()
+            || indices.windows(2).any(|pair| pair[0] >= pair[1])
+            || indices[indices.len() - 1] >= proof.table_size
+        {
+            return Ok(false);
+        }
+        
+        if !self.verify(&proof.shout_proof, verifier_params)? {
+            return Ok(false);
+        }
+        
+        // Evaluate the interpolated compact table at the challenge point
+        let padded_size = indices.len().next_power_of_two();
+        let compact_values: Vec<FieldElement> = (0..padded_size)
+            .map(|j| indices.get(j).map_or(FieldElement::zero(), |&index| f(index)))
+            .collect();
+        let compact_poly = poly_utils::interpolate_domain(&compact_values);
+        
+        let point = Self::virtual_table_challenge(
+            &verifier_params.fiat_shamir_seed,
+            &proof.shout_proof,
+            proof.table_size,
+            indices,
+        );
+        let expected = crate::polynomials::poly_utils::evaluate_polynomial(&compact_poly, point);
+        
+        C::verify(
+            &C::verifier_key(verifier_params),
+            &proof.shout_proof.table_commitment,
+            point,
+            expected,
+            &proof.table_opening,
+        )
+    }
+    
+    /// Prove that the recorded lookups hit each table index exactly
+    /// `expected_counts[i]` times.
+    ///
+    /// The lookup argument already commits to a multiplicity column that its
+    /// LogUp sum pins to the true counts, so this is a plain lookup proof; the
+    /// verifier checks that column's opening against the public counts.
+    pub fn prove_multiplicities(&self, table: &LookupTable, expected_counts: &[usize]) -> Result<ShoutProof<C>> {
+        if expected_counts.len() != table.size() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Expected one count per table entry".to_string(),
+            ));
+        }
+        
+        let mut counts = vec![0usize; table.size()];
+        for op in &table.lookups {
+            counts[op.index] += 1;
+        }
+        if counts != expected_counts {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Lookup multiplicities do not match the expected counts".to_string(),
+            ));
+        }
+        
+        self.prove(table)
+    }
+    
+    /// Verify a proof from [`Shout::prove_multiplicities`] against the claimed counts
+    pub fn verify_multiplicities(
+        &self,
+        proof: &ShoutProof<C>,
+        expected_counts: &[usize],
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        // The counts fix the table size and the number of lookups
+        let num_lookups = expected_counts.iter().try_fold(0usize, |total, &count| total.checked_add(count));
+        if expected_counts.len() != proof.table_size || num_lookups != Some(proof.num_lookups) {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Ok(Self::verify_report(proof, verifier_params, &mut transcript, Some(expected_counts))?.is_valid())
+    }
+    
+    /// Prove the lookups of `table` followed by `new_lookups`, and that this
+    /// sequence begins with the lookups `previous` proved.
+    ///
+    /// `table` must hold exactly the lookups of `previous`, which must come from
+    /// [`Shout::prove`]; its index commitment serves as the commitment to the
+    /// shared prefix. Verify with [`Shout::verify_extends`].
+    pub fn prove_extends(
+        &self,
+        previous: &ShoutProof<C>,
+        table: &LookupTable,
+        new_lookups: &[LookupOp],
+    ) -> Result<ShoutProof<C>> {
+        let prefix_length = table.lookups.len();
+        let prefix_poly = self.vector_to_polynomial(&Self::padded_indices(&table.lookups))?;
+        let params = self.commitment_key();
+        if C::commit(&params, &prefix_poly)? != previous.index_commitment {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Lookups do not match the previously proven sequence".to_string(),
+            ));
+        }
+        
+        let mut extended = table.clone();
+        extended.lookups.extend_from_slice(new_lookups);
+        let mut proof = self.prove(&extended)?;
+        if proof.table_commitment != previous.table_commitment {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Extension must look up the same table".to_string(),
+            ));
+        }
+        
+        // index(X) - prefix(X) vanishes on the shared rows 0..prefix_length
+        let mut index_poly = self.vector_to_polynomial(&Self::padded_indices(&extended.lookups))?;
+        let mut difference = index_poly.clone();
+        difference.resize(difference.len().max(prefix_poly.len()), FieldElement::zero());
+        for (coeff, prefix_coeff) in difference.iter_mut().zip(&prefix_poly) {
+            *coeff -= prefix_coeff;
+        }
+        let vanishing = poly_utils::vanishing_polynomial(prefix_length);
+        let quotient = crate::commitments::polynomial_division(&difference, &vanishing)?;
+        let quotient_commitment = C::commit(&params, &quotient)?;
+        
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let point = Self::absorb_extension_statement(
+            &mut transcript,
+            previous,
+            &proof,
+            prefix_length,
+            &quotient_commitment,
+        );
+        
+        index_poly.resize(index_poly.len().max(1), FieldElement::zero());
+        let mut opening_proofs = Vec::with_capacity(3);
+        let mut evaluations = Vec::with_capacity(3);
+        for poly in [&index_poly, &prefix_poly, &quotient] {
+            let (evaluation, opening) = C::open(&params, poly, point)?;
+            evaluations.push(evaluation);
+            opening_proofs.push(opening);
+        }
+        
+        // Division leaves no remainder only when the prefix is kept
+        if evaluations[0] - evaluations[1] != poly_utils::evaluate_polynomial(&vanishing, point) * evaluations[2] {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Lookups do not begin with the previously proven sequence".to_string(),
+            ));
+        }
+        
+        proof.extension_proof = Some(PrefixExtensionProof {
+            prefix_length,
+            quotient_commitment,
+            opening_proofs,
+            evaluations,
+        });
+        Ok(proof)
+    }
+    
+    /// Verify a proof from [`Shout::prove_extends`] against the earlier proof it extends
+    pub fn verify_extends(
+        &self,
+        previous: &ShoutProof<C>,
+        proof: &ShoutProof<C>,
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        let extension = match &proof.extension_proof {
+            Some(extension) => extension,
+            None => return Ok(false),
+        };
+        
+        // The prefix length must be the earlier proof's lookup count and fit in this one
+        if extension.prefix_length != previous.num_lookups
+            || extension.prefix_length > proof.num_lookups
+            || previous.table_commitment != proof.table_commitment
+            || extension.opening_proofs.len() != 3
+            || extension.evaluations.len() != 3
+        {
+            return Ok(false);
+        }
+        
+        if !self.verify(previous, verifier_params)? || !self.verify(proof, verifier_params)? {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let point = Self::absorb_extension_statement(
+            &mut transcript,
+            previous,
+            proof,
+            extension.prefix_length,
+            &extension.quotient_commitment,
+        );
+        
+        let vk = C::verifier_key(verifier_params);
+        let commitments = [&proof.index_commitment, &previous.index_commitment, &extension.quotient_commitment];
+        for ((commitment, &evaluation), opening) in commitments.iter().zip(&extension.evaluations).zip(&extension.opening_proofs) {
+            if !C::verify(&vk, commitment, point, evaluation, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        let vanishing: FieldElement = (0..extension.prefix_length)
+            .map(|k| point - FieldElement::from(k as u64))
+            .product();
+        Ok(extension.evaluations[0] - extension.evaluations[1] == vanishing * extension.evaluations[2])
+    }
+    
+    /// Absorb the extension statement and derive the evaluation point
+    fn absorb_extension_statement(
+        transcript: &mut impl FiatShamirTranscript,
+        previous: &ShoutProof<C>,
+        proof: &ShoutProof<C>,
+        prefix_length: usize,
+        quotient_commitment: &C::Commitment,
+    ) -> FieldElement {
+        transcript.append_field_element(b"extension_table", &C::transcript_hash(&proof.table_commitment));
+        transcript.append_field_element(b"extension_prefix", &C::transcript_hash(&previous.index_commitment));
+        transcript.append_field_element(b"extension_indices", &C::transcript_hash(&proof.index_commitment));
+        transcript.append_field_element(b"extension_prefix_length", &FieldElement::from(prefix_length as u64));
+        transcript.append_field_element(b"extension_quotient", &C::transcript_hash(quotient_commitment));
+        
+        transcript.challenge_field_element(b"extension_point")
+    }
+    
+    /// Lookup indices zero-padded to a power of two, as committed by [`Shout::prove`]
+    fn padded_indices(lookups: &[LookupOp]) -> Vec<FieldElement> {
+        let mut indices: Vec<FieldElement> = lookups
+            .iter()
+            .map(|lookup| FieldElement::from(lookup.index as u64))
+            .collect();
+        indices.resize(lookups.len().next_power_of_two().max(1), FieldElement::zero());
+        indices
+    }
+    
+    /// Challenge point binding the compact table commitment to the accessed indices
+    fn virtual_table_challenge(
+        seed: &[u8; 32],
+        shout_proof: &ShoutProof<C>,
+        table_size: usize,
+        accessed_indices: &[usize],
+    ) -> FieldElement {
+        let mut transcript = Transcript::new(seed);
+        transcript.append_field_element(b"virtual_table_size", &FieldElement::from(table_size as u64));
+        transcript.append_field_element(b"virtual_table_commitment", &C::transcript_hash(&shout_proof.table_commitment));
+        let indices: Vec<FieldElement> = accessed_indices.iter().map(|&i| FieldElement::from(i as u64)).collect();
+        transcript.append_field_elements(b"virtual_table_indices", &indices);
+        transcript.challenge_field_element(b"virtual_table_point")
+    }
+    
+    /// Convert a vector to polynomial coefficients via interpolation
+    fn vector_to_polynomial(&self, vector: &[FieldElement]) -> Result<Vec<FieldElement>> {
+        Ok(poly_utils::interpolate_domain(vector))
+    }
+}
+
+
+/// Lookup-argument columns committed before the fingerprint challenges:
+/// index, value, table entry and multiplicity
+const LOOKUP_COLUMNS: usize = 4;
+
+/// Position of the multiplicity column among the lookup-argument columns
+const MULTIPLICITY_COLUMN: usize = 3;
+
+/// Inverse-fingerprint columns committed after the fingerprint challenges:
+/// lookups and table entries
+const LOOKUP_FINGERPRINT_COLUMNS: usize = 2;
+
+/// Per-variable degree of the lookup summand
+const LOOKUP_CHECK_DEGREE: usize = 3;
+
+/// Fiat-Shamir challenges of the lookup sum-check
+#[derive(Debug, Clone, Copy)]
+struct LookupChallenges {
+    /// Fingerprint challenges: pair (k, v) maps to γ - k - βv
+    beta: FieldElement,
+    gamma: FieldElement,
+    /// Combines the index, value and table columns in the bridge term
+    alpha: FieldElement,
+    /// Separates the two constraints within a row
+    lambda: FieldElement,
+    /// Separates the zero-check, the fingerprint sum and the bridge term
+    mu: FieldElement,
+}
+
+impl LookupChallenges {
+    /// Fingerprint of the pair (index, value)
+    fn fingerprint(&self, index: FieldElement, value: FieldElement) -> FieldElement {
+        self.gamma - index - self.beta * value
+    }
+}
+
+/// Inverse-fingerprint columns h and g for the columns from
+/// [`Shout::lookup_check_columns`]: the inverse fingerprint of each lookup,
+/// and of each table entry weighted by its multiplicity, zero past the entries
+fn lookup_fingerprint_columns(
+    columns: &[Vec<FieldElement>],
+    table_size: usize,
+    beta: FieldElement,
+    gamma: FieldElement,
+) -> Result<Vec<Vec<FieldElement>>> {
+    let rows = columns[0].len();
+    let fingerprint = |index: FieldElement, value: FieldElement| gamma - index - beta * value;
+    let mut denominators: Vec<FieldElement> = (0..rows).map(|i| fingerprint(columns[0][i], columns[1][i])).collect();
+    denominators.extend((0..table_size).map(|k| fingerprint(FieldElement::from(k as u64), columns[2][k])));
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err(TwistAndShoutError::ProofGeneration(
+            "Fingerprint challenge collided with a lookup".to_string(),
+        ));
+    }
+    
+    let mut lookups = crate::utils::field_utils::batch_inverse(&denominators);
+    let mut entries = lookups.split_off(rows);
+    for (entry, multiplicity) in entries.iter_mut().zip(&columns[3]) {
+        *entry *= multiplicity;
+    }
+    entries.resize(rows, FieldElement::zero());
+    Ok(vec![lookups, entries])
+}
+
+/// Summand of the lookup sum-check at one point.
+///
+/// `public` holds the tables the verifier evaluates itself: eq(τ, ·), the
+/// row index, the selector of the real table entries, and the Lagrange
+/// weights ℓ_z over the lookup and table domains. `columns` holds the
+/// committed columns in commitment order. The summand is
+/// eq·(c_h + λc_g) + μ(h - g) + μ²(ℓ_L(idx + αv) + α²ℓ_S·t), where c_h says
+/// h inverts each lookup's fingerprint and c_g says g is each entry's
+/// multiplicity over its fingerprint.
+///
+/// The fingerprint term sums to zero exactly when every lookup (idx, v) is
+/// an entry (k, T[k]) of the table, the LogUp identity. The bridge term
+/// sums to the univariate commitments opened at z, tying them to the
+/// committed multilinear columns.
+fn lookup_check_summand(public: [FieldElement; 5], columns: &[FieldElement], challenges: &LookupChallenges) -> FieldElement {
+    let [eq, id, in_table, lookup_lagrange, table_lagrange] = public;
+    let &[index, value, entry, multiplicity, lookup_inverse, entry_inverse] = columns else {
+        return FieldElement::zero();
+    };
+    
+    let lookup_constraint = lookup_inverse * challenges.fingerprint(index, value) - FieldElement::one();
+    let entry_constraint = entry_inverse * challenges.fingerprint(id, entry) - multiplicity * in_table;
+    let alpha = challenges.alpha;
+    let bridge = lookup_lagrange * (index + alpha * value) + alpha * alpha * table_lagrange * entry;
+    eq * (lookup_constraint + challenges.lambda * entry_constraint)
+        + challenges.mu * (lookup_inverse - entry_inverse + challenges.mu * bridge)
+}
+
+/// Claimed sum of the lookup sum-check, given the table, index and value
+/// commitments opened at z
+fn lookup_check_claim(bridge_evaluations: &[FieldElement], challenges: &LookupChallenges) -> FieldElement {
+    let &[table, index, value] = bridge_evaluations else {
+        return FieldElement::zero();
+    };
+    let alpha = challenges.alpha;
+    challenges.mu * challenges.mu * (index + alpha * (value + alpha * table))
+}
+
+/// Summand of the multi-table sum-check: Σₖ ρᵏ·sₖ over the tables'
+/// [`lookup_check_summand`]s. `public` holds eq(τ, ·) and the row index,
+/// then each table's entry selector and Lagrange weights over its lookup and
+/// table domains; `columns` holds every table's lookup columns, then every
+/// table's inverse-fingerprint columns.
+fn multi_lookup_summand(public: &[FieldElement], columns: &[FieldElement], challenges: &LookupChallenges, rho: FieldElement) -> FieldElement {
+    let (&[eq, id], table_public) = public.split_at(2) else {
+        return FieldElement::zero();
+    };
+    let num_tables = table_public.len() / 3;
+    if columns.len() != num_tables * (LOOKUP_COLUMNS + LOOKUP_FINGERPRINT_COLUMNS) {
+        return FieldElement::zero();
+    }
+    let (lookup_columns, fingerprint_columns) = columns.split_at(num_tables * LOOKUP_COLUMNS);
+    
+    let summands = table_public
+        .chunks_exact(3)
+        .zip(lookup_columns.chunks_exact(LOOKUP_COLUMNS))
+        .zip(fingerprint_columns.chunks_exact(LOOKUP_FINGERPRINT_COLUMNS))
+        .map(|((public, lookup), fingerprint)| {
+            let table_columns = [lookup[0], lookup[1], lookup[2], lookup[3], fingerprint[0], fingerprint[1]];
+            lookup_check_summand([eq, id, public[0], public[1], public[2]], &table_columns, challenges)
+        });
+    summands.rev().fold(FieldElement::zero(), |acc, summand| acc * rho + summand)
+}
+
+/// Claimed sum of the multi-table sum-check, given each table's table,
+/// index and value commitments opened at z
+fn multi_lookup_claim(bridge_evaluations: &[FieldElement], challenges: &LookupChallenges, rho: FieldElement) -> FieldElement {
+    bridge_evaluations
+        .chunks_exact(3)
+        .rev()
+        .fold(FieldElement::zero(), |acc, evaluations| acc * rho + lookup_check_claim(evaluations, challenges))
+}
+
+/// Public tables of [`lookup_check_summand`] evaluated at `point`
+fn lookup_check_public_values(
+    tau: &[FieldElement],
+    point: &[FieldElement],
+    table_size: usize,
+    lookup_rows: usize,
+    table_rows: usize,
+    bridge_point: FieldElement,
+) -> Result<[FieldElement; 5]> {
+    Ok([
+        eq_eval(tau, point)?,
+        poly_utils::hypercube_index(point),
+        poly_utils::prefix_selector(point, table_size),
+        poly_utils::lagrange_basis_mle(lookup_rows, bridge_point, point),
+        poly_utils::lagrange_basis_mle(table_rows, bridge_point, point),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::setup_params;
+    
+    #[test]
+    fn test_private_table_rejects_substituted_value() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let mut rng = ark_std::test_rng();
+        let shout = Shout::new(&prover_params);
+        
+        let entries: Vec<FieldElement> = (0..8u64).map(|i| FieldElement::from(i * i + 3)).collect();
+        let mut table = shout.commit_private_table(entries.clone(), 4, &mut rng).unwrap();
+        let indices = [6, 1, 4];
+        let honest: Vec<FieldElement> = indices.iter().map(|&i| entries[i]).collect();
+        
+        let proof = shout.prove_private_table_values(&mut table, &indices, &honest, &mut rng).unwrap();
+        assert!(shout.verify_private_table(table.commitment(), &indices, &proof, &verifier_params).unwrap());
+        
+        for k in 0..indices.len() {
+            let mut substituted = honest.clone();
+            substituted[k] += FieldElement::from(1u64);
+            let proof = shout.prove_private_table_values(&mut table, &indices, &substituted, &mut rng).unwrap();
+            assert!(!shout.verify_private_table(table.commitment(), &indices, &proof, &verifier_params).unwrap());
+        }
+        assert_eq!(table.remaining_proofs(), 0);
+        
+        // Repeated indices must read the same value
+        let mut table = shout.commit_private_table(entries.clone(), 1, &mut rng).unwrap();
+        let split = [entries[2], entries[2] + FieldElement::from(1u64)];
+        assert!(shout.prove_private_table_values(&mut table, &[2, 2], &split, &mut rng).is_err());
+    }
+    
+    /// Follow [`Shout::prove_lookups`] for the lookups of `table`, but let
+    /// `tamper` edit the lookup-argument columns before they are committed and
+    /// run the lookup rounds on a constant that sums to the claim, as a prover
+    /// whose columns do not satisfy the constraints must
+    fn forge_proof(shout: &Shout, table: &LookupTable, tamper: impl Fn(&mut Vec<Vec<FieldElement>>)) -> ShoutProof {
+        let table_rows = table.entries.len().next_power_of_two();
+        let lookup_rows = table.lookups.len().next_power_of_two().max(1);
+        let rows = table_rows.max(lookup_rows);
+        let num_vars = poly_utils::log2_exact(rows);
+        let mut columns = Shout::<KZGCommitment>::lookup_check_columns(table, rows).unwrap();
+        tamper(&mut columns);
+        
+        let polynomials = [&columns[2][..table_rows], &columns[0][..lookup_rows], &columns[1][..lookup_rows]]
+            .map(|column| shout.vector_to_polynomial(column).unwrap());
+        let commitments = polynomials.clone().map(|polynomial| KZGCommitment::commit(&shout.commitment_key(), &polynomial).unwrap());
+        let mut transcript = Transcript::new(&shout.prover_params.fiat_shamir_seed);
+        Shout::<KZGCommitment>::absorb_public_inputs(
+            &mut transcript,
+            table.entries.len(),
+            table.lookups.len(),
+            [&commitments[0], &commitments[1], &commitments[2]],
+        );
+        
+        let column_params = MultilinearKZG::derive_params(&shout.prover_params.commitment_params, num_vars).unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let (beta, gamma) = Shout::<KZGCommitment>::absorb_lookup_columns(&mut transcript, &column_commitments);
+        
+        let fingerprints = lookup_fingerprint_columns(&columns, table.entries.len(), beta, gamma).unwrap();
+        let slices: Vec<&[FieldElement]> = fingerprints.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let bridge_point = Shout::<KZGCommitment>::absorb_lookup_fingerprints(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        columns.extend(fingerprints);
+        
+        let (final_evaluations, opening_proofs): (Vec<_>, Vec<_>) = polynomials
+            .iter()
+            .map(|polynomial| KZGCommitment::open(&shout.commitment_key(), polynomial, bridge_point).unwrap())
+            .unzip();
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Shout::<KZGCommitment>::absorb_lookup_bridge(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claim = lookup_check_claim(&final_evaluations, &challenges);
+        
+        let constant = claim * FieldElement::from(rows as u64).inverse().unwrap();
+        let (lookup_proof, point) = SumCheck::new(num_vars, claim)
+            .with_max_degree(LOOKUP_CHECK_DEGREE)
+            .prove_with_challenges(|_: &[FieldElement]| constant, &mut transcript)
+            .unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let lookup_opening = ColumnOpening::open(&column_params, column_commitments, &slices, &point, &mut transcript).unwrap();
+        
+        let [table_commitment, index_commitment, value_commitment] = commitments;
+        ShoutProof {
+            params: shout.prover_params.proof_params(),
+            table_size: table.entries.len(),
+            num_lookups: table.lookups.len(),
+            table_commitment,
+            index_commitment,
+            value_commitment,
+            lookup_proof,
+            lookup_opening,
+            opening_proofs,
+            final_evaluations,
+            extension_proof: None,
+        }
+    }
+    
+    #[test]
+    fn test_rejects_sumcheck_unbacked_by_column_openings() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let shout = Shout::new(&prover_params);
+        let entries: Vec<FieldElement> = (0..4u64).map(|i| FieldElement::from(10 * i)).collect();
+        
+        // A lookup of entry 1 that reads 999 is no table entry
+        let mut table = LookupTable::new(entries);
+        table.lookups.push(LookupOp { index: 1, value: FieldElement::from(999u64) });
+        assert!(shout.prove(&table).is_err());
+        
+        // Every round checks out, but the opened columns do not give the
+        // final evaluation
+        let forged = forge_proof(&shout, &table, |_| {});
+        let report = shout.verify_verbose(&forged, &verifier_params).unwrap();
+        assert!(report.check("lookup_sumcheck").unwrap().passed);
+        assert!(!report.check("lookup_binding").unwrap().passed);
+        assert!(!report.is_valid());
+        
+        // Nor does a table column that claims entry 1 holds 999
+        let forged = forge_proof(&shout, &table, |columns| columns[2][1] = FieldElement::from(999u64));
+        assert!(!shout.verify(&forged, &verifier_params).unwrap());
+        
+        table.lookups[0].value = FieldElement::from(10u64);
+        assert!(shout.verify(&shout.prove(&table).unwrap(), &verifier_params).unwrap());
+    }
+    
+    #[test]
+    fn test_rejects_forged_lookup_in_table_padding() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let shout = Shout::new(&prover_params);
+        
+        // Index 6 of a 5-entry table reads the zero padding, which the table
+        // selector leaves out of the lookup argument
+        let mut table = LookupTable::new((1..=5u64).map(FieldElement::from).collect());
+        table.lookups.push(LookupOp { index: 6, value: FieldElement::zero() });
+        assert!(shout.prove(&table).is_err());
+        
+        let forged = forge_proof(&shout, &table, |_| {});
+        assert_eq!(forged.table_size, 5);
+        let report = shout.verify_verbose(&forged, &verifier_params).unwrap();
+        assert!(report.check("lookup_sumcheck").unwrap().passed);
+        assert!(!report.check("lookup_binding").unwrap().passed);
+    }
+    
+    /// Build a multi-table proof the way [`Shout::prove_multi`] does, but run
+    /// the batched rounds on a constant that sums to the claim
+    fn forge_multi_proof(shout: &Shout, tables: &[LookupTable]) -> MultiShoutProof {
+        let table_sizes: Vec<usize> = tables.iter().map(LookupTable::size).collect();
+        let num_lookups: Vec<usize> = tables.iter().map(|table| table.lookups.len()).collect();
+        let (shapes, num_vars) = Shout::<KZGCommitment>::multi_shape(&table_sizes, &num_lookups).unwrap();
+        let rows = 1 << num_vars;
+        
+        let mut columns = Vec::new();
+        let mut polynomials = Vec::new();
+        for (table, &(table_rows, lookup_rows)) in tables.iter().zip(&shapes) {
+            let table_columns = Shout::<KZGCommitment>::lookup_check_columns(table, rows).unwrap();
+            polynomials.push(shout.vector_to_polynomial(&table_columns[2][..table_rows]).unwrap());
+            polynomials.push(shout.vector_to_polynomial(&table_columns[0][..lookup_rows]).unwrap());
+            polynomials.push(shout.vector_to_polynomial(&table_columns[1][..lookup_rows]).unwrap());
+            columns.extend(table_columns);
+        }
+        let commitments: Vec<_> = polynomials
+            .iter()
+            .map(|polynomial| KZGCommitment::commit(&shout.commitment_key(), polynomial).unwrap())
+            .collect();
+        let mut transcript = Transcript::new(&shout.prover_params.fiat_shamir_seed);
+        Shout::<KZGCommitment>::absorb_multi_statement(&mut transcript, &table_sizes, &num_lookups, &commitments);
+        
+        let column_params = MultilinearKZG::derive_params(&shout.prover_params.commitment_params, num_vars).unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let (beta, gamma) = Shout::<KZGCommitment>::absorb_lookup_columns(&mut transcript, &column_commitments);
+        
+        let mut fingerprints = Vec::new();
+        for (table_columns, table) in columns.chunks(LOOKUP_COLUMNS).zip(tables) {
+            fingerprints.extend(lookup_fingerprint_columns(table_columns, table.entries.len(), beta, gamma).unwrap());
+        }
+        let slices: Vec<&[FieldElement]> = fingerprints.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let bridge_point = Shout::<KZGCommitment>::absorb_lookup_fingerprints(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        columns.extend(fingerprints);
+        
+        let (final_evaluations, opening_proofs): (Vec<_>, Vec<_>) = polynomials
+            .iter()
+            .map(|polynomial| KZGCommitment::open(&shout.commitment_key(), polynomial, bridge_point).unwrap())
+            .unzip();
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Shout::<KZGCommitment>::absorb_lookup_bridge(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let rho = transcript.challenge_field_element(b"multi_lookup_rho");
+        let claim = multi_lookup_claim(&final_evaluations, &challenges, rho);
+        
+        let constant = claim * FieldElement::from(rows as u64).inverse().unwrap();
+        let (lookup_proof, point) = SumCheck::new(num_vars, claim)
+            .with_max_degree(LOOKUP_CHECK_DEGREE)
+            .prove_with_challenges(|_: &[FieldElement]| constant, &mut transcript)
+            .unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let lookup_opening = ColumnOpening::open(&column_params, column_commitments, &slices, &point, &mut transcript).unwrap();
+        
+        let column = |i: usize| commitments.chunks(3).map(|group| group[i].clone()).collect();
+        MultiShoutProof {
+            params: shout.prover_params.proof_params(),
+            table_sizes,
+            num_lookups,
+            table_commitments: column(0),
+            index_commitments: column(1),
+            value_commitments: column(2),
+            lookup_proof,
+            lookup_opening,
+            opening_proofs,
+            final_evaluations,
+        }
+    }
+    
+    #[test]
+    fn test_rejects_forged_multi_table_lookup() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let shout = Shout::new(&prover_params);
+        let mut small = LookupTable::new((0..4u64).map(FieldElement::from).collect());
+        let mut large = LookupTable::new((0..8u64).map(|i| FieldElement::from(i * i)).collect());
+        small.lookup(3).unwrap();
+        large.lookup(5).unwrap();
+        large.lookup(2).unwrap();
+        
+        // Every round checks out, but a value that is no table entry leaves
+        // the opened columns short of the final evaluation
+        large.lookups[1].value = FieldElement::from(5u64);
+        let tables = [small, large];
+        assert!(shout.prove_multi(&tables).is_err());
+        let forged = forge_multi_proof(&shout, &tables);
+        assert!(!shout.verify_multi(&forged, &verifier_params).unwrap());
+    }
+    
+    /// Bitwise AND of two 4-bit operands packed as `a + 16·b`
+    struct AndTable;
+    
+    impl StructuredTable for AndTable {
+        fn num_vars(&self) -> usize {
+            8
+        }
+        
+        fn evaluate_mle(&self, point: &[FieldElement]) -> FieldElement {
+            (0..4).rev().fold(FieldElement::zero(), |acc, l| acc.double() + point[l] * point[l + 4])
+        }
+        
+        fn degree(&self) -> usize {
+            2
+        }
+    }
+    
+    #[test]
+    fn test_structured_lookup_with_quadratic_table() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let shout = Shout::new(&prover_params);
+        
+        let lookups: Vec<LookupOp> = [(0b1100usize, 0b1010usize), (0b0111, 0b0101), (0b1111, 0b0000)]
+            .iter()
+            .map(|&(a, b)| LookupOp { index: a + 16 * b, value: FieldElement::from((a & b) as u64) })
+            .collect();
+        assert!(lookups.iter().all(|op| AndTable.entry(op.index) == op.value));
+        
+        let proof = shout.prove_structured(&AndTable, &lookups).unwrap();
+        assert!(shout.verify_structured(&AndTable, &proof, &verifier_params).unwrap());
+        
+        // The same commitments do not pass as range checks of the same width
+        assert!(!shout.verify_structured(&RangeCheckTable::new(8), &proof, &verifier_params).unwrap());
+    }
     
     #[test]
     fn test_lookup_table() {
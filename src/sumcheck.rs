@@ -4,22 +4,111 @@
 //! It allows a prover to convince a verifier that the sum of a multivariate polynomial
 //! over the Boolean hypercube equals a claimed value.
 
-use crate::utils::{FieldElement, Transcript};
-use crate::polynomials::MultilinearExtension;
+use crate::utils::{CommitmentParams, CommitmentVerificationKey, FieldElement, G1Element, FiatShamirTranscript};
+use crate::polynomials::{poly_utils, MultilinearExtension};
+use crate::commitments::{BatchedMleProof, CommitmentScheme, KZGCommitment, KZGCommitmentValue, KZGProof, MultilinearKZG, MultilinearKZGParams, MultilinearKZGProof, MultilinearKZGVerifierKey};
 use crate::{Result, TwistAndShoutError};
-use ark_ff::{Field, Zero, One};
+use ark_ff::{Field, FftField, Zero, One};
+use ark_std::{rand::RngCore, UniformRand};
 use serde::{Deserialize, Serialize};
+use ark_std::{boxed::Box, format, string::ToString, vec, vec::Vec};
+
+/// Strategy for choosing the points at which round polynomials are sampled
+/// before interpolation.
+///
+/// The chosen points are absorbed into the transcript, so a verifier must be
+/// constructed with the same strategy as the prover.
+pub trait SamplePoints {
+    /// Return `count` distinct evaluation points, or an error if the strategy
+    /// cannot supply that many
+    fn points(&self, count: usize) -> Result<Vec<FieldElement>>;
+    
+    /// Write `out.len()` evaluation points into `out`, matching [`SamplePoints::points`]
+    fn fill_points(&self, out: &mut [FieldElement]) -> Result<()> {
+        out.copy_from_slice(&self.points(out.len())?);
+        Ok(())
+    }
+}
+
+/// Sample round polynomials at the integers 0, 1, 2, ...
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IntegerPoints;
+
+impl SamplePoints for IntegerPoints {
+    fn points(&self, count: usize) -> Result<Vec<FieldElement>> {
+        Ok((0..count).map(|i| FieldElement::from(i as u64)).collect())
+    }
+    
+    fn fill_points(&self, out: &mut [FieldElement]) -> Result<()> {
+        for (i, point) in out.iter_mut().enumerate() {
+            *point = FieldElement::from(i as u64);
+        }
+        Ok(())
+    }
+}
+
+/// Sample round polynomials at powers of a root of unity, so evaluations can be
+/// reused with FFT machinery
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RootsOfUnity;
+
+impl RootsOfUnity {
+    /// Generator of the smallest power-of-two subgroup holding `count` points
+    fn generator(count: usize) -> Result<FieldElement> {
+        count
+            .checked_next_power_of_two()
+            .and_then(|domain_size| FieldElement::get_root_of_unity(domain_size as u64))
+            .ok_or_else(|| {
+                TwistAndShoutError::SumCheck(format!(
+                    "{} sample points exceed the field's two-adicity", count
+                ))
+            })
+    }
+}
+
+impl SamplePoints for RootsOfUnity {
+    fn points(&self, count: usize) -> Result<Vec<FieldElement>> {
+        let omega = Self::generator(count)?;
+        Ok(crate::utils::field_utils::powers(omega, count))
+    }
+    
+    fn fill_points(&self, out: &mut [FieldElement]) -> Result<()> {
+        let omega = Self::generator(out.len())?;
+        let mut power = FieldElement::one();
+        for point in out.iter_mut() {
+            *point = power;
+            power *= omega;
+        }
+        Ok(())
+    }
+}
 
 /// Sum-check protocol instance
 #[derive(Debug, Clone)]
-pub struct SumCheck {
+pub struct SumCheck<S: SamplePoints = IntegerPoints> {
     /// Number of variables in the polynomial
     pub num_vars: usize,
     
     /// Claimed sum over the Boolean hypercube
     pub claimed_sum: FieldElement,
+    
+    /// Strategy for sampling round polynomial evaluations
+    pub sample_points: S,
+    
+    /// Bound on the polynomial's degree in each variable; round polynomials
+    /// are sampled at `max_degree + 1` points
+    pub max_degree: usize,
+    
+    /// Whether the instance came from [`SumCheck::new_zk`]
+    zero_knowledge: bool,
 }
 
+/// One polynomial of a [`SumCheck::prove_batch`] batch
+pub type BatchPolynomial = Box<dyn Fn(&[FieldElement]) -> FieldElement>;
+
+/// Per-variable degree bound used by [`SumCheck::new`]
+pub const DEFAULT_MAX_DEGREE: usize = 3;
+
 /// Sum-check proof
 #[derive(Debug, Clone)]
 pub struct SumCheckProof {
@@ -30,6 +119,436 @@ pub struct SumCheckProof {
     pub final_evaluation: FieldElement,
 }
 
+/// Zero-knowledge sum-check proof from [`SumCheck::prove_zk`].
+///
+/// The rounds prove Σ (f + ρ·g) = H + ρ·G for a random mask
+/// g(x) = Σ g_i(x_i), so the round polynomials say nothing about f beyond
+/// the claimed sum. The mask is opened separately at the final point.
+#[derive(Debug, Clone)]
+pub struct ZkSumCheckProof {
+    /// KZG commitments to the mask's univariate pieces g_i
+    pub mask_commitments: Vec<KZGCommitmentValue>,
+    
+    /// G, the sum of the mask over the hypercube
+    pub mask_sum: FieldElement,
+    
+    /// Sum-check of the masked polynomial f + ρ·g
+    pub masked_proof: SumCheckProof,
+    
+    /// g_i(r_i) at each round challenge, with opening proofs
+    pub mask_evaluations: Vec<FieldElement>,
+    pub mask_proofs: Vec<KZGProof>,
+    
+    /// The unmasked claim f(r), left for the caller to check. It is sent in
+    /// the clear, so the proof reveals this one evaluation of f; callers that
+    /// must hide it should open f(r) through a hiding commitment instead
+    pub final_evaluation: FieldElement,
+}
+
+impl SumCheckProof {
+    /// Size in bytes of the compressed canonical serialization
+    pub fn serialized_size(&self) -> usize {
+        use ark_serialize::CanonicalSerialize;
+        self.round_polynomials.compressed_size() + self.final_evaluation.compressed_size()
+    }
+    
+    /// Append the round count and each round polynomial's length to a
+    /// [`crate::utils::ProofLayout::Recursive`] header
+    pub(crate) fn push_shape(&self, header: &mut Vec<u64>) {
+        header.push(self.round_polynomials.len() as u64);
+        header.extend(self.round_polynomials.iter().map(|round| round.len() as u64));
+    }
+    
+    /// Read the round polynomial lengths written by [`Self::push_shape`]
+    pub(crate) fn read_shape(
+        header: &mut impl Iterator<Item = u64>,
+    ) -> ark_std::result::Result<Vec<usize>, ark_serialize::SerializationError> {
+        let rounds = crate::utils::next_header_entry(header)?;
+        (0..rounds).map(|_| crate::utils::next_header_entry(header)).collect()
+    }
+    
+    /// Write the round coefficients, round by round, then the final evaluation
+    pub(crate) fn write_scalars<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        use ark_serialize::CanonicalSerialize;
+        for coefficient in self.round_polynomials.iter().flatten() {
+            coefficient.serialize_compressed(&mut writer)?;
+        }
+        self.final_evaluation.serialize_compressed(&mut writer)
+    }
+    
+    /// Read a proof of the given shape written by [`Self::write_scalars`]
+    pub(crate) fn read_scalars<R: ark_serialize::Read>(
+        shape: &[usize],
+        mut reader: R,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        use ark_serialize::CanonicalDeserialize;
+        let round_polynomials = shape
+            .iter()
+            .map(|&length| {
+                (0..length)
+                    .map(|_| FieldElement::deserialize_compressed(&mut reader))
+                    .collect()
+            })
+            .collect::<ark_std::result::Result<Vec<_>, _>>()?;
+        Ok(Self {
+            round_polynomials,
+            final_evaluation: FieldElement::deserialize_compressed(&mut reader)?,
+        })
+    }
+}
+
+impl ark_serialize::Valid for SumCheckProof {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.round_polynomials.check()?;
+        self.final_evaluation.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for SumCheckProof {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.round_polynomials.serialize_with_mode(&mut writer, compress)?;
+        self.final_evaluation.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.round_polynomials.serialized_size(compress) + self.final_evaluation.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for SumCheckProof {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let round_polynomials = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        let final_evaluation = FieldElement::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { round_polynomials, final_evaluation })
+    }
+}
+
+/// Claimed evaluation of a committed factor at the sum-check point, with its
+/// multilinear KZG opening proof
+#[derive(Debug, Clone)]
+pub struct FactorOpening {
+    pub value: FieldElement,
+    pub proof: MultilinearKZGProof,
+}
+
+impl ark_serialize::Valid for FactorOpening {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.value.check()?;
+        self.proof.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for FactorOpening {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.value.serialize_with_mode(&mut writer, compress)?;
+        self.proof.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.value.serialized_size(compress) + self.proof.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for FactorOpening {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let value = FieldElement::deserialize_with_mode(&mut reader, compress, validate)?;
+        let proof = MultilinearKZGProof::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { value, proof })
+    }
+}
+
+/// Binds a zero-check's final evaluation to committed constraint rows.
+///
+/// The prover commits to each family of rows before the zero-check weights
+/// τ and λ are drawn, then proves Σₓ eq(τ, x)·R(x) = 0 for the combination
+/// R = Σₖ λᵏ rowsₖ. The commitment to R follows from the family commitments
+/// by homomorphism, so opening it at the sum-check point r lets the verifier
+/// check `final_evaluation` = eq(τ, r)·R(r) instead of taking it on trust.
+#[derive(Debug, Clone)]
+pub struct ConstraintOpening {
+    /// Multilinear KZG commitment to each family of rows
+    pub row_commitments: Vec<KZGCommitmentValue>,
+    
+    /// R(r) with its opening against the combined commitment
+    pub opening: FactorOpening,
+}
+
+impl ConstraintOpening {
+    /// Commit to each family of rows
+    pub fn commit(params: &MultilinearKZGParams, families: &[&[FieldElement]]) -> Result<Vec<KZGCommitmentValue>> {
+        families
+            .iter()
+            .map(|rows| KZGCommitment::commit_mle(params, rows))
+            .collect()
+    }
+    
+    /// The rows Σₖ λᵏ rowsₖ, row by row
+    pub fn combine_rows(families: &[&[FieldElement]], lambda: FieldElement) -> Vec<FieldElement> {
+        let len = families.first().map_or(0, |rows| rows.len());
+        (0..len)
+            .map(|i| families.iter().rev().fold(FieldElement::zero(), |acc, rows| acc * lambda + rows[i]))
+            .collect()
+    }
+    
+    /// Open the combined rows at `point`, keeping the family commitments
+    /// alongside for the verifier
+    pub fn open(
+        params: &MultilinearKZGParams,
+        row_commitments: Vec<KZGCommitmentValue>,
+        combined: &MultilinearExtension,
+        point: &[FieldElement],
+    ) -> Result<Self> {
+        let (value, proof) = MultilinearKZG::open(params, combined, point)?;
+        Ok(Self {
+            row_commitments,
+            opening: FactorOpening { value, proof },
+        })
+    }
+    
+    /// Commitment to Σₖ λᵏ rowsₖ, derived from the family commitments
+    pub fn combined_commitment(&self, lambda: FieldElement) -> Option<KZGCommitmentValue> {
+        self.row_commitments
+            .iter()
+            .rev()
+            .cloned()
+            .reduce(|acc, commitment| acc.scale(lambda) + commitment)
+    }
+    
+    /// Check the opening of the combined rows at `point`, returning the opened
+    /// value R(r) if it verifies
+    pub fn verify(
+        &self,
+        vk: &MultilinearKZGVerifierKey,
+        lambda: FieldElement,
+        point: &[FieldElement],
+    ) -> Result<Option<FieldElement>> {
+        let Some(commitment) = self.combined_commitment(lambda) else {
+            return Ok(None);
+        };
+        let valid = MultilinearKZG::verify(vk, &commitment, point, self.opening.value, &self.opening.proof)?;
+        Ok(valid.then_some(self.opening.value))
+    }
+    
+    /// Transcript hashes of the family commitments
+    pub fn commitment_hashes(row_commitments: &[KZGCommitmentValue]) -> Vec<FieldElement> {
+        row_commitments.iter().map(KZGCommitmentValue::hash).collect()
+    }
+    
+    /// Read the counts written by [`Self::push_shape`]
+    pub(crate) fn read_shape(
+        header: &mut impl Iterator<Item = u64>,
+    ) -> ark_std::result::Result<(usize, usize), ark_serialize::SerializationError> {
+        Ok((crate::utils::next_header_entry(header)?, crate::utils::next_header_entry(header)?))
+    }
+}
+
+impl ark_serialize::Valid for ConstraintOpening {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.row_commitments.check()?;
+        self.opening.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for ConstraintOpening {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.row_commitments.serialize_with_mode(&mut writer, compress)?;
+        self.opening.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.row_commitments.serialized_size(compress) + self.opening.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for ConstraintOpening {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let row_commitments = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        let opening = FactorOpening::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { row_commitments, opening })
+    }
+}
+
+/// Multilinear KZG commitments to the columns a sum-check's summand is built
+/// from, opened together at the sum-check point.
+///
+/// The prover commits to every column before the challenges that depend on
+/// them are drawn. Opening the columns at the point r the sum-check ends on
+/// lets the verifier recompute the summand at r itself and compare it with
+/// `final_evaluation`, rather than taking that value on trust.
+#[derive(Debug, Clone)]
+pub struct ColumnOpening {
+    /// Multilinear KZG commitment to each column
+    pub commitments: Vec<KZGCommitmentValue>,
+    
+    /// Each column's multilinear extension at the sum-check point
+    pub evaluations: Vec<FieldElement>,
+    
+    /// Single opening of the columns' random combination at that point
+    pub proof: BatchedMleProof,
+}
+
+impl ColumnOpening {
+    /// Commit to each column
+    pub fn commit(params: &MultilinearKZGParams, columns: &[&[FieldElement]]) -> Result<Vec<KZGCommitmentValue>> {
+        ConstraintOpening::commit(params, columns)
+    }
+    
+    /// Open the committed columns at `point`
+    pub fn open(
+        params: &MultilinearKZGParams,
+        commitments: Vec<KZGCommitmentValue>,
+        columns: &[&[FieldElement]],
+        point: &[FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<Self> {
+        let (evaluations, proof) = MultilinearKZG::open_batch_committed(params, columns, &commitments, point, transcript)?;
+        Ok(Self { commitments, evaluations, proof })
+    }
+    
+    /// Check that every column opens to its claimed evaluation at `point`
+    pub fn verify(
+        &self,
+        vk: &MultilinearKZGVerifierKey,
+        point: &[FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<bool> {
+        MultilinearKZG::verify_batch(vk, &self.commitments, point, &self.evaluations, &self.proof, transcript)
+    }
+    
+    /// Transcript hashes of the column commitments
+    pub fn commitment_hashes(commitments: &[KZGCommitmentValue]) -> Vec<FieldElement> {
+        ConstraintOpening::commitment_hashes(commitments)
+    }
+    
+    /// Append the column and quotient counts to a
+    /// [`crate::utils::ProofLayout::Recursive`] header
+    pub(crate) fn push_shape(&self, header: &mut Vec<u64>) {
+        header.push(self.commitments.len() as u64);
+        header.push(self.proof.combined.quotients.len() as u64);
+    }
+    
+    /// Read the counts written by [`Self::push_shape`]
+    pub(crate) fn read_shape(
+        header: &mut impl Iterator<Item = u64>,
+    ) -> ark_std::result::Result<(usize, usize), ark_serialize::SerializationError> {
+        ConstraintOpening::read_shape(header)
+    }
+    
+    /// Write the column evaluations to the scalar section
+    pub(crate) fn write_scalars<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        use ark_serialize::CanonicalSerialize;
+        for evaluation in &self.evaluations {
+            evaluation.serialize_compressed(&mut writer)?;
+        }
+        Ok(())
+    }
+    
+    /// Write the column commitments, then the opening quotients
+    pub(crate) fn write_groups<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        use ark_serialize::CanonicalSerialize;
+        for commitment in &self.commitments {
+            commitment.serialize_compressed(&mut writer)?;
+        }
+        for quotient in &self.proof.combined.quotients {
+            quotient.serialize_compressed(&mut writer)?;
+        }
+        Ok(())
+    }
+    
+    /// Read an opening of the given shape written by [`Self::write_groups`],
+    /// with its evaluations from the scalar section
+    pub(crate) fn read_groups<R: ark_serialize::Read>(
+        (num_commitments, num_quotients): (usize, usize),
+        evaluations: Vec<FieldElement>,
+        mut reader: R,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        use ark_serialize::CanonicalDeserialize;
+        let commitments = (0..num_commitments)
+            .map(|_| KZGCommitmentValue::deserialize_compressed(&mut reader))
+            .collect::<ark_std::result::Result<Vec<_>, _>>()?;
+        let quotients = (0..num_quotients)
+            .map(|_| G1Element::deserialize_compressed(&mut reader))
+            .collect::<ark_std::result::Result<Vec<_>, _>>()?;
+        Ok(Self {
+            commitments,
+            evaluations,
+            proof: BatchedMleProof { combined: MultilinearKZGProof { quotients } },
+        })
+    }
+}
+
+impl ark_serialize::Valid for ColumnOpening {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.commitments.check()?;
+        self.evaluations.check()?;
+        self.proof.combined.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for ColumnOpening {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.commitments.serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)?;
+        self.proof.combined.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.commitments.serialized_size(compress)
+            + self.evaluations.serialized_size(compress)
+            + self.proof.combined.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for ColumnOpening {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let commitments = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        let evaluations = Vec::deserialize_with_mode(&mut reader, compress, validate)?;
+        let combined = MultilinearKZGProof::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { commitments, evaluations, proof: BatchedMleProof { combined } })
+    }
+}
+
 /// Sum-check verifier state
 #[derive(Debug, Clone)]
 pub struct SumCheckVerifier {
@@ -43,34 +562,512 @@ pub struct SumCheckVerifier {
     pub expected_sum: FieldElement,
 }
 
+/// Write `prefix` followed by the decimal digits of `round` into `buf`,
+/// returning the label length. Matches `format!("{prefix}{round}")` without
+/// allocating.
+fn round_label(buf: &mut [u8; 48], prefix: &[u8], round: usize) -> usize {
+    buf[..prefix.len()].copy_from_slice(prefix);
+    let mut digits = [0u8; 20];
+    let mut count = 0;
+    let mut value = round;
+    loop {
+        digits[count] = b'0' + (value % 10) as u8;
+        count += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    for (offset, &digit) in digits[..count].iter().rev().enumerate() {
+        buf[prefix.len() + offset] = digit;
+    }
+    prefix.len() + count
+}
+
 impl SumCheck {
     /// Create a new sum-check instance
     pub fn new(num_vars: usize, claimed_sum: FieldElement) -> Self {
         Self {
             num_vars,
             claimed_sum,
+            sample_points: IntegerPoints,
+            max_degree: DEFAULT_MAX_DEGREE,
+            zero_knowledge: false,
+        }
+    }
+    
+    /// Create a zero-knowledge instance whose rounds are masked by a random
+    /// polynomial; prove with [`SumCheck::prove_zk`]
+    pub fn new_zk(num_vars: usize, claimed_sum: FieldElement) -> Self {
+        Self {
+            zero_knowledge: true,
+            ..Self::new(num_vars, claimed_sum)
+        }
+    }
+}
+
+impl<S: SamplePoints> SumCheck<S> {
+    /// Use a different strategy for sampling round polynomial evaluations
+    pub fn with_sample_points<T: SamplePoints>(self, sample_points: T) -> SumCheck<T> {
+        SumCheck {
+            num_vars: self.num_vars,
+            claimed_sum: self.claimed_sum,
+            sample_points,
+            max_degree: self.max_degree,
+            zero_knowledge: self.zero_knowledge,
         }
     }
     
+    /// Bound the polynomial's degree in each variable, for constraints that
+    /// multiply more than three multilinear factors
+    pub fn with_max_degree(mut self, max_degree: usize) -> Self {
+        self.max_degree = max_degree;
+        self
+    }
+    
+    /// Number of points each round polynomial is sampled at
+    fn round_points(&self) -> usize {
+        self.max_degree + 1
+    }
+    
     /// Prove that a polynomial sums to the claimed value over {0,1}^n
     pub fn prove<F>(
         &self,
         polynomial: F,
-        transcript: &mut Transcript,
+        transcript: &mut impl FiatShamirTranscript,
     ) -> Result<SumCheckProof>
     where
         F: Fn(&[FieldElement]) -> FieldElement,
     {
+        self.prove_with_challenges(polynomial, transcript).map(|(proof, _)| proof)
+    }
+    
+    /// Prove that a polynomial sums to the claimed value and return the random
+    /// point the final evaluation is claimed at, the same challenges
+    /// [`SumCheck::verify`] returns
+    pub fn prove_with_challenges<F>(
+        &self,
+        polynomial: F,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(SumCheckProof, Vec<FieldElement>)>
+    where
+        F: Fn(&[FieldElement]) -> FieldElement,
+    {
+        self.prove_with_point(polynomial, self.claimed_sum, transcript)
+    }
+    
+    /// Prove Σₓ combine(f₁(x), ..., fₖ(x)) = claimed sum, where fⱼ is the
+    /// multilinear extension of `tables[j]`.
+    ///
+    /// The rounds are those [`SumCheck::prove`] produces for the polynomial
+    /// x ↦ combine(f₁(x), ..., fₖ(x)), but each round folds the tables in
+    /// half rather than re-evaluating every extension over the remaining
+    /// hypercube, so proving is linear in the table size. Returns the proof,
+    /// the random point, and each fⱼ at that point.
+    pub fn prove_tables<F>(
+        &self,
+        mut tables: Vec<Vec<FieldElement>>,
+        combine: F,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(SumCheckProof, Vec<FieldElement>, Vec<FieldElement>)>
+    where
+        F: Fn(&[FieldElement]) -> FieldElement,
+    {
+        let size = 1usize.checked_shl(self.num_vars as u32).unwrap_or(0);
+        if size == 0 || tables.iter().any(|table| table.len() != size) {
+            return Err(TwistAndShoutError::SumCheck(
+                "Every table must hold one value per hypercube point".to_string(),
+            ));
+        }
+        
         let mut round_polynomials = Vec::with_capacity(self.num_vars);
+        let mut point = Vec::with_capacity(self.num_vars);
         let mut current_sum = self.claimed_sum;
+        let mut row = vec![FieldElement::zero(); tables.len()];
+        
+        let sample_points = self.sample_points.points(self.round_points())?;
+        if self.num_vars > 0 {
+            transcript.append_field_elements(b"sumcheck_sample_points", &sample_points);
+        }
+        
+        for round in 0..self.num_vars {
+            // g(t) = Σ_y combine(fⱼ(t, y)), with fⱼ linear in the variable being fixed
+            let half = size >> (round + 1);
+            let mut evaluations = Vec::with_capacity(sample_points.len());
+            for &t in &sample_points {
+                let mut sum = FieldElement::zero();
+                for y in 0..half {
+                    for (value, table) in row.iter_mut().zip(&tables) {
+                        let low = table[2 * y];
+                        *value = low + t * (table[2 * y + 1] - low);
+                    }
+                    sum += combine(&row);
+                }
+                evaluations.push(sum);
+            }
+            let points: Vec<(FieldElement, FieldElement)> = sample_points
+                .iter()
+                .cloned()
+                .zip(evaluations)
+                .collect();
+            let round_poly = crate::polynomials::poly_utils::lagrange_interpolate(&points);
+            
+            let g_0 = self.evaluate_round_polynomial(&round_poly, FieldElement::zero());
+            let g_1 = self.evaluate_round_polynomial(&round_poly, FieldElement::one());
+            if g_0 + g_1 != current_sum {
+                return Err(TwistAndShoutError::SumCheck(
+                    format!("Round {} consistency check failed", round),
+                ));
+            }
+            
+            transcript.append_field_elements(
+                format!("sumcheck_round_{}", round).as_bytes(),
+                &round_poly,
+            );
+            let challenge = transcript.challenge_field_element(
+                format!("sumcheck_challenge_{}", round).as_bytes(),
+            );
+            
+            for table in tables.iter_mut() {
+                for y in 0..half {
+                    let low = table[2 * y];
+                    table[y] = low + challenge * (table[2 * y + 1] - low);
+                }
+                table.truncate(half);
+            }
+            current_sum = self.evaluate_round_polynomial(&round_poly, challenge);
+            round_polynomials.push(round_poly);
+            point.push(challenge);
+        }
+        
+        let values: Vec<FieldElement> = tables.iter().map(|table| table[0]).collect();
+        let final_evaluation = combine(&values);
+        if final_evaluation != current_sum {
+            return Err(TwistAndShoutError::SumCheck(
+                "Tables do not sum to the claimed value".to_string(),
+            ));
+        }
+        
+        Ok((SumCheckProof { round_polynomials, final_evaluation }, point, values))
+    }
+    
+    /// Prove several sums over {0,1}^n with a single sum-check.
+    ///
+    /// The claimed sums are absorbed, a batching challenge γ is drawn, and the
+    /// sum-check runs on Σ γ^i·f_i against Σ γ^i·s_i. The instance's own
+    /// `claimed_sum` is not used.
+    pub fn prove_batch(
+        &self,
+        polys: &[BatchPolynomial],
+        claimed_sums: &[FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<SumCheckProof> {
+        self.prove_batch_with_challenges(polys, claimed_sums, transcript).map(|(proof, _)| proof)
+    }
+    
+    /// Prove a batch as [`SumCheck::prove_batch`] and return the random point
+    /// the combined final evaluation is claimed at, the same challenges
+    /// [`SumCheck::verify_batch`] returns
+    pub fn prove_batch_with_challenges(
+        &self,
+        polys: &[BatchPolynomial],
+        claimed_sums: &[FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(SumCheckProof, Vec<FieldElement>)> {
+        if polys.is_empty() || polys.len() != claimed_sums.len() {
+            return Err(TwistAndShoutError::SumCheck(
+                "Batch needs one claimed sum per polynomial".to_string(),
+            ));
+        }
+        
+        let powers = Self::batching_powers(claimed_sums, transcript);
+        let combined_sum = powers.iter().zip(claimed_sums).map(|(power, sum)| *power * sum).sum();
+        let combined = |vars: &[FieldElement]| {
+            powers.iter().zip(polys).map(|(power, poly)| *power * poly(vars)).sum()
+        };
+        
+        self.prove_with_point(combined, combined_sum, transcript)
+    }
+    
+    /// Verify a proof from [`SumCheck::prove_batch`] against the same claimed sums.
+    ///
+    /// The returned challenges are the point at which the verifier must check
+    /// the final evaluation against Σ γ^i·f_i.
+    pub fn verify_batch(
+        &self,
+        proof: &SumCheckProof,
+        claimed_sums: &[FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(bool, Vec<FieldElement>)> {
+        if claimed_sums.is_empty() {
+            return Ok((false, Vec::new()));
+        }
+        
+        let powers = Self::batching_powers(claimed_sums, transcript);
+        let combined_sum = powers.iter().zip(claimed_sums).map(|(power, sum)| *power * sum).sum();
+        self.verify_claim(proof, combined_sum, transcript)
+    }
+    
+    /// Absorb the claimed sums and return 1, γ, γ², ... for a batching challenge γ
+    fn batching_powers(claimed_sums: &[FieldElement], transcript: &mut impl FiatShamirTranscript) -> Vec<FieldElement> {
+        transcript.append_field_elements(b"sumcheck_batch_sums", claimed_sums);
+        let gamma = transcript.challenge_field_element(b"sumcheck_batch_gamma");
+        crate::utils::field_utils::powers(gamma, claimed_sums.len())
+    }
+    
+    /// Prove that the product of multilinear factors sums to the claimed value.
+    ///
+    /// The factors' commitments are absorbed before any challenge is drawn.
+    /// Returns the proof and the random point at which each factor must then be
+    /// opened for [`SumCheck::verify_product`].
+    pub fn prove_product(
+        &self,
+        factors: &[MultilinearExtension],
+        factor_commitments: &[KZGCommitmentValue],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(SumCheckProof, Vec<FieldElement>)> {
+        if factor_commitments.len() != factors.len() {
+            return Err(TwistAndShoutError::SumCheck(
+                "Every factor needs exactly one commitment".to_string(),
+            ));
+        }
+        if factors.is_empty() || factors.len() > self.max_degree {
+            return Err(TwistAndShoutError::SumCheck(format!(
+                "Product sum-check supports 1 to {} factors", self.max_degree
+            )));
+        }
+        if factors.iter().any(|factor| factor.num_vars != self.num_vars) {
+            return Err(TwistAndShoutError::SumCheck(
+                "Every factor must have the sum-check's number of variables".to_string(),
+            ));
+        }
+        
+        Self::absorb_factor_commitments(factor_commitments, transcript);
+        self.prove_with_point(
+            |vars: &[FieldElement]| {
+                let eq = poly_utils::eq_table(vars);
+                factors.iter().map(|factor| factor.evaluate_with_eq(&eq)).product()
+            },
+            self.claimed_sum,
+            transcript,
+        )
+    }
+    
+    /// Verify a product sum-check and open every committed factor at its final point.
+    ///
+    /// Accepts only if the rounds verify, every opening is valid for its
+    /// commitment at the sum-check point, and the opened values multiply to the
+    /// proof's final evaluation.
+    pub fn verify_product(
+        &self,
+        proof: &SumCheckProof,
+        transcript: &mut impl FiatShamirTranscript,
+        factor_commitments: &[KZGCommitmentValue],
+        openers: &[FactorOpening],
+        vk: &MultilinearKZGVerifierKey,
+    ) -> Result<bool> {
+        if factor_commitments.is_empty() || factor_commitments.len() != openers.len() {
+            return Ok(false);
+        }
+        
+        Self::absorb_factor_commitments(factor_commitments, transcript);
+        let (rounds_valid, point) = self.verify(proof, transcript)?;
+        if !rounds_valid {
+            return Ok(false);
+        }
+        
+        let mut product = FieldElement::one();
+        for (commitment, opening) in factor_commitments.iter().zip(openers) {
+            if !MultilinearKZG::verify(vk, commitment, &point, opening.value, &opening.proof)? {
+                return Ok(false);
+            }
+            product *= opening.value;
+        }
+        
+        Ok(product == proof.final_evaluation)
+    }
+    
+    /// Absorb the commitments of a product sum-check's factors
+    fn absorb_factor_commitments(factor_commitments: &[KZGCommitmentValue], transcript: &mut impl FiatShamirTranscript) {
+        transcript.append_field_elements(b"product_factor_commitments", &ColumnOpening::commitment_hashes(factor_commitments));
+    }
+    
+    /// Prove the sum in zero knowledge, following Chiesa–Forbes–Spooner.
+    ///
+    /// The prover commits to a random mask g(x) = Σ g_i(x_i) with each g_i of
+    /// degree `max_degree`, absorbs the commitments and G = Σ g, draws ρ, and
+    /// runs the sum-check on f + ρ·g against H + ρ·G. Each g_i is then opened
+    /// at its round challenge. The mask is drawn from `rng` on every call, so
+    /// no two proofs share one. Only instances from [`SumCheck::new_zk`] can
+    /// prove; the returned point is where `final_evaluation` = f(r) is claimed,
+    /// and that value is revealed in the clear.
+    pub fn prove_zk<F, R>(
+        &self,
+        polynomial: F,
+        params: &CommitmentParams,
+        rng: &mut R,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(ZkSumCheckProof, Vec<FieldElement>)>
+    where
+        F: Fn(&[FieldElement]) -> FieldElement,
+        R: RngCore,
+    {
+        if !self.zero_knowledge {
+            return Err(TwistAndShoutError::SumCheck(
+                "Zero-knowledge proving needs an instance from SumCheck::new_zk".to_string(),
+            ));
+        }
+        if self.num_vars == 0 {
+            return Err(TwistAndShoutError::SumCheck(
+                "Zero-knowledge sum-check needs at least one variable".to_string(),
+            ));
+        }
+        
+        let masks: Vec<Vec<FieldElement>> = (0..self.num_vars)
+            .map(|_| (0..=self.max_degree).map(|_| FieldElement::rand(rng)).collect())
+            .collect();
+        
+        // Each g_i sums to 2^(n-1)·(g_i(0) + g_i(1)) over the hypercube
+        let half_cube = FieldElement::from(2u64).pow([(self.num_vars - 1) as u64]);
+        let mask_sum: FieldElement = masks
+            .iter()
+            .map(|g| half_cube * (g[0] + g.iter().sum::<FieldElement>()))
+            .sum();
+        let mask_commitments = masks
+            .iter()
+            .map(|g| KZGCommitment::commit(params, g))
+            .collect::<Result<Vec<_>>>()?;
+        
+        let rho = Self::absorb_mask_statement(transcript, &mask_commitments, mask_sum);
+        let (masked_proof, point) = self.prove_with_point(
+            |vars: &[FieldElement]| {
+                let mask: FieldElement = masks
+                    .iter()
+                    .zip(vars)
+                    .map(|(g, &x)| self.evaluate_round_polynomial(g, x))
+                    .sum();
+                polynomial(vars) + rho * mask
+            },
+            self.claimed_sum + rho * mask_sum,
+            transcript,
+        )?;
+        
+        let (mask_evaluations, mask_proofs): (Vec<FieldElement>, Vec<KZGProof>) = masks
+            .iter()
+            .zip(&point)
+            .map(|(g, &r)| KZGCommitment::open(params, g, r))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let final_evaluation = masked_proof.final_evaluation - rho * mask_evaluations.iter().sum::<FieldElement>();
+        
+        Ok((ZkSumCheckProof {
+            mask_commitments,
+            mask_sum,
+            masked_proof,
+            mask_evaluations,
+            mask_proofs,
+            final_evaluation,
+        }, point))
+    }
+    
+    /// Verify a [`SumCheck::prove_zk`] proof.
+    ///
+    /// Checks the masked rounds against H + ρ·G and every mask opening, then
+    /// that the masked final evaluation is `final_evaluation` + ρ·g(r). The
+    /// caller must still check `final_evaluation` against its oracle for f at
+    /// the returned point. Any instance can verify.
+    pub fn verify_zk(
+        &self,
+        proof: &ZkSumCheckProof,
+        vk: &CommitmentVerificationKey,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(bool, Vec<FieldElement>)> {
+        if self.num_vars == 0
+            || proof.mask_commitments.len() != self.num_vars
+            || proof.mask_evaluations.len() != self.num_vars
+            || proof.mask_proofs.len() != self.num_vars
+        {
+            return Ok((false, Vec::new()));
+        }
+        
+        let rho = Self::absorb_mask_statement(transcript, &proof.mask_commitments, proof.mask_sum);
+        let (rounds_valid, point) = self.verify_claim(
+            &proof.masked_proof,
+            self.claimed_sum + rho * proof.mask_sum,
+            transcript,
+        )?;
+        if !rounds_valid {
+            return Ok((false, point));
+        }
+        
+        for (((commitment, &r), &value), opening) in proof
+            .mask_commitments
+            .iter()
+            .zip(&point)
+            .zip(&proof.mask_evaluations)
+            .zip(&proof.mask_proofs)
+        {
+            if !KZGCommitment::verify(vk, commitment, r, value, opening)? {
+                return Ok((false, point));
+            }
+        }
+        
+        let mask_at_point: FieldElement = proof.mask_evaluations.iter().sum();
+        Ok((proof.masked_proof.final_evaluation == proof.final_evaluation + rho * mask_at_point, point))
+    }
+    
+    /// Absorb the mask commitments and sum, returning the masking challenge ρ
+    fn absorb_mask_statement(
+        transcript: &mut impl FiatShamirTranscript,
+        mask_commitments: &[KZGCommitmentValue],
+        mask_sum: FieldElement,
+    ) -> FieldElement {
+        let hashes: Vec<FieldElement> = mask_commitments.iter().map(KZGCommitmentValue::hash).collect();
+        transcript.append_field_elements(b"zk_sumcheck_mask_commitments", &hashes);
+        transcript.append_field_element(b"zk_sumcheck_mask_sum", &mask_sum);
+        transcript.challenge_field_element(b"zk_sumcheck_rho")
+    }
+    
+    /// Prove the sum and return the challenges fixed along the way
+    fn prove_with_point<F>(
+        &self,
+        polynomial: F,
+        claimed_sum: FieldElement,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(SumCheckProof, Vec<FieldElement>)>
+    where
+        F: Fn(&[FieldElement]) -> FieldElement,
+    {
+        // With no variables the hypercube is a single point, so the "sum" is
+        // just the polynomial's value there and no rounds are needed
+        if self.num_vars == 0 {
+            let final_evaluation = polynomial(&[]);
+            if final_evaluation != claimed_sum {
+                return Err(TwistAndShoutError::SumCheck(
+                    "Zero-variable polynomial does not equal the claimed sum".to_string(),
+                ));
+            }
+            
+            return Ok((SumCheckProof {
+                round_polynomials: Vec::new(),
+                final_evaluation,
+            }, Vec::new()));
+        }
+        
+        let mut round_polynomials = Vec::with_capacity(self.num_vars);
+        let mut current_sum = claimed_sum;
         let mut fixed_variables = Vec::new();
         
+        let sample_points = self.sample_points.points(self.round_points())?;
+        transcript.append_field_elements(b"sumcheck_sample_points", &sample_points);
+        
         for round in 0..self.num_vars {
             // Compute the univariate polynomial for this round
             let round_poly = self.compute_round_polynomial(
                 &polynomial,
                 &fixed_variables,
-                round,
+                &sample_points,
             )?;
             
             // Check that g(0) + g(1) equals the expected sum
@@ -103,28 +1100,113 @@ impl SumCheck {
         // Final evaluation
         let final_evaluation = polynomial(&fixed_variables);
         
-        Ok(SumCheckProof {
+        Ok((SumCheckProof {
             round_polynomials,
             final_evaluation,
-        })
+        }, fixed_variables))
     }
     
     /// Verify a sum-check proof
     pub fn verify(
         &self,
         proof: &SumCheckProof,
-        transcript: &mut Transcript,
+        transcript: &mut impl FiatShamirTranscript,
     ) -> Result<(bool, Vec<FieldElement>)> {
+        self.verify_claim(proof, self.claimed_sum, transcript)
+    }
+    
+    /// Verify a sum-check proof without heap allocation, writing the sample
+    /// points and round challenges into `scratch`.
+    ///
+    /// `scratch` needs at least [`SumCheck::scratch_len`] elements; on success
+    /// the challenges are in `scratch[..num_vars]`. The transcript draws the
+    /// same challenges as [`SumCheck::verify`].
+    pub fn verify_with_scratch(
+        &self,
+        proof: &SumCheckProof,
+        transcript: &mut impl FiatShamirTranscript,
+        scratch: &mut [FieldElement],
+    ) -> Result<bool> {
         if proof.round_polynomials.len() != self.num_vars {
             return Err(TwistAndShoutError::SumCheck(
                 "Proof has wrong number of rounds".to_string(),
             ));
         }
+        if scratch.len() < self.scratch_len() {
+            return Err(TwistAndShoutError::ScratchTooSmall { required: self.scratch_len(), provided: scratch.len() });
+        }
+        
+        if self.num_vars == 0 {
+            return Ok(proof.final_evaluation == self.claimed_sum);
+        }
+        
+        let (challenges, sample_points) = scratch.split_at_mut(self.num_vars);
+        let sample_points = &mut sample_points[..self.round_points()];
+        self.sample_points.fill_points(sample_points)?;
+        transcript.append_field_elements(b"sumcheck_sample_points", sample_points);
         
+        // Round labels are formatted into a stack buffer
+        let mut label = [0u8; 48];
         let mut current_sum = self.claimed_sum;
+        for (round, round_poly) in proof.round_polynomials.iter().enumerate() {
+            if round_poly.len() > self.round_points() {
+                return Ok(false);
+            }
+            
+            let g_0 = self.evaluate_round_polynomial(round_poly, FieldElement::zero());
+            let g_1 = self.evaluate_round_polynomial(round_poly, FieldElement::one());
+            if g_0 + g_1 != current_sum {
+                return Ok(false);
+            }
+            
+            let len = round_label(&mut label, b"sumcheck_round_", round);
+            transcript.append_field_elements(&label[..len], round_poly);
+            
+            let len = round_label(&mut label, b"sumcheck_challenge_", round);
+            challenges[round] = transcript.challenge_field_element(&label[..len]);
+            
+            current_sum = self.evaluate_round_polynomial(round_poly, challenges[round]);
+        }
+        
+        Ok(current_sum == proof.final_evaluation)
+    }
+    
+    /// Scratch elements [`SumCheck::verify_with_scratch`] needs: one challenge
+    /// per variable plus the round sample points
+    pub fn scratch_len(&self) -> usize {
+        self.num_vars + self.round_points()
+    }
+    
+    /// Verify a sum-check proof against the given claimed sum
+    fn verify_claim(
+        &self,
+        proof: &SumCheckProof,
+        claimed_sum: FieldElement,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<(bool, Vec<FieldElement>)> {
+        if proof.round_polynomials.len() != self.num_vars {
+            return Err(TwistAndShoutError::SumCheck(
+                "Proof has wrong number of rounds".to_string(),
+            ));
+        }
+        
+        // Zero-variable case: the final evaluation is the whole sum
+        if self.num_vars == 0 {
+            return Ok((proof.final_evaluation == claimed_sum, Vec::new()));
+        }
+        
+        let mut current_sum = claimed_sum;
         let mut challenges = Vec::with_capacity(self.num_vars);
         
+        let sample_points = self.sample_points.points(self.round_points())?;
+        transcript.append_field_elements(b"sumcheck_sample_points", &sample_points);
+        
         for (round, round_poly) in proof.round_polynomials.iter().enumerate() {
+            // A round polynomial may not exceed the degree bound
+            if round_poly.len() > self.round_points() {
+                return Ok((false, challenges));
+            }
+            
             // Check that g(0) + g(1) equals the expected sum
             let g_0 = self.evaluate_round_polynomial(round_poly, FieldElement::zero());
             let g_1 = self.evaluate_round_polynomial(round_poly, FieldElement::one());
@@ -157,7 +1239,7 @@ impl SumCheck {
         &self,
         polynomial: &F,
         fixed_variables: &[FieldElement],
-        _round: usize,
+        sample_points: &[FieldElement],
     ) -> Result<Vec<FieldElement>>
     where
         F: Fn(&[FieldElement]) -> FieldElement,
@@ -170,10 +1252,9 @@ impl SumCheck {
         // Sample points to determine the degree of the univariate polynomial
         let mut evaluations = Vec::new();
         
-        // Evaluate at X = 0, 1, 2, ... to get enough points for interpolation
+        // Evaluate at the sample points to get enough points for interpolation
         // The degree should be at most the degree of the original polynomial in this variable
-        for x_val in 0..=3 {  // Assume degree <= 3 for now, can be made adaptive
-            let x = FieldElement::from(x_val as u64);
+        for &x in sample_points {
             let mut sum = FieldElement::zero();
             
             for suffix_index in 0..num_points {
@@ -198,8 +1279,10 @@ impl SumCheck {
         }
         
         // Interpolate to get polynomial coefficients
-        let points: Vec<(FieldElement, FieldElement)> = (0..evaluations.len())
-            .map(|i| (FieldElement::from(i as u64), evaluations[i]))
+        let points: Vec<(FieldElement, FieldElement)> = sample_points
+            .iter()
+            .cloned()
+            .zip(evaluations)
             .collect();
         
         let coeffs = crate::polynomials::poly_utils::lagrange_interpolate(&points);
@@ -215,7 +1298,7 @@ impl SumCheck {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::utils::setup_params;
+    use crate::utils::{setup_params, RecordingTranscript, Transcript};
     
     #[test]
     fn test_sumcheck_simple() {
@@ -243,4 +1326,216 @@ mod tests {
         
         assert!(is_valid);
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_sumcheck_sample_point_strategies() {
+        // f(x1, x2, x3) = x1 * x2 + x3, summing to 2 + 4 = 6 over {0,1}^3
+        let polynomial = |vars: &[FieldElement]| -> FieldElement {
+            vars[0] * vars[1] + vars[2]
+        };
+        let claimed_sum = FieldElement::from(6u64);
+        
+        let integer = SumCheck::new(3, claimed_sum);
+        let roots = SumCheck::new(3, claimed_sum).with_sample_points(RootsOfUnity);
+        
+        let integer_proof = integer.prove(polynomial, &mut Transcript::new(&[7u8; 32])).unwrap();
+        let roots_proof = roots.prove(polynomial, &mut Transcript::new(&[7u8; 32])).unwrap();
+        
+        let (integer_valid, _) = integer.verify(&integer_proof, &mut Transcript::new(&[7u8; 32])).unwrap();
+        let (roots_valid, _) = roots.verify(&roots_proof, &mut Transcript::new(&[7u8; 32])).unwrap();
+        assert!(integer_valid);
+        assert!(roots_valid);
+        
+        // A verifier using a different strategy derives different challenges
+        let (mismatched, _) = integer.verify(&roots_proof, &mut Transcript::new(&[7u8; 32])).unwrap();
+        assert!(!mismatched);
+        
+        // Beyond the 2^28 subgroup of BN254's scalar field there is no root to sample at
+        assert!(RootsOfUnity.points((1 << 28) + 1).is_err());
+        assert!(RootsOfUnity.fill_points(&mut []).is_ok());
+    }
+    
+    #[test]
+    fn test_sumcheck_degree_four() {
+        // Degree 4 in x_0: Σ x_0^4 + x_0·x_1 over {0,1}^2 is 2 + 1
+        let polynomial = |vars: &[FieldElement]| vars[0].pow([4u64]) + vars[0] * vars[1];
+        let claimed_sum = FieldElement::from(3u64);
+        
+        // A cubic interpolation of the first round throws off every later round
+        let cubic = SumCheck::new(2, claimed_sum);
+        assert!(cubic.prove(polynomial, &mut Transcript::new(&[4u8; 32])).is_err());
+        
+        let quartic = SumCheck::new(2, claimed_sum).with_max_degree(4);
+        let proof = quartic.prove(polynomial, &mut Transcript::new(&[4u8; 32])).unwrap();
+        assert!(proof.round_polynomials.iter().all(|round| round.len() == 5));
+        
+        let (is_valid, challenges) = quartic.verify(&proof, &mut Transcript::new(&[4u8; 32])).unwrap();
+        assert!(is_valid);
+        assert_eq!(proof.final_evaluation, polynomial(&challenges));
+        
+        // A verifier with a lower bound rejects the longer round polynomials
+        let (is_valid, _) = cubic.verify(&proof, &mut Transcript::new(&[4u8; 32])).unwrap();
+        assert!(!is_valid);
+    }
+    
+    #[test]
+    fn test_sumcheck_batch() {
+        let mle = MultilinearExtension::from_evaluations_vec(
+            3,
+            (1..=8u64).map(FieldElement::from).collect(),
+        );
+        let polys: Vec<BatchPolynomial> = vec![
+            Box::new(move |vars: &[FieldElement]| mle.evaluate(vars)),
+            Box::new(|vars: &[FieldElement]| vars[0] * vars[1] + vars[2]),
+            Box::new(|_: &[FieldElement]| FieldElement::from(5u64)),
+        ];
+        // 1 + ... + 8, two x_0·x_1 terms plus four x_2 terms, and 8 · 5
+        let claimed_sums = [36u64, 6, 40].map(FieldElement::from);
+        
+        let sumcheck = SumCheck::new(3, FieldElement::zero());
+        let proof = sumcheck.prove_batch(&polys, &claimed_sums, &mut Transcript::new(&[7u8; 32])).unwrap();
+        assert_eq!(proof.round_polynomials.len(), 3);
+        
+        // The final evaluation is the γ-combination of all three polynomials
+        let mut transcript = RecordingTranscript::wrap(Transcript::new(&[7u8; 32]));
+        let (is_valid, point) = sumcheck.verify_batch(&proof, &claimed_sums, &mut transcript).unwrap();
+        assert!(is_valid);
+        let gamma = transcript.challenges()[0];
+        let combined: FieldElement = polys
+            .iter()
+            .zip(crate::utils::field_utils::powers(gamma, 3))
+            .map(|(poly, power)| power * poly(&point))
+            .sum();
+        assert_eq!(proof.final_evaluation, combined);
+        
+        // Any wrong sum in the batch is caught by both prover and verifier
+        let mut wrong_sums = claimed_sums;
+        wrong_sums[1] += FieldElement::one();
+        assert!(sumcheck.prove_batch(&polys, &wrong_sums, &mut Transcript::new(&[7u8; 32])).is_err());
+        let (is_valid, _) = sumcheck.verify_batch(&proof, &wrong_sums, &mut Transcript::new(&[7u8; 32])).unwrap();
+        assert!(!is_valid);
+        
+        assert!(sumcheck.prove_batch(&polys, &claimed_sums[..2], &mut Transcript::new(&[7u8; 32])).is_err());
+    }
+    
+    #[test]
+    fn test_sumcheck_zero_variables() {
+        let constant = |_: &[FieldElement]| FieldElement::from(5u64);
+        
+        let sumcheck = SumCheck::new(0, FieldElement::from(5u64));
+        let proof = sumcheck.prove(constant, &mut Transcript::new(&[1u8; 32])).unwrap();
+        assert!(proof.round_polynomials.is_empty());
+        assert_eq!(proof.final_evaluation, FieldElement::from(5u64));
+        
+        let (is_valid, challenges) = sumcheck.verify(&proof, &mut Transcript::new(&[1u8; 32])).unwrap();
+        assert!(is_valid);
+        assert!(challenges.is_empty());
+        
+        // A wrong claimed sum is caught by both prover and verifier
+        let wrong = SumCheck::new(0, FieldElement::from(6u64));
+        assert!(wrong.prove(constant, &mut Transcript::new(&[1u8; 32])).is_err());
+        let (is_valid, _) = wrong.verify(&proof, &mut Transcript::new(&[1u8; 32])).unwrap();
+        assert!(!is_valid);
+    }
+    
+    #[test]
+    fn test_zk_sumcheck_masks_round_polynomials() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let polynomial = |vars: &[FieldElement]| vars[0] * vars[1] + FieldElement::from(2u64) * vars[2];
+        let claimed_sum = FieldElement::from(10u64);
+        
+        let mut rng = ark_std::test_rng();
+        let prover = SumCheck::new_zk(3, claimed_sum);
+        let params = &prover_params.commitment_params;
+        let (proof, prover_point) = prover
+            .prove_zk(polynomial, params, &mut rng, &mut Transcript::new(&[4u8; 32]))
+            .unwrap();
+        let (other_proof, _) = prover
+            .prove_zk(polynomial, params, &mut rng, &mut Transcript::new(&[4u8; 32]))
+            .unwrap();
+        
+        // The verifier needs no mask of its own
+        let verifier = SumCheck::new(3, claimed_sum);
+        let vk = &verifier_params.commitment_vk;
+        let (is_valid, point) = verifier.verify_zk(&proof, vk, &mut Transcript::new(&[4u8; 32])).unwrap();
+        assert!(is_valid);
+        assert_eq!(point, prover_point);
+        assert_eq!(proof.final_evaluation, polynomial(&point));
+        assert!(verifier.verify_zk(&other_proof, vk, &mut Transcript::new(&[4u8; 32])).unwrap().0);
+        
+        // Each proof from the same instance draws a fresh mask
+        assert_ne!(
+            proof.masked_proof.round_polynomials[0],
+            other_proof.masked_proof.round_polynomials[0],
+        );
+        
+        // A wrong sum, a shifted claim or a tampered mask opening is rejected
+        let wrong = SumCheck::new(3, claimed_sum + FieldElement::one());
+        assert!(!wrong.verify_zk(&proof, vk, &mut Transcript::new(&[4u8; 32])).unwrap().0);
+        let mut tampered = proof.clone();
+        tampered.final_evaluation += FieldElement::one();
+        assert!(!verifier.verify_zk(&tampered, vk, &mut Transcript::new(&[4u8; 32])).unwrap().0);
+        let mut tampered = proof.clone();
+        tampered.mask_evaluations[1] += FieldElement::one();
+        assert!(!verifier.verify_zk(&tampered, vk, &mut Transcript::new(&[4u8; 32])).unwrap().0);
+        
+        // Only new_zk instances hold a mask to prove with
+        assert!(verifier
+            .prove_zk(polynomial, params, &mut rng, &mut Transcript::new(&[4u8; 32]))
+            .is_err());
+    }
+    
+    #[test]
+    fn test_sumcheck_product_with_committed_factors() {
+        let mut rng = ark_std::test_rng();
+        let (params, vk) = MultilinearKZG::setup(3, &mut rng);
+        
+        let f = MultilinearExtension::from_evaluations((1..=8u64).map(FieldElement::from).collect());
+        let g = MultilinearExtension::from_evaluations((0..8u64).map(|i| FieldElement::from(3 * i + 2)).collect());
+        let claimed_sum: FieldElement = f.evaluations.iter().zip(&g.evaluations).map(|(a, b)| *a * b).sum();
+        let commitments = vec![
+            MultilinearKZG::commit(&params, &f).unwrap(),
+            MultilinearKZG::commit(&params, &g).unwrap(),
+        ];
+        
+        let sumcheck = SumCheck::new(3, claimed_sum);
+        let (proof, point) = sumcheck
+            .prove_product(&[f.clone(), g.clone()], &commitments, &mut Transcript::new(&[5u8; 32]))
+            .unwrap();
+        let openers: Vec<FactorOpening> = [&f, &g]
+            .iter()
+            .map(|factor| {
+                let (value, proof) = MultilinearKZG::open(&params, factor, &point).unwrap();
+                FactorOpening { value, proof }
+            })
+            .collect();
+        
+        assert!(sumcheck
+            .verify_product(&proof, &mut Transcript::new(&[5u8; 32]), &commitments, &openers, &vk)
+            .unwrap());
+        
+        // A tampered factor opening fails
+        let mut tampered = openers.clone();
+        tampered[1].value += FieldElement::one();
+        assert!(!sumcheck
+            .verify_product(&proof, &mut Transcript::new(&[5u8; 32]), &commitments, &tampered, &vk)
+            .unwrap());
+        
+        // So does opening a different committed factor
+        let swapped = vec![commitments[0].clone(), commitments[0].clone()];
+        assert!(!sumcheck
+            .verify_product(&proof, &mut Transcript::new(&[5u8; 32]), &swapped, &openers, &vk)
+            .unwrap());
+        
+        // The commitments are absorbed before the rounds: swapping the factors'
+        // order changes the challenges, so the same rounds no longer verify
+        let reordered = vec![commitments[1].clone(), commitments[0].clone()];
+        let reordered_openers = vec![openers[1].clone(), openers[0].clone()];
+        assert!(!sumcheck
+            .verify_product(&proof, &mut Transcript::new(&[5u8; 32]), &reordered, &reordered_openers, &vk)
+            .unwrap());
+        assert!(sumcheck
+            .prove_product(&[f.clone(), g.clone()], &commitments[..1], &mut Transcript::new(&[5u8; 32]))
+            .is_err());
+    }
+}
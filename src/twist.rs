@@ -3,20 +3,65 @@
 //! The Twist protocol allows efficient zero-knowledge proofs of memory consistency
 //! for read-write operations, enforcing that reads return the last written value.
 
-use crate::utils::{FieldElement, ProverParams, VerifierParams, Transcript};
-use crate::polynomials::MultilinearExtension;
-use crate::commitments::{CommitmentScheme, KZGCommitment, KZGCommitmentValue, KZGProof};
-use crate::sumcheck::{SumCheck, SumCheckProof};
+use crate::utils::{FieldElement, ProofLayout, ProofParams, ProofSizeBreakdown, ProverParams, VerifierParams, VerifyReport, FiatShamirTranscript, RecordingTranscript, Transcript};
+use crate::polynomials::{check_constraint_rows, eq_eval, poly_utils, MultilinearExtension};
+use crate::commitments::{CommitmentScheme, KZGCommitment, KZGCommitmentValue, MultilinearKZG, MultilinearKZGVerifierKey};
+use crate::shout::{LookupTable, Shout, ShoutProof};
+use crate::sumcheck::{ColumnOpening, SumCheck, SumCheckProof};
 use crate::{Result, TwistAndShoutError};
-use ark_ff::{Field, Zero, One};
+use ark_ff::{BigInteger, Field, PrimeField, Zero, One};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest};
+use ark_std::borrow::Cow;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use ark_std::collections::{BTreeMap, BTreeSet};
+use ark_std::ops::Range;
+#[cfg(feature = "std")]
+use ark_std::fs::File;
+use ark_std::io::{Read, Write};
+#[cfg(feature = "std")]
+use ark_std::io::{BufReader, BufWriter};
+use ark_std::marker::PhantomData;
+#[cfg(feature = "std")]
+use ark_std::path::Path;
+use ark_std::{format, string::ToString, vec, vec::Vec};
 
 /// Memory operation type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum MemoryOp {
     Read { address: usize, value: FieldElement },
     Write { address: usize, value: FieldElement },
+    /// Filler row from [`MemoryTrace::align_batch`], ignored by the consistency constraint
+    Padding,
+}
+
+impl MemoryOp {
+    /// Address accessed by the operation
+    pub fn address(&self) -> usize {
+        match self {
+            MemoryOp::Read { address, .. } | MemoryOp::Write { address, .. } => *address,
+            MemoryOp::Padding => 0,
+        }
+    }
+    
+    /// Value read or written by the operation
+    pub fn value(&self) -> FieldElement {
+        match self {
+            MemoryOp::Read { value, .. } | MemoryOp::Write { value, .. } => *value,
+            MemoryOp::Padding => FieldElement::zero(),
+        }
+    }
+    
+    /// Whether the operation is a write
+    pub fn is_write(&self) -> bool {
+        matches!(self, MemoryOp::Write { .. })
+    }
+    
+    /// Whether the operation is a padding row
+    pub fn is_padding(&self) -> bool {
+        matches!(self, MemoryOp::Padding)
+    }
 }
 
 /// Memory trace containing a sequence of operations
@@ -28,10 +73,31 @@ pub struct MemoryTrace {
     /// Sequence of memory operations
     pub operations: Vec<MemoryOp>,
     
+    /// Timestamp of each operation, strictly increasing across the trace
+    pub timestamps: Vec<u64>,
+    
     /// Current memory state
     memory: Vec<FieldElement>,
+    
+    /// Allowed value sets for reads constrained by [`MemoryTrace::constrain_read_in_set`],
+    /// keyed by operation index
+    read_sets: BTreeMap<usize, Vec<FieldElement>>,
+    
+    /// Bit width of each operation on a cell last written by [`MemoryTrace::write_sized`],
+    /// keyed by operation index
+    widths: BTreeMap<usize, usize>,
+    
+    /// Bit width of each cell last written by [`MemoryTrace::write_sized`]
+    cell_widths: BTreeMap<usize, usize>,
+    
+    /// Address ranges no write may target, from [`MemoryTrace::assert_immutable_range`]
+    immutable_ranges: Vec<Range<usize>>,
 }
 
+/// Bits available to packed values in one field element, leaving headroom
+/// below the 254-bit BN254 scalar modulus
+pub const PACKED_SLOT_BITS: usize = 248;
+
 impl MemoryTrace {
     /// Create a new memory trace with given size
     pub fn new(memory_size: usize) -> Self {
@@ -40,8 +106,86 @@ impl MemoryTrace {
         Self {
             memory_size,
             operations: Vec::new(),
+            timestamps: Vec::new(),
             memory: vec![FieldElement::zero(); memory_size],
+            read_sets: BTreeMap::new(),
+            widths: BTreeMap::new(),
+            cell_widths: BTreeMap::new(),
+            immutable_ranges: Vec::new(),
+        }
+    }
+    
+    /// Rebuild a trace from operations captured elsewhere, replaying them to
+    /// reconstruct the memory state.
+    ///
+    /// Operations are timestamped by position. Errors if an address is out of
+    /// bounds or a read records a value other than the one last written.
+    pub fn from_operations(memory_size: usize, operations: Vec<MemoryOp>) -> Result<Self> {
+        if !memory_size.is_power_of_two() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Memory size must be power of 2".to_string(),
+            ));
+        }
+        
+        let mut trace = Self::new(memory_size);
+        for (i, op) in operations.into_iter().enumerate() {
+            match op {
+                MemoryOp::Write { address, value } => trace.write(address, value)?,
+                MemoryOp::Read { address, value } => {
+                    if trace.read(address)? != value {
+                        return Err(TwistAndShoutError::InvalidParameters(format!(
+                            "Read at operation {} does not return the value last written to address {}",
+                            i, address
+                        )));
+                    }
+                }
+                MemoryOp::Padding => {
+                    let timestamp = trace.next_timestamp();
+                    trace.timestamps.push(timestamp);
+                    trace.operations.push(MemoryOp::Padding);
+                }
+            }
+        }
+        
+        Ok(trace)
+    }
+    
+    /// Replay the operations from zeroed memory and check that every address
+    /// is in bounds and every read records the value last written.
+    ///
+    /// [`MemoryTrace::operations`] is public, so a trace edited by hand can
+    /// hold reads that no execution could produce. Every Twist prover taking a
+    /// trace runs this first and refuses to prove such a trace;
+    /// [`Twist::prove_streaming`] and [`IncrementalTwist`] make the same checks as
+    /// the operations arrive.
+    pub fn validate(&self) -> Result<()> {
+        let mut memory = vec![FieldElement::zero(); self.memory_size];
+        for (i, op) in self.operations.iter().enumerate() {
+            if op.is_padding() {
+                continue;
+            }
+            
+            let address = op.address();
+            if address >= self.memory_size {
+                return Err(TwistAndShoutError::ProofGeneration(format!(
+                    "Operation {} accesses address {} outside memory of size {}",
+                    i, address, self.memory_size
+                )));
+            }
+            
+            match *op {
+                MemoryOp::Write { value, .. } => memory[address] = value,
+                MemoryOp::Read { value, .. } if memory[address] != value => {
+                    return Err(TwistAndShoutError::ProofGeneration(format!(
+                        "Read at operation {} does not return the value last written to address {}",
+                        i, address
+                    )));
+                }
+                _ => {}
+            }
         }
+        
+        Ok(())
     }
     
     /// Write a value to memory
@@ -53,10 +197,55 @@ impl MemoryTrace {
         }
         
         self.memory[address] = value;
+        self.cell_widths.remove(&address);
+        self.timestamps.push(self.next_timestamp());
         self.operations.push(MemoryOp::Write { address, value });
         Ok(())
     }
     
+    /// Write a value of at most `bits` bits (1 to 64).
+    ///
+    /// The cell keeps the width until its next write, and Twist range-checks
+    /// this write and every later read of the cell against it. The widths
+    /// are a public input of the proof.
+    pub fn write_sized(&mut self, address: usize, value: FieldElement, bits: usize) -> Result<()> {
+        if bits == 0 || bits > 64 {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Value width must be between 1 and 64 bits".to_string(),
+            ));
+        }
+        
+        if value.into_bigint().num_bits() as usize > bits {
+            return Err(TwistAndShoutError::InvalidParameters(
+                format!("Value does not fit in {} bits", bits),
+            ));
+        }
+        
+        self.write(address, value)?;
+        self.cell_widths.insert(address, bits);
+        self.widths.insert(self.operations.len() - 1, bits);
+        Ok(())
+    }
+    
+    /// Bit width recorded for the operation at `op_index`, if it accessed a sized cell
+    pub fn width(&self, op_index: usize) -> Option<usize> {
+        self.widths.get(&op_index).copied()
+    }
+    
+    /// Pack the values of sized operations, in trace order, into as few field
+    /// elements as possible.
+    ///
+    /// Layout: each value occupies exactly its recorded width, little-endian,
+    /// so the first value of an element sits in its lowest bits. A value that
+    /// would cross bit [`PACKED_SLOT_BITS`] starts a new element instead;
+    /// unused high bits are zero. Operations without a recorded width are skipped.
+    ///
+    /// Twist commits to these elements and checks them against the value column.
+    pub fn packed_values(&self) -> Vec<FieldElement> {
+        let values: Vec<FieldElement> = self.operations.iter().map(MemoryOp::value).collect();
+        pack_values(&values, &self.widths)
+    }
+    
     /// Read a value from memory
     pub fn read(&mut self, address: usize) -> Result<FieldElement> {
         if address >= self.memory_size {
@@ -66,252 +255,4504 @@ impl MemoryTrace {
         }
         
         let value = self.memory[address];
+        if let Some(&bits) = self.cell_widths.get(&address) {
+            self.widths.insert(self.operations.len(), bits);
+        }
+        self.timestamps.push(self.next_timestamp());
         self.operations.push(MemoryOp::Read { address, value });
         Ok(value)
     }
-}
-
-/// Twist protocol proof
-#[derive(Debug, Clone)]
-pub struct TwistProof {
-    /// Commitments to address and value vectors
-    pub address_commitment: KZGCommitmentValue,
-    pub value_commitment: KZGCommitmentValue,
     
-    /// Sum-check proofs for memory consistency
-    pub consistency_proof: SumCheckProof,
+    /// Current value at `address`, without recording a read in the trace
+    pub fn peek(&self, address: usize) -> Result<FieldElement> {
+        if address >= self.memory_size {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Address out of bounds".to_string(),
+            ));
+        }
+        
+        Ok(self.memory[address])
+    }
     
-    /// Opening proofs for final checks
-    pub opening_proofs: Vec<KZGProof>,
+    /// Require the read at `op_index` to return one of the `allowed` values.
+    ///
+    /// Twist enforces `∏(value - allowed_i) = 0` for the read alongside memory
+    /// consistency, so the read must both match memory and be a valid value.
+    /// The set is a public input: the proof carries it in
+    /// [`TwistProof::constraints`] for the verifier to check.
+    pub fn constrain_read_in_set(&mut self, op_index: usize, allowed: &[FieldElement]) -> Result<()> {
+        match self.operations.get(op_index) {
+            Some(MemoryOp::Read { .. }) => {}
+            Some(_) => {
+                return Err(TwistAndShoutError::InvalidParameters(
+                    "Only reads can be constrained to a value set".to_string(),
+                ));
+            }
+            None => {
+                return Err(TwistAndShoutError::InvalidParameters(
+                    "Operation index out of bounds".to_string(),
+                ));
+            }
+        }
+        
+        if allowed.is_empty() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Allowed value set must not be empty".to_string(),
+            ));
+        }
+        
+        self.read_sets.insert(op_index, allowed.to_vec());
+        Ok(())
+    }
     
-    /// Final polynomial evaluations
-    pub final_evaluations: Vec<FieldElement>,
+    /// Require that no write in the trace targets an address in `range`.
+    ///
+    /// This applies to the trace as a whole, including writes already recorded:
+    /// the range is a public input of the proof, and Twist checks that every
+    /// cell in it is written zero times, so proving fails if any write lands
+    /// in the range.
+    pub fn assert_immutable_range(&mut self, range: Range<usize>) -> Result<()> {
+        if range.is_empty() || range.end > self.memory_size {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Immutable range must be non-empty and within memory".to_string(),
+            ));
+        }
+        
+        self.immutable_ranges.push(range);
+        Ok(())
+    }
+    
+    /// The public constraints recorded on the trace, as a proof of it carries them
+    pub fn constraints(&self) -> TraceConstraints {
+        TraceConstraints {
+            read_sets: self.read_sets.clone(),
+            immutable_ranges: self.immutable_ranges.clone(),
+            widths: self.widths.clone(),
+        }
+    }
+    
+    /// Save the operations to a file that can be proven with [`Twist::prove_streaming`].
+    ///
+    /// See [`StreamingTrace`] for the file layout. Timestamps, read-set
+    /// constraints, value widths and immutable ranges are not stored; a
+    /// streamed trace is timestamped by position.
+    #[cfg(feature = "std")]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let file = File::create(path).map_err(io_error)?;
+        let mut writer = BufWriter::new(file);
+        
+        writer.write_all(&(self.memory_size as u64).to_le_bytes()).map_err(io_error)?;
+        for op in &self.operations {
+            let tag = match op {
+                MemoryOp::Read { .. } => OP_TAG_READ,
+                MemoryOp::Write { .. } => OP_TAG_WRITE,
+                MemoryOp::Padding => OP_TAG_PADDING,
+            };
+            writer.write_all(&[tag]).map_err(io_error)?;
+            writer.write_all(&(op.address() as u64).to_le_bytes()).map_err(io_error)?;
+            op.value()
+                .serialize_compressed(&mut writer)
+                .map_err(|e| TwistAndShoutError::Serialization(e.to_string()))?;
+        }
+        
+        writer.flush().map_err(io_error)
+    }
+    
+    /// Operations sorted by address, then by timestamp.
+    ///
+    /// The sort is stable, so operations with equal keys keep their trace order.
+    pub fn sorted_view(&self) -> Vec<MemoryOp> {
+        let mut sorted: Vec<(u64, &MemoryOp)> = self.timestamped_operations().collect();
+        sorted.sort_by_key(|&(timestamp, op)| (op.address(), timestamp));
+        sorted.into_iter().map(|(_, op)| *op).collect()
+    }
+    
+    /// Each operation paired with its timestamp, in trace order
+    pub fn timestamped_operations(&self) -> impl Iterator<Item = (u64, &MemoryOp)> {
+        self.timestamps.iter().copied().zip(&self.operations)
+    }
+    
+    /// Timestamp for the next appended operation: one past the last recorded
+    /// timestamp, so the counter keeps increasing after timestamps are edited
+    fn next_timestamp(&self) -> u64 {
+        self.timestamps.last().map_or(0, |&t| t + 1)
+    }
+    
+    /// Pad every trace with [`MemoryOp::Padding`] rows up to the largest
+    /// power-of-two operation count among them, so a batched argument runs
+    /// over uniform dimensions
+    pub fn align_batch(traces: &mut [MemoryTrace]) {
+        let target = traces
+            .iter()
+            .map(|trace| trace.operations.len().next_power_of_two().max(1))
+            .max()
+            .unwrap_or(1);
+        
+        for trace in traces.iter_mut() {
+            while trace.operations.len() < target {
+                let timestamp = trace.next_timestamp();
+                trace.timestamps.push(timestamp);
+                trace.operations.push(MemoryOp::Padding);
+            }
+        }
+    }
 }
 
-/// Twist protocol implementation
+/// Memory trace whose cells hold words of `W` field-element limbs.
+///
+/// Each limb position is kept as its own single-limb lane, and every word
+/// operation appends one operation to each lane, so all lanes share the same
+/// addresses and timestamps.
 #[derive(Debug, Clone)]
-pub struct Twist {
-    /// Prover parameters
-    prover_params: ProverParams,
+pub struct WordMemoryTrace<const W: usize> {
+    /// Maximum memory size in words (must be power of 2)
+    pub memory_size: usize,
+    
+    lanes: [MemoryTrace; W],
 }
 
-impl Twist {
-    /// Create a new Twist instance
-    pub fn new(prover_params: &ProverParams) -> Self {
+impl<const W: usize> WordMemoryTrace<W> {
+    /// Create a new word memory trace with given size
+    pub fn new(memory_size: usize) -> Self {
         Self {
-            prover_params: prover_params.clone(),
+            memory_size,
+            lanes: ark_std::array::from_fn(|_| MemoryTrace::new(memory_size)),
         }
     }
     
-    /// Generate a proof for memory consistency
-    pub fn prove(&self, trace: &MemoryTrace) -> Result<TwistProof> {
-        if trace.operations.len() > self.prover_params.max_operations {
+    /// Write a word to memory
+    pub fn write(&mut self, address: usize, word: [FieldElement; W]) -> Result<()> {
+        if address >= self.memory_size {
             return Err(TwistAndShoutError::InvalidParameters(
-                "Too many operations".to_string(),
+                "Address out of bounds".to_string(),
             ));
         }
         
-        // Extract addresses and values from operations
-        let addresses: Vec<FieldElement> = trace.operations
-            .iter()
-            .map(|op| match op {
-                MemoryOp::Read { address, .. } | MemoryOp::Write { address, .. } => {
-                    FieldElement::from(*address as u64)
-                }
-            })
-            .collect();
-        
-        let values: Vec<FieldElement> = trace.operations
-            .iter()
-            .map(|op| match op {
-                MemoryOp::Read { value, .. } | MemoryOp::Write { value, .. } => *value,
-            })
-            .collect();
-        
-        // Create operation type indicators (0 for read, 1 for write)
-        let op_types: Vec<FieldElement> = trace.operations
-            .iter()
-            .map(|op| match op {
-                MemoryOp::Read { .. } => FieldElement::zero(),
-                MemoryOp::Write { .. } => FieldElement::one(),
-            })
-            .collect();
-        
-        // Pad to power of 2 for polynomial operations
-        let padded_size = (addresses.len() as usize).next_power_of_two().max(1);
-        let mut padded_addresses = addresses;
-        let mut padded_values = values;
-        let mut padded_op_types = op_types;
+        for (lane, limb) in self.lanes.iter_mut().zip(word) {
+            lane.write(address, limb)?;
+        }
+        Ok(())
+    }
+    
+    /// Read a word from memory
+    pub fn read(&mut self, address: usize) -> Result<[FieldElement; W]> {
+        if address >= self.memory_size {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Address out of bounds".to_string(),
+            ));
+        }
         
-        padded_addresses.resize(padded_size, FieldElement::zero());
-        padded_values.resize(padded_size, FieldElement::zero());
-        padded_op_types.resize(padded_size, FieldElement::zero());
+        let mut word = [FieldElement::zero(); W];
+        for (lane, limb) in self.lanes.iter_mut().zip(word.iter_mut()) {
+            *limb = lane.read(address)?;
+        }
+        Ok(word)
+    }
+    
+    /// Number of word operations recorded so far
+    pub fn num_operations(&self) -> usize {
+        self.lanes.first().map_or(0, |lane| lane.operations.len())
+    }
+    
+    /// Single-limb trace of limb position `index`
+    pub fn lane(&self, index: usize) -> &MemoryTrace {
+        &self.lanes[index]
+    }
+}
+
+/// Record tag for a read operation in a saved trace
+#[cfg(feature = "std")]
+const OP_TAG_READ: u8 = 0;
+
+/// Record tag for a write operation in a saved trace
+#[cfg(feature = "std")]
+const OP_TAG_WRITE: u8 = 1;
+
+/// Record tag for a padding row in a saved trace
+#[cfg(feature = "std")]
+const OP_TAG_PADDING: u8 = 2;
+
+/// Memory trace read lazily, one operation at a time, from a file written by
+/// [`MemoryTrace::save`].
+///
+/// File layout (all integers little-endian): an 8-byte memory size header,
+/// followed by one record per operation consisting of a 1-byte tag
+/// (0 = read, 1 = write, 2 = padding), an 8-byte address and a 32-byte compressed field
+/// element value.
+#[cfg(feature = "std")]
+pub struct StreamingTrace {
+    /// Memory size recorded in the file header
+    pub memory_size: usize,
+    
+    reader: BufReader<File>,
+}
+
+#[cfg(feature = "std")]
+impl StreamingTrace {
+    /// Open a saved trace and read its header
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path).map_err(io_error)?;
+        let mut reader = BufReader::new(file);
         
-        // Convert to polynomials for commitment
-        let address_poly = self.vector_to_polynomial(&padded_addresses)?;
-        let value_poly = self.vector_to_polynomial(&padded_values)?;
+        let mut header = [0u8; 8];
+        reader.read_exact(&mut header).map_err(io_error)?;
         
-        // Commit to address and value polynomials
-        let address_commitment = KZGCommitment::commit(
-            &self.prover_params.commitment_params,
-            &address_poly,
-        )?;
+        Ok(Self {
+            memory_size: u64::from_le_bytes(header) as usize,
+            reader,
+        })
+    }
+    
+    /// Read the next record, returning `None` at a clean end of file
+    fn read_op(&mut self) -> Result<Option<MemoryOp>> {
+        let mut tag = [0u8; 1];
+        match self.reader.read_exact(&mut tag) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ark_std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(io_error(e)),
+        }
         
-        let value_commitment = KZGCommitment::commit(
-            &self.prover_params.commitment_params,
-            &value_poly,
-        )?;
+        let mut address_bytes = [0u8; 8];
+        self.reader.read_exact(&mut address_bytes).map_err(io_error)?;
+        let address = u64::from_le_bytes(address_bytes) as usize;
+        if address >= self.memory_size {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Address out of bounds".to_string(),
+            ));
+        }
         
-        // For now, use a simple polynomial that evaluates to zero everywhere 
-        // for the sum-check to pass, indicating perfect consistency
-        let log_ops = (padded_size as f64).log2() as usize;
-        let sumcheck = SumCheck::new(log_ops, FieldElement::zero());
+        let value = FieldElement::deserialize_compressed(&mut self.reader)
+            .map_err(|e| TwistAndShoutError::Serialization(e.to_string()))?;
         
-        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        match tag[0] {
+            OP_TAG_READ => Ok(Some(MemoryOp::Read { address, value })),
+            OP_TAG_WRITE => Ok(Some(MemoryOp::Write { address, value })),
+            OP_TAG_PADDING => Ok(Some(MemoryOp::Padding)),
+            other => Err(TwistAndShoutError::Serialization(
+                format!("Unknown operation tag {}", other),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Iterator for StreamingTrace {
+    type Item = Result<MemoryOp>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_op().transpose()
+    }
+}
+
+/// Incremental prover input fed one operation at a time, created with
+/// [`Twist::incremental`].
+///
+/// Each operation goes straight into the proof's columns, and reads are checked
+/// against a running memory state as they arrive, so no `MemoryOp` list is
+/// kept. This is not a bounded-memory prover: the columns grow with the number
+/// of operations. Peak memory of O(window + memory_size) is out of reach for a
+/// single pass over the operations, since every sum-check round folds all rows
+/// with a challenge drawn only after every row is committed, so the prover
+/// needs the whole trace again once per round.
+pub struct IncrementalTwist<'a, C: CommitmentScheme = KZGCommitment> {
+    twist: &'a Twist<C>,
+    memory: Vec<FieldElement>,
+    columns: OperationColumns,
+    num_operations: usize,
+}
+
+impl<'a, C: CommitmentScheme<Scalar = FieldElement>> IncrementalTwist<'a, C> {
+    /// Append one operation.
+    ///
+    /// Errors if the address is out of bounds, a read records a value other
+    /// than the one last written, or the operation limit is exceeded.
+    pub fn push_op(&mut self, op: MemoryOp) -> Result<()> {
+        if self.num_operations >= self.twist.prover_params.max_operations {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Too many operations".to_string(),
+            ));
+        }
         
-        // Add commitments to transcript
-        transcript.append_field_element(b"address_commitment", &address_commitment.hash());
-        transcript.append_field_element(b"value_commitment", &value_commitment.hash());
-        
-        // Create multilinear extensions for memory consistency checking
-        let address_mle = MultilinearExtension::from_evaluations_vec(log_ops, padded_addresses.clone());
-        let value_mle = MultilinearExtension::from_evaluations_vec(log_ops, padded_values.clone());
-        let op_type_mle = MultilinearExtension::from_evaluations_vec(log_ops, padded_op_types.clone());
-        
-        // Define the memory consistency polynomial
-        // This polynomial encodes the constraint that reads return the last written value
-        // For each operation i, if it's a read at address a with value v, then there must exist
-        // a previous write operation j < i at the same address a with the same value v,
-        // and no write operations between j and i at address a
-        let consistency_polynomial = {
-            let addr_mle = address_mle.clone();
-            let val_mle = value_mle.clone();
-            let op_mle = op_type_mle.clone();
-            
-            move |vars: &[FieldElement]| -> FieldElement {
-                if vars.len() != log_ops {
-                    return FieldElement::zero();
+        match op {
+            MemoryOp::Write { address, value } => {
+                if address >= self.memory.len() {
+                    return Err(TwistAndShoutError::InvalidParameters(
+                        "Address out of bounds".to_string(),
+                    ));
+                }
+                self.memory[address] = value;
+            }
+            MemoryOp::Read { address, value } => {
+                if address >= self.memory.len() {
+                    return Err(TwistAndShoutError::InvalidParameters(
+                        "Address out of bounds".to_string(),
+                    ));
                 }
-                
-                // Evaluate the multilinear extensions at the given point
-                let current_addr = addr_mle.evaluate(vars);
-                let current_value = val_mle.evaluate(vars);
-                let current_op_type = op_mle.evaluate(vars);
-                
-                // If this is a write operation (op_type = 1), the constraint is trivially satisfied
-                if current_op_type == FieldElement::one() {
-                    return FieldElement::zero();
+                if self.memory[address] != value {
+                    return Err(TwistAndShoutError::InvalidParameters(format!(
+                        "Read at operation {} does not return the value last written to address {}",
+                        self.num_operations, address
+                    )));
                 }
-                
-                // For read operations (op_type = 0), we need to verify consistency
-                // In a production implementation, this would involve a more complex constraint
-                // For now, we implement a simplified version that checks basic consistency
-                
-                // The polynomial should be zero when memory is consistent
-                // For this simplified version, we assume perfect consistency
-                FieldElement::zero()
             }
-        };
-        
-        let consistency_proof = sumcheck.prove(consistency_polynomial, &mut transcript)?;
-        
-        // Generate opening proofs at challenge points from the sum-check
-        let challenges = transcript.challenge_field_elements(b"opening_challenges", log_ops);
-        
-        let mut opening_proofs = Vec::new();
-        let mut final_evaluations = Vec::new();
-        
-        // Create opening proofs for address and value polynomials at the challenge point
-        // Only if we have at least one challenge
-        if !challenges.is_empty() {
-            let (address_eval, address_opening) = KZGCommitment::open(
-                &self.prover_params.commitment_params,
-                &address_poly,
-                challenges[0], // Use first challenge as evaluation point
-            )?;
-            
-            let (value_eval, value_opening) = KZGCommitment::open(
-                &self.prover_params.commitment_params,
-                &value_poly,
-                challenges[0], // Use first challenge as evaluation point
-            )?;
-            
-            opening_proofs.push(address_opening);
-            opening_proofs.push(value_opening);
-            final_evaluations.push(address_eval);
-            final_evaluations.push(value_eval);
+            MemoryOp::Padding => {}
         }
         
-        Ok(TwistProof {
-            address_commitment,
-            value_commitment,
-            consistency_proof,
-            opening_proofs,
-            final_evaluations,
-        })
+        self.columns.push(&op, self.num_operations as u64);
+        self.num_operations += 1;
+        Ok(())
     }
     
-    /// Verify a Twist proof
-    pub fn verify(&self, proof: &TwistProof, verifier_params: &VerifierParams) -> Result<bool> {
-        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
-        
-        // Add commitments to transcript
-        transcript.append_field_element(b"address_commitment", &proof.address_commitment.hash());
-        transcript.append_field_element(b"value_commitment", &proof.value_commitment.hash());
-        
-        // Verify sum-check proof - use the same number of variables as in the proof
-        let num_vars = proof.consistency_proof.round_polynomials.len();
-        let sumcheck = SumCheck::new(num_vars, FieldElement::zero());
-        let (sumcheck_valid, _challenges) = sumcheck.verify(&proof.consistency_proof, &mut transcript)?;
-        
-        if !sumcheck_valid {
-            return Ok(false);
+    /// Number of operations pushed so far
+    pub fn num_operations(&self) -> usize {
+        self.num_operations
+    }
+    
+    /// Prove memory consistency for every operation pushed so far
+    pub fn finalize(mut self) -> Result<TwistProof<C>> {
+        self.columns.pad(self.num_operations.next_power_of_two().max(1));
+        self.twist.prove_columns(
+            self.num_operations,
+            self.memory.len(),
+            self.columns,
+            &mut Transcript::new(&self.twist.prover_params.fiat_shamir_seed),
+        )
+    }
+}
+
+/// Convert an I/O error into a library error
+#[cfg(feature = "std")]
+fn io_error(e: ark_std::io::Error) -> TwistAndShoutError {
+    TwistAndShoutError::Serialization(e.to_string())
+}
+
+/// Public constraints a Twist proof enforces on the trace alongside memory
+/// consistency, carried in the proof and bound into its transcript; check
+/// them against the expected ones with [`Twist::verify_with_constraints`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TraceConstraints {
+    /// Allowed value sets of constrained reads, keyed by operation index,
+    /// from [`MemoryTrace::constrain_read_in_set`]
+    pub read_sets: BTreeMap<usize, Vec<FieldElement>>,
+    
+    /// Address ranges no write may target, from [`MemoryTrace::assert_immutable_range`]
+    pub immutable_ranges: Vec<Range<usize>>,
+    
+    /// Bit widths of sized operations, keyed by operation index, from
+    /// [`MemoryTrace::write_sized`]
+    pub widths: BTreeMap<usize, usize>,
+}
+
+impl TraceConstraints {
+    /// Size of the largest allowed read set: the number of running-product
+    /// columns the memory check commits to
+    fn read_set_size(&self) -> usize {
+        self.read_sets.values().map(Vec::len).max().unwrap_or(0)
+    }
+    
+    /// Number of public read-set tables the memory-checking summand reads:
+    /// the selector of the constrained rows and, for each running product,
+    /// the allowed value it multiplies in; none without read sets
+    fn read_set_tables(&self) -> usize {
+        match self.read_set_size() {
+            0 => 0,
+            size => size + 1,
         }
-        
-        // Generate the same challenge points used in proof generation
-        let opening_challenges = transcript.challenge_field_elements(b"opening_challenges", num_vars);
-        
-        // Verify opening proofs if they exist
-        if !opening_challenges.is_empty() && proof.opening_proofs.len() >= 2 && proof.final_evaluations.len() >= 2 {
-            // Verify address polynomial opening
-            let address_valid = KZGCommitment::verify(
-                &verifier_params.commitment_vk,
-                &proof.address_commitment,
-                opening_challenges[0],
-                proof.final_evaluations[0],
-                &proof.opening_proofs[0],
-            )?;
-            
-            if !address_valid {
-                return Ok(false);
-            }
-            
-            // Verify value polynomial opening
-            let value_valid = KZGCommitment::verify(
-                &verifier_params.commitment_vk,
-                &proof.value_commitment,
-                opening_challenges[0],
-                proof.final_evaluations[1],
-                &proof.opening_proofs[1],
-            )?;
-            
-            if !value_valid {
-                return Ok(false);
+    }
+    
+    /// Widest recorded value width: the number of bit columns the memory
+    /// check commits to
+    fn width_size(&self) -> usize {
+        self.widths.values().copied().max().unwrap_or(0)
+    }
+    
+    /// Number of columns the memory check commits to for the widths: the
+    /// bits of each sized value and the packed values; none without widths
+    fn width_columns(&self) -> usize {
+        match self.width_size() {
+            0 => 0,
+            size => size + 1,
+        }
+    }
+    
+    /// Number of public width tables the memory-checking summand reads: the
+    /// selector of the sized rows, the weight 2^j·[j < width] of each bit
+    /// column, then the packing weights of the value and packed columns;
+    /// none without widths
+    fn width_tables(&self) -> usize {
+        match self.width_size() {
+            0 => 0,
+            size => size + 3,
+        }
+    }
+    
+    /// Number of public tables the memory-checking summand reads: the
+    /// read-set tables, then the width tables
+    fn constraint_tables(&self) -> usize {
+        self.read_set_tables() + self.width_tables()
+    }
+    
+    /// Whether every read set names an operation of a trace of
+    /// `num_operations` operations and is non-empty, every immutable range
+    /// is a non-empty range of a memory of `memory_size` cells, and every
+    /// width names an operation of the trace and lies between 1 and 64 bits
+    fn is_valid_for(&self, num_operations: usize, memory_size: usize) -> bool {
+        self.read_sets
+            .iter()
+            .all(|(&op_index, allowed)| op_index < num_operations && !allowed.is_empty())
+            && self
+                .immutable_ranges
+                .iter()
+                .all(|range| !range.is_empty() && range.end <= memory_size)
+            && self
+                .widths
+                .iter()
+                .all(|(&op_index, &bits)| op_index < num_operations && (1..=64).contains(&bits))
+    }
+    
+    /// Absorb the constraints into the transcript
+    fn absorb(&self, transcript: &mut impl FiatShamirTranscript) {
+        transcript.append_field_element(b"read_set_count", &FieldElement::from(self.read_sets.len() as u64));
+        for (&op_index, allowed) in &self.read_sets {
+            transcript.append_field_element(b"read_set_index", &FieldElement::from(op_index as u64));
+            transcript.append_field_elements(b"read_set_values", allowed);
+        }
+        transcript.append_field_element(
+            b"immutable_range_count",
+            &FieldElement::from(self.immutable_ranges.len() as u64),
+        );
+        for range in &self.immutable_ranges {
+            transcript.append_field_elements(
+                b"immutable_range",
+                &[FieldElement::from(range.start as u64), FieldElement::from(range.end as u64)],
+            );
+        }
+        transcript.append_field_element(b"width_count", &FieldElement::from(self.widths.len() as u64));
+        for (&op_index, &bits) in &self.widths {
+            transcript.append_field_elements(
+                b"width",
+                &[FieldElement::from(op_index as u64), FieldElement::from(bits as u64)],
+            );
+        }
+    }
+    
+    /// The immutable-range table over `rows` rows: the number of ranges
+    /// containing each cell, as [`Self::immutable_selector`] evaluates it
+    fn immutable_column(&self, rows: usize) -> Vec<FieldElement> {
+        let mut table = vec![FieldElement::zero(); rows];
+        for range in &self.immutable_ranges {
+            for entry in &mut table[range.clone()] {
+                *entry += FieldElement::one();
             }
         }
-        
-        Ok(true)
+        table
     }
     
-    /// Convert a vector to polynomial coefficients via interpolation
-    fn vector_to_polynomial(&self, vector: &[FieldElement]) -> Result<Vec<FieldElement>> {
-        let points: Vec<(FieldElement, FieldElement)> = vector
+    /// The immutable-range table's multilinear extension at `point`
+    fn immutable_selector(&self, point: &[FieldElement]) -> FieldElement {
+        self.immutable_ranges
             .iter()
-            .enumerate()
-            .map(|(i, &val)| (FieldElement::from(i as u64), val))
-            .collect();
-        
-        Ok(crate::polynomials::poly_utils::lagrange_interpolate(&points))
+            .map(|range| poly_utils::prefix_selector(point, range.end) - poly_utils::prefix_selector(point, range.start))
+            .sum()
+    }
+    
+    /// The read-set tables over `rows` rows, as [`Self::read_set_values`]
+    /// evaluates them
+    fn read_set_columns(&self, rows: usize) -> Vec<Vec<FieldElement>> {
+        let mut tables = vec![vec![FieldElement::zero(); rows]; self.read_set_tables()];
+        for (&op_index, allowed) in &self.read_sets {
+            tables[0][op_index] = FieldElement::one();
+            for (table, value) in tables[1..].iter_mut().zip(padded_read_set(allowed, self.read_set_size())) {
+                table[op_index] = value;
+            }
+        }
+        tables
+    }
+    
+    /// The width tables over `rows` rows, as [`Self::width_values`]
+    /// evaluates them, with packing challenge `beta`
+    fn width_table_columns(&self, rows: usize, beta: FieldElement) -> Vec<Vec<FieldElement>> {
+        let size = self.width_size();
+        let mut tables = vec![vec![FieldElement::zero(); rows]; self.width_tables()];
+        for (op_index, bits, slot, offset) in packing_slots(&self.widths) {
+            tables[0][op_index] = FieldElement::one();
+            for (j, weight) in tables[1..=bits].iter_mut().enumerate() {
+                weight[op_index] = FieldElement::from(2u64).pow([j as u64]);
+            }
+            let slot_weight = beta.pow([slot as u64]);
+            tables[size + 1][op_index] = slot_weight * FieldElement::from(2u64).pow([offset as u64]);
+            tables[size + 2][slot] = slot_weight;
+        }
+        tables
+    }
+    
+    /// The width tables' multilinear extensions at `point`, written into
+    /// `values`, which holds [`Self::width_tables`] elements
+    fn width_values(&self, point: &[FieldElement], beta: FieldElement, values: &mut [FieldElement]) {
+        values.fill(FieldElement::zero());
+        let Some((selector, values)) = values.split_first_mut() else {
+            return;
+        };
+        let (weights, packing) = values.split_at_mut(self.width_size());
+        let mut last_slot = None;
+        for (op_index, bits, slot, offset) in packing_slots(&self.widths) {
+            let eq = poly_utils::eq_at_index(point, op_index);
+            *selector += eq;
+            let mut weight = eq;
+            for value in &mut weights[..bits] {
+                *value += weight;
+                weight.double_in_place();
+            }
+            let slot_weight = beta.pow([slot as u64]);
+            packing[0] += eq * slot_weight * FieldElement::from(2u64).pow([offset as u64]);
+            if last_slot != Some(slot) {
+                packing[1] += poly_utils::eq_at_index(point, slot) * slot_weight;
+                last_slot = Some(slot);
+            }
+        }
+    }
+    
+    /// The public tables' multilinear extensions at `point`, written into
+    /// `values`, which holds [`Self::constraint_tables`] elements
+    fn constraint_values(&self, point: &[FieldElement], beta: FieldElement, values: &mut [FieldElement]) {
+        let (read_set, widths) = values.split_at_mut(self.read_set_tables());
+        self.read_set_values(point, read_set);
+        self.width_values(point, beta, widths);
+    }
+    
+    /// The read-set tables' multilinear extensions at `point`, written into
+    /// `values`, which holds [`Self::read_set_tables`] elements
+    fn read_set_values(&self, point: &[FieldElement], values: &mut [FieldElement]) {
+        values.fill(FieldElement::zero());
+        let Some((selector, allowed_values)) = values.split_first_mut() else {
+            return;
+        };
+        for (&op_index, allowed) in &self.read_sets {
+            let eq = poly_utils::eq_at_index(point, op_index);
+            *selector += eq;
+            for (value, allowed) in allowed_values.iter_mut().zip(padded_read_set(allowed, self.read_set_size())) {
+                *value += eq * allowed;
+            }
+        }
+    }
+}
+
+/// Slot and bit offset of each sized value in the layout of
+/// [`MemoryTrace::packed_values`], as (op index, width, slot, offset)
+fn packing_slots(widths: &BTreeMap<usize, usize>) -> impl Iterator<Item = (usize, usize, usize, usize)> + '_ {
+    widths.iter().scan((0, 0), |(slot, offset), (&op_index, &bits)| {
+        if *offset + bits > PACKED_SLOT_BITS {
+            *slot += 1;
+            *offset = 0;
+        }
+        let position = (op_index, bits, *slot, *offset);
+        *offset += bits;
+        Some(position)
+    })
+}
+
+/// Pack the sized values among `values` as [`MemoryTrace::packed_values`] describes
+fn pack_values(values: &[FieldElement], widths: &BTreeMap<usize, usize>) -> Vec<FieldElement> {
+    let mut packed = Vec::new();
+    for (op_index, _, slot, offset) in packing_slots(widths) {
+        if slot == packed.len() {
+            packed.push(FieldElement::zero());
+        }
+        packed[slot] += values[op_index] * FieldElement::from(2u64).pow([offset as u64]);
+    }
+    packed
+}
+
+/// An allowed read set extended to `size` values by repeating its last, which
+/// leaves the values the product ∏(v - allowed) vanishes on unchanged
+fn padded_read_set(allowed: &[FieldElement], size: usize) -> impl Iterator<Item = FieldElement> + '_ {
+    let last = allowed.last().copied().unwrap_or_default();
+    allowed.iter().copied().chain(ark_std::iter::repeat(last)).take(size)
+}
+
+impl ark_serialize::Valid for TraceConstraints {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.read_sets.check()?;
+        self.widths.check()?;
+        if self.immutable_ranges.iter().any(|range| range.start > range.end) {
+            return Err(ark_serialize::SerializationError::InvalidData);
+        }
+        Ok(())
+    }
+}
+
+impl CanonicalSerialize for TraceConstraints {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.read_sets.serialize_with_mode(&mut writer, compress)?;
+        self.range_bounds().serialize_with_mode(&mut writer, compress)?;
+        self.widths.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.read_sets.serialized_size(compress)
+            + self.range_bounds().serialized_size(compress)
+            + self.widths.serialized_size(compress)
+    }
+}
+
+impl CanonicalDeserialize for TraceConstraints {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let read_sets = BTreeMap::deserialize_with_mode(&mut reader, compress, validate)?;
+        let bounds = Vec::<(usize, usize)>::deserialize_with_mode(&mut reader, compress, validate)?;
+        let constraints = Self {
+            read_sets,
+            immutable_ranges: bounds.into_iter().map(|(start, end)| start..end).collect(),
+            widths: BTreeMap::deserialize_with_mode(&mut reader, compress, validate)?,
+        };
+        if validate == ark_serialize::Validate::Yes {
+            ark_serialize::Valid::check(&constraints)?;
+        }
+        Ok(constraints)
+    }
+}
+
+impl TraceConstraints {
+    /// The immutable ranges as (start, end) pairs, as they are serialized
+    fn range_bounds(&self) -> Vec<(usize, usize)> {
+        self.immutable_ranges.iter().map(|range| (range.start, range.end)).collect()
+    }
+}
+
+/// Twist protocol proof.
+///
+/// The commitments are deterministic in the trace and every challenge is
+/// drawn from them, so two proofs of one trace are identical and a proof
+/// cannot be re-randomized without proving again.
+#[derive(Debug, Clone)]
+pub struct TwistProof<C: CommitmentScheme = KZGCommitment> {
+    /// Parameters the proof was generated under
+    pub params: ProofParams,
+    
+    /// Number of operations in the proven trace (public input)
+    pub num_operations: usize,
+    
+    /// Number of memory cells, all zero before the first operation (public input)
+    pub memory_size: usize,
+    
+    /// Constraints on the trace beyond memory consistency (public input)
+    pub constraints: TraceConstraints,
+    
+    /// Commitments to address and value vectors
+    pub address_commitment: C::Commitment,
+    pub value_commitment: C::Commitment,
+    
+    /// Commitment to the op types: 0 for a read, 1 for a write, 2 for padding
+    pub op_commitment: C::Commitment,
+    
+    /// Commitment to the operation timestamps
+    pub timestamp_commitment: C::Commitment,
+    
+    /// Memory-checking sum-check, which also checks that timestamps strictly
+    /// increase; see [`Twist::verify`]
+    pub consistency_proof: SumCheckProof,
+    
+    /// Commitments to the memory-checking columns, opened at the consistency
+    /// sum-check's point
+    pub consistency_opening: ColumnOpening,
+    
+    /// Openings of the address, value, op-type and timestamp commitments at
+    /// the point z tying them to the memory-checking columns, then of the
+    /// timestamp commitment at z - 1
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// The commitments' values at those points, in the same order
+    pub final_evaluations: Vec<FieldElement>,
+    
+    /// Argument that every value fits in a bit width, present only for
+    /// proofs from [`Twist::prove_with_range`]
+    pub range_proof: Option<ValueRangeProof<C>>,
+}
+
+/// Borrowed view of the parts of a [`TwistProof`] that verification reads.
+///
+/// This is the minimal verification payload: anything outside this view is
+/// prover-only and never needs to be sent to the verifier.
+#[derive(Debug)]
+pub struct VerifierProofView<'a, C: CommitmentScheme = KZGCommitment> {
+    /// Parameters the proof was generated under
+    pub params: ProofParams,
+    
+    /// Number of operations in the proven trace (public input)
+    pub num_operations: usize,
+    
+    /// Number of memory cells (public input)
+    pub memory_size: usize,
+    
+    /// Constraints on the trace beyond memory consistency (public input)
+    pub constraints: &'a TraceConstraints,
+    
+    /// Commitments to address, value and op-type vectors
+    pub address_commitment: &'a C::Commitment,
+    pub value_commitment: &'a C::Commitment,
+    pub op_commitment: &'a C::Commitment,
+    
+    /// Commitment to the operation timestamps
+    pub timestamp_commitment: &'a C::Commitment,
+    
+    /// Sum-check proof for memory consistency and timestamp monotonicity
+    pub consistency_proof: &'a SumCheckProof,
+    
+    /// Column commitments and openings behind the sum-check
+    pub consistency_opening: &'a ColumnOpening,
+    
+    /// Opening proofs for final checks
+    pub opening_proofs: &'a [C::Proof],
+    
+    /// Final polynomial evaluations
+    pub final_evaluations: &'a [FieldElement],
+}
+
+// The view only holds references, so it is copyable whatever the scheme
+impl<C: CommitmentScheme> Clone for VerifierProofView<'_, C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: CommitmentScheme> Copy for VerifierProofView<'_, C> {}
+
+impl<C: CommitmentScheme> TwistProof<C> {
+    /// Borrow only the data needed by the verifier
+    pub fn verifier_view(&self) -> VerifierProofView<'_, C> {
+        VerifierProofView {
+            params: self.params,
+            num_operations: self.num_operations,
+            memory_size: self.memory_size,
+            constraints: &self.constraints,
+            address_commitment: &self.address_commitment,
+            value_commitment: &self.value_commitment,
+            op_commitment: &self.op_commitment,
+            timestamp_commitment: &self.timestamp_commitment,
+            consistency_proof: &self.consistency_proof,
+            consistency_opening: &self.consistency_opening,
+            opening_proofs: &self.opening_proofs,
+            final_evaluations: &self.final_evaluations,
+        }
+    }
+    
+    /// Commitment to the timestamp polynomial
+    pub fn timestamp_commitment(&self) -> &C::Commitment {
+        &self.timestamp_commitment
+    }
+    
+    /// Compressed serialized size of each proof component
+    pub fn size_breakdown(&self) -> ProofSizeBreakdown {
+        // An optional range argument costs a presence flag plus its bit width,
+        // bit and quotient commitments and openings
+        let range = self.range_proof.as_ref();
+        let range_inputs = true.compressed_size() + range.map_or(0, |range| range.value_bits.compressed_size());
+        let range_commitments = range.map_or(0, |range| {
+            range.bit_commitments.compressed_size() + range.quotient_commitment.compressed_size()
+        });
+        let range_openings = range.map_or(0, |range| {
+            range.opening_proofs.compressed_size() + range.evaluations.compressed_size()
+        });
+        
+        // Memory columns cost their commitments plus their openings
+        let row_commitments = self.consistency_opening.commitments.compressed_size();
+        let row_proofs = self.consistency_opening.evaluations.compressed_size()
+            + self.consistency_opening.proof.combined.compressed_size();
+        
+        ProofSizeBreakdown {
+            public_inputs: self.params.serialized_size()
+                + self.num_operations.compressed_size()
+                + self.memory_size.compressed_size()
+                + self.constraints.compressed_size()
+                + range_inputs,
+            commitments: self.address_commitment.compressed_size()
+                + self.value_commitment.compressed_size()
+                + self.op_commitment.compressed_size()
+                + self.timestamp_commitment.compressed_size()
+                + row_commitments
+                + range_commitments,
+            sumcheck: self.consistency_proof.serialized_size(),
+            openings: self.opening_proofs.compressed_size()
+                + self.final_evaluations.compressed_size()
+                + row_proofs
+                + range_openings,
+        }
+    }
+    
+    /// Blake2b-256 digest of the public inputs: the parameter fingerprint, the
+    /// operation count, the memory size, the trace constraints and the four
+    /// commitments, in that order, compressed
+    pub fn public_digest(&self) -> [u8; 32] {
+        let mut public_inputs = Vec::new();
+        (
+            self.params.log_size,
+            self.params.max_operations,
+            self.params.vk_hash,
+            self.num_operations,
+        )
+            .serialize_compressed(&mut public_inputs)
+            .expect("Public input serialization should not fail");
+        (self.memory_size, self.constraints.clone())
+            .serialize_compressed(&mut public_inputs)
+            .expect("Public input serialization should not fail");
+        for commitment in [&self.address_commitment, &self.value_commitment, &self.op_commitment, &self.timestamp_commitment] {
+            commitment
+                .serialize_compressed(&mut public_inputs)
+                .expect("Commitment serialization should not fail");
+        }
+        
+        let mut hasher = Blake2b::<U32>::new();
+        hasher.update(PUBLIC_DIGEST_DOMAIN);
+        hasher.update(&public_inputs);
+        hasher.finalize().into()
+    }
+    
+    /// Serialize the proof, compressed, in the given layout.
+    ///
+    /// In [`ProofLayout::Recursive`] the header holds `log_size`,
+    /// `max_operations`, `num_operations`, `memory_size`, the number of
+    /// opening proofs, the number of final evaluations and a range presence
+    /// flag, followed by the number of read sets and each
+    /// one's operation index and size, the number of immutable ranges and
+    /// each one's bounds, the number of sized operations and each one's
+    /// operation index and width, the round shapes of the consistency
+    /// sum-check, the commitment and quotient
+    /// counts of the memory-column opening and, for a range argument, its bit
+    /// width. The scalar section holds `vk_hash`, the allowed read values,
+    /// the consistency sum-check, the memory-column
+    /// evaluations, the final evaluations, then the range evaluations. The
+    /// group section holds the
+    /// address, value, op-type and timestamp commitments, the column
+    /// commitments and quotients, the opening proofs, then the range
+    /// argument's bit and quotient commitments and opening proofs.
+    pub fn serialize_with_layout<W: Write>(
+        &self,
+        layout: ProofLayout,
+        mut writer: W,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        if layout == ProofLayout::Component {
+            return self.serialize_compressed(writer);
+        }
+        
+        let mut header = vec![
+            self.params.log_size as u64,
+            self.params.max_operations as u64,
+            self.num_operations as u64,
+            self.memory_size as u64,
+            self.opening_proofs.len() as u64,
+            self.final_evaluations.len() as u64,
+            self.range_proof.is_some() as u64,
+            self.constraints.read_sets.len() as u64,
+        ];
+        for (&op_index, allowed) in &self.constraints.read_sets {
+            header.extend([op_index as u64, allowed.len() as u64]);
+        }
+        header.push(self.constraints.immutable_ranges.len() as u64);
+        for range in &self.constraints.immutable_ranges {
+            header.extend([range.start as u64, range.end as u64]);
+        }
+        header.push(self.constraints.widths.len() as u64);
+        for (&op_index, &bits) in &self.constraints.widths {
+            header.extend([op_index as u64, bits as u64]);
+        }
+        self.consistency_proof.push_shape(&mut header);
+        self.consistency_opening.push_shape(&mut header);
+        if let Some(range) = &self.range_proof {
+            header.push(range.value_bits as u64);
+        }
+        header.serialize_compressed(&mut writer)?;
+        
+        self.params.vk_hash.serialize_compressed(&mut writer)?;
+        for value in self.constraints.read_sets.values().flatten() {
+            value.serialize_compressed(&mut writer)?;
+        }
+        self.consistency_proof.write_scalars(&mut writer)?;
+        self.consistency_opening.write_scalars(&mut writer)?;
+        let range_evaluations = self.range_proof.iter().flat_map(|range| &range.evaluations);
+        for evaluation in self.final_evaluations.iter().chain(range_evaluations) {
+            evaluation.serialize_compressed(&mut writer)?;
+        }
+        
+        for commitment in [&self.address_commitment, &self.value_commitment, &self.op_commitment, &self.timestamp_commitment] {
+            commitment.serialize_compressed(&mut writer)?;
+        }
+        self.consistency_opening.write_groups(&mut writer)?;
+        for opening in &self.opening_proofs {
+            opening.serialize_compressed(&mut writer)?;
+        }
+        if let Some(range) = &self.range_proof {
+            for commitment in range.bit_commitments.iter().chain([&range.quotient_commitment]) {
+                commitment.serialize_compressed(&mut writer)?;
+            }
+            for opening in &range.opening_proofs {
+                opening.serialize_compressed(&mut writer)?;
+            }
+        }
+        Ok(())
+    }
+    
+    /// Deserialize a proof written by [`Self::serialize_with_layout`]
+    pub fn deserialize_with_layout<R: Read>(
+        layout: ProofLayout,
+        mut reader: R,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        if layout == ProofLayout::Component {
+            return Self::deserialize_compressed(reader);
+        }
+        
+        let header = Vec::<u64>::deserialize_compressed(&mut reader)?;
+        let mut header = header.into_iter();
+        let log_size = crate::utils::next_header_entry(&mut header)?;
+        let max_operations = crate::utils::next_header_entry(&mut header)?;
+        let num_operations = crate::utils::next_header_entry(&mut header)?;
+        let memory_size = crate::utils::next_header_entry(&mut header)?;
+        let num_openings = crate::utils::next_header_entry(&mut header)?;
+        let num_evaluations = crate::utils::next_header_entry(&mut header)?;
+        let flag = |entry| match entry {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ark_serialize::SerializationError::InvalidData),
+        };
+        let has_range = flag(crate::utils::next_header_entry(&mut header)?)?;
+        let read_set_shapes = (0..crate::utils::next_header_entry(&mut header)?)
+            .map(|_| Ok((crate::utils::next_header_entry(&mut header)?, crate::utils::next_header_entry(&mut header)?)))
+            .collect::<ark_std::result::Result<Vec<_>, ark_serialize::SerializationError>>()?;
+        let immutable_ranges = (0..crate::utils::next_header_entry(&mut header)?)
+            .map(|_| Ok(crate::utils::next_header_entry(&mut header)?..crate::utils::next_header_entry(&mut header)?))
+            .collect::<ark_std::result::Result<Vec<_>, ark_serialize::SerializationError>>()?;
+        let mut widths = BTreeMap::new();
+        for _ in 0..crate::utils::next_header_entry(&mut header)? {
+            let op_index = crate::utils::next_header_entry(&mut header)?;
+            if widths.insert(op_index, crate::utils::next_header_entry(&mut header)?).is_some() {
+                return Err(ark_serialize::SerializationError::InvalidData);
+            }
+        }
+        let consistency_shape = SumCheckProof::read_shape(&mut header)?;
+        let consistency_opening_shape = ColumnOpening::read_shape(&mut header)?;
+        // Range arguments cover 1 to 64 bits, as Twist::prove_with_range requires
+        let range_bits = if has_range {
+            let bits = crate::utils::next_header_entry(&mut header)?;
+            if !(1..=64).contains(&bits) {
+                return Err(ark_serialize::SerializationError::InvalidData);
+            }
+            Some(bits)
+        } else {
+            None
+        };
+        if header.next().is_some() {
+            return Err(ark_serialize::SerializationError::InvalidData);
+        }
+        
+        let vk_hash = FieldElement::deserialize_compressed(&mut reader)?;
+        let mut read_sets = BTreeMap::new();
+        for (op_index, size) in read_set_shapes {
+            let allowed = (0..size)
+                .map(|_| FieldElement::deserialize_compressed(&mut reader))
+                .collect::<ark_std::result::Result<Vec<_>, _>>()?;
+            if read_sets.insert(op_index, allowed).is_some() {
+                return Err(ark_serialize::SerializationError::InvalidData);
+            }
+        }
+        let consistency_proof = SumCheckProof::read_scalars(&consistency_shape, &mut reader)?;
+        let mut read_scalars = |count: usize| {
+            (0..count)
+                .map(|_| FieldElement::deserialize_compressed(&mut reader))
+                .collect::<ark_std::result::Result<Vec<_>, _>>()
+        };
+        let column_evaluations = read_scalars(consistency_opening_shape.0)?;
+        let final_evaluations = read_scalars(num_evaluations)?;
+        // A range argument evaluates the value column, each bit column and the quotient
+        let range_evaluations = range_bits.map(|bits| read_scalars(bits + 2)).transpose()?;
+        
+        let address_commitment = C::Commitment::deserialize_compressed(&mut reader)?;
+        let value_commitment = C::Commitment::deserialize_compressed(&mut reader)?;
+        let op_commitment = C::Commitment::deserialize_compressed(&mut reader)?;
+        let timestamp_commitment = C::Commitment::deserialize_compressed(&mut reader)?;
+        let consistency_opening = ColumnOpening::read_groups(consistency_opening_shape, column_evaluations, &mut reader)?;
+        let opening_proofs = (0..num_openings)
+            .map(|_| C::Proof::deserialize_compressed(&mut reader))
+            .collect::<ark_std::result::Result<Vec<_>, _>>()?;
+        let range_proof = match (range_bits, range_evaluations) {
+            (Some(value_bits), Some(evaluations)) => Some(ValueRangeProof {
+                value_bits,
+                bit_commitments: (0..value_bits)
+                    .map(|_| C::Commitment::deserialize_compressed(&mut reader))
+                    .collect::<ark_std::result::Result<Vec<_>, _>>()?,
+                quotient_commitment: C::Commitment::deserialize_compressed(&mut reader)?,
+                opening_proofs: (0..value_bits + 2)
+                    .map(|_| C::Proof::deserialize_compressed(&mut reader))
+                    .collect::<ark_std::result::Result<Vec<_>, _>>()?,
+                evaluations,
+            }),
+            _ => None,
+        };
+        
+        Ok(Self {
+            params: ProofParams { log_size, max_operations, vk_hash },
+            num_operations,
+            memory_size,
+            constraints: TraceConstraints { read_sets, immutable_ranges, widths },
+            address_commitment,
+            value_commitment,
+            op_commitment,
+            timestamp_commitment,
+            consistency_proof,
+            consistency_opening,
+            opening_proofs,
+            final_evaluations,
+            range_proof,
+        })
+    }
+}
+
+impl<C: CommitmentScheme> ark_serialize::Valid for TwistProof<C> {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.params.check()?;
+        self.constraints.check()?;
+        self.address_commitment.check()?;
+        self.value_commitment.check()?;
+        self.op_commitment.check()?;
+        self.timestamp_commitment.check()?;
+        self.consistency_proof.check()?;
+        self.consistency_opening.check()?;
+        self.opening_proofs.check()?;
+        self.final_evaluations.check()?;
+        self.range_proof.check()
+    }
+}
+
+impl<C: CommitmentScheme> CanonicalSerialize for TwistProof<C> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.params.serialize_with_mode(&mut writer, compress)?;
+        self.num_operations.serialize_with_mode(&mut writer, compress)?;
+        self.memory_size.serialize_with_mode(&mut writer, compress)?;
+        self.constraints.serialize_with_mode(&mut writer, compress)?;
+        self.address_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.value_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.op_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.timestamp_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.consistency_proof.serialize_with_mode(&mut writer, compress)?;
+        self.consistency_opening.serialize_with_mode(&mut writer, compress)?;
+        self.opening_proofs.serialize_with_mode(&mut writer, compress)?;
+        self.final_evaluations.serialize_with_mode(&mut writer, compress)?;
+        self.range_proof.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        CanonicalSerialize::serialized_size(&self.params, compress)
+            + self.num_operations.serialized_size(compress)
+            + self.memory_size.serialized_size(compress)
+            + self.constraints.serialized_size(compress)
+            + self.address_commitment.serialized_size(compress)
+            + self.value_commitment.serialized_size(compress)
+            + self.op_commitment.serialized_size(compress)
+            + self.timestamp_commitment.serialized_size(compress)
+            + CanonicalSerialize::serialized_size(&self.consistency_proof, compress)
+            + self.consistency_opening.serialized_size(compress)
+            + self.opening_proofs.serialized_size(compress)
+            + self.final_evaluations.serialized_size(compress)
+            + self.range_proof.serialized_size(compress)
+    }
+}
+
+impl<C: CommitmentScheme> CanonicalDeserialize for TwistProof<C> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        Ok(Self {
+            params: ProofParams::deserialize_with_mode(&mut reader, compress, validate)?,
+            num_operations: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            memory_size: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            constraints: TraceConstraints::deserialize_with_mode(&mut reader, compress, validate)?,
+            address_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            value_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            op_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            timestamp_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            consistency_proof: SumCheckProof::deserialize_with_mode(&mut reader, compress, validate)?,
+            consistency_opening: ColumnOpening::deserialize_with_mode(&mut reader, compress, validate)?,
+            opening_proofs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            final_evaluations: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            range_proof: Option::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+/// JSON-friendly encoding: the compressed canonical bytes as a hex string
+impl<C: CommitmentScheme> Serialize for TwistProof<C> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> ark_std::result::Result<S::Ok, S::Error> {
+        let mut bytes = Vec::new();
+        CanonicalSerialize::serialize_compressed(self, &mut bytes).map_err(serde::ser::Error::custom)?;
+        crate::utils::serialize_hex(&bytes, serializer)
+    }
+}
+
+impl<'de, C: CommitmentScheme> Deserialize<'de> for TwistProof<C> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> ark_std::result::Result<Self, D::Error> {
+        let bytes = crate::utils::deserialize_hex(deserializer)?;
+        CanonicalDeserialize::deserialize_compressed(&bytes[..]).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Domain separator for [`TwistProof::public_digest`]
+const PUBLIC_DIGEST_DOMAIN: &[u8] = b"twist-and-shout/twist-public-inputs";
+
+/// Argument that every committed value fits in `value_bits` bits.
+///
+/// The value column is decomposed into bit columns `B_l`, each interpolated
+/// over the rows 0, 1, 2, ... like the value column `V` itself. The values are
+/// in range exactly when `V(X) = Σ_l 2^l B_l(X)` and
+/// `Σ_l γ^l B_l(X)(B_l(X) - 1) = Z(X)·quotient(X)` for `Z(X)` vanishing on the
+/// rows. Both identities are checked at a single challenge point.
+#[derive(Debug, Clone)]
+pub struct ValueRangeProof<C: CommitmentScheme = KZGCommitment> {
+    /// Bit width every value is bounded by
+    pub value_bits: usize,
+    
+    /// Commitment to bit `l` of every value, for each `l`
+    pub bit_commitments: Vec<C::Commitment>,
+    
+    /// Commitment to the quotient polynomial
+    pub quotient_commitment: C::Commitment,
+    
+    /// Openings of the value column, each bit column and the quotient at the challenge point
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// Evaluations of the value column, each bit column and the quotient at the challenge point
+    pub evaluations: Vec<FieldElement>,
+}
+
+impl<C: CommitmentScheme> ark_serialize::Valid for ValueRangeProof<C> {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.bit_commitments.check()?;
+        self.quotient_commitment.check()?;
+        self.opening_proofs.check()?;
+        self.evaluations.check()
+    }
+}
+
+impl<C: CommitmentScheme> CanonicalSerialize for ValueRangeProof<C> {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.value_bits.serialize_with_mode(&mut writer, compress)?;
+        self.bit_commitments.serialize_with_mode(&mut writer, compress)?;
+        self.quotient_commitment.serialize_with_mode(&mut writer, compress)?;
+        self.opening_proofs.serialize_with_mode(&mut writer, compress)?;
+        self.evaluations.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.value_bits.serialized_size(compress)
+            + self.bit_commitments.serialized_size(compress)
+            + self.quotient_commitment.serialized_size(compress)
+            + self.opening_proofs.serialized_size(compress)
+            + self.evaluations.serialized_size(compress)
+    }
+}
+
+impl<C: CommitmentScheme> CanonicalDeserialize for ValueRangeProof<C> {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        Ok(Self {
+            value_bits: usize::deserialize_with_mode(&mut reader, compress, validate)?,
+            bit_commitments: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            quotient_commitment: C::Commitment::deserialize_with_mode(&mut reader, compress, validate)?,
+            opening_proofs: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+            evaluations: Vec::deserialize_with_mode(&mut reader, compress, validate)?,
+        })
+    }
+}
+
+/// Sub-argument that a claimed address-sorted view is a sorted permutation of
+/// a trace, with the layout of [`InterleavedTraceProof`] but without its
+/// read-after-write constraint
+pub type SortedTraceProof<C = KZGCommitment> = InterleavedTraceProof<C>;
+
+/// Proof that several cores' programs are sequentially consistent: a merged
+/// trace is memory-consistent, and its rows are the cores' operations, each
+/// core's in program order
+#[derive(Debug, Clone)]
+pub struct SequentialConsistencyProof<C: CommitmentScheme = KZGCommitment> {
+    /// Memory-consistency proof of the merged trace
+    pub trace_proof: TwistProof<C>,
+    
+    /// One past the merged row of each core's last operation, zero for cores
+    /// without operations
+    pub final_rows: Vec<u64>,
+    
+    /// Sum-check tagging each merged row with its core and position in that
+    /// core's program; see [`program_order_summand`]
+    pub order_proof: SumCheckProof,
+    
+    /// Commitments to the argument's columns, opened at the sum-check's point
+    pub order_opening: ColumnOpening,
+    
+    /// Openings of the merged trace's address, value and op-type commitments
+    /// at the bridge point z
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// The commitments' values at z, in the same order
+    pub final_evaluations: Vec<FieldElement>,
+}
+
+/// Proof that a trace refines a public abstract specification: the trace is
+/// memory-consistent, and its reads and writes are the specification's, as a
+/// multiset
+#[derive(Debug, Clone)]
+pub struct RefinementProof<C: CommitmentScheme = KZGCommitment> {
+    /// Memory-consistency proof of the concrete trace
+    pub trace_proof: TwistProof<C>,
+    
+    /// Sum-check that the trace's operations have the specification's
+    /// inverse-fingerprint sum; see [`refinement_summand`]
+    pub refinement_proof: SumCheckProof,
+    
+    /// Commitments to the argument's columns, opened at the sum-check's point
+    pub refinement_opening: ColumnOpening,
+    
+    /// Openings of the trace's address, value and op-type commitments at the
+    /// bridge point z
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// The commitments' values at z, in the same order
+    pub final_evaluations: Vec<FieldElement>,
+}
+
+/// Single-pass offline memory-checking argument over the address-sorted view of a trace
+#[derive(Debug, Clone)]
+pub struct InterleavedTraceProof<C: CommitmentScheme = KZGCommitment> {
+    /// Number of operations in the trace (public input)
+    pub num_operations: usize,
+    
+    /// Number of memory cells (public input)
+    pub memory_size: usize,
+    
+    /// Commitments to the original address, value and op-type vectors
+    pub address_commitment: C::Commitment,
+    pub value_commitment: C::Commitment,
+    pub op_commitment: C::Commitment,
+    
+    /// Commitments to the sorted view's address, value and original-row vectors
+    pub sorted_address_commitment: C::Commitment,
+    pub sorted_value_commitment: C::Commitment,
+    pub sorted_row_commitment: C::Commitment,
+    
+    /// One sum-check batching the permutation, ordering and per-address
+    /// read-after-write constraints; see [`interleaved_summand`]
+    pub consistency_proof: SumCheckProof,
+    
+    /// Commitments to the argument's columns, opened at the sum-check's point
+    pub consistency_opening: ColumnOpening,
+    
+    /// Openings of the six commitments at the bridge point z, then of the
+    /// three sorted ones at z - 1
+    pub opening_proofs: Vec<C::Proof>,
+    
+    /// The commitments' values at those points, in the same order
+    pub final_evaluations: Vec<FieldElement>,
+}
+
+/// Proof from [`Twist::prove_state_equals_table`] that the final memory of a
+/// Twist trace equals a Shout table.
+///
+/// The trace is extended by one read per address, in address order, as its
+/// last `state_size` rows. With V, A, O the trace's value, address and
+/// op-type polynomials, T the table polynomial and Z the vanishing polynomial
+/// of {0, ..., state_size - 1}, the quotient Q satisfies
+/// V(X + offset) - T(X) + β(A(X + offset) - X) + β²O(X + offset) = Z(X)·Q(X),
+/// so each final row is a read (op type 0) of its address's table entry.
+#[derive(Debug, Clone)]
+pub struct EqualityProof<C: CommitmentScheme = KZGCommitment> {
+    /// Row of the first final-state access
+    pub offset: usize,
+    
+    /// Number of memory cells and table entries
+    pub state_size: usize,
+    
+    /// Commitment to the quotient Q
+    pub quotient_commitment: C::Commitment,
+    
+    /// Openings of V, A and O at r + offset, then of T and Q at r
+    pub opening_proofs: Vec<C::Proof>,
+    pub evaluations: Vec<FieldElement>,
+}
+
+/// Proof from [`Twist::prove_words`] that a [`WordMemoryTrace`] is memory consistent.
+///
+/// Every limb lane has its own value commitment; the address, op-type and
+/// timestamp columns are shared, and one memory check covers all lanes
+/// through their combination Σ_j λ^j v_j.
+#[derive(Debug, Clone)]
+pub struct WordTwistProof<C: CommitmentScheme = KZGCommitment> {
+    /// Commitment to each lane's value vector, in limb order
+    pub lane_commitments: Vec<C::Commitment>,
+    
+    /// Memory check of the trace of combined values, which commits to the
+    /// shared address, op-type and timestamp columns
+    pub memory_proof: TwistProof<C>,
+    
+    /// Openings of each lane and then of the combined value column at a
+    /// point drawn after the memory check
+    pub opening_proofs: Vec<C::Proof>,
+    pub final_evaluations: Vec<FieldElement>,
+}
+
+/// A-priori estimate of the work [`Twist::prove`] performs for a trace.
+///
+/// These are analytic operation counts derived from the padded trace length,
+/// not timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProveCostEstimate {
+    /// Padded number of operations the proof ranges over
+    pub padded_operations: usize,
+    
+    /// Approximate number of field multiplications
+    pub field_multiplications: usize,
+    
+    /// Total number of base/scalar pairs across all commitment MSMs
+    pub msm_size: usize,
+    
+    /// Number of sum-check rounds
+    pub sumcheck_rounds: usize,
+}
+
+/// Twist protocol implementation, generic over the polynomial commitment scheme
+#[derive(Debug, Clone)]
+pub struct Twist<C: CommitmentScheme = KZGCommitment> {
+    /// Prover parameters
+    prover_params: ProverParams,
+    /// Check every constraint row before proving and report the first violation
+    debug_constraints: bool,
+    _scheme: PhantomData<C>,
+}
+
+impl Twist {
+    /// Create a new Twist instance committing with KZG
+    pub fn new(prover_params: &ProverParams) -> Self {
+        Self::with_scheme(prover_params)
+    }
+}
+
+/// Verify a KZG Twist proof given only serialized bytes.
+///
+/// `vk_bytes` is a [`VerifierParams::write`] encoding and `proof_bytes` a
+/// compressed [`TwistProof`]. Either input failing to parse, or carrying
+/// trailing bytes, counts as a rejection. This is the entry point for
+/// single-threaded `wasm32-unknown-unknown` verifiers, built with
+/// `--no-default-features`.
+pub fn verify_proof_bytes(vk_bytes: &[u8], proof_bytes: &[u8]) -> bool {
+    let mut vk_reader = vk_bytes;
+    let Ok(verifier_params) = VerifierParams::read(&mut vk_reader) else {
+        return false;
+    };
+    let mut proof_reader = proof_bytes;
+    let Ok(proof) = TwistProof::<KZGCommitment>::deserialize_compressed(&mut proof_reader) else {
+        return false;
+    };
+    if !vk_reader.is_empty() || !proof_reader.is_empty() {
+        return false;
+    }
+    
+    Twist::<KZGCommitment>::verify_with_seed(
+        proof.verifier_view(),
+        &verifier_params,
+        &verifier_params.fiat_shamir_seed,
+    )
+    .unwrap_or(false)
+}
+
+impl<C: CommitmentScheme<Scalar = FieldElement>> Twist<C> {
+    /// Create a new Twist instance committing with the scheme `C`
+    pub fn with_scheme(prover_params: &ProverParams) -> Self {
+        Self {
+            prover_params: prover_params.clone(),
+            debug_constraints: false,
+            _scheme: PhantomData,
+        }
+    }
+    
+    /// Commitment key for `C`
+    fn commitment_key(&self) -> Cow<'_, C::Params> {
+        C::prover_key(&self.prover_params)
+    }
+    
+    /// Enable constraint debugging, turning opaque sum-check failures into
+    /// errors that name the first violating row
+    pub fn with_constraint_debugging(mut self, enabled: bool) -> Self {
+        self.debug_constraints = enabled;
+        self
+    }
+    
+    /// Generate a proof for memory consistency
+    pub fn prove(&self, trace: &MemoryTrace) -> Result<TwistProof<C>> {
+        self.prove_with_seed(trace, &self.prover_params.fiat_shamir_seed)
+    }
+    
+    /// Generate a proof whose transcript is seeded from a hash of the public statement
+    /// instead of the parameters' Fiat-Shamir seed, so the same statement always
+    /// yields the same proof. Verify with [`Twist::verify_deterministic`].
+    pub fn prove_deterministic(
+        &self,
+        trace: &MemoryTrace,
+        statement_hash: [u8; 32],
+    ) -> Result<TwistProof<C>> {
+        self.prove_with_seed(trace, &statement_hash)
+    }
+    
+    /// Generate a proof with the transcript initialized from the given seed
+    fn prove_with_seed(&self, trace: &MemoryTrace, seed: &[u8; 32]) -> Result<TwistProof<C>> {
+        self.prove_with_transcript(trace, &mut Transcript::new(seed))
+    }
+    
+    /// Generate a proof against a caller-managed transcript, so the proof can be
+    /// composed with other arguments sharing one Fiat-Shamir transcript.
+    ///
+    /// The proof's messages are appended to whatever `transcript` already holds;
+    /// verify with [`Twist::verify_with_transcript`] from the same transcript state.
+    pub fn prove_with_transcript(&self, trace: &MemoryTrace, transcript: &mut impl FiatShamirTranscript) -> Result<TwistProof<C>> {
+        trace.validate()?;
+        
+        if trace.operations.len() > self.prover_params.max_operations {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Too many operations".to_string(),
+            ));
+        }
+        
+        if trace.timestamps.len() != trace.operations.len() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Every operation needs exactly one timestamp".to_string(),
+            ));
+        }
+        
+        // Extract addresses, values, operation types and timestamps, padded to a power of 2
+        let padded_size = trace.operations.len().next_power_of_two().max(1);
+        let columns = OperationColumns::from_trace(trace, padded_size);
+        
+        self.prove_columns(trace.operations.len(), trace.memory_size, columns, transcript)
+    }
+    
+    /// Generate a proof for a trace stored in a file written by [`MemoryTrace::save`].
+    ///
+    /// Operations are read one at a time and fed to an [`IncrementalTwist`],
+    /// which checks each against the memory state as [`MemoryTrace::validate`]
+    /// does, so the `MemoryOp` list is never held in RAM. The columns still
+    /// take space linear in the number of operations, as [`IncrementalTwist`]
+    /// explains.
+    #[cfg(feature = "std")]
+    pub fn prove_streaming(&self, trace_path: &Path) -> Result<TwistProof<C>> {
+        let trace = StreamingTrace::open(trace_path)?;
+        let mut incremental = self.incremental(trace.memory_size)?;
+        for op in trace {
+            incremental.push_op(op?)?;
+        }
+        incremental.finalize()
+    }
+    
+    /// Start an incremental proof over a memory of `memory_size` cells
+    ///
+    /// `memory_size` may come from an untrusted file header, so it is checked
+    /// against `2^log_size` of the prover parameters before the memory state is
+    /// allocated.
+    pub fn incremental(&self, memory_size: usize) -> Result<IncrementalTwist<'_, C>> {
+        if !memory_size.is_power_of_two() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Memory size must be power of 2".to_string(),
+            ));
+        }
+        let max_memory_size = 1usize
+            .checked_shl(self.prover_params.log_size as u32)
+            .unwrap_or(usize::MAX);
+        if memory_size > max_memory_size {
+            return Err(TwistAndShoutError::InvalidParameters(format!(
+                "Memory size {} exceeds the parameters' maximum of {}",
+                memory_size, max_memory_size
+            )));
+        }
+        
+        Ok(IncrementalTwist {
+            twist: self,
+            memory: vec![FieldElement::zero(); memory_size],
+            columns: OperationColumns::default(),
+            num_operations: 0,
+        })
+    }
+    
+    /// Prove memory consistency for already extracted, padded operation columns
+    /// over a memory of `memory_size` cells
+    fn prove_columns(
+        &self,
+        num_operations: usize,
+        memory_size: usize,
+        columns: OperationColumns,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<TwistProof<C>> {
+        let padded_size = columns.addresses.len();
+        let log_ops = poly_utils::log2_exact(padded_size);
+        let rows = padded_size.max(memory_size.next_power_of_two());
+        let num_vars = poly_utils::log2_exact(rows);
+        
+        let mut memory_columns = columns.memory_check_columns(memory_size, rows)?;
+        
+        // Memory consistency: each read returns the value of the tuple it
+        // consumes, and timestamps strictly increase, as the summand checks
+        // from the committed columns
+        if self.debug_constraints {
+            let constraint_mle = MultilinearExtension::from_evaluations_vec(num_vars, read_constraint_rows(&memory_columns));
+            let constraint = |vars: &[FieldElement]| constraint_mle.evaluate(vars);
+            check_constraint_rows(num_vars, &constraint, "Memory consistency")?;
+            
+            let timestamp_mle = MultilinearExtension::from_evaluations_vec(
+                num_vars,
+                timestamp_constraint_rows(&memory_columns, num_operations),
+            );
+            let timestamps = |vars: &[FieldElement]| timestamp_mle.evaluate(vars);
+            check_constraint_rows(num_vars, &timestamps, "Timestamp monotonicity")?;
+            
+            let read_set_mle = MultilinearExtension::from_evaluations_vec(log_ops, columns.read_set_rows());
+            let read_set = |vars: &[FieldElement]| read_set_mle.evaluate(vars);
+            check_constraint_rows(log_ops, &read_set, "Read value set")?;
+            
+            let range_mle = MultilinearExtension::from_evaluations_vec(log_ops, columns.range_rows());
+            let range = |vars: &[FieldElement]| range_mle.evaluate(vars);
+            check_constraint_rows(log_ops, &range, "Value width")?;
+            
+            let immutable_mle = MultilinearExtension::from_evaluations_vec(log_ops, columns.immutable_rows());
+            let immutable = |vars: &[FieldElement]| immutable_mle.evaluate(vars);
+            check_constraint_rows(log_ops, &immutable, "Immutable range")?;
+        }
+        
+        // Commit to the address, value, op-type and timestamp columns as
+        // univariate polynomials over the rows 0, 1, 2, ...
+        let timestamp_values: Vec<FieldElement> = columns.timestamps.iter().map(|&t| FieldElement::from(t)).collect();
+        let polynomials = [&columns.addresses, &columns.values, &columns.op_types, &timestamp_values]
+            .into_iter()
+            .map(|column| self.vector_to_polynomial(column))
+            .collect::<Result<Vec<_>>>()?;
+        let address_commitment = C::commit(&self.commitment_key(), &polynomials[0])?;
+        let value_commitment = C::commit(&self.commitment_key(), &polynomials[1])?;
+        let op_commitment = C::commit(&self.commitment_key(), &polynomials[2])?;
+        let timestamp_commitment = C::commit(&self.commitment_key(), &polynomials[3])?;
+        Self::absorb_public_inputs(
+            transcript,
+            num_operations,
+            memory_size,
+            &columns.constraints,
+            [&address_commitment, &value_commitment, &op_commitment, &timestamp_commitment],
+        );
+        
+        // Commit to the memory-checking columns before drawing any challenge
+        // that depends on them
+        let column_params = MultilinearKZG::derive_params(&self.prover_params.commitment_params, num_vars)?;
+        let column_slices: Vec<&[FieldElement]> = memory_columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &column_slices)?;
+        let (beta, gamma) = Self::absorb_memory_columns(transcript, &column_commitments);
+        
+        let fingerprint_columns = memory_fingerprint_columns(&memory_columns, memory_size, beta, gamma)?;
+        let fingerprint_slices: Vec<&[FieldElement]> = fingerprint_columns.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &fingerprint_slices)?;
+        let bridge_point = Self::absorb_fingerprint_columns(transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        memory_columns.extend(fingerprint_columns);
+        
+        // Open the four commitments at the bridge point z, and the timestamp
+        // commitment at z - 1 for the previous-timestamp column
+        let mut opening_proofs = Vec::with_capacity(BRIDGE_OPENINGS);
+        let mut final_evaluations = Vec::with_capacity(BRIDGE_OPENINGS);
+        for (polynomial, point) in bridge_openings(&polynomials, bridge_point) {
+            let (evaluation, opening) = C::open(&self.commitment_key(), polynomial, point)?;
+            opening_proofs.push(opening);
+            final_evaluations.push(evaluation);
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(transcript, &final_evaluations, &mut tau, beta, gamma);
+        
+        let initial_sum = initial_memory_sum(gamma, memory_size).ok_or_else(|| {
+            TwistAndShoutError::ProofGeneration("Fingerprint challenge collided with a memory cell".to_string())
+        })?;
+        let claimed_sum = memory_check_claim(initial_sum, &final_evaluations, &challenges);
+        
+        // The summand reads the public tables, then the read-set and width
+        // tables, ahead of the committed columns
+        let read_set_tables = columns.constraints.read_set_tables();
+        let width_tables = columns.constraints.width_tables();
+        let mut tables = vec![
+            poly_utils::eq_table(&tau),
+            (0..rows).map(|i| FieldElement::from(i as u64)).collect(),
+            (0..rows).map(|i| FieldElement::from((i < num_operations) as u64)).collect(),
+            (0..rows).map(|i| FieldElement::from((i < memory_size) as u64)).collect(),
+            (0..rows).map(|i| FieldElement::from((1..num_operations).contains(&i) as u64)).collect(),
+            poly_utils::lagrange_basis_at(padded_size, bridge_point),
+            columns.constraints.immutable_column(rows),
+        ];
+        tables[5].resize(rows, FieldElement::zero());
+        tables.extend(columns.constraints.read_set_columns(rows));
+        tables.extend(columns.constraints.width_table_columns(rows, beta));
+        tables.extend(memory_columns.iter().cloned());
+        let summand = |row: &[FieldElement]| {
+            let (read_set, row_rest) = row[7..].split_at(read_set_tables);
+            let (widths, columns) = row_rest.split_at(width_tables);
+            let public = [row[0], row[1], row[2], row[3], row[4], row[5], row[6]];
+            memory_check_summand(public, read_set, widths, columns, &challenges)
+        };
+        let (consistency_proof, consistency_point, _) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .prove_tables(tables, summand, transcript)?;
+        let column_slices: Vec<&[FieldElement]> = memory_columns.iter().map(|column| &column[..]).collect();
+        let consistency_opening = ColumnOpening::open(
+            &column_params,
+            column_commitments,
+            &column_slices,
+            &consistency_point,
+            transcript,
+        )?;
+        
+        Ok(TwistProof {
+            params: self.prover_params.proof_params(),
+            num_operations,
+            memory_size,
+            constraints: columns.constraints,
+            address_commitment,
+            value_commitment,
+            op_commitment,
+            timestamp_commitment,
+            consistency_proof,
+            consistency_opening,
+            opening_proofs,
+            final_evaluations,
+            range_proof: None,
+        })
+    }
+    
+    /// Verify a Twist proof
+    pub fn verify(&self, proof: &TwistProof<C>, verifier_params: &VerifierParams) -> Result<bool> {
+        Self::verify_with_seed(proof.verifier_view(), verifier_params, &verifier_params.fiat_shamir_seed)
+    }
+    
+    /// Verify a proof like [`Twist::verify`], keeping every sum-check and
+    /// challenge vector in the caller's `scratch` instead of the heap.
+    ///
+    /// `scratch` needs [`Twist::no_alloc_scratch_len`] elements. Every
+    /// transcript, sum-check and constraint check runs first and allocates
+    /// nothing; only the commitment openings, checked last, allocate inside
+    /// the pairing code. A proof rejected before its openings is rejected
+    /// without touching the heap.
+    pub fn verify_no_alloc(
+        &self,
+        proof: &TwistProof<C>,
+        verifier_params: &VerifierParams,
+        scratch: &mut [FieldElement],
+    ) -> Result<bool> {
+        let required = Self::no_alloc_scratch_len(proof);
+        if scratch.len() < required {
+            return Err(TwistAndShoutError::ScratchTooSmall { required, provided: scratch.len() });
+        }
+        
+        if !verifier_params.is_compatible_with(&proof.params) {
+            return Ok(false);
+        }
+        
+        let vk = C::verifier_key(verifier_params);
+        let Some((padded_size, num_vars)) = Self::proof_shape(proof.num_operations, proof.memory_size) else {
+            return Ok(false);
+        };
+        if proof.consistency_proof.round_polynomials.len() != num_vars
+            || !C::supports_degree(&vk, padded_size - 1)
+            || proof.opening_proofs.len() != BRIDGE_OPENINGS
+            || proof.final_evaluations.len() != BRIDGE_OPENINGS
+            || !proof.constraints.is_valid_for(proof.num_operations, proof.memory_size)
+            || !Self::column_counts_valid(proof.verifier_view(), num_vars)
+        {
+            return Ok(false);
+        }
+        let Ok(column_vk) = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, num_vars) else {
+            return Ok(false);
+        };
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Self::absorb_public_inputs(
+            &mut transcript,
+            proof.num_operations,
+            proof.memory_size,
+            &proof.constraints,
+            [&proof.address_commitment, &proof.value_commitment, &proof.op_commitment, &proof.timestamp_commitment],
+        );
+        let fingerprint_start = committed_columns(num_vars, &proof.constraints);
+        let commitments = &proof.consistency_opening.commitments;
+        let (beta, gamma) = Self::absorb_memory_columns(&mut transcript, &commitments[..fingerprint_start]);
+        let bridge_point = Self::absorb_fingerprint_columns(&mut transcript, &commitments[fingerprint_start..]);
+        
+        // The sum-check reuses the front of `scratch`; τ, the consistency
+        // point and the constraint tables' values are kept past it
+        let scratch_len = SumCheck::new(num_vars, FieldElement::zero()).with_max_degree(MEMORY_CHECK_DEGREE).scratch_len();
+        let (scratch, kept) = scratch.split_at_mut(scratch_len);
+        let (tau, kept) = kept.split_at_mut(num_vars);
+        let (consistency_point, constraint_values) = kept.split_at_mut(num_vars);
+        let constraint_values = &mut constraint_values[..proof.constraints.constraint_tables()];
+        let challenges = Self::absorb_bridge_evaluations(&mut transcript, &proof.final_evaluations, tau, beta, gamma);
+        let Some(initial_sum) = initial_memory_sum(gamma, proof.memory_size) else {
+            return Ok(false);
+        };
+        
+        let sumcheck = SumCheck::new(num_vars, memory_check_claim(initial_sum, &proof.final_evaluations, &challenges))
+            .with_max_degree(MEMORY_CHECK_DEGREE);
+        if !sumcheck.verify_with_scratch(&proof.consistency_proof, &mut transcript, scratch)? {
+            return Ok(false);
+        }
+        consistency_point.copy_from_slice(&scratch[..num_vars]);
+        let memory_checked = Self::memory_check_matches(
+            &column_vk,
+            proof.verifier_view(),
+            tau,
+            consistency_point,
+            bridge_point,
+            &challenges,
+            constraint_values,
+            &mut transcript,
+        )?;
+        if !memory_checked {
+            return Ok(false);
+        }
+        
+        // No challenge depends on the bridge openings, so their pairings can
+        // wait until everything else has passed
+        let commitments = [
+            &proof.address_commitment,
+            &proof.value_commitment,
+            &proof.op_commitment,
+            &proof.timestamp_commitment,
+        ];
+        let openings = bridge_openings(&commitments, bridge_point).zip(&proof.final_evaluations).zip(&proof.opening_proofs);
+        for (((commitment, point), &evaluation), opening) in openings {
+            if !C::verify(&vk, commitment, point, evaluation, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        Ok(true)
+    }
+    
+    /// Scratch elements [`Twist::verify_no_alloc`] needs for `proof`: the
+    /// sum-check scratch plus room for τ, the consistency point and the
+    /// constraint tables' values
+    pub fn no_alloc_scratch_len(proof: &TwistProof<C>) -> usize {
+        let num_vars = proof.consistency_proof.round_polynomials.len();
+        SumCheck::new(num_vars, FieldElement::zero()).with_max_degree(MEMORY_CHECK_DEGREE).scratch_len()
+            + 2 * num_vars
+            + proof.constraints.constraint_tables()
+    }
+    
+    /// Verify a proof and check that it covers exactly `expected_operations` operations
+    pub fn verify_with_operation_count(
+        &self,
+        proof: &TwistProof<C>,
+        verifier_params: &VerifierParams,
+        expected_operations: usize,
+    ) -> Result<bool> {
+        if proof.num_operations != expected_operations {
+            return Ok(false);
+        }
+        
+        self.verify(proof, verifier_params)
+    }
+    
+    /// Verify a proof and check that it was produced under `expected_constraints`
+    pub fn verify_with_constraints(
+        &self,
+        proof: &TwistProof<C>,
+        verifier_params: &VerifierParams,
+        expected_constraints: &TraceConstraints,
+    ) -> Result<bool> {
+        if proof.constraints != *expected_constraints {
+            return Ok(false);
+        }
+        
+        self.verify(proof, verifier_params)
+    }
+    
+    /// Verify a proof and check that its public inputs hash to `expected_public_digest`
+    pub fn verify_with_public_digest(
+        &self,
+        proof: &TwistProof<C>,
+        verifier_params: &VerifierParams,
+        expected_public_digest: Option<[u8; 32]>,
+    ) -> Result<bool> {
+        if expected_public_digest.is_some_and(|digest| digest != proof.public_digest()) {
+            return Ok(false);
+        }
+        
+        self.verify(proof, verifier_params)
+    }
+    
+    /// Verify a proof produced by [`Twist::prove_with_transcript`], replaying it
+    /// against `transcript` in the state the prover started from
+    pub fn verify_with_transcript(
+        &self,
+        proof: &TwistProof<C>,
+        verifier_params: &VerifierParams,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<bool> {
+        Ok(Self::verify_report(proof.verifier_view(), verifier_params, transcript)?.is_valid())
+    }
+    
+    /// Verify a proof given only its verifier view
+    pub fn verify_view(&self, view: VerifierProofView<'_, C>, verifier_params: &VerifierParams) -> Result<bool> {
+        Self::verify_with_seed(view, verifier_params, &verifier_params.fiat_shamir_seed)
+    }
+    
+    /// Verify a proof produced by [`Twist::prove_deterministic`] for the given statement hash
+    pub fn verify_deterministic(
+        &self,
+        proof: &TwistProof<C>,
+        verifier_params: &VerifierParams,
+        statement_hash: [u8; 32],
+    ) -> Result<bool> {
+        Self::verify_with_seed(proof.verifier_view(), verifier_params, &statement_hash)
+    }
+    
+    /// Verify a Twist proof and report the outcome of every check
+    pub fn verify_verbose(&self, proof: &TwistProof<C>, verifier_params: &VerifierParams) -> Result<VerifyReport> {
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Self::verify_report(proof.verifier_view(), verifier_params, &mut transcript)
+    }
+    
+    /// Replay verification of a valid proof and return every Fiat-Shamir
+    /// challenge it draws, in order, for comparing transcripts across
+    /// implementations
+    pub fn challenge_log(&self, proof: &TwistProof<C>, verifier_params: &VerifierParams) -> Result<Vec<FieldElement>> {
+        let mut transcript = RecordingTranscript::wrap(Transcript::new(&verifier_params.fiat_shamir_seed));
+        let report = Self::verify_report(proof.verifier_view(), verifier_params, &mut transcript)?;
+        if !report.is_valid() {
+            return Err(TwistAndShoutError::ProofVerification(report.to_string()));
+        }
+        
+        Ok(transcript.challenges().to_vec())
+    }
+    
+    /// Verify a proof with the transcript initialized from the given seed
+    fn verify_with_seed(
+        proof: VerifierProofView<'_, C>,
+        verifier_params: &VerifierParams,
+        seed: &[u8; 32],
+    ) -> Result<bool> {
+        Ok(Self::verify_report(proof, verifier_params, &mut Transcript::new(seed))?.is_valid())
+    }
+    
+    /// Run every verifier check, stopping once the transcript can no longer be trusted
+    fn verify_report(
+        proof: VerifierProofView<'_, C>,
+        verifier_params: &VerifierParams,
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<VerifyReport> {
+        let mut report = VerifyReport::default();
+        
+        if !report.record(
+            "parameters",
+            verifier_params.is_compatible_with(&proof.params),
+            "proof parameters are compatible with the verifier key",
+        ) {
+            return Ok(report);
+        }
+        
+        let vk = C::verifier_key(verifier_params);
+        
+        // Add public inputs and commitments to transcript
+        Self::absorb_public_inputs(
+            transcript,
+            proof.num_operations,
+            proof.memory_size,
+            proof.constraints,
+            [proof.address_commitment, proof.value_commitment, proof.op_commitment, proof.timestamp_commitment],
+        );
+        
+        // The consistency sum-check ranges over the operation rows or the
+        // memory cells, whichever is more
+        let shape = Self::proof_shape(proof.num_operations, proof.memory_size);
+        let Some((padded_size, num_vars)) =
+            shape.filter(|&(_, num_vars)| proof.consistency_proof.round_polynomials.len() == num_vars)
+        else {
+            report.record(
+                "round_count",
+                false,
+                format!(
+                    "{} consistency sum-check rounds for {} operations over {} cells",
+                    proof.consistency_proof.round_polynomials.len(),
+                    proof.num_operations,
+                    proof.memory_size
+                ),
+            );
+            return Ok(report);
+        };
+        report.record("round_count", true, format!("{} sum-check rounds", num_vars));
+        
+        if !report.record(
+            "constraints",
+            proof.constraints.is_valid_for(proof.num_operations, proof.memory_size),
+            "read sets name operations of the trace and allow some value; immutable ranges lie in memory",
+        ) {
+            return Ok(report);
+        }
+        
+        // The proof may come from a smaller SRS sharing the same tau; it only
+        // needs to fit within ours
+        if !report.record(
+            "degree",
+            C::supports_degree(&vk, padded_size - 1),
+            format!("verifier key supports degree {}", padded_size - 1),
+        ) {
+            return Ok(report);
+        }
+        
+        // Every memory-checking column needs its own commitment and evaluation
+        let column_vk = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, num_vars);
+        if !report.record(
+            "row_commitments",
+            Self::column_counts_valid(proof, num_vars) && column_vk.is_ok(),
+            format!(
+                "{} memory column commitments, expected {}",
+                proof.consistency_opening.commitments.len(),
+                committed_columns(num_vars, proof.constraints) + FINGERPRINT_COLUMNS
+            ),
+        ) {
+            return Ok(report);
+        }
+        let column_vk = column_vk?;
+        let fingerprint_start = committed_columns(num_vars, proof.constraints);
+        let commitments = &proof.consistency_opening.commitments;
+        let (beta, gamma) = Self::absorb_memory_columns(transcript, &commitments[..fingerprint_start]);
+        let bridge_point = Self::absorb_fingerprint_columns(transcript, &commitments[fingerprint_start..]);
+        
+        // The four commitments are opened at the bridge point z, and the
+        // timestamp commitment again at z - 1
+        if !report.record(
+            "opening_count",
+            proof.opening_proofs.len() == BRIDGE_OPENINGS && proof.final_evaluations.len() == BRIDGE_OPENINGS,
+            format!(
+                "{} opening proofs and {} evaluations, expected {} of each",
+                proof.opening_proofs.len(),
+                proof.final_evaluations.len(),
+                BRIDGE_OPENINGS
+            ),
+        ) {
+            return Ok(report);
+        }
+        let commitments = [proof.address_commitment, proof.value_commitment, proof.op_commitment, proof.timestamp_commitment];
+        let openings = [
+            ("address_opening", "address polynomial opening"),
+            ("value_opening", "value polynomial opening"),
+            ("op_opening", "op-type polynomial opening"),
+            ("timestamp_opening", "timestamp polynomial opening"),
+            ("previous_timestamp_opening", "timestamp polynomial opening one row back"),
+        ];
+        for (i, ((commitment, point), (name, description))) in
+            bridge_openings(&commitments, bridge_point).zip(openings).enumerate()
+        {
+            let valid = C::verify(&vk, commitment, point, proof.final_evaluations[i], &proof.opening_proofs[i])?;
+            report.record(name, valid, description);
+        }
+        
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(transcript, proof.final_evaluations, &mut tau, beta, gamma);
+        let claimed_sum = initial_memory_sum(gamma, proof.memory_size)
+            .map(|initial_sum| memory_check_claim(initial_sum, proof.final_evaluations, &challenges));
+        let (sumcheck_valid, consistency_point) = match claimed_sum {
+            Some(claimed_sum) => SumCheck::new(num_vars, claimed_sum)
+                .with_max_degree(MEMORY_CHECK_DEGREE)
+                .verify(proof.consistency_proof, transcript)?,
+            None => (false, Vec::new()),
+        };
+        if !report.record("consistency_sumcheck", sumcheck_valid, "memory-checking sum-check") {
+            return Ok(report);
+        }
+        
+        // The rounds only reduce the claim to the summand at r; the committed
+        // columns must back that value
+        let consistency_bound = Self::memory_check_matches(
+            &column_vk,
+            proof,
+            &tau,
+            &consistency_point,
+            bridge_point,
+            &challenges,
+            &mut vec![FieldElement::zero(); proof.constraints.constraint_tables()],
+            transcript,
+        )?;
+        report.record(
+            "consistency_binding",
+            consistency_bound,
+            "memory columns open to values whose summand is the sum-check's final evaluation",
+        );
+        
+        Ok(report)
+    }
+    
+    /// Padded operation count and the variable count of the consistency
+    /// sum-check for the given public inputs, or `None` if they describe no
+    /// trace a proof could cover
+    fn proof_shape(num_operations: usize, memory_size: usize) -> Option<(usize, usize)> {
+        if !memory_size.is_power_of_two() {
+            return None;
+        }
+        let padded_size = num_operations.checked_next_power_of_two()?.max(1);
+        let log_ops = padded_size.trailing_zeros() as usize;
+        Some((padded_size, log_ops.max(memory_size.trailing_zeros() as usize)))
+    }
+    
+    /// Absorb the operation count, the memory size, the trace constraints and
+    /// the address, value, op-type and timestamp commitments, in that order,
+    /// before anything else
+    fn absorb_public_inputs(
+        transcript: &mut impl FiatShamirTranscript,
+        num_operations: usize,
+        memory_size: usize,
+        constraints: &TraceConstraints,
+        commitments: [&C::Commitment; 4],
+    ) {
+        transcript.append_field_element(b"num_operations", &FieldElement::from(num_operations as u64));
+        transcript.append_field_element(b"memory_size", &FieldElement::from(memory_size as u64));
+        constraints.absorb(transcript);
+        for (label, commitment) in [&b"address_commitment"[..], b"value_commitment", b"op_commitment", b"timestamp_commitment"]
+            .into_iter()
+            .zip(commitments)
+        {
+            transcript.append_field_element(label, &C::transcript_hash(commitment));
+        }
+    }
+    
+    /// Absorb the memory-checking columns committed before the fingerprint
+    /// challenges, then draw the fingerprint challenges β and γ
+    fn absorb_memory_columns(transcript: &mut impl FiatShamirTranscript, columns: &[KZGCommitmentValue]) -> (FieldElement, FieldElement) {
+        transcript.append_field_elements_from(b"memory_column_commitments", columns.iter().map(KZGCommitmentValue::hash));
+        (
+            transcript.challenge_field_element(b"memory_fingerprint_beta"),
+            transcript.challenge_field_element(b"memory_fingerprint_gamma"),
+        )
+    }
+    
+    /// Absorb the inverse-fingerprint column commitments and draw the point z
+    /// the univariate column commitments are opened at
+    fn absorb_fingerprint_columns(transcript: &mut impl FiatShamirTranscript, columns: &[KZGCommitmentValue]) -> FieldElement {
+        transcript.append_field_elements_from(b"memory_fingerprint_commitments", columns.iter().map(KZGCommitmentValue::hash));
+        transcript.challenge_field_element(b"memory_bridge_point")
+    }
+    
+    /// Absorb the commitments' values at the bridge points, then draw the
+    /// remaining memory-checking challenges, writing τ into `tau`
+    fn absorb_bridge_evaluations(
+        transcript: &mut impl FiatShamirTranscript,
+        evaluations: &[FieldElement],
+        tau: &mut [FieldElement],
+        beta: FieldElement,
+        gamma: FieldElement,
+    ) -> MemoryChallenges {
+        transcript.append_field_elements(b"memory_bridge_evaluations", evaluations);
+        let alpha = transcript.challenge_field_element(b"memory_bridge_alpha");
+        transcript.challenge_batch_into(b"consistency_tau", tau);
+        let lambda = transcript.challenge_field_element(b"consistency_lambda");
+        let mu = transcript.challenge_field_element(b"consistency_mu");
+        MemoryChallenges { beta, gamma, alpha, lambda, mu }
+    }
+    
+    /// Whether the proof commits to and evaluates every memory-checking
+    /// column for `num_vars` variables and its read-set constraints
+    fn column_counts_valid(proof: VerifierProofView<'_, C>, num_vars: usize) -> bool {
+        let columns = committed_columns(num_vars, proof.constraints) + FINGERPRINT_COLUMNS;
+        proof.consistency_opening.commitments.len() == columns && proof.consistency_opening.evaluations.len() == columns
+    }
+    
+    /// Whether the memory-checking columns open at `point` to values whose
+    /// summand is the consistency sum-check's final evaluation;
+    /// `constraint_values` holds room for the constraint tables' values
+    #[allow(clippy::too_many_arguments)]
+    fn memory_check_matches(
+        vk: &MultilinearKZGVerifierKey,
+        proof: VerifierProofView<'_, C>,
+        tau: &[FieldElement],
+        point: &[FieldElement],
+        bridge_point: FieldElement,
+        challenges: &MemoryChallenges,
+        constraint_values: &mut [FieldElement],
+        transcript: &mut impl FiatShamirTranscript,
+    ) -> Result<bool> {
+        let Some(padded_size) = proof.num_operations.checked_next_power_of_two() else {
+            return Ok(false);
+        };
+        let public = memory_check_public_values(
+            tau,
+            point,
+            proof.num_operations,
+            proof.memory_size,
+            padded_size,
+            bridge_point,
+            proof.constraints,
+        )?;
+        proof.constraints.constraint_values(point, challenges.beta, constraint_values);
+        let (read_set, widths) = constraint_values.split_at(proof.constraints.read_set_tables());
+        let summand = memory_check_summand(public, read_set, widths, &proof.consistency_opening.evaluations, challenges);
+        if summand != proof.consistency_proof.final_evaluation {
+            return Ok(false);
+        }
+        
+        // The pairing checks come last, after every check that runs without
+        // allocating
+        proof.consistency_opening.verify(vk, point, transcript)
+    }
+    
+    /// **Test only.** Check that every public value of a proof of `trace` is
+    /// bound into the Fiat-Shamir transcript.
+    ///
+    /// Proves `trace`, then changes the operation count, the memory size, the
+    /// trace constraints and each of the four commitments in turn and replays
+    /// the verifier on the changed proof, recording the fingerprint challenges
+    /// it draws. A change the verifier rejects before drawing them cannot be
+    /// swapped in either. The error names the first value whose change left
+    /// the challenges as they were, i.e. a value a prover could swap after
+    /// seeing the challenges.
+    pub fn fiat_shamir_self_check(&self, trace: &MemoryTrace, verifier_params: &VerifierParams) -> Result<()>
+    where
+        C: Clone,
+    {
+        let proof = self.prove(trace)?;
+        self.check_public_inputs_bound(&proof, |proof| Self::fingerprint_challenges(proof, verifier_params))
+    }
+    
+    /// The fingerprint challenges β and γ the verifier draws for `proof`, or
+    /// `None` if it rejects the proof before drawing them
+    fn fingerprint_challenges(proof: &TwistProof<C>, verifier_params: &VerifierParams) -> Result<Option<Vec<FieldElement>>> {
+        let mut transcript = RecordingTranscript::wrap(Transcript::new(&verifier_params.fiat_shamir_seed));
+        Self::verify_report(proof.verifier_view(), verifier_params, &mut transcript)?;
+        let challenges = transcript.challenges();
+        Ok((challenges.len() >= 2).then(|| challenges[..2].to_vec()))
+    }
+    
+    /// Run [`Twist::fiat_shamir_self_check`] on `proof`, deriving the
+    /// fingerprint challenges of each changed proof with `challenges`
+    fn check_public_inputs_bound(
+        &self,
+        proof: &TwistProof<C>,
+        challenges: impl Fn(&TwistProof<C>) -> Result<Option<Vec<FieldElement>>>,
+    ) -> Result<()>
+    where
+        C: Clone,
+    {
+        let Some(expected) = challenges(proof)? else {
+            return Err(TwistAndShoutError::ProofVerification(
+                "The verifier rejects the honest proof before its fingerprint challenges".to_string(),
+            ));
+        };
+        let bound = |changed: &TwistProof<C>, name: &str| match challenges(changed)? {
+            Some(drawn) if drawn == expected => Err(TwistAndShoutError::ProofVerification(format!(
+                "{} is not bound into the Fiat-Shamir transcript",
+                name
+            ))),
+            _ => Ok(()),
+        };
+        
+        // Keep the sum-check's shape where possible, so the verifier gets as
+        // far as the challenges
+        let (_, num_vars) = Self::proof_shape(proof.num_operations, proof.memory_size).unwrap_or_default();
+        let same_shape = |num_operations: usize, memory_size: usize| {
+            Self::proof_shape(num_operations, memory_size).is_some_and(|(_, vars)| vars == num_vars)
+        };
+        let mut changed = proof.clone();
+        changed.num_operations = [proof.num_operations + 1, proof.num_operations.saturating_sub(1)]
+            .into_iter()
+            .find(|&n| same_shape(n, proof.memory_size))
+            .unwrap_or(proof.num_operations + 1);
+        bound(&changed, "num_operations")?;
+        
+        let mut changed = proof.clone();
+        changed.memory_size = [2 * proof.memory_size, proof.memory_size / 2]
+            .into_iter()
+            .find(|&m| m > 0 && same_shape(proof.num_operations, m))
+            .unwrap_or(2 * proof.memory_size);
+        bound(&changed, "memory_size")?;
+        
+        let mut changed = proof.clone();
+        changed.constraints.read_sets.entry(0).or_default().push(FieldElement::one());
+        bound(&changed, "constraints")?;
+        
+        let names = ["address_commitment", "value_commitment", "op_commitment", "timestamp_commitment"];
+        for (i, name) in names.into_iter().enumerate() {
+            // At most one of two distinct constants can match the original
+            let mut changed = proof.clone();
+            let commitment = match i {
+                0 => &mut changed.address_commitment,
+                1 => &mut changed.value_commitment,
+                2 => &mut changed.op_commitment,
+                _ => &mut changed.timestamp_commitment,
+            };
+            *commitment = [1u64, 2]
+                .into_iter()
+                .map(|c| C::commit(&self.commitment_key(), &[FieldElement::from(c)]))
+                .collect::<Result<Vec<_>>>()?
+                .into_iter()
+                .find(|candidate| candidate != commitment)
+                .expect("Distinct constants have distinct commitments");
+            bound(&changed, name)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// The read-consistency term of the memory-checking summand over the
+    /// rows of a proof of `trace`: (1 - op)(2 - op)(v - pv) in the committed
+    /// op-type, value and previous-value columns, so twice a read's error and
+    /// zero on writes and padding. Identically zero exactly when every read in
+    /// `trace` returns the last value written to its address.
+    pub fn consistency_constraint_mle(&self, trace: &MemoryTrace) -> Result<MultilinearExtension> {
+        if trace.timestamps.len() != trace.operations.len() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Every operation needs exactly one timestamp".to_string(),
+            ));
+        }
+        
+        let padded_size = trace.operations.len().next_power_of_two().max(1);
+        let rows = padded_size.max(trace.memory_size.next_power_of_two());
+        let columns = OperationColumns::from_trace(trace, padded_size).memory_check_columns(trace.memory_size, rows)?;
+        Ok(MultilinearExtension::from_evaluations_vec(poly_utils::log2_exact(rows), read_constraint_rows(&columns)))
+    }
+    
+    /// Estimate the cost of proving `trace` without running the prover
+    pub fn estimate_prove_cost(&self, trace: &MemoryTrace) -> ProveCostEstimate {
+        let n = trace.operations.len().next_power_of_two().max(1);
+        let rounds = n.trailing_zeros() as usize;
+        
+        // Lagrange interpolation of the address, value and timestamp columns: O(n³) each
+        let interpolation = 3 * n * n * n;
+        
+        // Sum-checks: ~4n closure evaluations each, evaluating three n-entry MLEs
+        // for consistency and one for timestamp monotonicity, with `rounds`
+        // multiplications per basis polynomial
+        let sumcheck = 4 * n * 4 * n * rounds.max(1);
+        
+        // Quotient computation for the two openings
+        let openings = 2 * n;
+        
+        // Three commitments of n coefficients plus two quotient commitments
+        let msm_size = 3 * n + 2 * n.saturating_sub(1);
+        
+        ProveCostEstimate {
+            padded_operations: n,
+            field_multiplications: interpolation + sumcheck + openings,
+            msm_size,
+            sumcheck_rounds: rounds,
+        }
+    }
+    
+    /// Prove that `sorted_view` is a permutation of the trace's operations that is
+    /// sorted by address, then by row.
+    ///
+    /// Each entry of the view is matched to the earliest unused trace row
+    /// holding the same operation, and the argument of
+    /// [`Twist::prove_interleaved`] runs on the view in that order without
+    /// its read constraint: the committed view permutes the trace's
+    /// operations, addresses never decrease, and rows increase within an
+    /// address. Padding operations are left out of the view.
+    pub fn prove_sorted(&self, trace: &MemoryTrace, sorted_view: &[MemoryOp]) -> Result<SortedTraceProof<C>> {
+        trace.validate()?;
+        if !trace.memory_size.is_power_of_two() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Memory size must be a power of two".to_string(),
+            ));
+        }
+        
+        let mut unused: Vec<bool> = trace.operations.iter().map(|op| *op != MemoryOp::Padding).collect();
+        let mut order = Vec::with_capacity(trace.operations.len());
+        for op in sorted_view.iter().filter(|op| **op != MemoryOp::Padding) {
+            let row = (0..trace.operations.len())
+                .find(|&i| unused[i] && trace.operations[i] == *op)
+                .ok_or_else(|| {
+                    TwistAndShoutError::ProofGeneration(
+                        "Sorted view holds an operation the trace does not".to_string(),
+                    )
+                })?;
+            unused[row] = false;
+            order.push(row);
+        }
+        if unused.contains(&true) {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Sorted view must contain every trace operation".to_string(),
+            ));
+        }
+        
+        let padded_size = trace.operations.len().next_power_of_two().max(1);
+        let original = OperationColumns::from_trace(trace, padded_size);
+        let columns = sorted_view_columns(&original, &order, trace.memory_size)?;
+        self.prove_view_argument(trace.operations.len(), trace.memory_size, columns, false)
+    }
+    
+    /// Verify a [`SortedTraceProof`]
+    pub fn verify_sorted(&self, proof: &SortedTraceProof<C>, verifier_params: &VerifierParams) -> Result<bool> {
+        Self::verify_view_argument(proof, verifier_params, false)
+    }
+    
+    /// Prove memory consistency of a trace with interleaved accesses through its
+    /// address-sorted view, in a single sum-check.
+    ///
+    /// The view lists the operations sorted by address, then by row, followed
+    /// by padding rows that repeat its last row. The prover commits to the
+    /// trace's columns and the view's, and the sum-check shows that the view
+    /// permutes the trace's operations, that each row either continues its
+    /// predecessor's address at a later row or moves to a higher address, and
+    /// that each read returns its group's previous value, or zero at the start
+    /// of a group; see [`interleaved_summand`].
+    pub fn prove_interleaved(&self, trace: &MemoryTrace) -> Result<InterleavedTraceProof<C>> {
+        trace.validate()?;
+        if trace.timestamps.len() != trace.operations.len() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Every operation needs exactly one timestamp".to_string(),
+            ));
+        }
+        if !trace.memory_size.is_power_of_two() {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Memory size must be a power of two".to_string(),
+            ));
+        }
+        
+        let memory_size = trace.memory_size;
+        let padded_size = trace.operations.len().next_power_of_two().max(1);
+        let num_vars = poly_utils::log2_exact(padded_size);
+        let original = OperationColumns::from_trace(trace, padded_size);
+        let columns = interleaved_columns(&original, memory_size)?;
+        
+        // Only the per-row constraints vanish row by row; the permutation
+        // vanishes in aggregate
+        if self.debug_constraints {
+            let read_mle = MultilinearExtension::from_evaluations_vec(num_vars, interleaved_read_rows(&columns));
+            let reads = |vars: &[FieldElement]| read_mle.evaluate(vars);
+            check_constraint_rows(num_vars, &reads, "Interleaved consistency")?;
+        }
+        
+        self.prove_view_argument(trace.operations.len(), memory_size, columns, true)
+    }
+    
+    /// Commit to the columns of the interleaved argument and run its
+    /// sum-check, with or without the read constraint
+    fn prove_view_argument(
+        &self,
+        num_operations: usize,
+        memory_size: usize,
+        mut columns: Vec<Vec<FieldElement>>,
+        check_reads: bool,
+    ) -> Result<InterleavedTraceProof<C>> {
+        let padded_size = columns[0].len();
+        let num_vars = poly_utils::log2_exact(padded_size);
+        let params = self.commitment_key();
+        let polynomials = [0, 1, 2, 3, 4, 6]
+            .into_iter()
+            .map(|column| self.vector_to_polynomial(&columns[column]))
+            .collect::<Result<Vec<_>>>()?;
+        let commitments = polynomials
+            .iter()
+            .map(|polynomial| C::commit(&params, polynomial))
+            .collect::<Result<Vec<_>>>()?;
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        Self::absorb_interleaved_commitments(&mut transcript, check_reads, num_operations, memory_size, &commitments);
+        
+        let column_params = MultilinearKZG::derive_params(&self.prover_params.commitment_params, num_vars)?;
+        let column_slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &column_slices)?;
+        let (beta, gamma) = Self::absorb_memory_columns(&mut transcript, &column_commitments);
+        
+        let fingerprint_columns = interleaved_fingerprint_columns(&columns, beta, gamma)?;
+        let fingerprint_slices: Vec<&[FieldElement]> = fingerprint_columns.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &fingerprint_slices)?;
+        let bridge_point = Self::absorb_fingerprint_columns(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        columns.extend(fingerprint_columns);
+        
+        let mut opening_proofs = Vec::with_capacity(INTERLEAVED_OPENINGS);
+        let mut final_evaluations = Vec::with_capacity(INTERLEAVED_OPENINGS);
+        for (polynomial, point) in interleaved_openings(&polynomials, bridge_point) {
+            let (evaluation, opening) = C::open(&params, polynomial, point)?;
+            opening_proofs.push(opening);
+            final_evaluations.push(evaluation);
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claimed_sum = memory_check_claim(FieldElement::zero(), &final_evaluations, &challenges);
+        
+        let mut tables = vec![
+            poly_utils::eq_table(&tau),
+            (0..padded_size).map(|i| FieldElement::from(i as u64)).collect(),
+            (0..padded_size).map(|i| FieldElement::from((i < num_operations) as u64)).collect(),
+            (0..padded_size).map(|i| FieldElement::from((1..num_operations).contains(&i) as u64)).collect(),
+            (0..padded_size).map(|i| FieldElement::from((i == 0) as u64)).collect(),
+            poly_utils::lagrange_basis_at(padded_size, bridge_point),
+        ];
+        tables.extend(columns.iter().cloned());
+        let address_bits = poly_utils::log2_exact(memory_size);
+        let summand = |row: &[FieldElement]| {
+            interleaved_summand([row[0], row[1], row[2], row[3], row[4], row[5]], &row[6..], address_bits, check_reads, &challenges)
+        };
+        let (consistency_proof, consistency_point, _) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .prove_tables(tables, summand, &mut transcript)?;
+        let column_slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let consistency_opening = ColumnOpening::open(
+            &column_params,
+            column_commitments,
+            &column_slices,
+            &consistency_point,
+            &mut transcript,
+        )?;
+        
+        let mut commitments = commitments.into_iter();
+        let mut next = || commitments.next().expect("Six commitments");
+        Ok(InterleavedTraceProof {
+            num_operations,
+            memory_size,
+            address_commitment: next(),
+            value_commitment: next(),
+            op_commitment: next(),
+            sorted_address_commitment: next(),
+            sorted_value_commitment: next(),
+            sorted_row_commitment: next(),
+            consistency_proof,
+            consistency_opening,
+            opening_proofs,
+            final_evaluations,
+        })
+    }
+    
+    /// Verify an [`InterleavedTraceProof`]
+    pub fn verify_interleaved(
+        &self,
+        proof: &InterleavedTraceProof<C>,
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        Self::verify_view_argument(proof, verifier_params, true)
+    }
+    
+    /// Verify the interleaved argument, with or without the read constraint
+    fn verify_view_argument(
+        proof: &InterleavedTraceProof<C>,
+        verifier_params: &VerifierParams,
+        check_reads: bool,
+    ) -> Result<bool> {
+        let vk = C::verifier_key(verifier_params);
+        let Some(padded_size) = proof.num_operations.checked_next_power_of_two().map(|size| size.max(1)) else {
+            return Ok(false);
+        };
+        let num_vars = padded_size.trailing_zeros() as usize;
+        if !proof.memory_size.is_power_of_two() {
+            return Ok(false);
+        }
+        let address_bits = proof.memory_size.trailing_zeros() as usize;
+        let fingerprint_start = INTERLEAVED_COLUMNS + address_bits + num_vars;
+        let opening = &proof.consistency_opening;
+        if proof.consistency_proof.round_polynomials.len() != num_vars
+            || !C::supports_degree(&vk, padded_size - 1)
+            || proof.opening_proofs.len() != INTERLEAVED_OPENINGS
+            || proof.final_evaluations.len() != INTERLEAVED_OPENINGS
+            || opening.commitments.len() != fingerprint_start + 2
+            || opening.evaluations.len() != fingerprint_start + 2
+        {
+            return Ok(false);
+        }
+        let Ok(column_vk) = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, num_vars) else {
+            return Ok(false);
+        };
+        
+        let commitments = [
+            &proof.address_commitment,
+            &proof.value_commitment,
+            &proof.op_commitment,
+            &proof.sorted_address_commitment,
+            &proof.sorted_value_commitment,
+            &proof.sorted_row_commitment,
+        ];
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Self::absorb_interleaved_commitments(&mut transcript, check_reads, proof.num_operations, proof.memory_size, commitments);
+        let (beta, gamma) = Self::absorb_memory_columns(&mut transcript, &opening.commitments[..fingerprint_start]);
+        let bridge_point = Self::absorb_fingerprint_columns(&mut transcript, &opening.commitments[fingerprint_start..]);
+        
+        let openings = interleaved_openings(&commitments, bridge_point).zip(&proof.final_evaluations).zip(&proof.opening_proofs);
+        for (((commitment, point), &evaluation), opening_proof) in openings {
+            if !C::verify(&vk, commitment, point, evaluation, opening_proof)? {
+                return Ok(false);
+            }
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(&mut transcript, &proof.final_evaluations, &mut tau, beta, gamma);
+        
+        let claimed_sum = memory_check_claim(FieldElement::zero(), &proof.final_evaluations, &challenges);
+        let (sumcheck_valid, point) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .verify(&proof.consistency_proof, &mut transcript)?;
+        if !sumcheck_valid || !opening.verify(&column_vk, &point, &mut transcript)? {
+            return Ok(false);
+        }
+        
+        // The committed columns must give the final evaluation
+        let in_trace = poly_utils::prefix_selector(&point, proof.num_operations);
+        let public = [
+            eq_eval(&tau, &point)?,
+            poly_utils::hypercube_index(&point),
+            in_trace,
+            in_trace - poly_utils::prefix_selector(&point, proof.num_operations.min(1)),
+            poly_utils::eq_at_index(&point, 0),
+            poly_utils::lagrange_basis_mle(padded_size, bridge_point, &point),
+        ];
+        let summand = interleaved_summand(public, &opening.evaluations, address_bits, check_reads, &challenges);
+        Ok(summand == proof.consistency_proof.final_evaluation)
+    }
+    
+    /// Absorb the interleaved-trace statement: whether reads are checked,
+    /// the operation count, the memory size and the trace and sorted-view
+    /// commitments
+    fn absorb_interleaved_commitments<'a>(
+        transcript: &mut impl FiatShamirTranscript,
+        check_reads: bool,
+        num_operations: usize,
+        memory_size: usize,
+        commitments: impl IntoIterator<Item = &'a C::Commitment>,
+    ) where
+        C::Commitment: 'a,
+    {
+        transcript.append_field_element(b"check_reads", &FieldElement::from(check_reads));
+        transcript.append_field_element(b"num_operations", &FieldElement::from(num_operations as u64));
+        transcript.append_field_element(b"memory_size", &FieldElement::from(memory_size as u64));
+        for commitment in commitments {
+            transcript.append_field_element(b"interleaved_commitment", &C::transcript_hash(commitment));
+        }
+    }
+    
+    /// Prove memory consistency of a trace of `W`-limb words.
+    ///
+    /// Once the lanes are committed, a challenge λ combines them into one
+    /// value column Σ_j λ^j v_j, and the memory check of [`Twist::prove`]
+    /// runs on the combined trace. A read that returns a stale limb in any
+    /// lane returns a stale combined value except with probability W/|F|.
+    pub fn prove_words<const W: usize>(&self, trace: &WordMemoryTrace<W>) -> Result<WordTwistProof<C>> {
+        if W == 0 {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Words need at least one limb".to_string(),
+            ));
+        }
+        
+        for lane in &trace.lanes {
+            lane.validate()?;
+        }
+        let num_operations = trace.num_operations();
+        if num_operations > self.prover_params.max_operations {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Too many operations".to_string(),
+            ));
+        }
+        
+        let padded_size = num_operations.next_power_of_two().max(1);
+        let params = self.commitment_key();
+        let lane_values: Vec<Vec<FieldElement>> = trace
+            .lanes
+            .iter()
+            .map(|lane| {
+                let mut values: Vec<FieldElement> = lane.operations.iter().map(MemoryOp::value).collect();
+                values.resize(padded_size, FieldElement::zero());
+                values
+            })
+            .collect();
+        let lane_polys = lane_values
+            .iter()
+            .map(|values| self.vector_to_polynomial(values))
+            .collect::<Result<Vec<_>>>()?;
+        let lane_commitments = lane_polys
+            .iter()
+            .map(|poly| C::commit(&params, poly))
+            .collect::<Result<Vec<_>>>()?;
+        
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let lambda = Self::absorb_word_commitments(&mut transcript, num_operations, &lane_commitments);
+        
+        // The lanes share addresses, op types and timestamps; only the values differ
+        let mut columns = OperationColumns::from_trace(&trace.lanes[0], padded_size);
+        columns.constraints = TraceConstraints::default();
+        columns.values = vec![FieldElement::zero(); padded_size];
+        let mut power = FieldElement::one();
+        for values in &lane_values {
+            for (combined, &limb) in columns.values.iter_mut().zip(values) {
+                *combined += power * limb;
+            }
+            power *= lambda;
+        }
+        let combined_poly = self.vector_to_polynomial(&columns.values)?;
+        let memory_proof = self.prove_columns(num_operations, trace.memory_size, columns, &mut transcript)?;
+        
+        // Opening every lane and the combined column at a fresh point ties
+        // the committed combination to the lanes
+        let point = transcript.challenge_field_element(b"word_lane_point");
+        let (opening_proofs, final_evaluations) = lane_polys
+            .iter()
+            .chain([&combined_poly])
+            .map(|poly| C::open(&params, poly, point).map(|(value, proof)| (proof, value)))
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        
+        Ok(WordTwistProof {
+            lane_commitments,
+            memory_proof,
+            opening_proofs,
+            final_evaluations,
+        })
+    }
+    
+    /// Verify a [`WordTwistProof`]
+    pub fn verify_words(&self, proof: &WordTwistProof<C>, verifier_params: &VerifierParams) -> Result<bool> {
+        let num_lanes = proof.lane_commitments.len();
+        if num_lanes == 0
+            || proof.opening_proofs.len() != num_lanes + 1
+            || proof.final_evaluations.len() != num_lanes + 1
+        {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let lambda = Self::absorb_word_commitments(&mut transcript, proof.memory_proof.num_operations, &proof.lane_commitments);
+        if !Self::verify_report(proof.memory_proof.verifier_view(), verifier_params, &mut transcript)?.is_valid() {
+            return Ok(false);
+        }
+        
+        let vk = C::verifier_key(verifier_params);
+        let point = transcript.challenge_field_element(b"word_lane_point");
+        for ((commitment, &value), opening) in proof
+            .lane_commitments
+            .iter()
+            .chain([&proof.memory_proof.value_commitment])
+            .zip(&proof.final_evaluations)
+            .zip(&proof.opening_proofs)
+        {
+            if !C::verify(&vk, commitment, point, value, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        let (&combined, lanes) = proof.final_evaluations.split_last().expect("Checked non-empty above");
+        let expected = lanes.iter().rev().fold(FieldElement::zero(), |acc, &limb| acc * lambda + limb);
+        Ok(combined == expected)
+    }
+    
+    /// Absorb the word-trace statement and derive the lane combining challenge λ
+    fn absorb_word_commitments(
+        transcript: &mut impl FiatShamirTranscript,
+        num_operations: usize,
+        lane_commitments: &[C::Commitment],
+    ) -> FieldElement {
+        transcript.append_field_element(b"num_operations", &FieldElement::from(num_operations as u64));
+        transcript.append_field_element(b"num_lanes", &FieldElement::from(lane_commitments.len() as u64));
+        for commitment in lane_commitments {
+            transcript.append_field_element(b"lane_commitment", &C::transcript_hash(commitment));
+        }
+        transcript.challenge_field_element(b"word_lane_challenge")
+    }
+    
+    /// Prove that a concrete trace refines an abstract specification: both contain
+    /// the same reads and writes (address, value and kind), possibly reordered.
+    ///
+    /// The specification is public, so the verifier computes Σ 1/fp(op) over
+    /// its operations itself. The prover commits to the trace's address, value
+    /// and op-type columns and their inverse fingerprints, ties the columns to
+    /// the trace proof's commitments at a bridge point, and shows in one
+    /// sum-check that the inverses sum to the same value. Padding operations
+    /// on either side are left out.
+    pub fn prove_refinement(&self, concrete: &MemoryTrace, abstract_ops: &[MemoryOp]) -> Result<RefinementProof<C>> {
+        let trace_proof = self.prove(concrete)?;
+        let padded_size = concrete.operations.len().next_power_of_two().max(1);
+        let num_vars = poly_utils::log2_exact(padded_size);
+        let operations = OperationColumns::from_trace(concrete, padded_size);
+        let mut columns = vec![operations.addresses, operations.values, operations.op_types];
+        let polynomials = columns
+            .iter()
+            .map(|column| self.vector_to_polynomial(column))
+            .collect::<Result<Vec<_>>>()?;
+        
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        Self::absorb_refinement_statement(&mut transcript, &trace_proof, abstract_ops);
+        let column_params = MultilinearKZG::derive_params(&self.prover_params.commitment_params, num_vars)?;
+        let column_slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &column_slices)?;
+        let (beta, gamma) = Self::absorb_memory_columns(&mut transcript, &column_commitments);
+        
+        let fingerprint_column = refinement_fingerprint_column(&columns, beta, gamma)?;
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &[&fingerprint_column])?;
+        let bridge_point = Self::absorb_fingerprint_columns(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        columns.push(fingerprint_column);
+        
+        let mut opening_proofs = Vec::with_capacity(polynomials.len());
+        let mut final_evaluations = Vec::with_capacity(polynomials.len());
+        for polynomial in &polynomials {
+            let (evaluation, opening) = C::open(&self.commitment_key(), polynomial, bridge_point)?;
+            opening_proofs.push(opening);
+            final_evaluations.push(evaluation);
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claimed_sum = refinement_claim(abstract_ops, &final_evaluations, &challenges).ok_or_else(|| {
+            TwistAndShoutError::ProofGeneration("Fingerprint challenge collided with an operation".to_string())
+        })?;
+        
+        let mut tables = vec![poly_utils::eq_table(&tau), poly_utils::lagrange_basis_at(padded_size, bridge_point)];
+        tables.extend(columns.iter().cloned());
+        let summand = |row: &[FieldElement]| refinement_summand([row[0], row[1]], &row[2..], &challenges);
+        let (refinement_proof, refinement_point, _) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .prove_tables(tables, summand, &mut transcript)
+            .map_err(|_| {
+                TwistAndShoutError::ProofGeneration(
+                    "Trace operations do not match the abstract specification".to_string(),
+                )
+            })?;
+        let column_slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let refinement_opening =
+            ColumnOpening::open(&column_params, column_commitments, &column_slices, &refinement_point, &mut transcript)?;
+        
+        Ok(RefinementProof {
+            trace_proof,
+            refinement_proof,
+            refinement_opening,
+            opening_proofs,
+            final_evaluations,
+        })
+    }
+    
+    /// Verify a proof from [`Twist::prove_refinement`] against the abstract specification
+    pub fn verify_refinement(
+        &self,
+        proof: &RefinementProof<C>,
+        abstract_ops: &[MemoryOp],
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        let trace_proof = &proof.trace_proof;
+        let Some(padded_size) = trace_proof.num_operations.checked_next_power_of_two().map(|size| size.max(1)) else {
+            return Ok(false);
+        };
+        let num_vars = padded_size.trailing_zeros() as usize;
+        let opening = &proof.refinement_opening;
+        if proof.refinement_proof.round_polynomials.len() != num_vars
+            || proof.opening_proofs.len() != 3
+            || proof.final_evaluations.len() != 3
+            || opening.commitments.len() != REFINEMENT_COLUMNS
+            || opening.evaluations.len() != REFINEMENT_COLUMNS
+            || !self.verify(trace_proof, verifier_params)?
+        {
+            return Ok(false);
+        }
+        let Ok(column_vk) = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, num_vars) else {
+            return Ok(false);
+        };
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Self::absorb_refinement_statement(&mut transcript, trace_proof, abstract_ops);
+        let (beta, gamma) = Self::absorb_memory_columns(&mut transcript, &opening.commitments[..3]);
+        let bridge_point = Self::absorb_fingerprint_columns(&mut transcript, &opening.commitments[3..]);
+        
+        let vk = C::verifier_key(verifier_params);
+        let commitments = [&trace_proof.address_commitment, &trace_proof.value_commitment, &trace_proof.op_commitment];
+        for ((commitment, &evaluation), opening_proof) in commitments.into_iter().zip(&proof.final_evaluations).zip(&proof.opening_proofs) {
+            if !C::verify(&vk, commitment, bridge_point, evaluation, opening_proof)? {
+                return Ok(false);
+            }
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(&mut transcript, &proof.final_evaluations, &mut tau, beta, gamma);
+        let Some(claimed_sum) = refinement_claim(abstract_ops, &proof.final_evaluations, &challenges) else {
+            return Ok(false);
+        };
+        
+        let (sumcheck_valid, point) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .verify(&proof.refinement_proof, &mut transcript)?;
+        if !sumcheck_valid || !opening.verify(&column_vk, &point, &mut transcript)? {
+            return Ok(false);
+        }
+        
+        // The committed columns must give the final evaluation
+        let public = [eq_eval(&tau, &point)?, poly_utils::lagrange_basis_mle(padded_size, bridge_point, &point)];
+        Ok(refinement_summand(public, &opening.evaluations, &challenges) == proof.refinement_proof.final_evaluation)
+    }
+    
+    /// Prove memory consistency and that every value in the trace lies in
+    /// `[0, 2^value_bits)`, for `value_bits` from 1 to 64.
+    ///
+    /// The value column is bit-decomposed; see [`ValueRangeProof`]. Proving
+    /// fails if any value does not fit.
+    pub fn prove_with_range(&self, trace: &MemoryTrace, value_bits: usize) -> Result<TwistProof<C>> {
+        if value_bits == 0 || value_bits > 64 {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Value width must be between 1 and 64 bits".to_string(),
+            ));
+        }
+        if let Some(i) = trace.operations.iter().position(|op| op.value().into_bigint().num_bits() as usize > value_bits) {
+            return Err(TwistAndShoutError::ProofGeneration(format!(
+                "Value of operation {} does not fit in {} bits", i, value_bits
+            )));
+        }
+        
+        let mut proof = self.prove(trace)?;
+        let padded_size = trace.operations.len().next_power_of_two().max(1);
+        let values = OperationColumns::from_trace(trace, padded_size).values;
+        let value_poly = self.vector_to_polynomial(&values)?;
+        
+        let bit_polys = (0..value_bits)
+            .map(|l| {
+                let bits: Vec<FieldElement> = values
+                    .iter()
+                    .map(|value| FieldElement::from((value.into_bigint().0[0] >> l) & 1))
+                    .collect();
+                self.vector_to_polynomial(&bits)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let bit_commitments = bit_polys
+            .iter()
+            .map(|poly| C::commit(&self.commitment_key(), poly))
+            .collect::<Result<Vec<_>>>()?;
+        
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let gamma = Self::absorb_range_statement(&mut transcript, &proof, value_bits, &bit_commitments);
+        
+        // Σ_l γ^l B_l(B_l - 1) vanishes on every row exactly when each B_l is a bit there
+        let mut booleanity = Vec::new();
+        for poly in bit_polys.iter().rev() {
+            let mut shifted = poly.clone();
+            shifted[0] -= FieldElement::one();
+            let square = poly_utils::multiply_polynomials(poly, &shifted);
+            booleanity.resize(booleanity.len().max(square.len()), FieldElement::zero());
+            for coeff in booleanity.iter_mut() {
+                *coeff *= gamma;
+            }
+            for (coeff, term) in booleanity.iter_mut().zip(square) {
+                *coeff += term;
+            }
+        }
+        let quotient = crate::commitments::polynomial_division(&booleanity, &poly_utils::vanishing_polynomial(padded_size))?;
+        let quotient_commitment = C::commit(&self.commitment_key(), &quotient)?;
+        
+        transcript.append_field_element(b"range_quotient", &C::transcript_hash(&quotient_commitment));
+        let point = transcript.challenge_field_element(b"range_point");
+        
+        let mut opening_proofs = Vec::with_capacity(value_bits + 2);
+        let mut evaluations = Vec::with_capacity(value_bits + 2);
+        for poly in ark_std::iter::once(&value_poly).chain(&bit_polys).chain([&quotient]) {
+            let (evaluation, opening) = C::open(&self.commitment_key(), poly, point)?;
+            opening_proofs.push(opening);
+            evaluations.push(evaluation);
+        }
+        
+        proof.range_proof = Some(ValueRangeProof {
+            value_bits,
+            bit_commitments,
+            quotient_commitment,
+            opening_proofs,
+            evaluations,
+        });
+        Ok(proof)
+    }
+    
+    /// Verify a proof from [`Twist::prove_with_range`], accepting only if every
+    /// value lies in `[0, 2^value_bits)`
+    pub fn verify_with_range(
+        &self,
+        proof: &TwistProof<C>,
+        value_bits: usize,
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        let range = match &proof.range_proof {
+            Some(range) => range,
+            None => return Ok(false),
+        };
+        
+        if range.value_bits != value_bits
+            || value_bits == 0
+            || value_bits > 64
+            || range.bit_commitments.len() != value_bits
+            || range.opening_proofs.len() != value_bits + 2
+            || range.evaluations.len() != value_bits + 2
+            || !self.verify(proof, verifier_params)?
+        {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let gamma = Self::absorb_range_statement(&mut transcript, proof, value_bits, &range.bit_commitments);
+        transcript.append_field_element(b"range_quotient", &C::transcript_hash(&range.quotient_commitment));
+        let point = transcript.challenge_field_element(b"range_point");
+        
+        let vk = C::verifier_key(verifier_params);
+        let commitments = ark_std::iter::once(&proof.value_commitment)
+            .chain(&range.bit_commitments)
+            .chain([&range.quotient_commitment]);
+        for ((commitment, &evaluation), opening) in commitments.zip(&range.evaluations).zip(&range.opening_proofs) {
+            if !C::verify(&vk, commitment, point, evaluation, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        let value = range.evaluations[0];
+        let bits = &range.evaluations[1..=value_bits];
+        let quotient = range.evaluations[value_bits + 1];
+        let recomposed = bits.iter().rev().fold(FieldElement::zero(), |acc, &bit| acc.double() + bit);
+        let booleanity = bits.iter().rev().fold(FieldElement::zero(), |acc, &bit| {
+            acc * gamma + bit * (bit - FieldElement::one())
+        });
+        let Some(padded_size) = proof.num_operations.checked_next_power_of_two() else {
+            return Ok(false);
+        };
+        let vanishing: FieldElement = (0..padded_size)
+            .map(|k| point - FieldElement::from(k as u64))
+            .product();
+        
+        Ok(value == recomposed && booleanity == vanishing * quotient)
+    }
+    
+    /// Prove that the operations of several cores are sequentially consistent:
+    /// some global interleaving preserves each core's program order and makes
+    /// every read return the most recent write.
+    ///
+    /// The prover searches for such an interleaving (exponential in the worst
+    /// case) and proves the merged trace with globally increasing timestamps.
+    /// It then tags each merged row with its core c and its position k in
+    /// that core's program. One sum-check shows that the tagged rows are the
+    /// cores' operations and, treating each core's next position as a memory
+    /// cell that every row of the core reads and increments, that each core's
+    /// rows come in program order; see [`program_order_summand`].
+    pub fn prove_sequential_consistency(&self, per_core: &[MemoryTrace]) -> Result<SequentialConsistencyProof<C>> {
+        let memory_size = match per_core.first() {
+            Some(trace) => trace.memory_size,
+            None => {
+                return Err(TwistAndShoutError::ProofGeneration(
+                    "Sequential consistency needs at least one core".to_string(),
+                ))
+            }
+        };
+        if per_core.iter().any(|trace| trace.memory_size != memory_size) {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "All cores must share the same memory size".to_string(),
+            ));
+        }
+        
+        let mut positions = vec![0; per_core.len()];
+        let mut memory = BTreeMap::new();
+        let mut order = Vec::new();
+        let mut visited = BTreeSet::new();
+        if !Self::find_interleaving(per_core, memory_size, &mut positions, &mut memory, &mut order, &mut visited) {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "No sequentially consistent interleaving of the cores exists".to_string(),
+            ));
+        }
+        
+        let mut merged = MemoryTrace::new(memory_size);
+        let mut tags = Vec::new();
+        let mut program_lengths = vec![0u64; per_core.len()];
+        for (core, index) in order {
+            match per_core[core].operations[index] {
+                MemoryOp::Write { address, value } => merged.write(address, value)?,
+                MemoryOp::Read { address, .. } => {
+                    merged.read(address)?;
+                }
+                MemoryOp::Padding => continue,
+            }
+            tags.push((core as u64, program_lengths[core]));
+            program_lengths[core] += 1;
+        }
+        let trace_proof = self.prove(&merged)?;
+        
+        let padded_size = merged.operations.len().next_power_of_two();
+        let num_vars = poly_utils::log2_exact(padded_size);
+        let operations = OperationColumns::from_trace(&merged, padded_size);
+        let (mut columns, final_rows) = program_order_columns(&operations, &tags, per_core.len());
+        let polynomials = [&operations.addresses, &operations.values, &operations.op_types]
+            .into_iter()
+            .map(|column| self.vector_to_polynomial(column))
+            .collect::<Result<Vec<_>>>()?;
+        
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        Self::absorb_program_order_statement(&mut transcript, &trace_proof, per_core, &final_rows);
+        let column_params = MultilinearKZG::derive_params(&self.prover_params.commitment_params, num_vars)?;
+        let column_slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &column_slices)?;
+        let (beta, gamma) = Self::absorb_memory_columns(&mut transcript, &column_commitments);
+        
+        let fingerprint_columns = program_order_fingerprint_columns(&columns, beta, gamma)?;
+        let fingerprint_slices: Vec<&[FieldElement]> = fingerprint_columns.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &fingerprint_slices)?;
+        let bridge_point = Self::absorb_fingerprint_columns(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        columns.extend(fingerprint_columns);
+        
+        let mut opening_proofs = Vec::with_capacity(polynomials.len());
+        let mut final_evaluations = Vec::with_capacity(polynomials.len());
+        for polynomial in &polynomials {
+            let (evaluation, opening) = C::open(&self.commitment_key(), polynomial, bridge_point)?;
+            opening_proofs.push(opening);
+            final_evaluations.push(evaluation);
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claimed_sum = program_order_claim(per_core, &final_rows, &final_evaluations, &challenges)
+            .ok_or_else(|| TwistAndShoutError::ProofGeneration("Fingerprint challenge collided with an operation".to_string()))?;
+        
+        let mut tables = vec![
+            poly_utils::eq_table(&tau),
+            (0..padded_size).map(|i| FieldElement::from(i as u64)).collect(),
+            poly_utils::lagrange_basis_at(padded_size, bridge_point),
+        ];
+        tables.extend(columns.iter().cloned());
+        let summand = |row: &[FieldElement]| program_order_summand([row[0], row[1], row[2]], &row[3..], &challenges);
+        let (order_proof, order_point, _) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .prove_tables(tables, summand, &mut transcript)?;
+        let column_slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let order_opening = ColumnOpening::open(&column_params, column_commitments, &column_slices, &order_point, &mut transcript)?;
+        
+        Ok(SequentialConsistencyProof {
+            trace_proof,
+            final_rows,
+            order_proof,
+            order_opening,
+            opening_proofs,
+            final_evaluations,
+        })
+    }
+    
+    /// Verify a proof from [`Twist::prove_sequential_consistency`] against the cores' traces
+    pub fn verify_sequential_consistency(
+        &self,
+        proof: &SequentialConsistencyProof<C>,
+        per_core: &[MemoryTrace],
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        let trace_proof = &proof.trace_proof;
+        let num_operations: usize = per_core
+            .iter()
+            .map(|trace| trace.operations.iter().filter(|op| !op.is_padding()).count())
+            .sum();
+        let padded_size = num_operations.next_power_of_two();
+        let num_vars = padded_size.trailing_zeros() as usize;
+        let opening = &proof.order_opening;
+        let columns = PROGRAM_ORDER_COLUMNS + num_vars + 3;
+        if per_core.iter().any(|trace| trace.memory_size != trace_proof.memory_size)
+            || trace_proof.num_operations != num_operations
+            || proof.final_rows.len() != per_core.len()
+            || proof.order_proof.round_polynomials.len() != num_vars
+            || proof.opening_proofs.len() != 3
+            || proof.final_evaluations.len() != 3
+            || opening.commitments.len() != columns
+            || opening.evaluations.len() != columns
+            || !self.verify(trace_proof, verifier_params)?
+        {
+            return Ok(false);
+        }
+        let Ok(column_vk) = MultilinearKZG::derive_verifier_key(&verifier_params.commitment_vk, num_vars) else {
+            return Ok(false);
+        };
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Self::absorb_program_order_statement(&mut transcript, trace_proof, per_core, &proof.final_rows);
+        let fingerprint_start = columns - 3;
+        let (beta, gamma) = Self::absorb_memory_columns(&mut transcript, &opening.commitments[..fingerprint_start]);
+        let bridge_point = Self::absorb_fingerprint_columns(&mut transcript, &opening.commitments[fingerprint_start..]);
+        
+        let vk = C::verifier_key(verifier_params);
+        let commitments = [&trace_proof.address_commitment, &trace_proof.value_commitment, &trace_proof.op_commitment];
+        for ((commitment, &evaluation), opening_proof) in commitments.into_iter().zip(&proof.final_evaluations).zip(&proof.opening_proofs) {
+            if !C::verify(&vk, commitment, bridge_point, evaluation, opening_proof)? {
+                return Ok(false);
+            }
+        }
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Self::absorb_bridge_evaluations(&mut transcript, &proof.final_evaluations, &mut tau, beta, gamma);
+        let Some(claimed_sum) = program_order_claim(per_core, &proof.final_rows, &proof.final_evaluations, &challenges) else {
+            return Ok(false);
+        };
+        
+        let (sumcheck_valid, point) = SumCheck::new(num_vars, claimed_sum)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .verify(&proof.order_proof, &mut transcript)?;
+        if !sumcheck_valid || !opening.verify(&column_vk, &point, &mut transcript)? {
+            return Ok(false);
+        }
+        
+        // The committed columns must give the final evaluation
+        let public = [
+            eq_eval(&tau, &point)?,
+            poly_utils::hypercube_index(&point),
+            poly_utils::lagrange_basis_mle(padded_size, bridge_point, &point),
+        ];
+        Ok(program_order_summand(public, &opening.evaluations, &challenges) == proof.order_proof.final_evaluation)
+    }
+    
+    /// Absorb the program-order statement: the merged trace's operation count
+    /// and address, value and op-type commitments, every core's operations
+    /// and the rows after each core's last operation
+    fn absorb_program_order_statement(
+        transcript: &mut impl FiatShamirTranscript,
+        trace_proof: &TwistProof<C>,
+        per_core: &[MemoryTrace],
+        final_rows: &[u64],
+    ) {
+        transcript.append_field_element(b"num_operations", &FieldElement::from(trace_proof.num_operations as u64));
+        for commitment in [&trace_proof.address_commitment, &trace_proof.value_commitment, &trace_proof.op_commitment] {
+            transcript.append_field_element(b"program_order_commitment", &C::transcript_hash(commitment));
+        }
+        for (core, trace) in per_core.iter().enumerate() {
+            transcript.append_field_element(b"program_core", &FieldElement::from(core as u64));
+            for op in trace.operations.iter().filter(|op| !op.is_padding()) {
+                transcript.append_field_elements(
+                    b"program_op",
+                    &[FieldElement::from(op.address() as u64), op.value(), FieldElement::from(op.is_write())],
+                );
+            }
+        }
+        let final_rows: Vec<FieldElement> = final_rows.iter().map(|&row| FieldElement::from(row)).collect();
+        transcript.append_field_elements(b"program_final_rows", &final_rows);
+    }
+    
+    /// Depth-first search for an interleaving that respects program order and
+    /// read-write semantics, memoising (positions, memory) states already
+    /// explored. Memory holds only the cells with a nonzero value, so each
+    /// state costs the cells written so far rather than the whole memory.
+    fn find_interleaving(
+        per_core: &[MemoryTrace],
+        memory_size: usize,
+        positions: &mut Vec<usize>,
+        memory: &mut BTreeMap<usize, FieldElement>,
+        order: &mut Vec<(usize, usize)>,
+        visited: &mut BTreeSet<(Vec<usize>, BTreeMap<usize, FieldElement>)>,
+    ) -> bool {
+        if positions.iter().zip(per_core).all(|(&position, trace)| position == trace.operations.len()) {
+            return true;
+        }
+        if !visited.insert((positions.clone(), memory.clone())) {
+            return false;
+        }
+        
+        for core in 0..per_core.len() {
+            let index = positions[core];
+            let Some(op) = per_core[core].operations.get(index) else {
+                continue;
+            };
+            
+            let previous = match *op {
+                MemoryOp::Read { address, value } => {
+                    let stored = memory.get(&address).copied().unwrap_or_else(FieldElement::zero);
+                    if address >= memory_size || stored != value {
+                        continue;
+                    }
+                    None
+                }
+                MemoryOp::Write { address, value } => {
+                    if address >= memory_size {
+                        continue;
+                    }
+                    let previous = if value.is_zero() { memory.remove(&address) } else { memory.insert(address, value) };
+                    Some((address, previous))
+                }
+                MemoryOp::Padding => None,
+            };
+            
+            positions[core] += 1;
+            order.push((core, index));
+            if Self::find_interleaving(per_core, memory_size, positions, memory, order, visited) {
+                return true;
+            }
+            order.pop();
+            positions[core] -= 1;
+            match previous {
+                Some((address, Some(value))) => {
+                    memory.insert(address, value);
+                }
+                Some((address, None)) => {
+                    memory.remove(&address);
+                }
+                None => {}
+            }
+        }
+        
+        false
+    }
+    
+    /// Prove that the final memory of `trace` equals the entries of `table`.
+    ///
+    /// The trace is extended by a read of every address in order, then
+    /// proven with Twist, the table with Shout, and an [`EqualityProof`] links
+    /// the final rows of the Twist value column to the Shout table commitment
+    /// under a transcript shared with both proofs' commitments. Proving fails
+    /// if the final memory differs from the table.
+    pub fn prove_state_equals_table(
+        &self,
+        trace: &MemoryTrace,
+        table: &LookupTable,
+    ) -> Result<(TwistProof<C>, ShoutProof<C>, EqualityProof<C>)> {
+        let state_size = trace.memory_size;
+        if table.entries.len() != state_size {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Table must have one entry per memory cell".to_string(),
+            ));
+        }
+        
+        let offset = trace.operations.len();
+        let mut extended = trace.clone();
+        for address in 0..state_size {
+            extended.read(address)?;
+        }
+        if extended.operations[offset..].iter().map(MemoryOp::value).ne(table.entries.iter().copied()) {
+            return Err(TwistAndShoutError::ProofGeneration(
+                "Final memory differs from the table".to_string(),
+            ));
+        }
+        
+        let twist_proof = self.prove(&extended)?;
+        let shout_proof = Shout::<C>::with_scheme(&self.prover_params).prove(table)?;
+        
+        let padded_size = extended.operations.len().next_power_of_two();
+        let columns = OperationColumns::from_trace(&extended, padded_size);
+        let value_poly = self.vector_to_polynomial(&columns.values)?;
+        let address_poly = self.vector_to_polynomial(&columns.addresses)?;
+        let op_poly = self.vector_to_polynomial(&columns.op_types)?;
+        let table_poly = self.vector_to_polynomial(&table.entries)?;
+        
+        let mut transcript = Transcript::new(&self.prover_params.fiat_shamir_seed);
+        let beta = Self::absorb_state_equality(&mut transcript, &twist_proof, &shout_proof, offset, state_size);
+        
+        // V(X + offset) - T(X) + β(A(X + offset) - X) + β²O(X + offset),
+        // vanishing on the table domain exactly when the final rows read the
+        // table's entries at addresses 0, 1, 2, ...
+        let shift = FieldElement::from(offset as u64);
+        let shifted_values = poly_utils::shift_polynomial(&value_poly, shift);
+        let shifted_addresses = poly_utils::shift_polynomial(&address_poly, shift);
+        let shifted_ops = poly_utils::shift_polynomial(&op_poly, shift);
+        let beta_squared = beta * beta;
+        let mut numerator: Vec<FieldElement> = shifted_values
+            .iter()
+            .zip(&shifted_addresses)
+            .zip(&shifted_ops)
+            .map(|((&v, &a), &o)| v + beta * a + beta_squared * o)
+            .collect();
+        for (coeff, &t) in numerator.iter_mut().zip(&table_poly) {
+            *coeff -= t;
+        }
+        if numerator.len() > 1 {
+            numerator[1] -= beta;
+        }
+        let quotient = crate::commitments::polynomial_division(
+            &numerator,
+            &poly_utils::vanishing_polynomial(state_size),
+        )?;
+        
+        let quotient_commitment = C::commit(&self.commitment_key(), &quotient)?;
+        transcript.append_field_element(b"equality_quotient", &C::transcript_hash(&quotient_commitment));
+        let point = transcript.challenge_field_element(b"equality_point");
+        let shifted_point = point + FieldElement::from(offset as u64);
+        
+        let mut opening_proofs = Vec::with_capacity(5);
+        let mut evaluations = Vec::with_capacity(5);
+        for (poly, at) in [
+            (&value_poly, shifted_point),
+            (&address_poly, shifted_point),
+            (&op_poly, shifted_point),
+            (&table_poly, point),
+            (&quotient, point),
+        ] {
+            let (evaluation, opening) = C::open(&self.commitment_key(), poly, at)?;
+            evaluations.push(evaluation);
+            opening_proofs.push(opening);
+        }
+        
+        let equality_proof = EqualityProof {
+            offset,
+            state_size,
+            quotient_commitment,
+            opening_proofs,
+            evaluations,
+        };
+        Ok((twist_proof, shout_proof, equality_proof))
+    }
+    
+    /// Verify the proofs from [`Twist::prove_state_equals_table`]
+    pub fn verify_state_equals_table(
+        &self,
+        twist_proof: &TwistProof<C>,
+        shout_proof: &ShoutProof<C>,
+        equality_proof: &EqualityProof<C>,
+        verifier_params: &VerifierParams,
+    ) -> Result<bool> {
+        let state_size = equality_proof.state_size;
+        if state_size == 0
+            || twist_proof.memory_size != state_size
+            || shout_proof.table_size != state_size
+            || equality_proof.offset.checked_add(state_size) != Some(twist_proof.num_operations)
+            || equality_proof.opening_proofs.len() != 5
+            || equality_proof.evaluations.len() != 5
+        {
+            return Ok(false);
+        }
+        
+        let shout = Shout::<C>::with_scheme(&self.prover_params);
+        if !self.verify(twist_proof, verifier_params)? || !shout.verify(shout_proof, verifier_params)? {
+            return Ok(false);
+        }
+        
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        let beta = Self::absorb_state_equality(
+            &mut transcript,
+            twist_proof,
+            shout_proof,
+            equality_proof.offset,
+            state_size,
+        );
+        transcript.append_field_element(b"equality_quotient", &C::transcript_hash(&equality_proof.quotient_commitment));
+        let point = transcript.challenge_field_element(b"equality_point");
+        let shifted_point = point + FieldElement::from(equality_proof.offset as u64);
+        
+        let vk = C::verifier_key(verifier_params);
+        let openings = [
+            (&twist_proof.value_commitment, shifted_point),
+            (&twist_proof.address_commitment, shifted_point),
+            (&twist_proof.op_commitment, shifted_point),
+            (&shout_proof.table_commitment, point),
+            (&equality_proof.quotient_commitment, point),
+        ];
+        for (((commitment, at), &evaluation), opening) in openings
+            .into_iter()
+            .zip(&equality_proof.evaluations)
+            .zip(&equality_proof.opening_proofs)
+        {
+            if !C::verify(&vk, commitment, at, evaluation, opening)? {
+                return Ok(false);
+            }
+        }
+        
+        let &[value, address, op, table, quotient] = &equality_proof.evaluations[..] else {
+            return Ok(false);
+        };
+        let vanishing: FieldElement = (0..state_size).map(|k| point - FieldElement::from(k as u64)).product();
+        Ok(value - table + beta * (address - point) + beta * beta * op == vanishing * quotient)
+    }
+    
+    /// Absorb the state-equality statement and derive the batching challenge β
+    fn absorb_state_equality(
+        transcript: &mut impl FiatShamirTranscript,
+        twist_proof: &TwistProof<C>,
+        shout_proof: &ShoutProof<C>,
+        offset: usize,
+        state_size: usize,
+    ) -> FieldElement {
+        transcript.append_field_element(b"equality_values", &C::transcript_hash(&twist_proof.value_commitment));
+        transcript.append_field_element(b"equality_addresses", &C::transcript_hash(&twist_proof.address_commitment));
+        transcript.append_field_element(b"equality_ops", &C::transcript_hash(&twist_proof.op_commitment));
+        transcript.append_field_element(b"equality_table", &C::transcript_hash(&shout_proof.table_commitment));
+        transcript.append_field_element(b"equality_offset", &FieldElement::from(offset as u64));
+        transcript.append_field_element(b"equality_size", &FieldElement::from(state_size as u64));
+        transcript.challenge_field_element(b"equality_beta")
+    }
+    
+    /// Absorb the range statement and derive the booleanity combiner γ
+    fn absorb_range_statement(
+        transcript: &mut impl FiatShamirTranscript,
+        proof: &TwistProof<C>,
+        value_bits: usize,
+        bit_commitments: &[C::Commitment],
+    ) -> FieldElement {
+        transcript.append_field_element(b"num_operations", &FieldElement::from(proof.num_operations as u64));
+        transcript.append_field_element(b"range_values", &C::transcript_hash(&proof.value_commitment));
+        transcript.append_field_element(b"range_bits", &FieldElement::from(value_bits as u64));
+        for commitment in bit_commitments {
+            transcript.append_field_element(b"range_bit_commitment", &C::transcript_hash(commitment));
+        }
+        
+        transcript.challenge_field_element(b"range_gamma")
+    }
+    
+    /// Absorb the refinement statement: the trace's operation count and
+    /// address, value and op-type commitments, then the specification
+    fn absorb_refinement_statement(transcript: &mut impl FiatShamirTranscript, trace_proof: &TwistProof<C>, abstract_ops: &[MemoryOp]) {
+        transcript.append_field_element(b"num_operations", &FieldElement::from(trace_proof.num_operations as u64));
+        for commitment in [&trace_proof.address_commitment, &trace_proof.value_commitment, &trace_proof.op_commitment] {
+            transcript.append_field_element(b"refinement_commitment", &C::transcript_hash(commitment));
+        }
+        transcript.append_field_element(b"num_spec_operations", &FieldElement::from(abstract_ops.len() as u64));
+        for op in abstract_ops {
+            transcript.append_field_elements(
+                b"refinement_spec",
+                &[FieldElement::from(op.address() as u64), op.value(), op_type(op)],
+            );
+        }
+    }
+    
+    /// Convert a vector to polynomial coefficients via interpolation
+    fn vector_to_polynomial(&self, vector: &[FieldElement]) -> Result<Vec<FieldElement>> {
+        Ok(poly_utils::interpolate_domain(vector))
+    }
+}
+
+/// Committed memory-checking columns drawn before the fingerprint challenges,
+/// ahead of the bits of each row's timestamp gap and of its step from the
+/// previous trace timestamp, the read-set running products, and the value
+/// bits and packed values of sized operations: address, value, op type,
+/// previous value, previous timestamp, final value, final timestamp,
+/// previous write count, final write count, and the trace timestamps of the
+/// row and of the row before it
+const MEMORY_COLUMNS: usize = 11;
+
+/// Inverse-fingerprint columns committed after the fingerprint challenges:
+/// writes, reads and the final memory state
+const FINGERPRINT_COLUMNS: usize = 3;
+
+/// Number of memory-checking columns committed before the fingerprint
+/// challenges for `num_vars` variables under `constraints`
+fn committed_columns(num_vars: usize, constraints: &TraceConstraints) -> usize {
+    MEMORY_COLUMNS + num_vars + TIMESTAMP_BITS + constraints.read_set_size() + constraints.width_columns()
+}
+
+/// Per-variable degree of the memory-checking summand
+const MEMORY_CHECK_DEGREE: usize = 4;
+
+/// Univariate openings tying the column commitments to the memory-checking
+/// columns: address, value, op type and timestamp at the bridge point z,
+/// then timestamp at z - 1
+const BRIDGE_OPENINGS: usize = 5;
+
+/// The address, value, op-type and timestamp polynomials or commitments, in
+/// that order, each paired with the point it is opened at: z, then z - 1 for
+/// the timestamps again, which the previous-timestamp column must match
+fn bridge_openings<T>(columns: &[T], point: FieldElement) -> impl Iterator<Item = (&T, FieldElement)> + '_ {
+    columns
+        .iter()
+        .map(move |column| (column, point))
+        .chain(columns.get(3).map(|timestamps| (timestamps, point - FieldElement::one())))
+}
+
+/// Fiat-Shamir challenges of the memory-checking sum-check
+#[derive(Debug, Clone, Copy)]
+struct MemoryChallenges {
+    /// Fingerprint challenges: tuple (a, v, t, w) maps to γ - a - β(v + β(t + βw))
+    beta: FieldElement,
+    gamma: FieldElement,
+    /// Combines the address, value, op-type and timestamp columns in the bridge term
+    alpha: FieldElement,
+    /// Separates the constraints within a row
+    lambda: FieldElement,
+    /// Separates the zero-check, the fingerprint sum and the bridge term
+    mu: FieldElement,
+}
+
+impl MemoryChallenges {
+    /// Fingerprint of the memory tuple (address, value, timestamp, write count)
+    fn fingerprint(&self, address: FieldElement, value: FieldElement, timestamp: FieldElement, writes: FieldElement) -> FieldElement {
+        memory_fingerprint(self.beta, self.gamma, address, value, timestamp, writes)
+    }
+}
+
+/// γ - a - β(v + β(t + βw)), the fingerprint of the memory tuple (a, v, t, w)
+/// of a cell holding v since timestamp t after w writes
+fn memory_fingerprint(
+    beta: FieldElement,
+    gamma: FieldElement,
+    address: FieldElement,
+    value: FieldElement,
+    timestamp: FieldElement,
+    writes: FieldElement,
+) -> FieldElement {
+    gamma - address - beta * (value + beta * (timestamp + beta * writes))
+}
+
+/// op(2 - op): one for a write and zero for a read or padding
+fn write_indicator(op: FieldElement) -> FieldElement {
+    op * (FieldElement::from(2u64) - op)
+}
+
+/// Inverse-fingerprint columns h_W, h_R and h_F for the columns from
+/// [`OperationColumns::memory_check_columns`]: the inverse fingerprints of the
+/// tuple each operation writes and consumes and of each cell's final tuple,
+/// zero on padding rows and past the memory
+fn memory_fingerprint_columns(
+    columns: &[Vec<FieldElement>],
+    memory_size: usize,
+    beta: FieldElement,
+    gamma: FieldElement,
+) -> Result<Vec<Vec<FieldElement>>> {
+    let rows = columns[0].len();
+    let two = FieldElement::from(2u64);
+    let mut positions = Vec::new();
+    let mut denominators = Vec::new();
+    for (i, &op) in columns[2].iter().enumerate() {
+        let id = FieldElement::from(i as u64);
+        if op != two {
+            let writes = columns[7][i] + write_indicator(op);
+            positions.push((0, i));
+            denominators.push(memory_fingerprint(beta, gamma, columns[0][i], columns[1][i], id + FieldElement::one(), writes));
+            positions.push((1, i));
+            denominators.push(memory_fingerprint(beta, gamma, columns[0][i], columns[3][i], columns[4][i], columns[7][i]));
+        }
+        if i < memory_size {
+            positions.push((2, i));
+            denominators.push(memory_fingerprint(beta, gamma, id, columns[5][i], columns[6][i], columns[8][i]));
+        }
+    }
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err(TwistAndShoutError::ProofGeneration(
+            "Fingerprint challenge collided with a memory tuple".to_string(),
+        ));
+    }
+    
+    let mut inverses = vec![vec![FieldElement::zero(); rows]; FINGERPRINT_COLUMNS];
+    for ((column, row), inverse) in positions.into_iter().zip(crate::utils::field_utils::batch_inverse(&denominators)) {
+        inverses[column][row] = inverse;
+    }
+    Ok(inverses)
+}
+
+/// Summand of the memory-checking sum-check at one point.
+///
+/// `public` holds the tables the verifier evaluates itself: eq(τ, ·), the
+/// row index, the selectors of the trace's operations, of the memory's cells
+/// and of the trace's rows after the first, the Lagrange weights ℓ_z of the
+/// bridge point and the count I of immutable ranges holding each cell;
+/// `read_set` holds the public read-set tables, the selector s of the
+/// constrained reads and the allowed values A_j, if any, and `widths` the
+/// public width tables, the selector s' of the sized rows, the bit weights
+/// u_j = 2^j·[j < width] and the packing weights c and q, if any.
+/// `columns` holds the committed columns in commitment order. The summand is
+/// eq·Σₖ λᵏ cₖ + μ(h_W - h_R - h_F) + μ²ℓ_z(a + α(v + α(op + α(ts + α·ts')))) + μ³(c·v - q·P),
+/// where the constraints cₖ say that:
+///
+/// - op is 0 (read), 1 (write) or 2 (padding), and every row past the trace is padding;
+/// - a read returns the value of the tuple it consumes;
+/// - each operation consumes a tuple written at a timestamp p no later than its own row;
+/// - each trace timestamp ts exceeds the one before it, ts', by one plus
+///   the 64-bit number its step bits spell;
+/// - the running products are P_j = ∏_{l ≤ j}(v - A_l), and s·P_K = 0, so a
+///   constrained read returns one of its allowed values;
+/// - h_W, h_R and h_F invert the fingerprints of the tuples each operation
+///   writes and consumes and of the final memory state, where an operation
+///   writes back the write count it consumed, plus one for a write;
+/// - I·fw = 0, so no cell in an immutable range is ever written;
+/// - s'·(v - Σ_j u_j b_j) = 0 over boolean value bits b_j, so a sized value
+///   fits its width.
+///
+/// The fingerprint term sums to minus the inverse fingerprints of the
+/// initial memory exactly when initial state and writes are, as multisets,
+/// the tuples consumed plus the final state. The bridge term sums to the
+/// univariate column commitments opened at z, and the timestamp commitment
+/// at z - 1, tying them to the committed multilinear columns. The packing
+/// term sums to zero exactly when, at the challenge β that c and q weight
+/// slot k by, each packed value P_k holds its slot's sized values shifted
+/// to their offsets.
+fn memory_check_summand(
+    public: [FieldElement; 7],
+    read_set: &[FieldElement],
+    widths: &[FieldElement],
+    columns: &[FieldElement],
+    challenges: &MemoryChallenges,
+) -> FieldElement {
+    let [eq, id, in_trace, in_memory, steps, lagrange, immutable] = public;
+    let (one, two) = (FieldElement::one(), FieldElement::from(2u64));
+    let read_set_size = read_set.len().saturating_sub(1);
+    let width_columns = widths.len().saturating_sub(2);
+    let Some(step_start) = columns
+        .len()
+        .checked_sub(TIMESTAMP_BITS + read_set_size + width_columns + FINGERPRINT_COLUMNS)
+        .filter(|&start| start >= MEMORY_COLUMNS)
+    else {
+        return FieldElement::zero();
+    };
+    let &[
+        address,
+        value,
+        op,
+        previous_value,
+        previous_timestamp,
+        final_value,
+        final_timestamp,
+        previous_writes,
+        final_writes,
+        timestamp,
+        last_timestamp,
+        ..,
+    ] = columns
+    else {
+        return FieldElement::zero();
+    };
+    let gap_bits = &columns[MEMORY_COLUMNS..step_start];
+    let step_bits = &columns[step_start..step_start + TIMESTAMP_BITS];
+    let products_end = step_start + TIMESTAMP_BITS + read_set_size;
+    let products = &columns[step_start + TIMESTAMP_BITS..products_end];
+    let (value_bits, packed) = match &columns[products_end..columns.len() - FINGERPRINT_COLUMNS] {
+        [value_bits @ .., packed] => (value_bits, *packed),
+        [] => (&[][..], FieldElement::zero()),
+    };
+    let &[write_inverse, read_inverse, final_inverse] = &columns[columns.len() - FINGERPRINT_COLUMNS..] else {
+        return FieldElement::zero();
+    };
+    
+    // Twice the indicator of an operation
+    let active = two - op * (op - one);
+    let gap = gap_bits.iter().rev().fold(FieldElement::zero(), |acc, &bit| acc.double() + bit);
+    let step = step_bits.iter().rev().fold(FieldElement::zero(), |acc, &bit| acc.double() + bit);
+    
+    let writes = previous_writes + write_indicator(op);
+    
+    let constraints = [
+        op * (op - one) * (op - two),
+        (one - in_trace) * (op - two),
+        read_constraint(op, value, previous_value),
+        active * (id - previous_timestamp - gap),
+        steps * (timestamp - last_timestamp - one - step),
+        two * write_inverse * challenges.fingerprint(address, value, id + one, writes) - active,
+        two * read_inverse * challenges.fingerprint(address, previous_value, previous_timestamp, previous_writes) - active,
+        final_inverse * challenges.fingerprint(id, final_value, final_timestamp, final_writes) - in_memory,
+        immutable * final_writes,
+    ];
+    let read_set_constraints = read_set.split_first().into_iter().flat_map(|(&selector, allowed)| {
+        let running = products.iter().zip(allowed).scan(one, |product, (&next, &allowed)| {
+            let constraint = next - *product * (value - allowed);
+            *product = next;
+            Some(constraint)
+        });
+        running.chain(products.last().map(|&last| selector * last))
+    });
+    let (width_constraint, packing) = match widths {
+        [selector, weights @ .., value_weight, packed_weight] => {
+            let bits: FieldElement = weights.iter().zip(value_bits).map(|(&weight, &bit)| weight * bit).sum();
+            (Some(*selector * (value - bits)), *value_weight * value - *packed_weight * packed)
+        }
+        _ => (None, FieldElement::zero()),
+    };
+    let combined = constraints
+        .into_iter()
+        .chain(read_set_constraints)
+        .chain(width_constraint)
+        .chain(gap_bits.iter().chain(step_bits).chain(value_bits).map(|&bit| bit * (bit - one)))
+        .fold(FieldElement::zero(), |acc, constraint| acc * challenges.lambda + constraint);
+    
+    let timestamps = timestamp + challenges.alpha * last_timestamp;
+    let bridge = lagrange * (address + challenges.alpha * (value + challenges.alpha * (op + challenges.alpha * timestamps)));
+    let tail = bridge + challenges.mu * packing;
+    eq * combined + challenges.mu * (write_inverse - read_inverse - final_inverse + challenges.mu * tail)
+}
+
+/// (1 - op)(2 - op)(v - pv): twice the error of a read, which must return
+/// the value pv of the tuple it consumes, and zero for writes and padding
+fn read_constraint(op: FieldElement, value: FieldElement, previous_value: FieldElement) -> FieldElement {
+    (FieldElement::one() - op) * (FieldElement::from(2u64) - op) * (value - previous_value)
+}
+
+/// [`read_constraint`] on every row of the columns from
+/// [`OperationColumns::memory_check_columns`]
+fn read_constraint_rows(columns: &[Vec<FieldElement>]) -> Vec<FieldElement> {
+    (0..columns[0].len())
+        .map(|i| read_constraint(columns[2][i], columns[1][i], columns[3][i]))
+        .collect()
+}
+
+/// steps·(ts - ts' - 1 - Σ_b 2^b δ_b) on every row of the columns from
+/// [`OperationColumns::memory_check_columns`]: zero exactly when each trace
+/// row after the first carries a timestamp above the one before it
+fn timestamp_constraint_rows(columns: &[Vec<FieldElement>], num_operations: usize) -> Vec<FieldElement> {
+    let step_start = MEMORY_COLUMNS + poly_utils::log2_exact(columns[0].len());
+    (0..columns[0].len())
+        .map(|i| {
+            if !(1..num_operations).contains(&i) {
+                return FieldElement::zero();
+            }
+            let step = columns[step_start..step_start + TIMESTAMP_BITS]
+                .iter()
+                .rev()
+                .fold(FieldElement::zero(), |acc, bits| acc.double() + bits[i]);
+            columns[9][i] - columns[10][i] - FieldElement::one() - step
+        })
+        .collect()
+}
+
+/// Σ_{k < memory_size} 1/(γ - k), the inverse fingerprints of the zeroed
+/// initial memory, accumulated as one fraction; `None` if γ hits a cell
+fn initial_memory_sum(gamma: FieldElement, memory_size: usize) -> Option<FieldElement> {
+    let (mut numerator, mut denominator) = (FieldElement::zero(), FieldElement::one());
+    for k in 0..memory_size {
+        let fingerprint = gamma - FieldElement::from(k as u64);
+        numerator = numerator * fingerprint + denominator;
+        denominator *= fingerprint;
+    }
+    denominator.inverse().map(|inverse| numerator * inverse)
+}
+
+/// Claimed sum of the memory-checking sum-check, given the initial-memory
+/// sum and the column commitments' values at the bridge points
+fn memory_check_claim(
+    initial_sum: FieldElement,
+    bridge_evaluations: &[FieldElement],
+    challenges: &MemoryChallenges,
+) -> FieldElement {
+    let bridge = bridge_evaluations
+        .iter()
+        .rev()
+        .fold(FieldElement::zero(), |acc, &evaluation| acc * challenges.alpha + evaluation);
+    challenges.mu * (challenges.mu * bridge - initial_sum)
+}
+
+/// Public tables of [`memory_check_summand`] evaluated at `point`: eq(τ, r),
+/// the row index, the three selectors, the bridge weights and the
+/// immutable-range counts
+fn memory_check_public_values(
+    tau: &[FieldElement],
+    point: &[FieldElement],
+    num_operations: usize,
+    memory_size: usize,
+    padded_operations: usize,
+    bridge_point: FieldElement,
+    constraints: &TraceConstraints,
+) -> Result<[FieldElement; 7]> {
+    let in_trace = poly_utils::prefix_selector(point, num_operations);
+    Ok([
+        eq_eval(tau, point)?,
+        poly_utils::hypercube_index(point),
+        in_trace,
+        poly_utils::prefix_selector(point, memory_size),
+        in_trace - poly_utils::prefix_selector(point, num_operations.min(1)),
+        poly_utils::lagrange_basis_mle(padded_operations, bridge_point, point),
+        constraints.immutable_selector(point),
+    ])
+}
+
+/// Committed columns of the interleaved argument drawn before the
+/// fingerprint challenges, ahead of the bits of each sorted row's address
+/// step and row step: the trace's address, value and op type, the sorted
+/// view's address, value, op type and original row, the sorted view's
+/// address, value and original row one row earlier, and the flag of rows
+/// continuing their predecessor's address
+const INTERLEAVED_COLUMNS: usize = 11;
+
+/// Univariate openings of the interleaved argument: the trace's address,
+/// value and op type and the sorted view's address, value and row at the
+/// bridge point z, then the sorted view's three at z - 1
+const INTERLEAVED_OPENINGS: usize = 9;
+
+/// The six interleaved-argument polynomials or commitments, each paired with
+/// the point it is opened at: z, then z - 1 for the last three again, which
+/// the sorted view's previous-row columns must match
+fn interleaved_openings<T>(columns: &[T], point: FieldElement) -> impl Iterator<Item = (&T, FieldElement)> + '_ {
+    columns
+        .iter()
+        .map(move |column| (column, point))
+        .chain(columns.iter().skip(3).map(move |column| (column, point - FieldElement::one())))
+}
+
+/// Columns of the interleaved argument committed before the fingerprint
+/// challenges; see [`interleaved_summand`] for the order.
+///
+/// The sorted view stably sorts the trace's reads and writes by address, so
+/// each address group stays in trace order, and pads with copies of its
+/// last row, advanced by one row each, that no constraint distinguishes
+/// from the row they copy.
+fn interleaved_columns(original: &OperationColumns, memory_size: usize) -> Result<Vec<Vec<FieldElement>>> {
+    let address_at = |i: usize| original.addresses[i].into_bigint().0[0];
+    let mut order: Vec<usize> = (0..original.addresses.len()).filter(|&i| !original.padding[i]).collect();
+    order.sort_by_key(|&i| address_at(i));
+    sorted_view_columns(original, &order, memory_size)
+}
+
+/// Columns of the interleaved argument for the sorted view listing the
+/// trace rows `order`, which must be sorted by address, then by row
+fn sorted_view_columns(original: &OperationColumns, order: &[usize], memory_size: usize) -> Result<Vec<Vec<FieldElement>>> {
+    let rows = original.addresses.len();
+    let address_bits = poly_utils::log2_exact(memory_size);
+    let row_bits = poly_utils::log2_exact(rows);
+    let address_at = |i: usize| original.addresses[i].into_bigint().0[0];
+    if let Some(&i) = order.iter().find(|&&i| address_at(i) >= memory_size as u64) {
+        return Err(TwistAndShoutError::ProofGeneration(format!(
+            "Operation {} accesses an address outside memory of size {}",
+            i, memory_size
+        )));
+    }
+    if let Some(pair) = order.windows(2).find(|pair| (address_at(pair[1]), pair[1]) <= (address_at(pair[0]), pair[0])) {
+        return Err(TwistAndShoutError::ProofGeneration(format!(
+            "Sorted view lists row {} after row {}, out of address and row order",
+            pair[1], pair[0]
+        )));
+    }
+    
+    let mut columns = vec![vec![FieldElement::zero(); rows]; INTERLEAVED_COLUMNS + address_bits + row_bits];
+    columns[0] = original.addresses.clone();
+    columns[1] = original.values.clone();
+    columns[2] = original.op_types.clone();
+    let step_start = INTERLEAVED_COLUMNS;
+    for row in 0..rows {
+        let sorted = order.get(row).map(|&i| (original.addresses[i], original.values[i], original.op_types[i], i as u64));
+        let (address, value, op, source) = match (sorted, row) {
+            (Some(entry), _) => entry,
+            (None, 0) => (FieldElement::zero(), FieldElement::zero(), FieldElement::from(2u64), 0),
+            (None, _) => {
+                let last = columns[6][row - 1].into_bigint().0[0];
+                (columns[3][row - 1], columns[4][row - 1], FieldElement::from(2u64), last + 1)
+            }
+        };
+        columns[3][row] = address;
+        columns[4][row] = value;
+        columns[5][row] = op;
+        columns[6][row] = FieldElement::from(source);
+        if row == 0 {
+            continue;
+        }
+        
+        let last_address = columns[3][row - 1];
+        let last_source = columns[6][row - 1].into_bigint().0[0];
+        let (same, step, bits) = if address == last_address {
+            (true, source - last_source - 1, &mut columns[step_start + address_bits..])
+        } else {
+            let step = address.into_bigint().0[0] - last_address.into_bigint().0[0] - 1;
+            (false, step, &mut columns[step_start..step_start + address_bits])
+        };
+        let width = bits.len();
+        for (column, bit) in bits.iter_mut().zip(little_endian_bits(step, width)) {
+            column[row] = FieldElement::from(bit);
+        }
+        columns[10][row] = FieldElement::from(same);
+    }
+    
+    // Row 0 has no predecessor; the sorted polynomials' values at -1 stand
+    // in, so each previous-row column interpolates to the polynomial at X - 1
+    let before_first = poly_utils::lagrange_basis_at(rows, -FieldElement::one());
+    for (previous, current) in [(7, 3), (8, 4), (9, 6)] {
+        columns[previous][0] = before_first.iter().zip(&columns[current]).map(|(&weight, &value)| weight * value).sum();
+        for row in 1..rows {
+            columns[previous][row] = columns[current][row - 1];
+        }
+    }
+    Ok(columns)
+}
+
+/// Read rows of the interleaved argument, (1 - op)(v - s·v'), on every row
+/// of the columns from [`interleaved_columns`]
+fn interleaved_read_rows(columns: &[Vec<FieldElement>]) -> Vec<FieldElement> {
+    (0..columns[0].len())
+        .map(|i| (FieldElement::one() - columns[5][i]) * (columns[4][i] - columns[10][i] * columns[8][i]))
+        .collect()
+}
+
+/// Inverse-fingerprint columns of the interleaved argument: the inverse
+/// fingerprints of each trace operation's tuple (a, v, op, row) and of each
+/// sorted row's, zero on padding
+fn interleaved_fingerprint_columns(
+    columns: &[Vec<FieldElement>],
+    beta: FieldElement,
+    gamma: FieldElement,
+) -> Result<Vec<Vec<FieldElement>>> {
+    let rows = columns[0].len();
+    let two = FieldElement::from(2u64);
+    let mut positions = Vec::new();
+    let mut denominators = Vec::new();
+    for (i, &op) in columns[2].iter().enumerate() {
+        if op != two {
+            positions.push((0, i));
+            let row = FieldElement::from(i as u64);
+            denominators.push(memory_fingerprint(beta, gamma, columns[0][i], columns[1][i], op, row));
+        }
+        if columns[5][i] != two {
+            positions.push((1, i));
+            denominators.push(memory_fingerprint(beta, gamma, columns[3][i], columns[4][i], columns[5][i], columns[6][i]));
+        }
+    }
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err(TwistAndShoutError::ProofGeneration(
+            "Fingerprint challenge collided with an operation".to_string(),
+        ));
+    }
+    
+    let mut inverses = vec![vec![FieldElement::zero(); rows]; 2];
+    for ((column, row), inverse) in positions.into_iter().zip(crate::utils::field_utils::batch_inverse(&denominators)) {
+        inverses[column][row] = inverse;
+    }
+    Ok(inverses)
+}
+
+/// Summand of the interleaved sum-check at one point.
+///
+/// `public` holds eq(τ, ·), the row index, the selectors of the trace's
+/// operations, of its rows after the first and of the first row, and the
+/// Lagrange weights ℓ_z of the bridge point. `columns` holds the committed
+/// columns in commitment order: the trace's a, v, op, the sorted view's sa,
+/// sv, sop, row, its previous row's sa', sv', row', the same-address flag s,
+/// the `address_bits` bits of each address step and the bits of each row
+/// step, then the inverse fingerprints h and h_s. The summand is
+/// eq·Σₖ λᵏ cₖ + μ(h - h_s) + μ²ℓ_z(a + αv + α²op + α³sa + α⁴sv + α⁵row + α⁶sa' + α⁷sv' + α⁸row'),
+/// where the constraints cₖ say that:
+///
+/// - op and sop are 0 (read), 1 (write) or 2 (padding), and every row past
+///   the trace is padding;
+/// - s is boolean, zero on the first row, and past it s·(sa - sa') = 0,
+///   (1 - s)(sa - sa' - 1 - Σ 2^b δ_b) = 0 and s(row - row' - 1 - Σ 2^b ε_b) = 0,
+///   so addresses never decrease and rows increase within an address;
+/// - (1 - sop)(sv - s·sv') = 0, so a read returns its group's previous value,
+///   or zero at the start of a group, and padding carries it; a sorted-view
+///   proof (`check_reads` false) leaves this one out;
+/// - h and h_s invert the fingerprints of the tuples (a, v, op, row) of the
+///   trace and of the sorted view, except on padding.
+///
+/// The fingerprint term sums to zero exactly when the sorted view's
+/// operations are, as a multiset, the trace's, and the bridge term sums to
+/// the commitments opened at z and z - 1.
+fn interleaved_summand(
+    public: [FieldElement; 6],
+    columns: &[FieldElement],
+    address_bits: usize,
+    check_reads: bool,
+    challenges: &MemoryChallenges,
+) -> FieldElement {
+    let [eq, id, in_trace, steps, first, lagrange] = public;
+    let (one, two) = (FieldElement::one(), FieldElement::from(2u64));
+    let Some(bits_end) = columns
+        .len()
+        .checked_sub(2)
+        .filter(|&end| end >= INTERLEAVED_COLUMNS + address_bits)
+    else {
+        return FieldElement::zero();
+    };
+    let &[
+        address,
+        value,
+        op,
+        sorted_address,
+        sorted_value,
+        sorted_op,
+        sorted_row,
+        last_address,
+        last_value,
+        last_row,
+        same,
+        ..,
+    ] = columns
+    else {
+        return FieldElement::zero();
+    };
+    let (address_step_bits, row_step_bits) = columns[INTERLEAVED_COLUMNS..bits_end].split_at(address_bits);
+    let &[inverse, sorted_inverse] = &columns[bits_end..] else {
+        return FieldElement::zero();
+    };
+    
+    let step = |bits: &[FieldElement]| bits.iter().rev().fold(FieldElement::zero(), |acc, &bit| acc.double() + bit);
+    let active = |op: FieldElement| two - op * (op - one);
+    let constraints = [
+        op * (op - one) * (op - two),
+        sorted_op * (sorted_op - one) * (sorted_op - two),
+        (one - in_trace) * (op - two),
+        same * (same - one),
+        first * same,
+        steps * same * (sorted_address - last_address),
+        steps * (one - same) * (sorted_address - last_address - one - step(address_step_bits)),
+        steps * same * (sorted_row - last_row - one - step(row_step_bits)),
+        FieldElement::from(check_reads) * (one - sorted_op) * (sorted_value - same * last_value),
+        two * inverse * challenges.fingerprint(address, value, op, id) - active(op),
+        two * sorted_inverse * challenges.fingerprint(sorted_address, sorted_value, sorted_op, sorted_row) - active(sorted_op),
+    ];
+    let combined = constraints
+        .into_iter()
+        .chain(address_step_bits.iter().chain(row_step_bits).map(|&bit| bit * (bit - one)))
+        .fold(FieldElement::zero(), |acc, constraint| acc * challenges.lambda + constraint);
+    
+    let bridged = [address, value, op, sorted_address, sorted_value, sorted_row, last_address, last_value, last_row];
+    let bridge = lagrange * bridged.iter().rev().fold(FieldElement::zero(), |acc, &column| acc * challenges.alpha + column);
+    eq * combined + challenges.mu * (inverse - sorted_inverse + challenges.mu * bridge)
+}
+
+/// Committed columns of the program-order argument drawn before the
+/// fingerprint challenges, ahead of the bits of each row's distance to its
+/// core's previous row: the merged trace's address, value and op type, and
+/// each row's core c, position k in that core's program and previous row p,
+/// one past the merged row of the core's last operation before it or zero
+const PROGRAM_ORDER_COLUMNS: usize = 6;
+
+/// Columns of the program-order argument committed before the fingerprint
+/// challenges, tagging row i of `operations` with `tags[i]`, and one past
+/// the row of each of the `num_cores` cores' last operation
+fn program_order_columns(
+    operations: &OperationColumns,
+    tags: &[(u64, u64)],
+    num_cores: usize,
+) -> (Vec<Vec<FieldElement>>, Vec<u64>) {
+    let rows = operations.addresses.len();
+    let num_bits = poly_utils::log2_exact(rows);
+    let mut columns = vec![vec![FieldElement::zero(); rows]; PROGRAM_ORDER_COLUMNS + num_bits];
+    columns[0] = operations.addresses.clone();
+    columns[1] = operations.values.clone();
+    columns[2] = operations.op_types.clone();
+    
+    let mut final_rows = vec![0u64; num_cores];
+    for (row, &(core, position)) in tags.iter().enumerate() {
+        let previous = final_rows[core as usize];
+        columns[3][row] = FieldElement::from(core);
+        columns[4][row] = FieldElement::from(position);
+        columns[5][row] = FieldElement::from(previous);
+        for (column, bit) in columns[PROGRAM_ORDER_COLUMNS..].iter_mut().zip(little_endian_bits(row as u64 - previous, num_bits)) {
+            column[row] = FieldElement::from(bit);
+        }
+        final_rows[core as usize] = row as u64 + 1;
+    }
+    (columns, final_rows)
+}
+
+/// γ - a - β(v + β(o + β(c + βk))), the fingerprint of operation (a, v, o)
+/// at position k of core c's program
+fn program_fingerprint(
+    challenges: &MemoryChallenges,
+    address: FieldElement,
+    value: FieldElement,
+    op: FieldElement,
+    core: FieldElement,
+    position: FieldElement,
+) -> FieldElement {
+    let beta = challenges.beta;
+    challenges.gamma - address - beta * (value + beta * (op + beta * (core + beta * position)))
+}
+
+/// Inverse-fingerprint columns of the program-order argument: for each
+/// operation row, the inverse fingerprints of its tagged operation, of the
+/// counter tuple (c, k + 1, i + 1) it writes and of the tuple (c, k, p) it
+/// reads; zero on padding
+fn program_order_fingerprint_columns(
+    columns: &[Vec<FieldElement>],
+    beta: FieldElement,
+    gamma: FieldElement,
+) -> Result<Vec<Vec<FieldElement>>> {
+    let challenges = MemoryChallenges {
+        beta,
+        gamma,
+        alpha: FieldElement::zero(),
+        lambda: FieldElement::zero(),
+        mu: FieldElement::zero(),
+    };
+    let one = FieldElement::one();
+    let rows: Vec<usize> = (0..columns[0].len()).filter(|&i| columns[2][i] != FieldElement::from(2u64)).collect();
+    let mut denominators = Vec::with_capacity(3 * rows.len());
+    for &i in &rows {
+        let (core, position) = (columns[3][i], columns[4][i]);
+        let row = FieldElement::from(i as u64);
+        denominators.push(program_fingerprint(&challenges, columns[0][i], columns[1][i], columns[2][i], core, position));
+        denominators.push(challenges.fingerprint(core, position + one, row + one, FieldElement::zero()));
+        denominators.push(challenges.fingerprint(core, position, columns[5][i], FieldElement::zero()));
+    }
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err(TwistAndShoutError::ProofGeneration(
+            "Fingerprint challenge collided with an operation".to_string(),
+        ));
+    }
+    
+    let mut inverses = vec![vec![FieldElement::zero(); columns[0].len()]; 3];
+    let mut batch = crate::utils::field_utils::batch_inverse(&denominators).into_iter();
+    for &i in &rows {
+        for column in inverses.iter_mut() {
+            column[i] = batch.next().expect("Three inverses per row");
+        }
+    }
+    Ok(inverses)
+}
+
+/// Claimed sum of the program-order sum-check: μ(P + μ(F - I + μB)), where P
+/// sums the inverse fingerprints of the cores' tagged operations, F and I
+/// those of each core's final counter (c, n_c, f_c) and initial counter
+/// (c, 0, 0), and B combines the commitments' values at the bridge point.
+/// `None` if a fingerprint is zero
+fn program_order_claim(
+    per_core: &[MemoryTrace],
+    final_rows: &[u64],
+    bridge_evaluations: &[FieldElement],
+    challenges: &MemoryChallenges,
+) -> Option<FieldElement> {
+    let zero = FieldElement::zero();
+    let mut program = Vec::new();
+    let mut counters = zero;
+    for ((core, trace), &final_row) in per_core.iter().enumerate().zip(final_rows) {
+        let core = FieldElement::from(core as u64);
+        let mut length = 0u64;
+        for op in trace.operations.iter().filter(|op| !op.is_padding()) {
+            let (address, op_type) = (FieldElement::from(op.address() as u64), FieldElement::from(op.is_write()));
+            program.push(program_fingerprint(challenges, address, op.value(), op_type, core, FieldElement::from(length)));
+            length += 1;
+        }
+        let initial = challenges.fingerprint(core, zero, zero, zero);
+        let last = challenges.fingerprint(core, FieldElement::from(length), FieldElement::from(final_row), zero);
+        counters += last.inverse()? - initial.inverse()?;
+    }
+    if program.iter().any(|d| d.is_zero()) {
+        return None;
+    }
+    let program: FieldElement = crate::utils::field_utils::batch_inverse(&program).into_iter().sum();
+    
+    let bridge = bridge_evaluations
+        .iter()
+        .rev()
+        .fold(zero, |acc, &evaluation| acc * challenges.alpha + evaluation);
+    Some(challenges.mu * (program + challenges.mu * (counters + challenges.mu * bridge)))
+}
+
+/// Summand of the program-order sum-check at one point.
+///
+/// `public` holds eq(τ, ·), the row index i and the Lagrange weights ℓ_z of
+/// the bridge point. `columns` holds the committed columns in commitment
+/// order: the merged trace's a, v, op, each row's core c, program position k
+/// and previous row p, the bits of i - p, then the inverse fingerprints h_P
+/// of (a, v, op, c, k), h_W of (c, k + 1, i + 1) and h_R of (c, k, p). The
+/// summand is eq·Σₖ λᵏ cₖ + μ(h_P + μ(h_W - h_R + μℓ_z(a + αv + α²op))), where
+/// the constraints cₖ say that the h columns invert their fingerprints on
+/// operation rows and vanish on padding, and that p ≤ i on operation rows.
+///
+/// Each core's next position is then a memory cell that every row of the
+/// core reads at p and writes at i + 1. With p ≤ i, offline memory checking
+/// shows that the core's rows read positions 0, 1, 2, ... in merged order,
+/// and the h_P sum matching the cores' programs shows that the row at
+/// position k is the core's k-th operation.
+fn program_order_summand(public: [FieldElement; 3], columns: &[FieldElement], challenges: &MemoryChallenges) -> FieldElement {
+    let [eq, id, lagrange] = public;
+    let (one, two) = (FieldElement::one(), FieldElement::from(2u64));
+    let Some(bits_end) = columns.len().checked_sub(3).filter(|&end| end >= PROGRAM_ORDER_COLUMNS) else {
+        return FieldElement::zero();
+    };
+    let &[address, value, op, core, position, previous, ..] = columns else {
+        return FieldElement::zero();
+    };
+    let bits = &columns[PROGRAM_ORDER_COLUMNS..bits_end];
+    let &[program_inverse, write_inverse, read_inverse] = &columns[bits_end..] else {
+        return FieldElement::zero();
+    };
+    
+    let distance = bits.iter().rev().fold(FieldElement::zero(), |acc, &bit| acc.double() + bit);
+    let active = two - op * (op - one);
+    let constraints = [
+        two * program_inverse * program_fingerprint(challenges, address, value, op, core, position) - active,
+        two * write_inverse * challenges.fingerprint(core, position + one, id + one, FieldElement::zero()) - active,
+        two * read_inverse * challenges.fingerprint(core, position, previous, FieldElement::zero()) - active,
+        (two - op) * (id - previous - distance),
+    ];
+    let combined = constraints
+        .into_iter()
+        .chain(bits.iter().map(|&bit| bit * (bit - one)))
+        .fold(FieldElement::zero(), |acc, constraint| acc * challenges.lambda + constraint);
+    
+    let bridge = lagrange * (address + challenges.alpha * (value + challenges.alpha * op));
+    eq * combined + challenges.mu * (program_inverse + challenges.mu * (write_inverse - read_inverse + challenges.mu * bridge))
+}
+
+/// Columns of the refinement argument: the trace's address, value and op
+/// type, then the inverse fingerprints of its operations
+const REFINEMENT_COLUMNS: usize = 4;
+
+/// Op type of an operation as committed: 0 for a read, 1 for a write, 2 for
+/// padding
+fn op_type(op: &MemoryOp) -> FieldElement {
+    FieldElement::from(match op {
+        MemoryOp::Read { .. } => 0u64,
+        MemoryOp::Write { .. } => 1,
+        MemoryOp::Padding => 2,
+    })
+}
+
+/// Inverse fingerprints of the refinement argument's (a, v, op) rows, zero
+/// on padding
+fn refinement_fingerprint_column(
+    columns: &[Vec<FieldElement>],
+    beta: FieldElement,
+    gamma: FieldElement,
+) -> Result<Vec<FieldElement>> {
+    let challenges = MemoryChallenges {
+        beta,
+        gamma,
+        alpha: FieldElement::zero(),
+        lambda: FieldElement::zero(),
+        mu: FieldElement::zero(),
+    };
+    let rows: Vec<usize> = (0..columns[0].len()).filter(|&i| columns[2][i] != FieldElement::from(2u64)).collect();
+    let denominators: Vec<FieldElement> = rows
+        .iter()
+        .map(|&i| challenges.fingerprint(columns[0][i], columns[1][i], columns[2][i], FieldElement::zero()))
+        .collect();
+    if denominators.iter().any(|d| d.is_zero()) {
+        return Err(TwistAndShoutError::ProofGeneration(
+            "Fingerprint challenge collided with an operation".to_string(),
+        ));
+    }
+    
+    let mut inverses = vec![FieldElement::zero(); columns[0].len()];
+    for (&i, inverse) in rows.iter().zip(crate::utils::field_utils::batch_inverse(&denominators)) {
+        inverses[i] = inverse;
+    }
+    Ok(inverses)
+}
+
+/// Claimed sum of the refinement sum-check: μ(S + μB), where S sums the
+/// inverse fingerprints of the specification's reads and writes and B
+/// combines the commitments' values at the bridge point. `None` if a
+/// fingerprint is zero
+fn refinement_claim(
+    abstract_ops: &[MemoryOp],
+    bridge_evaluations: &[FieldElement],
+    challenges: &MemoryChallenges,
+) -> Option<FieldElement> {
+    let zero = FieldElement::zero();
+    let fingerprints: Vec<FieldElement> = abstract_ops
+        .iter()
+        .filter(|op| !op.is_padding())
+        .map(|op| challenges.fingerprint(FieldElement::from(op.address() as u64), op.value(), op_type(op), zero))
+        .collect();
+    if fingerprints.iter().any(|d| d.is_zero()) {
+        return None;
+    }
+    let spec: FieldElement = crate::utils::field_utils::batch_inverse(&fingerprints).into_iter().sum();
+    
+    let bridge = bridge_evaluations
+        .iter()
+        .rev()
+        .fold(zero, |acc, &evaluation| acc * challenges.alpha + evaluation);
+    Some(challenges.mu * (spec + challenges.mu * bridge))
+}
+
+/// Summand of the refinement sum-check at one point.
+///
+/// `public` holds eq(τ, ·) and the Lagrange weights ℓ_z of the bridge point.
+/// `columns` holds the committed a, v, op and the inverse fingerprint h of
+/// (a, v, op). The summand is eq·(2h·fp(a, v, op) - active(op)) +
+/// μ(h + μℓ_z(a + αv + α²op)), where active(op) = 2 - op(op - 1) is 2 on
+/// reads and writes and 0 on padding, so h inverts the fingerprint of each
+/// operation and vanishes on padding, and h sums to the specification's
+/// inverse fingerprints exactly when the multisets agree.
+fn refinement_summand(public: [FieldElement; 2], columns: &[FieldElement], challenges: &MemoryChallenges) -> FieldElement {
+    let [eq, lagrange] = public;
+    let (one, two) = (FieldElement::one(), FieldElement::from(2u64));
+    let &[address, value, op, inverse] = columns else {
+        return FieldElement::zero();
+    };
+    
+    let constraint = two * inverse * challenges.fingerprint(address, value, op, FieldElement::zero()) - (two - op * (op - one));
+    let bridge = lagrange * (address + challenges.alpha * (value + challenges.alpha * op));
+    eq * constraint + challenges.mu * (inverse + challenges.mu * bridge)
+}
+
+/// Bit width of operation timestamps in the monotonicity constraint
+const TIMESTAMP_BITS: usize = 64;
+
+/// The low `width` bits of a value, least significant first
+fn little_endian_bits(value: u64, width: usize) -> Vec<bool> {
+    (0..width).map(|i| (value >> i) & 1 == 1).collect()
+}
+
+/// Per-operation address, value, op-type (0 for read, 1 for write, 2 for
+/// padding) and timestamp columns
+#[derive(Debug, Clone, Default)]
+struct OperationColumns {
+    addresses: Vec<FieldElement>,
+    values: Vec<FieldElement>,
+    op_types: Vec<FieldElement>,
+    timestamps: Vec<u64>,
+    /// Rows the consistency constraint skips: padding operations and zero-padding
+    padding: Vec<bool>,
+    /// Public constraints on the operations, keyed by row
+    constraints: TraceConstraints,
+}
+
+impl OperationColumns {
+    /// Build the columns for a list of operations timestamped by position,
+    /// zero-padded to `padded_size`
+    #[cfg(test)]
+    fn from_operations(operations: &[MemoryOp], padded_size: usize) -> Self {
+        let mut columns = Self::default();
+        for (i, op) in operations.iter().enumerate() {
+            columns.push(op, i as u64);
+        }
+        columns.pad(padded_size);
+        columns
+    }
+    
+    /// Build the columns for a trace using its recorded timestamps
+    fn from_trace(trace: &MemoryTrace, padded_size: usize) -> Self {
+        let mut columns = Self::default();
+        for (op, &timestamp) in trace.operations.iter().zip(&trace.timestamps) {
+            columns.push(op, timestamp);
+        }
+        columns.constraints = trace.constraints();
+        columns.pad(padded_size);
+        columns
+    }
+    
+    /// Append one operation
+    fn push(&mut self, op: &MemoryOp, timestamp: u64) {
+        self.addresses.push(FieldElement::from(op.address() as u64));
+        self.values.push(op.value());
+        self.op_types.push(op_type(op));
+        self.timestamps.push(timestamp);
+        self.padding.push(op.is_padding());
+    }
+    
+    /// Columns of the memory-checking argument committed before the
+    /// fingerprint challenges, each extended with padding to `rows` entries;
+    /// see [`memory_check_summand`] for the order.
+    ///
+    /// Replays the operations to find the tuple each one consumes: the value
+    /// its address holds and the timestamp it was left at, where the
+    /// operation in row i leaves its address at timestamp i + 1 and the
+    /// zeroed initial memory sits at timestamp 0, and the number of writes
+    /// its address has seen. Each row's step bits spell
+    /// its trace timestamp's increase over the row before, minus one, and are
+    /// zero where the timestamps do not increase.
+    fn memory_check_columns(&self, memory_size: usize, rows: usize) -> Result<Vec<Vec<FieldElement>>> {
+        let num_bits = poly_utils::log2_exact(rows);
+        let mut columns = vec![vec![FieldElement::zero(); rows]; MEMORY_COLUMNS + num_bits + TIMESTAMP_BITS];
+        columns[2] = vec![FieldElement::from(2u64); rows];
+        let mut state = vec![(FieldElement::zero(), 0u64, 0u64); memory_size];
+        
+        for (i, &padding) in self.padding.iter().enumerate() {
+            if padding {
+                continue;
+            }
+            
+            let address = self.addresses[i].into_bigint().0[0] as usize;
+            let Some(cell) = state.get_mut(address).filter(|_| FieldElement::from(address as u64) == self.addresses[i]) else {
+                return Err(TwistAndShoutError::ProofGeneration(format!(
+                    "Operation {} accesses an address outside memory of size {}",
+                    i, memory_size
+                )));
+            };
+            let (previous_value, previous_timestamp, previous_writes) = *cell;
+            columns[0][i] = self.addresses[i];
+            columns[1][i] = self.values[i];
+            columns[2][i] = self.op_types[i];
+            columns[3][i] = previous_value;
+            columns[4][i] = FieldElement::from(previous_timestamp);
+            columns[7][i] = FieldElement::from(previous_writes);
+            for (b, bit) in little_endian_bits(i as u64 - previous_timestamp, num_bits).into_iter().enumerate() {
+                columns[MEMORY_COLUMNS + b][i] = FieldElement::from(bit);
+            }
+            *cell = (self.values[i], i as u64 + 1, previous_writes + self.op_types[i].is_one() as u64);
+        }
+        
+        for (k, &(value, timestamp, writes)) in state.iter().enumerate() {
+            columns[5][k] = value;
+            columns[6][k] = FieldElement::from(timestamp);
+            columns[8][k] = FieldElement::from(writes);
+        }
+        
+        
+        // Row 0 has no row before it; the univariate timestamp polynomial's
+        // value at -1 stands in, so the column interpolates to Ts(X - 1)
+        let padded_size = self.timestamps.len();
+        let before_first = poly_utils::lagrange_basis_at(padded_size, -FieldElement::one())
+            .into_iter()
+            .zip(&self.timestamps)
+            .map(|(weight, &timestamp)| weight * FieldElement::from(timestamp))
+            .sum();
+        let step_start = MEMORY_COLUMNS + num_bits;
+        for (i, &timestamp) in self.timestamps.iter().enumerate() {
+            columns[9][i] = FieldElement::from(timestamp);
+            columns[10][i] = match i {
+                0 => before_first,
+                _ => FieldElement::from(self.timestamps[i - 1]),
+            };
+            let step = i
+                .checked_sub(1)
+                .and_then(|last| timestamp.checked_sub(self.timestamps[last]))
+                .and_then(|increase| increase.checked_sub(1));
+            for (b, bit) in little_endian_bits(step.unwrap_or(0), TIMESTAMP_BITS).into_iter().enumerate() {
+                columns[step_start + b][i] = FieldElement::from(bit);
+            }
+        }
+        
+        // Running products ∏_{l ≤ j}(v - A_l) against the public read-set
+        // tables, which are zero on unconstrained rows
+        if let Some((_, allowed_tables)) = self.constraints.read_set_columns(rows).split_first() {
+            let mut products = vec![FieldElement::one(); rows];
+            for allowed in allowed_tables {
+                for (i, product) in products.iter_mut().enumerate() {
+                    *product *= columns[1][i] - allowed[i];
+                }
+                columns.push(products.clone());
+            }
+        }
+        
+        // Bits of each sized value up to the widest width, then the packed
+        // values, which the summand ties to the value column
+        let width_size = self.constraints.width_size();
+        if width_size > 0 {
+            let mut bit_columns = vec![vec![FieldElement::zero(); rows]; width_size];
+            for &i in self.constraints.widths.keys() {
+                let value = self.values[i].into_bigint().0[0];
+                for (column, bit) in bit_columns.iter_mut().zip(little_endian_bits(value, width_size)) {
+                    column[i] = FieldElement::from(bit);
+                }
+            }
+            let mut packed = pack_values(&self.values, &self.constraints.widths);
+            packed.resize(rows, FieldElement::zero());
+            columns.extend(bit_columns);
+            columns.push(packed);
+        }
+        
+        Ok(columns)
+    }
+    
+    /// Read-set constraint rows: ∏_j (v_i - allowed_j) for each constrained
+    /// read, zero elsewhere
+    fn read_set_rows(&self) -> Vec<FieldElement> {
+        let mut rows = vec![FieldElement::zero(); self.addresses.len()];
+        for (&i, allowed) in &self.constraints.read_sets {
+            rows[i] = allowed.iter().map(|&a| self.values[i] - a).product();
+        }
+        rows
+    }
+    
+    /// Range constraint rows: v_i minus its low `bits_i` bits for each sized
+    /// operation, zero elsewhere; a row is zero exactly when v_i < 2^bits_i
+    fn range_rows(&self) -> Vec<FieldElement> {
+        let mut rows = vec![FieldElement::zero(); self.addresses.len()];
+        for (&i, &bits) in &self.constraints.widths {
+            let mask = if bits == 64 { u64::MAX } else { (1u64 << bits) - 1 };
+            let low_bits = self.values[i].into_bigint().0[0] & mask;
+            rows[i] = self.values[i] - FieldElement::from(low_bits);
+        }
+        rows
+    }
+    
+    /// Immutable-range constraint rows: op_i · [a_i ∈ R] over the immutable
+    /// ranges R, so a row is non-zero exactly when it writes into one of them
+    fn immutable_rows(&self) -> Vec<FieldElement> {
+        let mut rows = vec![FieldElement::zero(); self.addresses.len()];
+        if self.constraints.immutable_ranges.is_empty() {
+            return rows;
+        }
+        
+        for (i, row) in rows.iter_mut().enumerate() {
+            if self.padding[i] || !self.op_types[i].is_one() {
+                continue;
+            }
+            let address = self.addresses[i].into_bigint().0[0] as usize;
+            if self.constraints.immutable_ranges.iter().any(|range| range.contains(&address)) {
+                *row = self.op_types[i];
+            }
+        }
+        rows
+    }
+    
+    /// Zero-pad every column to `padded_size`
+    fn pad(&mut self, padded_size: usize) {
+        self.addresses.resize(padded_size, FieldElement::zero());
+        self.values.resize(padded_size, FieldElement::zero());
+        self.op_types.resize(padded_size, FieldElement::from(2u64));
+        self.timestamps.resize(padded_size, 0);
+        self.padding.resize(padded_size, true);
     }
 }
 
@@ -320,6 +4761,462 @@ mod tests {
     use super::*;
     use crate::utils::setup_params;
     
+    #[test]
+    fn test_openings_at_bridge_point() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let twist = Twist::new(&prover_params);
+        
+        let mut trace = MemoryTrace::new(8);
+        trace.write(2, FieldElement::from(11u64)).unwrap();
+        trace.read(2).unwrap();
+        trace.write(7, FieldElement::from(4u64)).unwrap();
+        trace.read(7).unwrap();
+        let proof = twist.prove(&trace).unwrap();
+        
+        // Replay the verifier up to the bridge point
+        let mut transcript = Transcript::new(&verifier_params.fiat_shamir_seed);
+        Twist::<KZGCommitment>::absorb_public_inputs(
+            &mut transcript,
+            proof.num_operations,
+            proof.memory_size,
+            &proof.constraints,
+            [&proof.address_commitment, &proof.value_commitment, &proof.op_commitment, &proof.timestamp_commitment],
+        );
+        let (commitments, fingerprint_start) = (&proof.consistency_opening.commitments, MEMORY_COLUMNS + 3 + TIMESTAMP_BITS);
+        Twist::<KZGCommitment>::absorb_memory_columns(&mut transcript, &commitments[..fingerprint_start]);
+        let point = Twist::<KZGCommitment>::absorb_fingerprint_columns(&mut transcript, &commitments[fingerprint_start..]);
+        
+        // The four columns are opened there, the timestamps again one row
+        // back, and nowhere else
+        let vk = KZGCommitment::verifier_key(&verifier_params);
+        let columns = [&proof.address_commitment, &proof.value_commitment, &proof.op_commitment, &proof.timestamp_commitment];
+        let points = [point, point, point, point, point - FieldElement::one()];
+        for (i, (&commitment, point)) in columns.iter().chain([&columns[3]]).zip(points).enumerate() {
+            let (value, opening) = (proof.final_evaluations[i], &proof.opening_proofs[i]);
+            assert!(KZGCommitment::verify(&vk, commitment, point, value, opening).unwrap());
+            assert!(!KZGCommitment::verify(&vk, commitment, point + FieldElement::one(), value, opening).unwrap());
+        }
+    }
+    
+    /// Follow [`Twist::prove_columns`] for `operations`, but let `tamper` edit
+    /// the memory-checking columns before they are committed and run the
+    /// consistency rounds on a constant that sums to the claim, as a prover
+    /// whose columns do not satisfy the constraints must
+    fn forge_proof(
+        twist: &Twist,
+        operations: &[MemoryOp],
+        memory_size: usize,
+        tamper: impl Fn(&mut Vec<Vec<FieldElement>>),
+    ) -> TwistProof {
+        let padded_size = operations.len().next_power_of_two();
+        let rows = padded_size.max(memory_size);
+        let num_vars = poly_utils::log2_exact(rows);
+        let columns = OperationColumns::from_operations(operations, padded_size);
+        let mut memory_columns = columns.memory_check_columns(memory_size, rows).unwrap();
+        tamper(&mut memory_columns);
+        
+        let timestamps: Vec<FieldElement> = columns.timestamps.iter().map(|&t| FieldElement::from(t)).collect();
+        let polynomials: Vec<_> = [&columns.addresses, &columns.values, &columns.op_types, &timestamps]
+            .into_iter()
+            .map(|column| twist.vector_to_polynomial(column).unwrap())
+            .collect();
+        let commitments: Vec<_> = polynomials
+            .iter()
+            .map(|polynomial| KZGCommitment::commit(&twist.commitment_key(), polynomial).unwrap())
+            .collect();
+        let mut transcript = Transcript::new(&twist.prover_params.fiat_shamir_seed);
+        Twist::<KZGCommitment>::absorb_public_inputs(
+            &mut transcript,
+            operations.len(),
+            memory_size,
+            &columns.constraints,
+            [&commitments[0], &commitments[1], &commitments[2], &commitments[3]],
+        );
+        
+        let column_params = MultilinearKZG::derive_params(&twist.prover_params.commitment_params, num_vars).unwrap();
+        let slices: Vec<&[FieldElement]> = memory_columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let (beta, gamma) = Twist::<KZGCommitment>::absorb_memory_columns(&mut transcript, &column_commitments);
+        
+        let fingerprints = memory_fingerprint_columns(&memory_columns, memory_size, beta, gamma).unwrap();
+        let slices: Vec<&[FieldElement]> = fingerprints.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let bridge_point = Twist::<KZGCommitment>::absorb_fingerprint_columns(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        memory_columns.extend(fingerprints);
+        
+        let (final_evaluations, opening_proofs): (Vec<_>, Vec<_>) = bridge_openings(&polynomials, bridge_point)
+            .map(|(polynomial, point)| KZGCommitment::open(&twist.commitment_key(), polynomial, point).unwrap())
+            .unzip();
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Twist::<KZGCommitment>::absorb_bridge_evaluations(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claim = memory_check_claim(initial_memory_sum(gamma, memory_size).unwrap(), &final_evaluations, &challenges);
+        
+        let constant = claim * FieldElement::from(rows as u64).inverse().unwrap();
+        let (consistency_proof, point) = SumCheck::new(num_vars, claim)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .prove_with_challenges(|_: &[FieldElement]| constant, &mut transcript)
+            .unwrap();
+        let slices: Vec<&[FieldElement]> = memory_columns.iter().map(|column| &column[..]).collect();
+        let consistency_opening = ColumnOpening::open(&column_params, column_commitments, &slices, &point, &mut transcript).unwrap();
+        
+        let mut commitments = commitments.into_iter();
+        TwistProof {
+            params: twist.prover_params.proof_params(),
+            num_operations: operations.len(),
+            memory_size,
+            constraints: TraceConstraints::default(),
+            address_commitment: commitments.next().unwrap(),
+            value_commitment: commitments.next().unwrap(),
+            op_commitment: commitments.next().unwrap(),
+            timestamp_commitment: commitments.next().unwrap(),
+            consistency_proof,
+            consistency_opening,
+            opening_proofs,
+            final_evaluations,
+            range_proof: None,
+        }
+    }
+    
+    #[test]
+    fn test_rejects_sumcheck_unbacked_by_column_openings() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let twist = Twist::new(&prover_params);
+        let ten = FieldElement::from(10u64);
+        let forged_value = FieldElement::from(999u64);
+        
+        // Reading 999 back after writing 10 breaks the read constraint or, if
+        // the read claims 999 was stored, the fingerprint balance
+        let operations = [
+            MemoryOp::Write { address: 0, value: ten },
+            MemoryOp::Read { address: 0, value: forged_value },
+        ];
+        let columns = OperationColumns::from_operations(&operations, 2);
+        assert!(twist.prove_columns(2, 4, columns, &mut Transcript::new(&prover_params.fiat_shamir_seed)).is_err());
+        
+        let mut scratch = vec![FieldElement::zero(); 64];
+        for claims_stored in [false, true] {
+            let forged = forge_proof(&twist, &operations, 4, |columns| {
+                if claims_stored {
+                    columns[3][1] = forged_value;
+                }
+            });
+            
+            // Every round checks out, but the opened columns do not give the
+            // final evaluation
+            let report = twist.verify_verbose(&forged, &verifier_params).unwrap();
+            assert!(report.check("consistency_sumcheck").unwrap().passed);
+            assert!(!report.check("consistency_binding").unwrap().passed);
+            assert!(!report.is_valid());
+            assert!(!twist.verify_no_alloc(&forged, &verifier_params, &mut scratch).unwrap());
+        }
+        
+        // The same forgery over honest columns only fails because the rounds
+        // are not those of the real summand
+        let honest_ops = [
+            MemoryOp::Write { address: 0, value: ten },
+            MemoryOp::Read { address: 0, value: ten },
+        ];
+        let trace = MemoryTrace::from_operations(4, honest_ops.to_vec()).unwrap();
+        assert!(twist.verify(&twist.prove(&trace).unwrap(), &verifier_params).unwrap());
+        assert!(!twist.verify(&forge_proof(&twist, &honest_ops, 4, |_| {}), &verifier_params).unwrap());
+    }
+    
+    /// Follow the interleaved prover on `columns`, but run the rounds on a
+    /// constant that sums to the claim: every round and opening checks out,
+    /// but the opened columns do not give the final evaluation
+    fn forge_view_proof(
+        twist: &Twist,
+        mut columns: Vec<Vec<FieldElement>>,
+        num_operations: usize,
+        memory_size: usize,
+        check_reads: bool,
+    ) -> InterleavedTraceProof {
+        let rows = columns[0].len();
+        let num_vars = poly_utils::log2_exact(rows);
+        let polynomials: Vec<_> = [0, 1, 2, 3, 4, 6]
+            .into_iter()
+            .map(|column| twist.vector_to_polynomial(&columns[column]).unwrap())
+            .collect();
+        let commitments: Vec<_> = polynomials
+            .iter()
+            .map(|polynomial| KZGCommitment::commit(&twist.commitment_key(), polynomial).unwrap())
+            .collect();
+        let mut transcript = Transcript::new(&twist.prover_params.fiat_shamir_seed);
+        Twist::<KZGCommitment>::absorb_interleaved_commitments(&mut transcript, check_reads, num_operations, memory_size, &commitments);
+        
+        let column_params = MultilinearKZG::derive_params(&twist.prover_params.commitment_params, num_vars).unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let (beta, gamma) = Twist::<KZGCommitment>::absorb_memory_columns(&mut transcript, &column_commitments);
+        let fingerprints = interleaved_fingerprint_columns(&columns, beta, gamma).unwrap();
+        let slices: Vec<&[FieldElement]> = fingerprints.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let bridge_point = Twist::<KZGCommitment>::absorb_fingerprint_columns(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        columns.extend(fingerprints);
+        
+        let (final_evaluations, opening_proofs): (Vec<_>, Vec<_>) = interleaved_openings(&polynomials, bridge_point)
+            .map(|(polynomial, point)| KZGCommitment::open(&twist.commitment_key(), polynomial, point).unwrap())
+            .unzip();
+        let mut tau = vec![FieldElement::zero(); num_vars];
+        let challenges = Twist::<KZGCommitment>::absorb_bridge_evaluations(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claim = memory_check_claim(FieldElement::zero(), &final_evaluations, &challenges);
+        let constant = claim * FieldElement::from(rows as u64).inverse().unwrap();
+        let (consistency_proof, point) = SumCheck::new(num_vars, claim)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .prove_with_challenges(|_: &[FieldElement]| constant, &mut transcript)
+            .unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let consistency_opening = ColumnOpening::open(&column_params, column_commitments, &slices, &point, &mut transcript).unwrap();
+        
+        let mut commitments = commitments.into_iter();
+        let mut next = || commitments.next().unwrap();
+        InterleavedTraceProof {
+            num_operations,
+            memory_size,
+            address_commitment: next(),
+            value_commitment: next(),
+            op_commitment: next(),
+            sorted_address_commitment: next(),
+            sorted_value_commitment: next(),
+            sorted_row_commitment: next(),
+            consistency_proof,
+            consistency_opening,
+            opening_proofs,
+            final_evaluations,
+        }
+    }
+    
+    #[test]
+    fn test_rejects_forged_interleaved_proof() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let twist = Twist::new(&prover_params);
+        
+        // The first access to address 3 reads a value only written later
+        let mut trace = MemoryTrace::new(4);
+        trace.write(1, FieldElement::from(10u64)).unwrap();
+        trace.read(3).unwrap();
+        trace.write(3, FieldElement::from(30u64)).unwrap();
+        trace.operations[1] = MemoryOp::Read { address: 3, value: FieldElement::from(30u64) };
+        assert!(twist.prove_interleaved(&trace).is_err());
+        
+        let original = OperationColumns::from_trace(&trace, 4);
+        let forged = forge_view_proof(&twist, interleaved_columns(&original, 4).unwrap(), 3, 4, true);
+        assert!(!twist.verify_interleaved(&forged, &verifier_params).unwrap());
+    }
+    
+    #[test]
+    fn test_rejects_forged_sorted_view() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let twist = Twist::new(&prover_params);
+        let mut trace = MemoryTrace::new(4);
+        trace.write(1, FieldElement::from(10u64)).unwrap();
+        trace.write(2, FieldElement::from(20u64)).unwrap();
+        trace.write(1, FieldElement::from(11u64)).unwrap();
+        
+        // A view listing the later write to address 1 first is sorted by
+        // address but not by row within it
+        let mut view = trace.sorted_view();
+        view.swap(0, 1);
+        assert!(twist.prove_sorted(&trace, &view).is_err());
+        
+        let original = OperationColumns::from_trace(&trace, 4);
+        let mut columns = sorted_view_columns(&original, &[0, 2, 1], 4).unwrap();
+        columns[4].swap(0, 1);
+        columns[6].swap(0, 1);
+        let forged = forge_view_proof(&twist, columns, 3, 4, false);
+        assert!(!twist.verify_sorted(&forged, &verifier_params).unwrap());
+    }
+    
+    #[test]
+    fn test_rejects_merged_trace_out_of_program_order() {
+        let (prover_params, verifier_params) = setup_params(2);
+        let twist = Twist::new(&prover_params);
+        let (zero, one) = (FieldElement::zero(), FieldElement::one());
+        
+        // Store buffering: each core sets its flag, then reads the other's as
+        // unset. Running both reads first is memory-consistent but breaks
+        // each core's program order
+        let cores: Vec<MemoryTrace> = [(0, 1), (1, 0)]
+            .into_iter()
+            .map(|(own, other)| {
+                let operations = vec![
+                    MemoryOp::Write { address: own, value: one },
+                    MemoryOp::Read { address: other, value: zero },
+                ];
+                MemoryTrace::from_operations(2, operations).unwrap()
+            })
+            .collect();
+        assert!(twist.prove_sequential_consistency(&cores).is_err());
+        
+        let mut merged = MemoryTrace::new(2);
+        merged.read(1).unwrap();
+        merged.read(0).unwrap();
+        merged.write(0, one).unwrap();
+        merged.write(1, one).unwrap();
+        let trace_proof = twist.prove(&merged).unwrap();
+        let operations = OperationColumns::from_trace(&merged, 4);
+        let (mut columns, final_rows) = program_order_columns(&operations, &[(0, 1), (1, 1), (0, 0), (1, 0)], 2);
+        let polynomials: Vec<_> = [&operations.addresses, &operations.values, &operations.op_types]
+            .into_iter()
+            .map(|column| twist.vector_to_polynomial(column).unwrap())
+            .collect();
+        
+        // Follow the prover, but run the rounds on a constant that sums to the claim
+        let mut transcript = Transcript::new(&prover_params.fiat_shamir_seed);
+        Twist::<KZGCommitment>::absorb_program_order_statement(&mut transcript, &trace_proof, &cores, &final_rows);
+        let column_params = MultilinearKZG::derive_params(&prover_params.commitment_params, 2).unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let mut column_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let (beta, gamma) = Twist::<KZGCommitment>::absorb_memory_columns(&mut transcript, &column_commitments);
+        let fingerprints = program_order_fingerprint_columns(&columns, beta, gamma).unwrap();
+        let slices: Vec<&[FieldElement]> = fingerprints.iter().map(|column| &column[..]).collect();
+        let fingerprint_commitments = ColumnOpening::commit(&column_params, &slices).unwrap();
+        let bridge_point = Twist::<KZGCommitment>::absorb_fingerprint_columns(&mut transcript, &fingerprint_commitments);
+        column_commitments.extend(fingerprint_commitments);
+        columns.extend(fingerprints);
+        
+        let (final_evaluations, opening_proofs): (Vec<_>, Vec<_>) = polynomials
+            .iter()
+            .map(|polynomial| KZGCommitment::open(&twist.commitment_key(), polynomial, bridge_point).unwrap())
+            .unzip();
+        let mut tau = vec![FieldElement::zero(); 2];
+        let challenges = Twist::<KZGCommitment>::absorb_bridge_evaluations(&mut transcript, &final_evaluations, &mut tau, beta, gamma);
+        let claim = program_order_claim(&cores, &final_rows, &final_evaluations, &challenges).unwrap();
+        let constant = claim * FieldElement::from(4u64).inverse().unwrap();
+        let (order_proof, point) = SumCheck::new(2, claim)
+            .with_max_degree(MEMORY_CHECK_DEGREE)
+            .prove_with_challenges(|_: &[FieldElement]| constant, &mut transcript)
+            .unwrap();
+        let slices: Vec<&[FieldElement]> = columns.iter().map(|column| &column[..]).collect();
+        let order_opening = ColumnOpening::open(&column_params, column_commitments, &slices, &point, &mut transcript).unwrap();
+        
+        // The merged trace and every round check out, but the opened columns
+        // do not give the final evaluation
+        let forged = SequentialConsistencyProof::<KZGCommitment> {
+            trace_proof,
+            final_rows,
+            order_proof,
+            order_opening,
+            opening_proofs,
+            final_evaluations,
+        };
+        assert!(twist.verify(&forged.trace_proof, &verifier_params).unwrap());
+        assert!(!twist.verify_sequential_consistency(&forged, &cores, &verifier_params).unwrap());
+    }
+    
+    #[test]
+    fn test_state_equality_rejects_appended_writes() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let twist = Twist::new(&prover_params);
+        let table = LookupTable::new([7u64, 8].iter().map(|&v| FieldElement::from(v)).collect());
+        
+        // The final rows write the table's entries instead of reading memory,
+        // so they match the table whatever the trace left behind
+        let mut trace = MemoryTrace::new(2);
+        trace.write(0, FieldElement::from(5u64)).unwrap();
+        trace.read(1).unwrap();
+        assert!(twist.prove_state_equals_table(&trace, &table).is_err());
+        let offset = trace.operations.len();
+        for (address, &entry) in table.entries.iter().enumerate() {
+            trace.write(address, entry).unwrap();
+        }
+        let twist_proof = twist.prove(&trace).unwrap();
+        let shout_proof = Shout::new(&prover_params).prove(&table).unwrap();
+        
+        // Divide out V(X + offset) - T(X) + β(A(X + offset) - X), which
+        // ignores the op types
+        let columns = OperationColumns::from_trace(&trace, 4);
+        let shift = FieldElement::from(offset as u64);
+        let polynomials: Vec<_> = [&columns.values, &columns.addresses, &columns.op_types, &table.entries]
+            .into_iter()
+            .map(|column| twist.vector_to_polynomial(column).unwrap())
+            .collect();
+        let mut transcript = Transcript::new(&prover_params.fiat_shamir_seed);
+        let beta = Twist::<KZGCommitment>::absorb_state_equality(&mut transcript, &twist_proof, &shout_proof, offset, 2);
+        let shifted_values = poly_utils::shift_polynomial(&polynomials[0], shift);
+        let shifted_addresses = poly_utils::shift_polynomial(&polynomials[1], shift);
+        let mut numerator: Vec<FieldElement> = shifted_values.iter().zip(&shifted_addresses).map(|(&v, &a)| v + beta * a).collect();
+        for (coeff, &t) in numerator.iter_mut().zip(&polynomials[3]) {
+            *coeff -= t;
+        }
+        numerator[1] -= beta;
+        let quotient = crate::commitments::polynomial_division(&numerator, &poly_utils::vanishing_polynomial(2)).unwrap();
+        
+        let quotient_commitment = KZGCommitment::commit(&twist.commitment_key(), &quotient).unwrap();
+        transcript.append_field_element(b"equality_quotient", &KZGCommitment::transcript_hash(&quotient_commitment));
+        let point = transcript.challenge_field_element(b"equality_point");
+        let (evaluations, opening_proofs): (Vec<_>, Vec<_>) = [
+            (&polynomials[0], point + shift),
+            (&polynomials[1], point + shift),
+            (&polynomials[2], point + shift),
+            (&polynomials[3], point),
+            (&quotient, point),
+        ]
+        .into_iter()
+        .map(|(polynomial, at)| KZGCommitment::open(&twist.commitment_key(), polynomial, at).unwrap())
+        .unzip();
+        
+        // Every opening checks out, but the final rows' op types are writes
+        let forged = EqualityProof { offset, state_size: 2, quotient_commitment, opening_proofs, evaluations };
+        assert!(!twist.verify_state_equals_table(&twist_proof, &shout_proof, &forged, &verifier_params).unwrap());
+    }
+    
+    #[test]
+    fn test_packing_term_binds_packed_values() {
+        let mut trace = MemoryTrace::new(8);
+        trace.write_sized(0, FieldElement::from(0xabu64), 8).unwrap();
+        trace.write_sized(1, FieldElement::from(0xdead_beefu64), 32).unwrap();
+        trace.read(0).unwrap();
+        let columns = OperationColumns::from_trace(&trace, 4);
+        let memory_columns = columns.memory_check_columns(8, 8).unwrap();
+        let packed = memory_columns.last().unwrap();
+        assert_eq!(packed[..1], trace.packed_values()[..]);
+        
+        // The verifier's table values match the prover's tables
+        let beta = FieldElement::from(7u64);
+        let tables = columns.constraints.width_table_columns(8, beta);
+        let point = [FieldElement::from(3u64), FieldElement::from(5u64), FieldElement::from(11u64)];
+        let mut values = vec![FieldElement::zero(); columns.constraints.width_tables()];
+        columns.constraints.width_values(&point, beta, &mut values);
+        for (table, &value) in tables.iter().zip(&values) {
+            assert_eq!(MultilinearExtension::from_evaluations_vec(3, table.clone()).evaluate(&point), value);
+        }
+        
+        // The packing term sums to zero only over the honest packed values
+        let (value_weights, packed_weights) = (&tables[33], &tables[34]);
+        let packing_sum = |packed: &[FieldElement]| -> FieldElement {
+            (0..8).map(|i| value_weights[i] * memory_columns[1][i] - packed_weights[i] * packed[i]).sum()
+        };
+        assert!(packing_sum(packed).is_zero());
+        let mut forged = packed.clone();
+        forged[0] += FieldElement::one();
+        assert!(!packing_sum(&forged).is_zero());
+    }
+    
+    #[test]
+    fn test_fiat_shamir_self_check() {
+        let (prover_params, verifier_params) = setup_params(3);
+        let twist = Twist::new(&prover_params);
+        
+        let mut trace = MemoryTrace::new(8);
+        trace.write(1, FieldElement::from(6u64)).unwrap();
+        trace.read(1).unwrap();
+        trace.write(3, FieldElement::from(2u64)).unwrap();
+        twist.fiat_shamir_self_check(&trace, &verifier_params).unwrap();
+        
+        // A verifier that ignores the value commitment is caught
+        let proof = twist.prove(&trace).unwrap();
+        let skip_value = |changed: &TwistProof| {
+            let mut replayed = changed.clone();
+            replayed.value_commitment = proof.value_commitment.clone();
+            Twist::fingerprint_challenges(&replayed, &verifier_params)
+        };
+        let error = twist.check_public_inputs_bound(&proof, skip_value).unwrap_err();
+        assert!(error.to_string().contains("value_commitment"));
+    }
+    
     #[test]
     fn test_memory_trace() {
         let mut trace = MemoryTrace::new(8);
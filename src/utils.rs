@@ -4,11 +4,13 @@
 //! including field arithmetic, random number generation, and parameter setup.
 
 use ark_ff::{Field, PrimeField, Zero, One};
-use ark_bn254::{Fr as Bn254Fr, G1Projective, G2Projective};
-use ark_ec::{CurveGroup, Group};
+use ark_bn254::{Bn254, Fr as Bn254Fr, G1Projective, G2Projective};
+use ark_ec::{CurveGroup, Group, pairing::Pairing, scalar_mul::wnaf::WnafContext};
 use ark_std::{rand::RngCore, UniformRand, rand::SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Serialize};
+use crate::{Result, TwistAndShoutError};
+use ark_std::{format, string::{String, ToString}, vec, vec::Vec};
 
 /// The field element type used throughout the library
 pub type FieldElement = Bn254Fr;
@@ -31,6 +33,91 @@ pub struct ProverParams {
     
     /// Random oracle parameters
     pub fiat_shamir_seed: [u8; 32],
+    
+    /// Fingerprint of the matching verification key and seed
+    pub vk_hash: FieldElement,
+}
+
+impl ProverParams {
+    /// Fingerprint of these parameters, embedded in every proof
+    pub fn proof_params(&self) -> ProofParams {
+        ProofParams {
+            log_size: self.log_size,
+            max_operations: self.max_operations,
+            vk_hash: self.vk_hash,
+        }
+    }
+    
+    /// Copy of these parameters keeping only the SRS powers `[τ^0]₁..[τ^max_degree]₁`.
+    ///
+    /// A prover that only handles small traces can ship the trimmed key: it
+    /// commits to polynomials of degree at most `max_degree` and its proofs
+    /// verify against the original [`VerifierParams`].
+    pub fn trim(&self, max_degree: usize) -> Result<ProverParams> {
+        let available = self.commitment_params.g1_powers.len();
+        if max_degree < 1 || max_degree >= available {
+            return Err(TwistAndShoutError::InvalidParameters(format!(
+                "Cannot trim an SRS of {} powers to degree {}",
+                available, max_degree
+            )));
+        }
+        
+        // Keep the largest multilinear basis that still fits in the trimmed size
+        let mle_vars = (max_degree + 1).ilog2() as usize;
+        let mut trimmed = self.clone();
+        trimmed.commitment_params.g1_powers.truncate(max_degree + 1);
+        if mle_vars < self.commitment_params.mle_vars() {
+            trimmed.commitment_params.mle_basis = crate::MultilinearKZG::restrict_basis(&self.commitment_params.mle_basis, mle_vars);
+        }
+        Ok(trimmed)
+    }
+    
+    /// Write the parameters in uncompressed canonical form.
+    ///
+    /// The setup secret `tau` is never written; parameters read back always
+    /// have `commitment_params.tau == None`.
+    pub fn write<W: ark_std::io::Write>(&self, mut writer: W) -> Result<()> {
+        use ark_serialize::CanonicalSerialize;
+        
+        self.log_size.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.max_operations.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.fiat_shamir_seed.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.vk_hash.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_params.g2_generator.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_params.g1_powers.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_params.mle_basis.serialize_uncompressed(&mut writer).map_err(serialization_error)
+    }
+    
+    /// Read parameters written by [`ProverParams::write`]
+    pub fn read<R: ark_std::io::Read>(mut reader: R) -> Result<Self> {
+        use ark_serialize::CanonicalDeserialize;
+        
+        let log_size = usize::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let max_operations = usize::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let fiat_shamir_seed = <[u8; 32]>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let vk_hash = FieldElement::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let g2_generator = G2Element::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let g1_powers = Vec::<G1Element>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let mle_basis = Vec::<G1Element>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        if g1_powers.len() < 2 {
+            return Err(TwistAndShoutError::Serialization(
+                "Prover parameters need at least two powers of tau".to_string(),
+            ));
+        }
+        if !mle_basis.len().is_power_of_two() {
+            return Err(TwistAndShoutError::Serialization(
+                "Multilinear basis must cover a whole hypercube".to_string(),
+            ));
+        }
+        
+        Ok(Self {
+            log_size,
+            max_operations,
+            commitment_params: CommitmentParams { g1_powers, g2_generator, mle_basis, tau: None },
+            fiat_shamir_seed,
+            vk_hash,
+        })
+    }
 }
 
 /// Parameters for the verifier
@@ -58,7 +145,14 @@ pub struct CommitmentParams {
     /// G2 generator
     pub g2_generator: G2Element,
     
-    /// Trusted setup tau (kept for transparency, not used in production)
+    /// `[eq(τ', x)]₁` for every x in the hypercube of log2(max_degree)
+    /// variables, under multilinear secrets τ' drawn independently of `tau`
+    /// (see [`crate::MultilinearKZG::derive_params`])
+    pub mle_basis: Vec<G1Element>,
+    
+    /// Trusted setup secret, present only in test setups from
+    /// [`setup_params_with_tau`]. No commitment code reads it; see
+    /// [`CommitmentParams::strip_secret`].
     pub tau: Option<FieldElement>,
 }
 
@@ -73,15 +167,591 @@ pub struct CommitmentVerificationKey {
     
     /// G2 element [tau]_2 for pairing checks
     pub g2_tau: G2Element,
+    
+    /// Largest polynomial degree supported by the SRS this key was derived from
+    pub max_degree: usize,
+    
+    /// [τ'_j]_2 for each variable of the multilinear basis in
+    /// [`CommitmentParams::mle_basis`] (see [`crate::MultilinearKZG::derive_verifier_key`])
+    pub g2_mle_taus: Vec<G2Element>,
+}
+
+/// Byte layout used by `serialize_with_layout` on [`crate::TwistProof`] and
+/// [`crate::ShoutProof`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProofLayout {
+    /// Components in declaration order, identical to the compressed
+    /// `CanonicalSerialize` encoding
+    #[default]
+    Component,
+    
+    /// Three contiguous sections, so a recursive verifier circuit can read
+    /// every scalar before any group element:
+    ///
+    /// 1. a header: a length-prefixed list of `u64` counts and presence flags
+    ///    fixing the shape of the proof
+    /// 2. every field element, compressed, with no length prefixes
+    /// 3. every commitment and opening proof, compressed, with no length prefixes
+    ///
+    /// Each proof type documents the order of the entries within each section.
+    Recursive,
+}
+
+/// Next entry of a [`ProofLayout::Recursive`] header
+pub(crate) fn next_header_entry(
+    header: &mut impl Iterator<Item = u64>,
+) -> ark_std::result::Result<usize, ark_serialize::SerializationError> {
+    header
+        .next()
+        .and_then(|entry| usize::try_from(entry).ok())
+        .ok_or(ark_serialize::SerializationError::InvalidData)
+}
+
+/// Fingerprint of the parameters a proof was generated under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProofParams {
+    /// Log of memory size
+    pub log_size: usize,
+    
+    /// Maximum number of memory operations
+    pub max_operations: usize,
+    
+    /// Hash of the verification key and Fiat-Shamir seed
+    pub vk_hash: FieldElement,
+}
+
+impl ProofParams {
+    /// Size in bytes of the compressed canonical serialization
+    pub fn serialized_size(&self) -> usize {
+        use ark_serialize::CanonicalSerialize;
+        self.log_size.compressed_size() + self.max_operations.compressed_size() + self.vk_hash.compressed_size()
+    }
+}
+
+impl ark_serialize::Valid for ProofParams {
+    fn check(&self) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.vk_hash.check()
+    }
+}
+
+impl ark_serialize::CanonicalSerialize for ProofParams {
+    fn serialize_with_mode<W: ark_serialize::Write>(
+        &self,
+        mut writer: W,
+        compress: ark_serialize::Compress,
+    ) -> ark_std::result::Result<(), ark_serialize::SerializationError> {
+        self.log_size.serialize_with_mode(&mut writer, compress)?;
+        self.max_operations.serialize_with_mode(&mut writer, compress)?;
+        self.vk_hash.serialize_with_mode(&mut writer, compress)
+    }
+    
+    fn serialized_size(&self, compress: ark_serialize::Compress) -> usize {
+        self.log_size.serialized_size(compress)
+            + self.max_operations.serialized_size(compress)
+            + self.vk_hash.serialized_size(compress)
+    }
+}
+
+impl ark_serialize::CanonicalDeserialize for ProofParams {
+    fn deserialize_with_mode<R: ark_serialize::Read>(
+        mut reader: R,
+        compress: ark_serialize::Compress,
+        validate: ark_serialize::Validate,
+    ) -> ark_std::result::Result<Self, ark_serialize::SerializationError> {
+        let log_size = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let max_operations = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let vk_hash = FieldElement::deserialize_with_mode(&mut reader, compress, validate)?;
+        Ok(Self { log_size, max_operations, vk_hash })
+    }
+}
+
+/// Serialized proof size in bytes, split by component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProofSizeBreakdown {
+    /// Parameter fingerprint and public inputs
+    pub public_inputs: usize,
+    
+    /// Polynomial commitments
+    pub commitments: usize,
+    
+    /// Sum-check round polynomials and final evaluations
+    pub sumcheck: usize,
+    
+    /// Opening proofs and the evaluations they open to
+    pub openings: usize,
+}
+
+impl ProofSizeBreakdown {
+    /// Total proof size in bytes
+    pub fn total(&self) -> usize {
+        self.public_inputs + self.commitments + self.sumcheck + self.openings
+    }
+}
+
+/// Outcome of a single verifier check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyCheck {
+    /// Short identifier of the check, e.g. `"address_opening"`
+    pub name: &'static str,
+    
+    /// Whether the check passed
+    pub passed: bool,
+    
+    /// Human-readable description of what was checked
+    pub message: String,
+}
+
+/// Check-by-check account of a verification run
+///
+/// Checks that depend on the transcript state are only run while every
+/// earlier transcript-affecting check has passed, so the first failure in
+/// `checks` is the root cause.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// Checks in the order the verifier ran them
+    pub checks: Vec<VerifyCheck>,
+}
+
+impl VerifyReport {
+    /// Record a check and return whether it passed
+    pub(crate) fn record(&mut self, name: &'static str, passed: bool, message: impl Into<String>) -> bool {
+        self.checks.push(VerifyCheck {
+            name,
+            passed,
+            message: message.into(),
+        });
+        passed
+    }
+    
+    /// Whether at least one check ran and every check passed
+    pub fn is_valid(&self) -> bool {
+        !self.checks.is_empty() && self.checks.iter().all(|check| check.passed)
+    }
+    
+    /// Look up a check by name
+    pub fn check(&self, name: &str) -> Option<&VerifyCheck> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+    
+    /// Checks that failed
+    pub fn failures(&self) -> impl Iterator<Item = &VerifyCheck> {
+        self.checks.iter().filter(|check| !check.passed)
+    }
+}
+
+impl ark_std::fmt::Display for VerifyReport {
+    fn fmt(&self, f: &mut ark_std::fmt::Formatter<'_>) -> ark_std::fmt::Result {
+        for check in &self.checks {
+            let status = if check.passed { "PASS" } else { "FAIL" };
+            writeln!(f, "[{}] {}: {}", status, check.name, check.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl VerifierParams {
+    /// Fingerprint of these parameters, comparable with a proof's [`ProofParams`]
+    pub fn proof_params(&self) -> ProofParams {
+        ProofParams {
+            log_size: self.log_size,
+            max_operations: self.max_operations,
+            vk_hash: vk_fingerprint(&self.commitment_vk, &self.fiat_shamir_seed),
+        }
+    }
+    
+    /// Write the parameters in uncompressed canonical form
+    pub fn write<W: ark_std::io::Write>(&self, mut writer: W) -> Result<()> {
+        use ark_serialize::CanonicalSerialize;
+        
+        self.log_size.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.max_operations.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.fiat_shamir_seed.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_vk.g1_generator.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_vk.g2_generator.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_vk.g2_tau.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_vk.max_degree.serialize_uncompressed(&mut writer).map_err(serialization_error)?;
+        self.commitment_vk.g2_mle_taus.serialize_uncompressed(&mut writer).map_err(serialization_error)
+    }
+    
+    /// Read parameters written by [`VerifierParams::write`]
+    pub fn read<R: ark_std::io::Read>(mut reader: R) -> Result<Self> {
+        use ark_serialize::CanonicalDeserialize;
+        
+        let log_size = usize::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let max_operations = usize::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let fiat_shamir_seed = <[u8; 32]>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+        let commitment_vk = CommitmentVerificationKey {
+            g1_generator: G1Element::deserialize_uncompressed(&mut reader).map_err(serialization_error)?,
+            g2_generator: G2Element::deserialize_uncompressed(&mut reader).map_err(serialization_error)?,
+            g2_tau: G2Element::deserialize_uncompressed(&mut reader).map_err(serialization_error)?,
+            max_degree: usize::deserialize_uncompressed(&mut reader).map_err(serialization_error)?,
+            g2_mle_taus: Vec::<G2Element>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?,
+        };
+        
+        Ok(Self {
+            log_size,
+            max_operations,
+            commitment_vk,
+            fiat_shamir_seed,
+        })
+    }
+    
+    /// Whether `prover_params` come from the same setup as these parameters:
+    /// matching fingerprint and seed, and a consistent `[tau]_1`
+    pub fn matches_prover(&self, prover_params: &ProverParams) -> bool {
+        prover_params.vk_hash == self.proof_params().vk_hash
+            && prover_params.fiat_shamir_seed == self.fiat_shamir_seed
+            && self.commitment_vk.is_consistent_with(&prover_params.commitment_params)
+    }
+    
+    /// Whether a proof generated under `proof_public_params` can be verified here:
+    /// the verification key and seed must match, and the proof's sizes must fit
+    /// within ours (a smaller SRS from the same setup is fine)
+    pub fn is_compatible_with(&self, proof_public_params: &ProofParams) -> bool {
+        let own = self.proof_params();
+        proof_public_params.vk_hash == own.vk_hash
+            && proof_public_params.log_size <= own.log_size
+            && proof_public_params.max_operations <= own.max_operations
+    }
+}
+
+/// Map an arkworks serialization failure into the crate error type
+fn serialization_error(e: ark_serialize::SerializationError) -> TwistAndShoutError {
+    TwistAndShoutError::Serialization(e.to_string())
+}
+
+/// Encode `value` as a JSON string
+pub fn to_json<T: serde::Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string(value).map_err(|e| TwistAndShoutError::Serialization(e.to_string()))
+}
+
+/// Decode a value written by [`to_json`]
+pub fn from_json<T: serde::de::DeserializeOwned>(json: &str) -> Result<T> {
+    serde_json::from_str(json).map_err(|e| TwistAndShoutError::Serialization(e.to_string()))
+}
+
+/// Serde encoding of canonical bytes as a lowercase hex string
+pub(crate) fn serialize_hex<S: serde::Serializer>(bytes: &[u8], serializer: S) -> ark_std::result::Result<S::Ok, S::Error> {
+    let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+    serializer.serialize_str(&hex)
+}
+
+/// Decode a hex string written by [`serialize_hex`]
+pub(crate) fn deserialize_hex<'de, D: serde::Deserializer<'de>>(deserializer: D) -> ark_std::result::Result<Vec<u8>, D::Error> {
+    use serde::de::Error;
+    
+    let hex = <String as serde::Deserialize>::deserialize(deserializer)?;
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return Err(D::Error::custom("invalid hex string"));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(D::Error::custom))
+        .collect()
+}
+
+impl serde::Serialize for VerifierParams {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> ark_std::result::Result<S::Ok, S::Error> {
+        let mut bytes = Vec::new();
+        self.write(&mut bytes).map_err(serde::ser::Error::custom)?;
+        serialize_hex(&bytes, serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for VerifierParams {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> ark_std::result::Result<Self, D::Error> {
+        let bytes = deserialize_hex(deserializer)?;
+        Self::read(&bytes[..]).map_err(serde::de::Error::custom)
+    }
 }
 
-/// Setup parameters for a given memory size
+/// Hash a verification key and transcript seed into a field element.
+/// The SRS size is left out so setups of different sizes sharing tau agree.
+fn vk_fingerprint(vk: &CommitmentVerificationKey, seed: &[u8; 32]) -> FieldElement {
+    use ark_serialize::CanonicalSerialize;
+    
+    // One G1 and two G2 points compress to 160 bytes, serialized on the stack
+    // so verifiers comparing fingerprints do not allocate
+    let mut bytes = [0u8; 160];
+    let mut writer = &mut bytes[..];
+    vk.g1_generator.serialize_compressed(&mut writer).expect("Group element serialization should not fail");
+    vk.g2_generator.serialize_compressed(&mut writer).expect("Group element serialization should not fail");
+    vk.g2_tau.serialize_compressed(&mut writer).expect("Group element serialization should not fail");
+    
+    let mut transcript = Transcript::new(seed);
+    transcript.append_field_elements_from(b"verification_key", bytes.chunks(31).map(FieldElement::from_le_bytes_mod_order));
+    transcript.challenge_field_element(b"vk_fingerprint")
+}
+
+/// Window-NAF tables for every SRS power, built by [`CommitmentParams::precompute_windows`]
+#[derive(Debug, Clone)]
+pub struct WindowTables {
+    /// Window width w
+    pub window_size: usize,
+    
+    /// The 2^(w-1) odd multiples g, 3g, 5g, ... of each power g
+    pub tables: Vec<Vec<G1Element>>,
+}
+
+impl CommitmentParams {
+    /// Drop the setup secret, leaving only the public powers of tau
+    pub fn strip_secret(&mut self) {
+        self.tau = None;
+    }
+    
+    /// Number of variables covered by [`CommitmentParams::mle_basis`]
+    pub fn mle_vars(&self) -> usize {
+        self.mle_basis.len().trailing_zeros() as usize
+    }
+    
+    /// Precompute window-NAF tables of every power of tau for
+    /// [`KZGCommitment::commit_windowed`](crate::KZGCommitment::commit_windowed).
+    ///
+    /// Memory grows as 2^(w-1) G1 points per power, so w = 4 already holds 8x
+    /// the SRS and w is capped at 16, 32768x. In exchange each committed coefficient needs only about
+    /// 256/(w+1) additions on top of its doublings, and no table is rebuilt per
+    /// commitment. This pays off when many polynomials are committed against
+    /// the same SRS; for a few commitments the precomputation dominates.
+    pub fn precompute_windows(&self, window_size: usize) -> Result<WindowTables> {
+        if !(2..=16).contains(&window_size) {
+            return Err(TwistAndShoutError::InvalidParameters(
+                "Window size must be between 2 and 16".to_string(),
+            ));
+        }
+        
+        let context = WnafContext::new(window_size);
+        Ok(WindowTables {
+            window_size,
+            tables: self.g1_powers.iter().map(|&power| context.table(power)).collect(),
+        })
+    }
+}
+
+impl CommitmentVerificationKey {
+    /// Whether polynomials of the given degree fit within this key's SRS
+    pub fn supports_degree(&self, degree: usize) -> bool {
+        degree <= self.max_degree
+    }
+    
+    /// Check that prover parameters were generated from the same `tau` and
+    /// multilinear secrets. The SRS sizes may differ; e([tau]_1, g2) = e(g1, [tau]_2)
+    /// must hold, and the multilinear basis must agree with `[τ'_0]_2`.
+    ///
+    /// This is a setup-time check, behind [`VerifierParams::matches_prover`].
+    /// Verification never sees prover parameters: a proof from another `tau`
+    /// fails its pairings against this key, and is turned away before them by
+    /// the `vk_hash` in its [`ProofParams`], which covers `[tau]_2`.
+    pub fn is_consistent_with(&self, params: &CommitmentParams) -> bool {
+        if params.g1_powers.len() < 2
+            || params.g1_powers[0] != self.g1_generator
+            || params.g2_generator != self.g2_generator
+        {
+            return false;
+        }
+        
+        let left = Bn254::pairing(params.g1_powers[1].into_affine(), self.g2_generator.into_affine());
+        let right = Bn254::pairing(self.g1_generator.into_affine(), self.g2_tau.into_affine());
+        if left != right {
+            return false;
+        }
+        
+        // The multilinear basis must sum to [1]₁ and, in its first variable,
+        // satisfy [eq(τ', 1)]₁·(1 - τ'_0) = [eq(τ', 0)]₁·τ'_0
+        let basis = &params.mle_basis;
+        if basis.iter().sum::<G1Element>() != self.g1_generator || params.mle_vars() > self.g2_mle_taus.len() {
+            return false;
+        }
+        basis.len() < 2 || Bn254::pairing(basis[1].into_affine(), (self.g2_generator - self.g2_mle_taus[0]).into_affine())
+            == Bn254::pairing(basis[0].into_affine(), self.g2_mle_taus[0].into_affine())
+    }
+}
+
+/// Setup parameters for a given memory size, with τ and the Fiat-Shamir seed
+/// drawn from OS entropy.
+///
+/// Every call is a fresh setup: parameters from two calls are not compatible.
+/// Use [`setup_params_with_rng`] for reproducible or shared setups.
+#[cfg(feature = "std")]
 pub fn setup_params(log_size: usize) -> (ProverParams, VerifierParams) {
-    let max_operations = 1 << (log_size + 2); // Allow 4x memory size operations
+    setup_params_with_rng(log_size, &mut ChaCha20Rng::from_entropy())
+}
+
+/// Setup parameters for a given memory size, sampling τ and the Fiat-Shamir
+/// seed from `rng`
+pub fn setup_params_with_rng<R: RngCore>(log_size: usize, rng: &mut R) -> (ProverParams, VerifierParams) {
+    // Generate trusted setup for KZG commitments
+    let tau = FieldElement::rand(rng);
+    
+    // Generate Fiat-Shamir seed
+    let mut seed = [0u8; 32];
+    rng.fill_bytes(&mut seed);
+    
+    let mle_taus = sample_mle_taus(log_size, rng);
+    let (mut prover_params, verifier_params) = setup_params_from_tau(log_size, tau, &mle_taus, seed);
+    prover_params.commitment_params.strip_secret();
+    (prover_params, verifier_params)
+}
+
+/// Load setup parameters for a given memory size from a powers-of-tau ceremony.
+///
+/// The reader holds the ceremony's public output in uncompressed canonical
+/// form: a `Vec` of `[τ^i]₁` followed by a `Vec` of `[τ^i]₂`, both starting at
+/// i = 0, then the multilinear section: a `Vec` of `[eq(τ', x)]₁` over a
+/// hypercube and a `Vec` of `[τ'_j]₂`, one per variable. No secret is read.
+/// At least `4·2^log_size + 1` G1 powers, two G2 powers and `log_size + 2`
+/// multilinear variables are needed; extra powers are ignored and extra
+/// variables summed out. The G1 powers are checked to form a geometric
+/// sequence, and the multilinear basis to factor as eq(τ', ·), with
+/// random-linear-combination pairing checks. The Fiat-Shamir seed is a hash
+/// of the parameters used, so everyone loading the same ceremony agrees on it.
+#[cfg(feature = "std")]
+pub fn load_setup_from_ceremony<R: ark_std::io::Read>(log_size: usize, mut reader: R) -> Result<(ProverParams, VerifierParams)> {
+    use ark_bn254::G1Affine;
+    use ark_ec::VariableBaseMSM;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use blake2::{digest::consts::U32, Blake2b, Digest};
+    
+    let mut g1_powers = Vec::<G1Element>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+    let g2_powers = Vec::<G2Element>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+    let mle_basis = Vec::<G1Element>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+    let mut g2_mle_taus = Vec::<G2Element>::deserialize_uncompressed(&mut reader).map_err(serialization_error)?;
+    
+    let max_operations = 1 << (log_size + 2);
+    let max_degree = max_operations;
+    let mle_vars = log_size + 2;
+    if g1_powers.len() <= max_degree || g2_powers.len() < 2 {
+        return Err(TwistAndShoutError::InvalidParameters(format!(
+            "Ceremony has {} G1 and {} G2 powers; {} and 2 are needed",
+            g1_powers.len(),
+            g2_powers.len(),
+            max_degree + 1
+        )));
+    }
+    if g2_mle_taus.len() < mle_vars || 1usize.checked_shl(g2_mle_taus.len() as u32) != Some(mle_basis.len()) {
+        return Err(TwistAndShoutError::InvalidParameters(format!(
+            "Ceremony has {} multilinear variables and {} basis elements; {} variables are needed",
+            g2_mle_taus.len(),
+            mle_basis.len(),
+            mle_vars
+        )));
+    }
+    g1_powers.truncate(max_degree + 1);
+    let g1_generator = g1_powers[0];
+    let g2_generator = g2_powers[0];
+    let g2_tau = g2_powers[1];
+    if g1_generator.is_zero() || g2_generator.is_zero() || g2_tau.is_zero() {
+        return Err(TwistAndShoutError::InvalidParameters(
+            "Ceremony generators and [τ]₂ must not be the identity".to_string(),
+        ));
+    }
+    
+    // e(Σ rᵢ·[τ^i]₁, [τ]₂) = e(Σ rᵢ·[τ^(i+1)]₁, [1]₂) for random rᵢ
+    let mut rng = ChaCha20Rng::from_entropy();
+    let weights: Vec<FieldElement> = (0..max_degree).map(|_| FieldElement::rand(&mut rng)).collect();
+    let affine = G1Element::normalize_batch(&g1_powers);
+    let lower = G1Element::msm(&affine[..max_degree], &weights).expect("Bases and weights have equal length");
+    let upper = G1Element::msm(&affine[1..], &weights).expect("Bases and weights have equal length");
+    if Bn254::pairing(lower.into_affine(), g2_tau.into_affine())
+        != Bn254::pairing(upper.into_affine(), g2_generator.into_affine())
+    {
+        return Err(TwistAndShoutError::InvalidParameters(
+            "Ceremony G1 powers are not successive powers of one tau".to_string(),
+        ));
+    }
+    
+    // The basis B sums to [1]₁ and, for each variable j and each x with bit j
+    // clear, satisfies B[x + 2^j]·(1 - τ'_j) = B[x]·τ'_j; only eq(τ', ·) does.
+    // Per variable: e(Σ rₓ·B[x + 2^j], [1 - τ'_j]₂) = e(Σ rₓ·B[x], [τ'_j]₂)
+    let mle_basis = crate::MultilinearKZG::restrict_basis(&mle_basis, mle_vars);
+    g2_mle_taus.truncate(mle_vars);
+    if mle_basis.iter().sum::<G1Element>() != g1_generator {
+        return Err(TwistAndShoutError::InvalidParameters(
+            "Ceremony multilinear basis does not sum to the generator".to_string(),
+        ));
+    }
+    let basis_affine = G1Element::normalize_batch(&mle_basis);
+    for (j, &g2_mle_tau) in g2_mle_taus.iter().enumerate() {
+        let bit = 1 << j;
+        let (low, high): (Vec<G1Affine>, Vec<G1Affine>) = (0..mle_basis.len())
+            .filter(|x| x & bit == 0)
+            .map(|x| (basis_affine[x], basis_affine[x | bit]))
+            .unzip();
+        let weights: Vec<FieldElement> = (0..low.len()).map(|_| FieldElement::rand(&mut rng)).collect();
+        let low = G1Element::msm(&low, &weights).expect("Bases and weights have equal length");
+        let high = G1Element::msm(&high, &weights).expect("Bases and weights have equal length");
+        if Bn254::pairing(high.into_affine(), (g2_generator - g2_mle_tau).into_affine())
+            != Bn254::pairing(low.into_affine(), g2_mle_tau.into_affine())
+        {
+            return Err(TwistAndShoutError::InvalidParameters(format!(
+                "Ceremony multilinear basis does not factor in variable {}",
+                j
+            )));
+        }
+    }
+    
+    let mut hasher = Blake2b::<U32>::new();
+    let mut bytes = Vec::new();
+    g1_powers.serialize_compressed(&mut bytes).map_err(serialization_error)?;
+    g2_generator.serialize_compressed(&mut bytes).map_err(serialization_error)?;
+    g2_tau.serialize_compressed(&mut bytes).map_err(serialization_error)?;
+    mle_basis.serialize_compressed(&mut bytes).map_err(serialization_error)?;
+    g2_mle_taus.serialize_compressed(&mut bytes).map_err(serialization_error)?;
+    hasher.update(b"twist-and-shout/ceremony-seed");
+    hasher.update(&bytes);
+    let seed: [u8; 32] = hasher.finalize().into();
+    
+    let commitment_vk = CommitmentVerificationKey {
+        g1_generator,
+        g2_generator,
+        g2_tau,
+        max_degree,
+        g2_mle_taus,
+    };
+    let vk_hash = vk_fingerprint(&commitment_vk, &seed);
+    
+    Ok((
+        ProverParams {
+            log_size,
+            max_operations,
+            commitment_params: CommitmentParams { g1_powers, g2_generator, mle_basis, tau: None },
+            fiat_shamir_seed: seed,
+            vk_hash,
+        },
+        VerifierParams {
+            log_size,
+            max_operations,
+            commitment_vk,
+            fiat_shamir_seed: seed,
+        },
+    ))
+}
+
+/// **Test only.** Setup parameters with a caller-chosen trusted setup secret.
+///
+/// `tau` is the toxic waste of the ceremony: anyone who knows it can open a
+/// commitment to any value. Use this only to cross-check commitments against
+/// hand-computed `[τ^i]₁` values. The Fiat-Shamir seed is fixed.
+pub fn setup_params_with_tau(log_size: usize, tau: FieldElement) -> (ProverParams, VerifierParams) {
     let mut rng = ChaCha20Rng::from_seed([42u8; 32]);
+    let _ = FieldElement::rand(&mut rng);
     
-    // Generate trusted setup for KZG commitments
-    let tau = FieldElement::rand(&mut rng);
+    let mut seed = [0u8; 32];
+    rng.fill_bytes(&mut seed);
+    
+    let mle_taus = sample_mle_taus(log_size, &mut rng);
+    setup_params_from_tau(log_size, tau, &mle_taus, seed)
+}
+
+/// Multilinear secrets τ'_0, ..., τ'_{log_size+1}, one per variable of the
+/// multilinear basis. Drawn in order, so smaller setups from the same rng
+/// state share a prefix.
+fn sample_mle_taus<R: RngCore>(log_size: usize, rng: &mut R) -> Vec<FieldElement> {
+    (0..log_size + 2).map(|_| FieldElement::rand(rng)).collect()
+}
+
+/// Build prover and verifier parameters from the setup secrets and transcript seed
+fn setup_params_from_tau(log_size: usize, tau: FieldElement, mle_taus: &[FieldElement], seed: [u8; 32]) -> (ProverParams, VerifierParams) {
+    let max_operations = 1 << (log_size + 2); // Allow 4x memory size operations
     let g1_gen = G1Element::generator();
     let g2_gen = G2Element::generator();
     
@@ -97,27 +767,36 @@ pub fn setup_params(log_size: usize) -> (ProverParams, VerifierParams) {
     
     let g2_tau = g2_gen * tau;
     
-    // Generate Fiat-Shamir seed
-    let mut seed = [0u8; 32];
-    rng.fill_bytes(&mut seed);
+    // Multilinear basis [eq(τ', x)]₁ over the max_degree-entry hypercube
+    let mle_basis = crate::polynomials::poly_utils::eq_table(mle_taus)
+        .into_iter()
+        .map(|eq| g1_gen * eq)
+        .collect();
+    let g2_mle_taus = mle_taus.iter().map(|&mle_tau| g2_gen * mle_tau).collect();
     
     let commitment_params = CommitmentParams {
         g1_powers,
         g2_generator: g2_gen,
-        tau: Some(tau), // Only for testing, remove in production
+        mle_basis,
+        tau: Some(tau),
     };
     
     let commitment_vk = CommitmentVerificationKey {
         g1_generator: g1_gen,
         g2_generator: g2_gen,
         g2_tau,
+        max_degree,
+        g2_mle_taus,
     };
     
+    let vk_hash = vk_fingerprint(&commitment_vk, &seed);
+    
     let prover_params = ProverParams {
         log_size,
         max_operations,
         commitment_params,
         fiat_shamir_seed: seed,
+        vk_hash,
     };
     
     let verifier_params = VerifierParams {
@@ -130,79 +809,331 @@ pub fn setup_params(log_size: usize) -> (ProverParams, VerifierParams) {
     (prover_params, verifier_params)
 }
 
-/// Fiat-Shamir transcript for non-interactive proofs
+/// Fiat-Shamir transcript the provers and verifiers absorb messages into.
+///
+/// Every `*_with_transcript` entry point is generic over it, so a proof can be
+/// made against [`Transcript`] or, with the `keccak` feature, against
+/// `KeccakTranscript` for an EVM verifier.
+pub trait FiatShamirTranscript {
+    /// Create a new transcript with the given seed
+    fn new(seed: &[u8; 32]) -> Self
+    where
+        Self: Sized;
+    
+    /// Append raw bytes, such as a serialized group element, to the transcript.
+    /// The bytes are length-prefixed, so consecutive appends of variable-length
+    /// data cannot be regrouped into the same stream.
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]);
+    
+    /// Append field elements under a single label as they are produced,
+    /// without collecting them first
+    fn append_field_elements_from<I>(&mut self, label: &[u8], elements: I)
+    where
+        I: IntoIterator<Item = FieldElement>,
+        Self: Sized;
+    
+    /// Challenge a random field element from the transcript
+    fn challenge_field_element(&mut self, label: &[u8]) -> FieldElement;
+    
+    /// Append a field element to the transcript
+    fn append_field_element(&mut self, label: &[u8], element: &FieldElement)
+    where
+        Self: Sized,
+    {
+        self.append_field_elements_from(label, [*element]);
+    }
+    
+    /// Append multiple field elements under a single label
+    fn append_field_elements(&mut self, label: &[u8], elements: &[FieldElement])
+    where
+        Self: Sized,
+    {
+        self.append_field_elements_from(label, elements.iter().copied());
+    }
+    
+    /// Challenge multiple random field elements
+    fn challenge_field_elements(&mut self, label: &[u8], count: usize) -> Vec<FieldElement> {
+        (0..count).map(|_| self.challenge_field_element(label)).collect()
+    }
+    
+    /// Squeeze `n` challenges under one label; transcripts may override this
+    /// to hash the state once for the whole batch
+    fn challenge_batch(&mut self, label: &[u8], n: usize) -> Vec<FieldElement> {
+        self.challenge_field_elements(label, n)
+    }
+    
+    /// [`FiatShamirTranscript::challenge_batch`] writing the challenges into
+    /// `out` instead of a new vector
+    fn challenge_batch_into(&mut self, label: &[u8], out: &mut [FieldElement]) {
+        for challenge in out.iter_mut() {
+            *challenge = self.challenge_field_element(label);
+        }
+    }
+}
+
+/// Fiat-Shamir transcript for non-interactive proofs.
+///
+/// Absorbed bytes stream into a Blake2b hasher rather than a growing buffer,
+/// and no challenges are kept, so absorbing and squeezing never allocate.
+/// Wrap it in [`RecordingTranscript`] to log the challenges.
+#[derive(Debug, Clone)]
 pub struct Transcript {
     rng: ChaCha20Rng,
-    state: Vec<u8>,
+    state: blake2::Blake2b<blake2::digest::consts::U32>,
 }
 
-impl Transcript {
-    /// Create a new transcript with the given seed
-    pub fn new(seed: &[u8; 32]) -> Self {
+impl FiatShamirTranscript for Transcript {
+    fn new(seed: &[u8; 32]) -> Self {
         use ark_std::rand::SeedableRng;
+        use blake2::Digest;
         Self {
             rng: ChaCha20Rng::from_seed(*seed),
-            state: Vec::new(),
+            state: blake2::Blake2b::new(),
         }
     }
     
-    /// Append a field element to the transcript
-    pub fn append_field_element(&mut self, label: &[u8], element: &FieldElement) {
-        self.state.extend_from_slice(label);
-        
-        // Serialize field element
-        let mut bytes = Vec::new();
-        ark_serialize::CanonicalSerialize::serialize_compressed(element, &mut bytes)
-            .expect("Field element serialization should not fail");
-        self.state.extend_from_slice(&bytes);
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        use blake2::Digest;
+        self.append_label(label);
+        self.state.update((bytes.len() as u64).to_le_bytes());
+        self.state.update(bytes);
     }
     
-    /// Append multiple field elements to the transcript
-    pub fn append_field_elements(&mut self, label: &[u8], elements: &[FieldElement]) {
-        self.state.extend_from_slice(label);
+    fn append_field_elements_from<I>(&mut self, label: &[u8], elements: I)
+    where
+        I: IntoIterator<Item = FieldElement>,
+    {
+        use blake2::Digest;
+        self.append_label(label);
         for element in elements {
-            let mut bytes = Vec::new();
-            ark_serialize::CanonicalSerialize::serialize_compressed(element, &mut bytes)
+            // Compressed scalars are 32 bytes, so they serialize onto the stack
+            let mut bytes = [0u8; 32];
+            ark_serialize::CanonicalSerialize::serialize_compressed(&element, &mut bytes[..])
                 .expect("Field element serialization should not fail");
-            self.state.extend_from_slice(&bytes);
+            self.state.update(bytes);
         }
     }
     
-    /// Challenge a random field element from the transcript
-    pub fn challenge_field_element(&mut self, label: &[u8]) -> FieldElement {
-        self.state.extend_from_slice(label);
-        
-        // Update RNG state with current transcript
+    fn challenge_field_element(&mut self, label: &[u8]) -> FieldElement {
+        self.append_label(label);
+        self.reseed();
+        FieldElement::rand(&mut self.rng)
+    }
+    
+    fn challenge_field_elements(&mut self, label: &[u8], count: usize) -> Vec<FieldElement> {
+        self.challenge_batch(label, count)
+    }
+    
+    /// Squeeze `n` challenges from a single hash of the transcript state
+    /// Costs O(state_size + n) instead of re-hashing the state per challenge
+    fn challenge_batch(&mut self, label: &[u8], n: usize) -> Vec<FieldElement> {
+        let mut challenges = vec![FieldElement::zero(); n];
+        self.challenge_batch_into(label, &mut challenges);
+        challenges
+    }
+    
+    fn challenge_batch_into(&mut self, label: &[u8], out: &mut [FieldElement]) {
+        use blake2::Digest;
+        self.append_label(label);
+        self.state.update((out.len() as u64).to_le_bytes());
+        self.reseed();
+        for challenge in out.iter_mut() {
+            *challenge = FieldElement::rand(&mut self.rng);
+        }
+    }
+}
+
+impl Transcript {
+    /// Append a label prefixed with its length, so no label is a prefix of
+    /// another label followed by data
+    fn append_label(&mut self, label: &[u8]) {
+        use blake2::Digest;
+        self.state.update((label.len() as u64).to_le_bytes());
+        self.state.update(label);
+    }
+    
+    /// Re-seed the RNG from a hash of everything absorbed so far
+    fn reseed(&mut self) {
         use ark_std::rand::SeedableRng;
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+        use blake2::Digest;
         
-        let mut hasher = DefaultHasher::new();
-        self.state.hash(&mut hasher);
-        let hash = hasher.finish();
+        let digest = self.state.clone().finalize();
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&digest);
         
-        let seed = hash.to_le_bytes();
-        let mut extended_seed = [0u8; 32];
-        for i in 0..4 {
-            extended_seed[i * 8..(i + 1) * 8].copy_from_slice(&seed);
+        self.rng = ChaCha20Rng::from_seed(seed);
+    }
+}
+
+/// Transcript that logs every challenge the wrapped transcript draws, for
+/// comparing transcripts across implementations
+#[derive(Debug, Clone)]
+pub struct RecordingTranscript<T> {
+    inner: T,
+    challenges: Vec<FieldElement>,
+}
+
+impl<T> RecordingTranscript<T> {
+    /// Start logging the challenges `inner` draws from now on
+    pub fn wrap(inner: T) -> Self {
+        Self { inner, challenges: Vec::new() }
+    }
+    
+    /// Every challenge drawn so far, in order
+    pub fn challenges(&self) -> &[FieldElement] {
+        &self.challenges
+    }
+    
+    /// The wrapped transcript
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: FiatShamirTranscript> FiatShamirTranscript for RecordingTranscript<T> {
+    fn new(seed: &[u8; 32]) -> Self {
+        Self::wrap(T::new(seed))
+    }
+    
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.inner.append_bytes(label, bytes);
+    }
+    
+    fn append_field_elements_from<I>(&mut self, label: &[u8], elements: I)
+    where
+        I: IntoIterator<Item = FieldElement>,
+    {
+        self.inner.append_field_elements_from(label, elements);
+    }
+    
+    fn challenge_field_element(&mut self, label: &[u8]) -> FieldElement {
+        let challenge = self.inner.challenge_field_element(label);
+        self.challenges.push(challenge);
+        challenge
+    }
+    
+    fn challenge_field_elements(&mut self, label: &[u8], count: usize) -> Vec<FieldElement> {
+        let challenges = self.inner.challenge_field_elements(label, count);
+        self.challenges.extend_from_slice(&challenges);
+        challenges
+    }
+    
+    fn challenge_batch(&mut self, label: &[u8], n: usize) -> Vec<FieldElement> {
+        let challenges = self.inner.challenge_batch(label, n);
+        self.challenges.extend_from_slice(&challenges);
+        challenges
+    }
+    
+    fn challenge_batch_into(&mut self, label: &[u8], out: &mut [FieldElement]) {
+        self.inner.challenge_batch_into(label, out);
+        self.challenges.extend_from_slice(out);
+    }
+}
+
+/// Keccak-256 Fiat-Shamir transcript matching an EVM verifier byte for byte.
+///
+/// The state starts as the 32-byte seed. Absorbing appends the label followed
+/// by each field element as a 32-byte big-endian integer, or by raw bytes after
+/// their length as a big-endian `uint64`. Each challenge hashes the state with
+/// its label, replaces the state with the digest and reduces the digest modulo r:
+///
+/// ```solidity
+/// state = abi.encodePacked(state, label, uint256(x));          // append
+/// state = abi.encodePacked(state, label, uint64(b.length), b); // append bytes
+/// state = abi.encodePacked(keccak256(abi.encodePacked(state, label)));
+/// challenge = uint256(bytes32(state)) % R;                     // challenge
+/// ```
+#[cfg(feature = "keccak")]
+#[derive(Debug, Clone)]
+pub struct KeccakTranscript {
+    state: Vec<u8>,
+}
+
+#[cfg(feature = "keccak")]
+impl FiatShamirTranscript for KeccakTranscript {
+    fn new(seed: &[u8; 32]) -> Self {
+        Self {
+            state: seed.to_vec(),
         }
+    }
+    
+    fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.state.extend_from_slice(label);
+        self.state.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+        self.state.extend_from_slice(bytes);
+    }
+    
+    fn append_field_elements_from<I>(&mut self, label: &[u8], elements: I)
+    where
+        I: IntoIterator<Item = FieldElement>,
+    {
+        use ark_ff::BigInteger;
         
-        self.rng = ChaCha20Rng::from_seed(extended_seed);
-        FieldElement::rand(&mut self.rng)
+        self.state.extend_from_slice(label);
+        for element in elements {
+            // Four 64-bit limbs, so always 32 bytes
+            self.state.extend_from_slice(&element.into_bigint().to_bytes_be());
+        }
     }
     
-    /// Challenge multiple random field elements
-    pub fn challenge_field_elements(&mut self, label: &[u8], count: usize) -> Vec<FieldElement> {
-        let mut challenges = Vec::with_capacity(count);
-        for i in 0..count {
-            let indexed_label = format!("{}_{}", 
-                std::str::from_utf8(label).unwrap_or("challenge"), i);
-            challenges.push(self.challenge_field_element(indexed_label.as_bytes()));
+    /// Challenge a field element: keccak256(state || label) reduced modulo r
+    fn challenge_field_element(&mut self, label: &[u8]) -> FieldElement {
+        use sha3::{Digest, Keccak256};
+        
+        self.state.extend_from_slice(label);
+        let digest = Keccak256::digest(&self.state);
+        self.state = digest.to_vec();
+        
+        FieldElement::from_be_bytes_mod_order(&digest)
+    }
+}
+
+/// Radix used when formatting field elements as strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldRadix {
+    Decimal,
+    Hex,
+}
+
+/// Format the canonical representative of a field element; hex output is `0x`-prefixed
+pub fn field_to_string(element: &FieldElement, radix: FieldRadix) -> String {
+    let repr = element.into_bigint();
+    match radix {
+        FieldRadix::Decimal => repr.to_string(),
+        FieldRadix::Hex => {
+            let mut limbs = repr.0.iter().rev().skip_while(|&&limb| limb == 0);
+            match limbs.next() {
+                Some(top) => limbs.fold(format!("0x{:x}", top), |acc, limb| format!("{}{:016x}", acc, limb)),
+                None => "0x0".to_string(),
+            }
         }
-        challenges
     }
 }
 
+/// Parse a decimal or `0x`-prefixed hex string into a field element,
+/// reducing values at or above the modulus
+pub fn field_from_str(s: &str) -> Result<FieldElement> {
+    let trimmed = s.trim();
+    let (digits, radix) = match trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None => (trimmed, 10),
+    };
+    
+    if digits.is_empty() {
+        return Err(TwistAndShoutError::InvalidParameters(
+            format!("Invalid field element string {:?}", s),
+        ));
+    }
+    
+    let base = FieldElement::from(radix as u64);
+    digits.chars().try_fold(FieldElement::zero(), |acc, c| {
+        let digit = c.to_digit(radix).ok_or_else(|| {
+            TwistAndShoutError::InvalidParameters(format!("Invalid field element string {:?}", s))
+        })?;
+        Ok(acc * base + FieldElement::from(digit as u64))
+    })
+}
+
 /// Utility functions for field arithmetic
 pub mod field_utils {
     use super::*;
@@ -327,4 +1258,273 @@ mod tests {
             assert_eq!(*elem * inv, FieldElement::one());
         }
     }
-}
\ No newline at end of file
+    
+    #[test]
+    fn test_transcript_challenge_batch() {
+        let mut first = Transcript::new(&[7u8; 32]);
+        let mut second = Transcript::new(&[7u8; 32]);
+        first.append_field_element(b"input", &FieldElement::from(5u64));
+        second.append_field_element(b"input", &FieldElement::from(5u64));
+        
+        let batch = first.challenge_batch(b"batch", 8);
+        assert_eq!(batch, second.challenge_batch(b"batch", 8));
+        
+        for i in 0..batch.len() {
+            for j in (i + 1)..batch.len() {
+                assert_ne!(batch[i], batch[j]);
+            }
+        }
+        
+        // The batch advances the state, so the next challenge differs
+        assert_ne!(first.challenge_field_element(b"next"), batch[0]);
+    }
+    
+    #[test]
+    fn test_transcript_labels_are_unambiguous() {
+        // Without a length prefix both transcripts would hash the bytes ff fe 01
+        let mut first = Transcript::new(&[7u8; 32]);
+        let mut second = Transcript::new(&[7u8; 32]);
+        first.append_bytes(b"\xff\xfe", b"\x01");
+        second.append_bytes(b"\xff", b"\xfe\x01");
+        assert_ne!(first.challenge_batch(b"\x80", 4), second.challenge_batch(b"\x80", 4));
+        
+        let mut first = Transcript::new(&[7u8; 32]);
+        let mut second = Transcript::new(&[7u8; 32]);
+        assert_ne!(
+            first.challenge_field_elements(b"\xc3\x28", 3),
+            second.challenge_field_elements(b"\xa0\xa1", 3)
+        );
+        
+        // Nor can data pose as the next append: without its own prefix, data
+        // spelling out label "b" with its length would hash like appending it
+        let mut first = Transcript::new(&[7u8; 32]);
+        let mut second = Transcript::new(&[7u8; 32]);
+        first.append_bytes(b"a", &[1, 0, 0, 0, 0, 0, 0, 0, b'b']);
+        second.append_bytes(b"a", &[]);
+        second.append_bytes(b"b", &[]);
+        assert_ne!(first.challenge_field_element(b"c"), second.challenge_field_element(b"c"));
+    }
+    
+    #[test]
+    fn test_field_from_str() {
+        assert_eq!(field_from_str("86").unwrap(), FieldElement::from(86u64));
+        assert_eq!(field_from_str("0x56").unwrap(), FieldElement::from(86u64));
+        assert!(matches!(field_from_str("0x5g"), Err(TwistAndShoutError::InvalidParameters(_))));
+        assert!(field_from_str("").is_err());
+        
+        // Values at or above the modulus are reduced
+        let modulus = FieldElement::MODULUS.to_string();
+        assert_eq!(field_from_str(&modulus).unwrap(), FieldElement::zero());
+        
+        // Round-trip through both display radixes
+        let x = -FieldElement::from(12345u64);
+        for radix in [FieldRadix::Decimal, FieldRadix::Hex] {
+            assert_eq!(field_from_str(&field_to_string(&x, radix)).unwrap(), x);
+        }
+        assert_eq!(field_to_string(&FieldElement::from(86u64), FieldRadix::Hex), "0x56");
+        assert_eq!(field_to_string(&FieldElement::zero(), FieldRadix::Hex), "0x0");
+    }
+    
+    #[test]
+    fn test_setup_params_with_tau() {
+        let tau = FieldElement::from(5u64);
+        let (prover_params, verifier_params) = setup_params_with_tau(2, tau);
+        let params = &prover_params.commitment_params;
+        let g1 = G1Element::generator();
+        
+        for (i, power) in params.g1_powers.iter().enumerate().take(4) {
+            assert_eq!(*power, g1 * tau.pow([i as u64]));
+        }
+        assert_eq!(verifier_params.commitment_vk.g2_tau, G2Element::generator() * tau);
+        
+        use crate::commitments::{CommitmentScheme, KZGCommitment};
+        let commit = |coeffs: &[FieldElement]| KZGCommitment::commit(params, coeffs).unwrap().commitment;
+        assert_eq!(commit(&[FieldElement::one()]), g1);
+        assert_eq!(commit(&[FieldElement::zero(), FieldElement::one()]), g1 * tau);
+        
+        // 3 + 2τ + τ² = 38 at τ = 5
+        let coeffs = [FieldElement::from(3u64), FieldElement::from(2u64), FieldElement::one()];
+        assert_eq!(commit(&coeffs), g1 * FieldElement::from(38u64));
+        
+        // The transcript seed does not depend on tau
+        let other_tau = setup_params_with_tau(2, FieldElement::from(7u64)).0;
+        assert_eq!(prover_params.fiat_shamir_seed, other_tau.fiat_shamir_seed);
+    }
+    
+    #[test]
+    fn test_setup_params_with_rng() {
+        let (_, first) = setup_params_with_rng(2, &mut ChaCha20Rng::from_seed([1u8; 32]));
+        let (_, second) = setup_params_with_rng(2, &mut ChaCha20Rng::from_seed([2u8; 32]));
+        assert_ne!(first.commitment_vk.g2_tau, second.commitment_vk.g2_tau);
+        assert_ne!(first.fiat_shamir_seed, second.fiat_shamir_seed);
+        
+        // The same RNG state reproduces the setup
+        let (_, again) = setup_params_with_rng(2, &mut ChaCha20Rng::from_seed([1u8; 32]));
+        assert_eq!(first.commitment_vk.g2_tau, again.commitment_vk.g2_tau);
+        assert_eq!(first.fiat_shamir_seed, again.fiat_shamir_seed);
+        
+        // The default setup draws a fresh tau each time
+        assert_ne!(setup_params(2).1.commitment_vk.g2_tau, setup_params(2).1.commitment_vk.g2_tau);
+    }
+    
+    #[test]
+    fn test_stripped_params_commit_and_open() {
+        use crate::commitments::{CommitmentScheme, KZGCommitment};
+        
+        let (mut prover_params, verifier_params) = setup_params_with_tau(2, FieldElement::from(5u64));
+        assert!(prover_params.commitment_params.tau.is_some());
+        prover_params.commitment_params.strip_secret();
+        assert!(prover_params.commitment_params.tau.is_none());
+        
+        let params = &prover_params.commitment_params;
+        let coeffs = [FieldElement::from(3u64), FieldElement::from(2u64), FieldElement::one()];
+        let commitment = KZGCommitment::commit(params, &coeffs).unwrap();
+        let point = FieldElement::from(4u64);
+        let (value, proof) = KZGCommitment::open(params, &coeffs, point).unwrap();
+        assert_eq!(value, FieldElement::from(27u64));
+        assert!(KZGCommitment::verify(&verifier_params.commitment_vk, &commitment, point, value, &proof).unwrap());
+        
+        // Setups meant for real use never carry the secret
+        assert!(setup_params(2).0.commitment_params.tau.is_none());
+    }
+    
+    #[test]
+    fn test_load_setup_from_ceremony() {
+        use crate::commitments::{CommitmentScheme, KZGCommitment};
+        use ark_serialize::CanonicalSerialize;
+        
+        let tau = FieldElement::from(9u64);
+        let mle_taus: Vec<FieldElement> = (2..7u64).map(FieldElement::from).collect();
+        let ceremony = |g1_count: u64, g2_count: u64, mle_vars: usize, tamper: Option<usize>| {
+            let mut g1_powers: Vec<G1Element> = (0..g1_count).map(|i| G1Element::generator() * tau.pow([i])).collect();
+            let g2_powers: Vec<G2Element> = (0..g2_count).map(|i| G2Element::generator() * tau.pow([i])).collect();
+            let mut mle_basis: Vec<G1Element> = crate::polynomials::poly_utils::eq_table(&mle_taus[..mle_vars])
+                .into_iter()
+                .map(|eq| G1Element::generator() * eq)
+                .collect();
+            let g2_mle_taus: Vec<G2Element> = mle_taus[..mle_vars].iter().map(|&t| G2Element::generator() * t).collect();
+            match tamper {
+                Some(0) => g1_powers[5] += G1Element::generator(),
+                // Moving weight between two entries keeps the basis sum intact
+                Some(_) => {
+                    mle_basis[3] += G1Element::generator();
+                    mle_basis[6] -= G1Element::generator();
+                }
+                None => {}
+            }
+            let mut bytes = Vec::new();
+            g1_powers.serialize_uncompressed(&mut bytes).unwrap();
+            g2_powers.serialize_uncompressed(&mut bytes).unwrap();
+            mle_basis.serialize_uncompressed(&mut bytes).unwrap();
+            g2_mle_taus.serialize_uncompressed(&mut bytes).unwrap();
+            bytes
+        };
+        
+        let (prover_params, verifier_params) = load_setup_from_ceremony(2, &ceremony(20, 20, 5, None)[..]).unwrap();
+        assert!(prover_params.commitment_params.tau.is_none());
+        assert_eq!(prover_params.commitment_params.g1_powers.len(), 17);
+        assert_eq!(prover_params.commitment_params.mle_vars(), 4);
+        assert!(verifier_params.matches_prover(&prover_params));
+        
+        // Same powers as a direct setup from tau; the extra multilinear
+        // variable is summed out
+        let (direct, _) = setup_params_with_tau(2, tau);
+        assert_eq!(prover_params.commitment_params.g1_powers, direct.commitment_params.g1_powers);
+        let expected_basis: Vec<G1Element> = crate::polynomials::poly_utils::eq_table(&mle_taus[..4])
+            .into_iter()
+            .map(|eq| G1Element::generator() * eq)
+            .collect();
+        assert_eq!(prover_params.commitment_params.mle_basis, expected_basis);
+        assert_eq!(verifier_params.commitment_vk.g2_mle_taus.len(), 4);
+        
+        let coeffs = [FieldElement::from(1u64), FieldElement::from(6u64)];
+        let commitment = KZGCommitment::commit(&prover_params.commitment_params, &coeffs).unwrap();
+        let (value, proof) = KZGCommitment::open(&prover_params.commitment_params, &coeffs, FieldElement::from(2u64)).unwrap();
+        assert!(KZGCommitment::verify(&verifier_params.commitment_vk, &commitment, FieldElement::from(2u64), value, &proof).unwrap());
+        
+        // Loading is deterministic, including the transcript seed
+        let (again, _) = load_setup_from_ceremony(2, &ceremony(20, 20, 5, None)[..]).unwrap();
+        assert_eq!(again.fiat_shamir_seed, prover_params.fiat_shamir_seed);
+        
+        // Too few powers or variables, a tampered G1 power or multilinear
+        // basis, and truncated input are rejected
+        assert!(load_setup_from_ceremony(2, &ceremony(16, 20, 4, None)[..]).is_err());
+        assert!(load_setup_from_ceremony(2, &ceremony(20, 1, 4, None)[..]).is_err());
+        assert!(load_setup_from_ceremony(2, &ceremony(20, 20, 3, None)[..]).is_err());
+        assert!(load_setup_from_ceremony(2, &ceremony(20, 20, 4, Some(0))[..]).is_err());
+        assert!(load_setup_from_ceremony(2, &ceremony(20, 20, 4, Some(1))[..]).is_err());
+        let bytes = ceremony(20, 20, 4, None);
+        assert!(load_setup_from_ceremony(2, &bytes[..bytes.len() - 1]).is_err());
+        
+        // An all-identity ceremony satisfies every pairing check trivially, so
+        // the generators themselves are checked
+        let mut zeros = Vec::new();
+        vec![G1Element::zero(); 20].serialize_uncompressed(&mut zeros).unwrap();
+        vec![G2Element::zero(); 20].serialize_uncompressed(&mut zeros).unwrap();
+        vec![G1Element::zero(); 32].serialize_uncompressed(&mut zeros).unwrap();
+        vec![G2Element::zero(); 5].serialize_uncompressed(&mut zeros).unwrap();
+        match load_setup_from_ceremony(2, &zeros[..]) {
+            Err(TwistAndShoutError::InvalidParameters(message)) => assert!(message.contains("identity")),
+            _ => panic!("all-zero ceremony was accepted"),
+        }
+    }
+    
+    #[test]
+    fn test_params_write_read_roundtrip() {
+        use crate::commitments::{CommitmentScheme, KZGCommitment};
+        
+        let (prover_params, verifier_params) = setup_params(3);
+        let mut bytes = Vec::new();
+        prover_params.write(&mut bytes).unwrap();
+        verifier_params.write(&mut bytes).unwrap();
+        
+        let mut reader = &bytes[..];
+        let loaded_prover = ProverParams::read(&mut reader).unwrap();
+        let loaded_verifier = VerifierParams::read(&mut reader).unwrap();
+        assert!(reader.is_empty());
+        assert!(loaded_prover.commitment_params.tau.is_none());
+        assert_eq!(loaded_prover.proof_params(), prover_params.proof_params());
+        assert!(loaded_verifier.matches_prover(&loaded_prover));
+        assert!(!setup_params_with_tau(3, FieldElement::from(5u64)).1.matches_prover(&loaded_prover));
+        
+        // Open with the reloaded prover key, verify with the reloaded verifier key
+        let coeffs = [FieldElement::from(3u64), FieldElement::from(2u64), FieldElement::one()];
+        let commitment = KZGCommitment::commit(&loaded_prover.commitment_params, &coeffs).unwrap();
+        let point = FieldElement::from(7u64);
+        let (value, proof) = KZGCommitment::open(&loaded_prover.commitment_params, &coeffs, point).unwrap();
+        assert!(KZGCommitment::verify(&loaded_verifier.commitment_vk, &commitment, point, value, &proof).unwrap());
+        assert!(KZGCommitment::verify(&verifier_params.commitment_vk, &commitment, point, value, &proof).unwrap());
+        
+        assert!(ProverParams::read(&bytes[..10]).is_err());
+    }
+    
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn test_keccak_transcript_vector() {
+        // Matches keccak256 over abi.encodePacked(seed, "a", 1, "b", 2, 3, "c")
+        // and then over abi.encodePacked(digest, "d")
+        let mut transcript = RecordingTranscript::wrap(KeccakTranscript::new(&[0u8; 32]));
+        transcript.append_field_element(b"a", &FieldElement::from(1u64));
+        transcript.append_field_elements(b"b", &[FieldElement::from(2u64), FieldElement::from(3u64)]);
+        
+        let first = transcript.challenge_field_element(b"c");
+        let second = transcript.challenge_field_element(b"d");
+        assert_eq!(
+            first,
+            field_from_str("21065596732733660767741980688509466082224427381151526136908546647175953263250").unwrap()
+        );
+        assert_eq!(
+            second,
+            field_from_str("17968700834915024032686269336989764348411396802965471422952052857411628010952").unwrap()
+        );
+        assert_eq!(transcript.challenges(), &[first, second]);
+        
+        // keccak256("") reduced modulo r
+        let mut empty = KeccakTranscript::new(&[0u8; 32]);
+        empty.state.clear();
+        assert_eq!(
+            empty.challenge_field_element(b""),
+            field_from_str("0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470").unwrap()
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Integration tests combining multiple protocol components
 
 use twist_and_shout::*;
-use ark_ff::{Field, Zero, One};
+use ark_ff::{BigInteger, Field, PrimeField, Zero, One};
 
 #[test]
 fn test_full_memory_consistency_workflow() {
@@ -318,4 +318,421 @@ fn test_error_handling() {
     
     let mut table = LookupTable::new(vec![FieldElement::one(); 2]);
     assert!(table.lookup(2).is_err()); // Out of bounds
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_verify_with_larger_compatible_srs() {
+    // Both setups share the same tau; only the SRS size differs
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    let (small_prover, small_verifier) = setup_params_with_rng(2, &mut ChaCha20Rng::from_seed([42u8; 32]));
+    let (large_prover, large_verifier) = setup_params_with_rng(4, &mut ChaCha20Rng::from_seed([42u8; 32]));
+    assert!(large_verifier.commitment_vk.is_consistent_with(&small_prover.commitment_params));
+    
+    let mut trace = MemoryTrace::new(4);
+    trace.write(1, FieldElement::from(5u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    let proof = Twist::new(&small_prover).prove(&trace).unwrap();
+    assert!(Twist::new(&small_prover).verify(&proof, &large_verifier).unwrap());
+    
+    let mut table = LookupTable::new(vec![FieldElement::from(7u64), FieldElement::from(8u64)]);
+    table.lookup(1).unwrap();
+    table.lookup(0).unwrap();
+    
+    let shout_proof = Shout::new(&small_prover).prove(&table).unwrap();
+    assert!(Shout::new(&small_prover).verify(&shout_proof, &large_verifier).unwrap());
+    
+    // Verification rejects a prover with another tau on its own, without
+    // the setup-time consistency check
+    let (other_prover, _) = setup_params_with_rng(2, &mut ChaCha20Rng::from_seed([43u8; 32]));
+    assert!(!large_verifier.commitment_vk.is_consistent_with(&other_prover.commitment_params));
+    let other_proof = Twist::new(&other_prover).prove(&trace).unwrap();
+    assert!(!Twist::new(&other_prover).verify(&other_proof, &large_verifier).unwrap());
+    
+    // A proof needing more degree than the verifier's SRS holds is rejected
+    let mut long_trace = MemoryTrace::new(16);
+    for i in 0..32 {
+        long_trace.write(i % 16, FieldElement::from(i as u64)).unwrap();
+    }
+    let long_proof = Twist::new(&large_prover).prove(&long_trace).unwrap();
+    assert!(!small_verifier.commitment_vk.supports_degree(31));
+    assert!(!Twist::new(&large_prover).verify(&long_proof, &small_verifier).unwrap());
+}
+
+#[test]
+fn test_proof_params_fingerprint() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let (other_prover, other_verifier) = twist_and_shout::utils::setup_params_with_tau(3, FieldElement::from(17u64));
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(6u64)).unwrap();
+    trace.read(2).unwrap();
+    
+    let proof = Twist::new(&prover_params).prove(&trace).unwrap();
+    assert_eq!(proof.params, verifier_params.proof_params());
+    assert!(verifier_params.is_compatible_with(&proof.params));
+    assert!(Twist::new(&prover_params).verify(&proof, &verifier_params).unwrap());
+    
+    // A proof under a different setup is rejected by a mismatched verifier
+    let other_proof = Twist::new(&other_prover).prove(&trace).unwrap();
+    assert!(!verifier_params.is_compatible_with(&other_proof.params));
+    assert!(!Twist::new(&prover_params).verify(&other_proof, &verifier_params).unwrap());
+    assert!(Twist::new(&other_prover).verify(&other_proof, &other_verifier).unwrap());
+    
+    let mut table = LookupTable::new(vec![FieldElement::from(1u64), FieldElement::from(2u64)]);
+    table.lookup(1).unwrap();
+    let shout_proof = Shout::new(&other_prover).prove(&table).unwrap();
+    assert!(!Shout::new(&prover_params).verify(&shout_proof, &verifier_params).unwrap());
+}
+
+/// Insecure scheme whose commitment is the coefficient vector itself, used to
+/// check that the protocols never rely on KZG specifics. Generic over the
+/// scalar field so it also stands in for schemes over other fields.
+#[derive(Debug, Clone)]
+struct TransparentCommitment<F = FieldElement>(std::marker::PhantomData<F>);
+
+impl<F: PrimeField> CommitmentScheme for TransparentCommitment<F> {
+    type Scalar = F;
+    type Commitment = Vec<F>;
+    type Proof = ();
+    type Params = ();
+    type VerifyKey = ();
+    
+    fn prover_key(_params: &ProverParams) -> std::borrow::Cow<'_, ()> {
+        std::borrow::Cow::Owned(())
+    }
+    
+    fn verifier_key(_params: &VerifierParams) -> std::borrow::Cow<'_, ()> {
+        std::borrow::Cow::Owned(())
+    }
+    
+    fn transcript_hash(commitment: &Vec<F>) -> FieldElement {
+        let hash = Self::evaluate(commitment, F::from(7u64));
+        FieldElement::from_le_bytes_mod_order(&hash.into_bigint().to_bytes_le())
+    }
+    
+    fn commit(_params: &(), polynomial: &[F]) -> twist_and_shout::Result<Vec<F>> {
+        Ok(polynomial.to_vec())
+    }
+    
+    fn open(_params: &(), polynomial: &[F], point: F) -> twist_and_shout::Result<(F, ())> {
+        Ok((Self::evaluate(polynomial, point), ()))
+    }
+    
+    fn verify(
+        _vk: &(),
+        commitment: &Vec<F>,
+        point: F,
+        value: F,
+        _proof: &(),
+    ) -> twist_and_shout::Result<bool> {
+        Ok(Self::evaluate(commitment, point) == value)
+    }
+}
+
+impl<F: PrimeField> TransparentCommitment<F> {
+    fn evaluate(coefficients: &[F], point: F) -> F {
+        coefficients.iter().rev().fold(F::zero(), |acc, &c| acc * point + c)
+    }
+}
+
+/// Commit to `polynomial`, open it at `point` and check the opening, using
+/// nothing but the `CommitmentScheme` interface
+fn commit_open_verify<C: CommitmentScheme>(
+    params: &C::Params,
+    vk: &C::VerifyKey,
+    polynomial: &[C::Scalar],
+    point: C::Scalar,
+) -> bool {
+    let commitment = C::commit(params, polynomial).unwrap();
+    let (value, proof) = C::open(params, polynomial, point).unwrap();
+    C::verify(vk, &commitment, point, value, &proof).unwrap()
+        && !C::verify(vk, &commitment, point, value + C::Scalar::one(), &proof).unwrap()
+}
+
+#[test]
+fn test_commitment_scheme_generic_over_scalar() {
+    let (prover_params, verifier_params) = setup_params(2);
+    let polynomial: Vec<FieldElement> = (1..5u64).map(FieldElement::from).collect();
+    assert!(commit_open_verify::<KZGCommitment>(
+        &KZGCommitment::prover_key(&prover_params),
+        &KZGCommitment::verifier_key(&verifier_params),
+        &polynomial,
+        FieldElement::from(9u64),
+    ));
+    
+    // The base field of BN254 is a different scalar field altogether
+    let polynomial: Vec<ark_bn254::Fq> = (1..5u64).map(ark_bn254::Fq::from).collect();
+    assert!(commit_open_verify::<TransparentCommitment<ark_bn254::Fq>>(
+        &(),
+        &(),
+        &polynomial,
+        ark_bn254::Fq::from(9u64),
+    ));
+}
+
+#[test]
+fn test_protocols_with_custom_commitment_scheme() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(3, FieldElement::from(11u64)).unwrap();
+    trace.read(3).unwrap();
+    trace.write(5, FieldElement::from(13u64)).unwrap();
+    
+    let twist = Twist::<TransparentCommitment>::with_scheme(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    assert_eq!(proof.address_commitment.len(), 4);
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.final_evaluations[1] += FieldElement::one();
+    assert!(!twist.verify(&tampered, &verifier_params).unwrap());
+    
+    let mut table = LookupTable::new((0..4u64).map(FieldElement::from).collect());
+    table.lookup(1).unwrap();
+    table.lookup(2).unwrap();
+    
+    let shout = Shout::<TransparentCommitment>::with_scheme(&prover_params);
+    let proof = shout.prove(&table).unwrap();
+    assert!(shout.verify(&proof, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_protocols_with_fri_commitments() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(21u64)).unwrap();
+    trace.write(6, FieldElement::from(34u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    let twist = Twist::<FriCommitment>::with_scheme(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    
+    let mut tampered = proof;
+    tampered.final_evaluations[0] += FieldElement::one();
+    assert!(!twist.verify(&tampered, &verifier_params).unwrap());
+    
+    let mut table = LookupTable::new((0..8u64).map(FieldElement::from).collect());
+    table.lookup(3).unwrap();
+    table.lookup(5).unwrap();
+    
+    let shout = Shout::<FriCommitment>::with_scheme(&prover_params);
+    let proof = shout.prove(&table).unwrap();
+    assert!(shout.verify(&proof, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_fri_commitments_with_trimmed_params() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let trimmed = prover_params.trim(3).unwrap();
+    
+    // FRI ignores the SRS, so a trimmed key must derive the verifier's FRI parameters
+    let mut trace = MemoryTrace::new(4);
+    trace.write(3, FieldElement::from(8u64)).unwrap();
+    trace.read(3).unwrap();
+    trace.write(1, FieldElement::from(2u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    let proof = Twist::<FriCommitment>::with_scheme(&trimmed).prove(&trace).unwrap();
+    let twist = Twist::<FriCommitment>::with_scheme(&prover_params);
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_benchmark_results_to_markdown() {
+    use std::time::Duration;
+    
+    let result = |millis: u64| BenchmarkResults {
+        setup_time: Duration::from_millis(millis),
+        prove_time: Duration::from_millis(2 * millis),
+        verify_time: Duration::from_millis(millis),
+        proof_size: 2048,
+        num_operations: 16,
+        memory_usage: 0,
+    };
+    let results = vec![(8, result(5)), (16, result(10)), (32, result(20))];
+    
+    let markdown = ProtocolBenchmarks::results_to_markdown("Twist", &results);
+    let table: Vec<&str> = markdown.lines().filter(|line| line.starts_with('|')).collect();
+    
+    // Header, delimiter and one row per result, each with six cells
+    assert_eq!(table.len(), 2 + results.len());
+    assert!(table[0].contains("Size") && table[0].contains("Ops/sec"));
+    assert!(table[1].chars().all(|c| matches!(c, '|' | '-' | ':')));
+    for line in &table {
+        assert!(line.ends_with('|'));
+        assert_eq!(line.matches('|').count(), 7);
+    }
+    assert!(table[3].starts_with("| 16 | 10.00 | 20.00 | 10.00 | 2.00 |"));
+}
+
+#[test]
+fn test_benchmark_reports_serialized_proof_size() {
+    use ark_serialize::CanonicalSerialize;
+    
+    let (prover_params, _) = setup_params(2);
+    
+    // The same trace and lookups the four-operation benchmarks prove
+    let mut trace = MemoryTrace::new(4);
+    trace.write(0, FieldElement::zero()).unwrap();
+    trace.read(0).unwrap();
+    trace.read(1).unwrap();
+    trace.write(3, FieldElement::from(126u64)).unwrap();
+    let twist_proof = Twist::new(&prover_params).prove(&trace).unwrap();
+    let mut twist_bytes = Vec::new();
+    twist_proof.serialize_compressed(&mut twist_bytes).unwrap();
+    
+    let mut table = LookupTable::new((0..4u64).map(|i| FieldElement::from(i * i)).collect());
+    table.lookup_batch(&[0, 1, 2, 3]).unwrap();
+    let shout_proof = Shout::new(&prover_params).prove(&table).unwrap();
+    let mut shout_bytes = Vec::new();
+    shout_proof.serialize_compressed(&mut shout_bytes).unwrap();
+    
+    assert_eq!(ProtocolBenchmarks::benchmark_twist_single(2, 4).proof_size, twist_bytes.len());
+    assert_eq!(ProtocolBenchmarks::benchmark_shout_single(2, 4).proof_size, shout_bytes.len());
+}
+
+#[test]
+fn test_benchmark_run_and_export() {
+    let json_path = std::env::temp_dir().join(format!("twist_and_shout_bench_{}.json", std::process::id()));
+    let records = ProtocolBenchmarks::run_and_export(2, 2, 4, &json_path, ExportFormat::Json).unwrap();
+    let json = std::fs::read_to_string(&json_path).unwrap();
+    std::fs::remove_file(&json_path).unwrap();
+    
+    // The JSON parses back into the same records, times in nanoseconds
+    let parsed: Vec<BenchmarkRecord> = from_json(&json).unwrap();
+    assert_eq!(parsed, records);
+    let protocols: Vec<&str> = parsed.iter().map(|record| record.protocol.as_str()).collect();
+    assert_eq!(protocols, vec!["Twist", "Shout", "GrandProduct"]);
+    assert!(parsed.iter().all(|record| record.size == 4 && record.results.num_operations == 4));
+    assert!(json.contains(&format!("\"prove_time_ns\":{}", records[0].results.prove_time.as_nanos())));
+    
+    let csv_path = std::env::temp_dir().join(format!("twist_and_shout_bench_{}.csv", std::process::id()));
+    let records = ProtocolBenchmarks::run_and_export(2, 2, 4, &csv_path, ExportFormat::Csv).unwrap();
+    let csv = std::fs::read_to_string(&csv_path).unwrap();
+    std::fs::remove_file(&csv_path).unwrap();
+    
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], format!("protocol,size,{}", BenchmarkResults::CSV_HEADER));
+    assert_eq!(lines[1], format!("Twist,4,{}", records[0].results.to_csv_row()));
+    assert_eq!(lines.len(), 4);
+    assert!(lines.iter().all(|line| line.split(',').count() == 8));
+}
+
+#[test]
+fn test_protocols_with_pedersen_commitments() {
+    let (prover_params, verifier_params) = setup_params(2);
+    
+    let mut trace = MemoryTrace::new(4);
+    trace.write(1, FieldElement::from(9u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    let twist = Twist::<PedersenCommitment>::with_scheme(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_and_shout_shared_transcript() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    let shout = Shout::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(5u64)).unwrap();
+    trace.read(2).unwrap();
+    let mut table = LookupTable::new((0..8u64).map(|i| FieldElement::from(i * i)).collect());
+    table.lookup(3).unwrap();
+    table.lookup(6).unwrap();
+    
+    // The outer protocol separates the two arguments with its own labels
+    let seed = [9u8; 32];
+    let mut prover_transcript = RecordingTranscript::wrap(Transcript::new(&seed));
+    prover_transcript.append_bytes(b"component", b"twist");
+    let twist_proof = twist.prove_with_transcript(&trace, &mut prover_transcript).unwrap();
+    prover_transcript.append_bytes(b"component", b"shout");
+    let shout_proof = shout.prove_with_transcript(&table, &mut prover_transcript).unwrap();
+    
+    let mut verifier_transcript = RecordingTranscript::wrap(Transcript::new(&seed));
+    verifier_transcript.append_bytes(b"component", b"twist");
+    assert!(twist.verify_with_transcript(&twist_proof, &verifier_params, &mut verifier_transcript).unwrap());
+    verifier_transcript.append_bytes(b"component", b"shout");
+    assert!(shout.verify_with_transcript(&shout_proof, &verifier_params, &mut verifier_transcript).unwrap());
+    assert_eq!(prover_transcript.challenges(), verifier_transcript.challenges());
+    
+    // The Shout proof depends on the Twist messages before it, so it does not
+    // verify standalone or out of order
+    assert!(!shout.verify(&shout_proof, &verifier_params).unwrap());
+    let mut reordered = Transcript::new(&seed);
+    reordered.append_bytes(b"component", b"shout");
+    assert!(!shout.verify_with_transcript(&shout_proof, &verifier_params, &mut reordered).unwrap());
+    
+    // Existing entry points are unchanged wrappers over a fresh transcript
+    let standalone = shout.prove(&table).unwrap();
+    let mut fresh = Transcript::new(&prover_params.fiat_shamir_seed);
+    assert!(shout.verify_with_transcript(&standalone, &verifier_params, &mut fresh).unwrap());
+}
+
+#[cfg(feature = "keccak")]
+#[test]
+fn test_twist_and_shout_with_keccak_transcript() {
+    use twist_and_shout::utils::KeccakTranscript;
+    
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    let shout = Shout::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(4, FieldElement::from(11u64)).unwrap();
+    trace.read(4).unwrap();
+    let mut table = LookupTable::new((0..8u64).map(FieldElement::from).collect());
+    table.lookup(5).unwrap();
+    
+    let seed = [3u8; 32];
+    let twist_proof = twist.prove_with_transcript(&trace, &mut KeccakTranscript::new(&seed)).unwrap();
+    let shout_proof = shout.prove_with_transcript(&table, &mut KeccakTranscript::new(&seed)).unwrap();
+    assert!(twist.verify_with_transcript(&twist_proof, &verifier_params, &mut KeccakTranscript::new(&seed)).unwrap());
+    assert!(shout.verify_with_transcript(&shout_proof, &verifier_params, &mut KeccakTranscript::new(&seed)).unwrap());
+    
+    // The challenges come from Keccak, so the default transcript rejects them
+    assert!(!twist.verify_with_transcript(&twist_proof, &verifier_params, &mut Transcript::new(&seed)).unwrap());
+    assert!(!shout.verify_with_transcript(&shout_proof, &verifier_params, &mut Transcript::new(&seed)).unwrap());
+}
+
+#[test]
+fn test_json_round_trip() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(5u64)).unwrap();
+    trace.write(6, FieldElement::from(9u64)).unwrap();
+    trace.read(1).unwrap();
+    let twist_proof = Twist::new(&prover_params).prove(&trace).unwrap();
+    
+    let mut table = LookupTable::new(vec![FieldElement::from(7u64), FieldElement::from(8u64)]);
+    table.lookup(1).unwrap();
+    table.lookup(0).unwrap();
+    let shout_proof = Shout::new(&prover_params).prove(&table).unwrap();
+    
+    // Everything a verifying service needs travels as JSON strings
+    let twist_json = to_json(&twist_proof).unwrap();
+    let shout_json = to_json(&shout_proof).unwrap();
+    let params_json = to_json(&verifier_params).unwrap();
+    
+    let decoded_twist: TwistProof = from_json(&twist_json).unwrap();
+    let decoded_shout: ShoutProof = from_json(&shout_json).unwrap();
+    let decoded_params: VerifierParams = from_json(&params_json).unwrap();
+    
+    assert_eq!(to_json(&decoded_twist).unwrap(), twist_json);
+    assert!(Twist::new(&prover_params).verify(&decoded_twist, &decoded_params).unwrap());
+    assert!(Shout::new(&prover_params).verify(&decoded_shout, &decoded_params).unwrap());
+    
+    // Malformed hex and truncated proof bytes are reported, not panicked on
+    assert!(from_json::<TwistProof>("\"0g\"").is_err());
+    assert!(from_json::<ShoutProof>(&shout_json[..shout_json.len() - 3]).is_err());
+    assert!(from_json::<VerifierParams>("\"00\"").is_err());
+}
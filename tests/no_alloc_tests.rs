@@ -0,0 +1,75 @@
+//! Heap-allocation checks for the allocation-free verifiers
+//!
+//! A counting global allocator records the allocations made on the current
+//! thread while a closure runs, so tests in this binary can run in parallel.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use ark_ff::{One, Zero};
+use twist_and_shout::*;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get().map(|n| n + 1)));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Run `f`, returning its result and the heap allocations it made
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    ALLOCATIONS.with(|count| count.set(Some(0)));
+    let result = f();
+    let allocations = ALLOCATIONS.with(|count| count.replace(None)).unwrap();
+    (result, allocations)
+}
+
+#[test]
+fn test_twist_verify_no_alloc_rejects_without_allocating() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(4u64)).unwrap();
+    trace.read(2).unwrap();
+    trace.write(6, FieldElement::from(8u64)).unwrap();
+    trace.read(6).unwrap();
+    let proof = twist.prove(&trace).unwrap();
+    let mut scratch = vec![FieldElement::zero(); Twist::no_alloc_scratch_len(&proof)];
+
+    // Only the commitment openings, checked last, may allocate
+    let (valid, _) = count_allocations(|| twist.verify_no_alloc(&proof, &verifier_params, &mut scratch));
+    assert!(valid.unwrap());
+
+    // A bridge evaluation changes every later challenge, failing the sum-check
+    let mut tampered = proof.clone();
+    tampered.final_evaluations[0] += FieldElement::one();
+    let (valid, allocations) = count_allocations(|| twist.verify_no_alloc(&tampered, &verifier_params, &mut scratch));
+    assert!(!valid.unwrap());
+    assert_eq!(allocations, 0);
+
+    // A column evaluation passes the sum-check but not the summand at its point
+    let mut tampered = proof.clone();
+    tampered.consistency_opening.evaluations[0] += FieldElement::one();
+    let (valid, allocations) = count_allocations(|| twist.verify_no_alloc(&tampered, &verifier_params, &mut scratch));
+    assert!(!valid.unwrap());
+    assert_eq!(allocations, 0);
+
+    // Too small a scratch buffer is reported without a formatted message
+    let (result, allocations) = count_allocations(|| twist.verify_no_alloc(&proof, &verifier_params, &mut scratch[1..]));
+    assert!(result.is_err());
+    assert_eq!(allocations, 0);
+}
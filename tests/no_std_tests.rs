@@ -0,0 +1,37 @@
+//! Verification from a `#![no_std]` crate, using only `core` and `alloc`.
+//!
+//! The harness itself links `std`, so this only runs with the crate's `std`
+//! feature off: `cargo test --no-default-features --features kzg --test no_std_tests`.
+//! That the library builds without `std` at all is checked by the `no_std`
+//! CI workflow, which compiles it for `thumbv7em-none-eabihf`.
+
+#![cfg(not(feature = "std"))]
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+use twist_and_shout::*;
+
+#[test]
+fn test_verify_deserialized_proof_without_std() {
+    let (prover_params, verifier_params) =
+        setup_params_with_rng(2, &mut ChaCha20Rng::from_seed([7u8; 32]));
+    
+    let mut trace = MemoryTrace::new(4);
+    trace.write(1, FieldElement::from(5u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    let mut bytes = Vec::new();
+    Twist::new(&prover_params)
+        .prove(&trace)
+        .unwrap()
+        .serialize_compressed(&mut bytes)
+        .unwrap();
+    
+    let proof = TwistProof::<KZGCommitment>::deserialize_compressed(&bytes[..]).unwrap();
+    let twist = Twist::<KZGCommitment>::new(&prover_params);
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+}
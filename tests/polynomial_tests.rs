@@ -151,6 +151,26 @@ fn test_polynomial_arithmetic() {
     assert_eq!(total, FieldElement::from(3u64)); // 1 + 2 = 3
 }
 
+#[test]
+fn test_mul_pointwise() {
+    let mut rng = test_rng();
+    let f = MultilinearExtension::from_evaluations((0..8).map(|_| FieldElement::rand(&mut rng)).collect());
+    let g = MultilinearExtension::from_evaluations((0..8).map(|_| FieldElement::rand(&mut rng)).collect());
+    
+    let product = f.mul_pointwise(&g);
+    for x in 0..8 {
+        assert_eq!(product.evaluations[x], f.evaluations[x] * g.evaluations[x]);
+    }
+    
+    // Σ f(x)·g(x) over the hypercube is the inner product of the tables
+    let inner_product: FieldElement = f.evaluations.iter().zip(&g.evaluations).map(|(a, b)| *a * b).sum();
+    assert_eq!(product.sum_evaluations(), inner_product);
+    
+    // Off the hypercube the multilinear product differs from f·g
+    let point: Vec<FieldElement> = (0..3).map(|_| FieldElement::rand(&mut rng)).collect();
+    assert_ne!(product.evaluate(&point), f.evaluate(&point) * g.evaluate(&point));
+}
+
 #[test]
 fn test_less_than_polynomial() {
     use twist_and_shout::polynomials::LessThanPolynomial;
@@ -167,6 +187,27 @@ fn test_less_than_polynomial() {
     assert_eq!(lt.evaluate_at_bits(&[true, true, false], &[false, true, false]), FieldElement::zero());  // 3 > 2
 }
 
+#[test]
+fn test_less_than_polynomial_matches_integer_order() {
+    use twist_and_shout::polynomials::LessThanPolynomial;
+    
+    let lt = LessThanPolynomial::new(2);
+    let bits = |x: usize| vec![x & 1 == 1, x & 2 == 2];
+    let mle = lt.to_multilinear_extension();
+    
+    for a in 0..4usize {
+        for b in 0..4usize {
+            let expected = if a < b { FieldElement::one() } else { FieldElement::zero() };
+            assert_eq!(lt.evaluate_at_bits(&bits(a), &bits(b)), expected, "lt({}, {})", a, b);
+            assert_eq!(
+                lt.evaluate_at_field_elements(FieldElement::from(a as u64), FieldElement::from(b as u64)),
+                expected,
+            );
+            assert_eq!(mle.evaluations[a | (b << 2)], expected);
+        }
+    }
+}
+
 #[test]
 fn test_less_than_polynomial_multilinear_extension() {
     use twist_and_shout::polynomials::LessThanPolynomial;
@@ -320,4 +361,61 @@ fn test_multilinear_extension_properties() {
     let expected_scaled = mle1.evaluate(&test_point) * scalar;
     
     assert_eq!(scaled_eval, expected_scaled);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_less_than_try_evaluate_mismatched_lengths() {
+    use twist_and_shout::polynomials::LessThanPolynomial;
+    
+    let lt_poly = LessThanPolynomial::new(3);
+    
+    // Matching lengths evaluate normally
+    let result = lt_poly.try_evaluate_at_bits(&[false, false, false], &[true, false, false]);
+    assert_eq!(result.unwrap(), FieldElement::one());
+    
+    // Mismatched lengths return an error instead of panicking
+    assert!(lt_poly.try_evaluate_at_bits(&[false, true], &[true, false, false]).is_err());
+    assert!(lt_poly.try_evaluate_at_bits(&[false, true, false], &[true; 4]).is_err());
+    
+    // Field elements wider than num_vars bits are rejected
+    assert!(lt_poly.try_evaluate_at_field_elements(FieldElement::from(3u64), FieldElement::from(7u64)).is_ok());
+    assert!(lt_poly.try_evaluate_at_field_elements(FieldElement::from(8u64), FieldElement::from(1u64)).is_err());
+}
+
+#[test]
+fn test_sparse_multilinear_extension_matches_dense() {
+    let mut rng = test_rng();
+    let num_vars = 12;
+    
+    let entries: Vec<(usize, FieldElement)> = [3usize, 100, 2047, 4095, 100]
+        .iter()
+        .map(|&index| (index, FieldElement::rand(&mut rng)))
+        .collect();
+    let sparse = SparseMultilinearExtension::from_entries(num_vars, &entries);
+    let dense = MultilinearExtension::from_sparse(num_vars, &entries);
+    
+    // The repeated index keeps its last value
+    assert_eq!(sparse.entries.len(), 4);
+    assert_eq!(sparse.to_dense().evaluations, dense.evaluations);
+    
+    for _ in 0..5 {
+        let point: Vec<FieldElement> = (0..num_vars).map(|_| FieldElement::rand(&mut rng)).collect();
+        assert_eq!(sparse.evaluate(&point), dense.evaluate(&point));
+    }
+    
+    let other = SparseMultilinearExtension::from_entries(num_vars, &[(3, -entries[0].1), (7, FieldElement::one())]);
+    let scalar = FieldElement::from(5u64);
+    let combined = sparse.add(&other).scalar_mul(scalar);
+    
+    // Cancelling entries are dropped rather than stored as zero
+    assert!(!combined.entries.contains_key(&3));
+    
+    let point: Vec<FieldElement> = (0..num_vars).map(|_| FieldElement::rand(&mut rng)).collect();
+    let expected = dense.add(&other.to_dense()).scalar_mul(scalar);
+    assert_eq!(combined.evaluate(&point), expected.evaluate(&point));
+    assert!(sparse.scalar_mul(FieldElement::zero()).entries.is_empty());
+    
+    // Address spaces of 64 or more variables hold every usize index
+    let wide = SparseMultilinearExtension::from_entries(64, &[(usize::MAX, FieldElement::one())]);
+    assert_eq!(wide.entries.len(), 1);
+}
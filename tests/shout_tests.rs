@@ -47,6 +47,25 @@ fn test_lookup_table_bounds_checking() {
     assert!(table.lookup(100).is_err());
 }
 
+#[test]
+fn test_lookup_table_batch() {
+    let entries: Vec<FieldElement> = (0..4u64).map(|i| FieldElement::from(10 * i)).collect();
+    let mut table = LookupTable::new(entries.clone());
+    
+    // An out-of-bounds index mid-batch records nothing
+    assert!(table.lookup_batch(&[0, 2, 7, 1]).is_err());
+    assert!(table.lookups.is_empty());
+    
+    let values = table.lookup_batch(&[3, 0, 3]).unwrap();
+    assert_eq!(values, vec![entries[3], entries[0], entries[3]]);
+    let indices: Vec<usize> = table.lookups.iter().map(|op| op.index).collect();
+    assert_eq!(indices, vec![3, 0, 3]);
+    
+    // An empty batch is a no-op
+    assert!(table.lookup_batch(&[]).unwrap().is_empty());
+    assert_eq!(table.lookups.len(), 3);
+}
+
 #[test]
 fn test_lookup_table_empty() {
     let mut table = LookupTable::new(vec![]);
@@ -96,6 +115,32 @@ fn test_shout_protocol_basic_lookup() {
     assert!(is_valid, "Shout proof should be valid for correct lookups");
 }
 
+#[test]
+fn test_shout_rejects_lookup_in_table_padding() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let shout = Shout::new(&prover_params);
+    
+    let mut table = LookupTable::new((1..=5u64).map(FieldElement::from).collect());
+    table.lookup(4).unwrap();
+    let proof = shout.prove(&table).unwrap();
+    assert_eq!(proof.table_size, 5);
+    assert!(shout.verify(&proof, &verifier_params).unwrap());
+    
+    // Index 6 lies in the zero padding up to 8 entries; a crafted lookup
+    // claiming the padded 0 must not be provable
+    table.lookups.push(LookupOp { index: 6, value: FieldElement::zero() });
+    assert!(shout.prove(&table).is_err());
+    
+    // The table size is bound into the transcript
+    let mut resized = proof.clone();
+    resized.table_size = 7;
+    assert!(!shout.verify(&resized, &verifier_params).unwrap());
+    
+    // An untrusted size with no power of two above it is rejected, not a panic
+    resized.table_size = usize::MAX;
+    assert!(!shout.verify(&resized, &verifier_params).unwrap());
+}
+
 #[test]
 fn test_shout_protocol_no_lookups() {
     let (prover_params, verifier_params) = setup_params(2);
@@ -329,4 +374,563 @@ fn test_shout_protocol_duplicate_values() {
     let proof = shout.prove(&table).unwrap();
     let is_valid = shout.verify(&proof, &verifier_params).unwrap();
     assert!(is_valid, "Lookups with duplicate values should have valid proof");
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_shout_single_lookup() {
+    let (prover_params, verifier_params) = setup_params(2);
+    
+    let mut table = LookupTable::new(vec![
+        FieldElement::from(5u64),
+        FieldElement::from(6u64),
+        FieldElement::from(7u64),
+    ]);
+    table.lookup(1).unwrap();
+    
+    let shout = Shout::new(&prover_params);
+    let proof = shout.prove(&table).unwrap();
+    
+    // The lookup argument runs over the padded table's four rows
+    assert_eq!(proof.lookup_proof.round_polynomials.len(), 2);
+    assert_eq!(proof.opening_proofs.len(), 3);
+    assert!(shout.verify(&proof, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_lookup_table_to_mle() {
+    let entries: Vec<FieldElement> = (0..5u64).map(|i| FieldElement::from(10 * i + 1)).collect();
+    let table = LookupTable::new(entries.clone());
+    
+    let mle = table.to_mle();
+    assert_eq!(mle.num_vars, 3, "Five entries pad to eight");
+    
+    for i in 0..8 {
+        let point: Vec<FieldElement> = (0..3)
+            .map(|j| if (i >> j) & 1 == 1 { FieldElement::one() } else { FieldElement::zero() })
+            .collect();
+        let expected = entries.get(i).copied().unwrap_or(FieldElement::zero());
+        assert_eq!(mle.evaluate(&point), expected);
+    }
+}
+
+#[test]
+fn test_lookup_table_merge() {
+    let opcodes = LookupTable::new((0..4u64).map(FieldElement::from).collect());
+    let mut operands = LookupTable::new((100..104u64).map(FieldElement::from).collect());
+    operands.lookup(3).unwrap();
+    
+    let (mut merged, offsets) = LookupTable::merge(&[opcodes, operands]);
+    assert_eq!(offsets, vec![0, 4]);
+    assert_eq!(merged.size(), 8);
+    
+    // Recorded lookups are translated into the merged index space
+    assert_eq!(merged.lookups[0].index, 7);
+    assert_eq!(merged.lookups[0].value, FieldElement::from(103u64));
+    
+    // Index 2 of the second sub-table
+    assert_eq!(merged.lookup(offsets[1] + 2).unwrap(), FieldElement::from(102u64));
+    assert_eq!(merged.lookup(offsets[0] + 1).unwrap(), FieldElement::from(1u64));
+}
+
+#[test]
+fn test_shout_prove_virtual_table() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let square = |i: usize| FieldElement::from((i * i) as u64);
+    let size = 1 << 16;
+    
+    let lookups: Vec<LookupOp> = [3usize, 255, 40_000, 3, 65_535]
+        .iter()
+        .map(|&index| LookupOp { index, value: square(index) })
+        .collect();
+    
+    let shout = Shout::new(&prover_params);
+    let proof = shout.prove_virtual(square, size, &lookups).unwrap();
+    assert_eq!(proof.accessed_indices, vec![3, 255, 40_000, 65_535]);
+    assert!(shout.verify_virtual(&proof, square, &verifier_params).unwrap());
+    
+    // The verifier's own table function must agree with the committed entries
+    let cube = |i: usize| FieldElement::from((i * i * i) as u64);
+    assert!(!shout.verify_virtual(&proof, cube, &verifier_params).unwrap());
+    
+    // A lookup whose value disagrees with f cannot be proven
+    let mut wrong = lookups.clone();
+    wrong[1].value += FieldElement::one();
+    assert!(shout.prove_virtual(square, size, &wrong).is_err());
+    
+    // Out-of-range lookups are rejected
+    let out_of_range = vec![LookupOp { index: size, value: square(size) }];
+    assert!(shout.prove_virtual(square, size, &out_of_range).is_err());
+}
+
+#[test]
+fn test_shout_private_lookup() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let mut rng = ark_std::test_rng();
+    
+    let table = LookupTable::new((0..8u64).map(|i| FieldElement::from(100 + i)).collect());
+    let shout = Shout::new(&prover_params);
+    
+    let first = shout.prove_private_lookup(&table, 1, &mut rng).unwrap();
+    let second = shout.prove_private_lookup(&table, 6, &mut rng).unwrap();
+    assert!(shout.verify_private_lookup(&first, &verifier_params).unwrap());
+    assert!(shout.verify_private_lookup(&second, &verifier_params).unwrap());
+    
+    // The table commitment is the one a public lookup proof carries
+    let mut public = table.clone();
+    public.lookup(1).unwrap();
+    let public_proof = shout.prove(&public).unwrap();
+    assert_eq!(first.table_commitment, public_proof.table_commitment);
+    
+    // Repeating the same private lookup yields an unlinkable proof
+    let repeat = shout.prove_private_lookup(&table, 1, &mut rng).unwrap();
+    assert!(shout.verify_private_lookup(&repeat, &verifier_params).unwrap());
+    assert_ne!(first.index_commitment, repeat.index_commitment);
+    assert_ne!(first.value_commitment, repeat.value_commitment);
+    
+    assert!(shout.prove_private_lookup(&table, 8, &mut rng).is_err());
+}
+
+#[test]
+fn test_shout_private_lookup_rejects_forgeries() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let mut rng = ark_std::test_rng();
+    let shout = Shout::new(&prover_params);
+    
+    let table = LookupTable::new((0..8u64).map(|i| FieldElement::from(100 + i)).collect());
+    let proof = shout.prove_private_lookup(&table, 3, &mut rng).unwrap();
+    let other = shout.prove_private_lookup(&table, 5, &mut rng).unwrap();
+    
+    // A value committed for another lookup does not match this index
+    let mut swapped = proof.clone();
+    swapped.value_commitment = other.value_commitment;
+    assert!(!shout.verify_private_lookup(&swapped, &verifier_params).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.evaluations[2] += FieldElement::from(1u64);
+    assert!(!shout.verify_private_lookup(&tampered, &verifier_params).unwrap());
+    
+    // An index past the claimed table size fails the range identity, even when
+    // the committed table agrees with it on every claimed row
+    let mut extended = table.entries.clone();
+    extended.extend((8..16u64).map(|i| FieldElement::from(100 + i)));
+    let outside = shout.prove_private_lookup(&LookupTable::new(extended), 10, &mut rng).unwrap();
+    let mut truncated = outside.clone();
+    truncated.table_size = 8;
+    assert!(!shout.verify_private_lookup(&truncated, &verifier_params).unwrap());
+    
+    let mut oversized = proof;
+    oversized.table_size = usize::MAX;
+    assert!(!shout.verify_private_lookup(&oversized, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_shout_private_table() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let mut rng = ark_std::test_rng();
+    let shout = Shout::new(&prover_params);
+    
+    let entries: Vec<FieldElement> = (0..8u64).map(|i| FieldElement::from(31 * i + 7)).collect();
+    let mut table = shout.commit_private_table(entries.clone(), 3, &mut rng).unwrap();
+    assert_eq!(table.size(), 8);
+    assert_eq!(table.commitment().table_size, 8);
+    
+    let indices = [2, 5, 2];
+    let proof = shout.prove_private_table(&mut table, &indices, &mut rng).unwrap();
+    assert!(shout.verify_private_table(table.commitment(), &indices, &proof, &verifier_params).unwrap());
+    
+    // Neither the commitment nor the revealed evaluations expose an entry
+    let public = shout.prove(&LookupTable::new(entries.clone())).unwrap();
+    assert_ne!(table.commitment().commitment, public.table_commitment);
+    assert!(proof.evaluations.iter().all(|evaluation| !entries.contains(evaluation)));
+    let again = shout.commit_private_table(entries.clone(), 3, &mut rng).unwrap();
+    assert_ne!(table.commitment().commitment, again.commitment().commitment);
+    
+    // The proof is tied to its indices, its table and the table's published size
+    assert!(!shout.verify_private_table(table.commitment(), &[2, 5, 3], &proof, &verifier_params).unwrap());
+    assert!(!shout.verify_private_table(again.commitment(), &indices, &proof, &verifier_params).unwrap());
+    assert!(!shout.verify_private_table(table.commitment(), &[2, 5, 8], &proof, &verifier_params).unwrap());
+    let mut resized = table.commitment().clone();
+    resized.table_size = 16;
+    assert!(!shout.verify_private_table(&resized, &indices, &proof, &verifier_params).unwrap());
+    assert!(shout.prove_private_table(&mut table, &[8], &mut rng).is_err());
+    
+    // Every proof opens the table at a fresh point, and the blinding only
+    // covers the proofs the table was committed for
+    let second = shout.prove_private_table(&mut table, &[7], &mut rng).unwrap();
+    assert!(shout.verify_private_table(table.commitment(), &[7], &second, &verifier_params).unwrap());
+    let third = shout.prove_private_table(&mut table, &[0, 1], &mut rng).unwrap();
+    assert!(shout.verify_private_table(table.commitment(), &[0, 1], &third, &verifier_params).unwrap());
+    assert_eq!(table.remaining_proofs(), 0);
+    assert!(shout.prove_private_table(&mut table, &[3], &mut rng).is_err());
+    assert!(shout.commit_private_table(entries, 0, &mut rng).is_err());
+}
+
+#[test]
+fn test_shout_prove_multi_three_tables() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let shout = Shout::new(&prover_params);
+    
+    // Opcode, range and 2-bit AND tables of different sizes and lookup counts
+    let mut opcodes = LookupTable::new([3u64, 17, 42, 9, 11].iter().map(|&op| FieldElement::from(op)).collect());
+    let mut range = LookupTable::new((0..16u64).map(FieldElement::from).collect());
+    let mut and = LookupTable::new((0..16u64).map(|i| FieldElement::from((i & 3) & (i >> 2))).collect());
+    for index in [2, 0, 4] {
+        opcodes.lookup(index).unwrap();
+    }
+    for index in [15, 7, 7, 0, 9] {
+        range.lookup(index).unwrap();
+    }
+    and.lookup(0b1011).unwrap();
+    
+    let tables = [opcodes, range, and];
+    let proof = shout.prove_multi(&tables).unwrap();
+    assert_eq!(proof.table_sizes, vec![5, 16, 16]);
+    assert_eq!(proof.opening_proofs.len(), 9);
+    assert!(shout.verify_multi(&proof, &verifier_params).unwrap());
+    
+    // Swapping two tables' commitments breaks the proof
+    let mut swapped = proof.clone();
+    swapped.table_commitments.swap(1, 2);
+    assert!(!shout.verify_multi(&swapped, &verifier_params).unwrap());
+    let mut truncated = proof.clone();
+    truncated.table_commitments.pop();
+    assert!(!shout.verify_multi(&truncated, &verifier_params).unwrap());
+    let mut swapped = proof.clone();
+    swapped.value_commitments.swap(0, 1);
+    assert!(!shout.verify_multi(&swapped, &verifier_params).unwrap());
+    
+    // The sum-check's final evaluation and the bridge evaluations are checked
+    let mut tampered = proof.clone();
+    tampered.lookup_proof.final_evaluation += FieldElement::one();
+    assert!(!shout.verify_multi(&tampered, &verifier_params).unwrap());
+    for i in 0..proof.final_evaluations.len() {
+        let mut tampered = proof.clone();
+        tampered.final_evaluations[i] += FieldElement::one();
+        assert!(!shout.verify_multi(&tampered, &verifier_params).unwrap());
+    }
+    
+    // An oversized lookup count is rejected rather than shifted out of range
+    let mut oversized = proof.clone();
+    oversized.num_lookups[0] = usize::MAX;
+    assert!(!shout.verify_multi(&oversized, &verifier_params).unwrap());
+    
+    // A wrong value in any one table has no honest proof
+    let mut tampered = tables.clone();
+    tampered[2].lookups[0].value += FieldElement::one();
+    assert!(shout.prove_multi(&tampered).is_err());
+}
+
+#[test]
+fn test_shout_structured_range_check() {
+    let (prover_params, verifier_params) = setup_params(2);
+    let shout = Shout::new(&prover_params);
+    
+    // 2^20 entries: the table is only ever evaluated through its MLE
+    let table = RangeCheckTable::new(20);
+    let lookups: Vec<LookupOp> = [0u64, 12345, (1 << 20) - 1]
+        .iter()
+        .map(|&v| LookupOp { index: v as usize, value: FieldElement::from(v) })
+        .collect();
+    
+    let proof = shout.prove_structured(&table, &lookups).unwrap();
+    assert_eq!(proof.bit_commitments.len(), 20);
+    assert!(shout.verify_structured(&table, &proof, &verifier_params).unwrap());
+    
+    // Values outside the range have no index to look up
+    let out_of_range = LookupOp { index: 1 << 20, value: FieldElement::from(1u64 << 20) };
+    assert!(shout.prove_structured(&table, &[out_of_range]).is_err());
+    let wrong_value = LookupOp { index: 7, value: FieldElement::from(8u64) };
+    assert!(shout.prove_structured(&table, &[wrong_value]).is_err());
+    
+    // The proof is tied to its table and its opened evaluations
+    assert!(!shout.verify_structured(&RangeCheckTable::new(19), &proof, &verifier_params).unwrap());
+    let mut tampered = proof.clone();
+    tampered.evaluations[0] += FieldElement::one();
+    assert!(!shout.verify_structured(&table, &tampered, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_shout_size_breakdown() {
+    use ark_serialize::CanonicalSerialize;
+    
+    let (prover_params, _) = setup_params(3);
+    let mut table = LookupTable::new((0..6u64).map(FieldElement::from).collect());
+    table.lookup(2).unwrap();
+    table.lookup(5).unwrap();
+    table.lookup(0).unwrap();
+    
+    let proof = Shout::new(&prover_params).prove(&table).unwrap();
+    
+    let mut bytes = Vec::new();
+    proof.params.log_size.serialize_compressed(&mut bytes).unwrap();
+    proof.params.max_operations.serialize_compressed(&mut bytes).unwrap();
+    proof.params.vk_hash.serialize_compressed(&mut bytes).unwrap();
+    proof.table_size.serialize_compressed(&mut bytes).unwrap();
+    proof.num_lookups.serialize_compressed(&mut bytes).unwrap();
+    proof.table_commitment.serialize_compressed(&mut bytes).unwrap();
+    proof.index_commitment.serialize_compressed(&mut bytes).unwrap();
+    proof.value_commitment.serialize_compressed(&mut bytes).unwrap();
+    proof.lookup_proof.round_polynomials.serialize_compressed(&mut bytes).unwrap();
+    proof.lookup_proof.final_evaluation.serialize_compressed(&mut bytes).unwrap();
+    proof.lookup_opening.serialize_compressed(&mut bytes).unwrap();
+    proof.opening_proofs.serialize_compressed(&mut bytes).unwrap();
+    proof.final_evaluations.serialize_compressed(&mut bytes).unwrap();
+    proof.extension_proof.is_some().serialize_compressed(&mut bytes).unwrap();
+    
+    let breakdown = proof.size_breakdown();
+    assert_eq!(breakdown.total(), bytes.len());
+    assert_eq!(breakdown.sumcheck, proof.lookup_proof.serialized_size());
+}
+
+#[test]
+fn test_shout_verify_verbose_pinpoints_opening() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let mut table = LookupTable::new((0..8u64).map(FieldElement::from).collect());
+    table.lookup(3).unwrap();
+    table.lookup(6).unwrap();
+    
+    let shout = Shout::new(&prover_params);
+    let mut proof = shout.prove(&table).unwrap();
+    assert!(shout.verify_verbose(&proof, &verifier_params).unwrap().is_valid());
+    
+    proof.final_evaluations[1] += FieldElement::from(1u64);
+    
+    // The openings are checked before the sum-check, whose claim the
+    // tampered evaluation also changes
+    let report = shout.verify_verbose(&proof, &verifier_params).unwrap();
+    assert!(report.check("table_opening").unwrap().passed);
+    assert!(report.check("value_opening").unwrap().passed);
+    let failures: Vec<_> = report.failures().map(|check| check.name).collect();
+    assert_eq!(failures, vec!["index_opening", "lookup_sumcheck"]);
+}
+
+#[test]
+fn test_shout_multiplicities() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let mut table = LookupTable::new((10..16u64).map(FieldElement::from).collect());
+    for index in [0, 3, 3, 5, 3, 0] {
+        table.lookup(index).unwrap();
+    }
+    
+    let shout = Shout::new(&prover_params);
+    let counts = [2, 0, 0, 3, 0, 1];
+    let proof = shout.prove_multiplicities(&table, &counts).unwrap();
+    assert!(shout.verify_multiplicities(&proof, &counts, &verifier_params).unwrap());
+    
+    // One count off by one, with the total preserved and not
+    assert!(!shout.verify_multiplicities(&proof, &[2, 0, 0, 2, 1, 1], &verifier_params).unwrap());
+    assert!(!shout.verify_multiplicities(&proof, &[2, 0, 0, 3, 0, 2], &verifier_params).unwrap());
+    
+    // The prover refuses counts that do not match its lookups
+    assert!(shout.prove_multiplicities(&table, &[2, 0, 0, 2, 1, 1]).is_err());
+    
+    // The counts are checked against the committed multiplicity column, so a
+    // plain lookup proof of the same lookups verifies too
+    let plain = shout.prove(&table).unwrap();
+    assert!(shout.verify_multiplicities(&plain, &counts, &verifier_params).unwrap());
+    
+    // Counts must cover the table and must not overflow
+    assert!(!shout.verify_multiplicities(&proof, &[2, 0, 0, 3, 0], &verifier_params).unwrap());
+    assert!(!shout.verify_multiplicities(&proof, &[usize::MAX, 0, 0, 3, 0, 4], &verifier_params).unwrap());
+    
+    // A proof whose multiplicity column disagrees with the counts fails the
+    // binding to that column
+    let mut tampered = proof.clone();
+    tampered.lookup_opening.evaluations[3] += FieldElement::one();
+    let report = shout.verify_verbose(&tampered, &verifier_params).unwrap();
+    assert!(!report.check("lookup_binding").unwrap().passed);
+    assert!(!shout.verify_multiplicities(&tampered, &counts, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_shout_rejects_corrupted_lookup_value() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let shout = Shout::new(&prover_params);
+    
+    let mut table = LookupTable::new((0..8u64).map(|i| FieldElement::from(10 * i)).collect());
+    table.lookup(3).unwrap();
+    table.lookup(5).unwrap();
+    table.lookup(3).unwrap();
+    assert!(shout.verify(&shout.prove(&table).unwrap(), &verifier_params).unwrap());
+    
+    // The recorded value no longer matches entries[index], so the
+    // inverse fingerprints cannot balance and there is no proof
+    let mut corrupted = table.clone();
+    corrupted.lookups[1].value = FieldElement::from(51u64);
+    assert!(shout.prove(&corrupted).is_err());
+    
+    // Nor does an honest proof accept a commitment to the corrupted values:
+    // they are an honest read of a table whose entry 5 is 51
+    let mut entries = table.entries.clone();
+    entries[5] = FieldElement::from(51u64);
+    let mut other = LookupTable::new(entries);
+    for index in [3, 5, 3] {
+        other.lookup(index).unwrap();
+    }
+    let mut swapped = shout.prove(&table).unwrap();
+    swapped.value_commitment = shout.prove(&other).unwrap().value_commitment;
+    assert!(!shout.verify(&swapped, &verifier_params).unwrap());
+    
+    let debugging = Shout::new(&prover_params).with_constraint_debugging(true);
+    assert!(debugging.prove(&corrupted).is_err());
+}
+
+#[test]
+fn test_shout_proof_serialization_roundtrip() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    
+    let (prover_params, verifier_params) = setup_params(4);
+    let shout = Shout::new(&prover_params);
+    
+    let mut table = LookupTable::new((0..4u64).map(|i| FieldElement::from(i + 100)).collect());
+    table.lookup(2).unwrap();
+    table.lookup(0).unwrap();
+    table.lookup(2).unwrap();
+    let proof = shout.prove(&table).unwrap();
+    
+    let mut bytes = Vec::new();
+    proof.serialize_uncompressed(&mut bytes).unwrap();
+    let decoded = ShoutProof::<KZGCommitment>::deserialize_uncompressed(&bytes[..]).unwrap();
+    
+    let mut reencoded = Vec::new();
+    decoded.serialize_uncompressed(&mut reencoded).unwrap();
+    assert_eq!(bytes, reencoded);
+    assert_eq!(decoded.final_evaluations, proof.final_evaluations);
+    assert!(shout.verify(&decoded, &verifier_params).unwrap());
+    
+    let mut compressed = Vec::new();
+    proof.serialize_compressed(&mut compressed).unwrap();
+    assert_eq!(compressed.len(), proof.size_breakdown().total());
+    let decoded = ShoutProof::<KZGCommitment>::deserialize_compressed(&compressed[..]).unwrap();
+    assert!(shout.verify(&decoded, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_shout_recursive_layout_roundtrip() {
+    use ark_serialize::CanonicalSerialize;
+    
+    let (prover_params, verifier_params) = setup_params(4);
+    let shout = Shout::new(&prover_params);
+    
+    let mut table = LookupTable::new((0..8u64).map(|i| FieldElement::from(5 * i + 2)).collect());
+    table.lookup(3).unwrap();
+    table.lookup(7).unwrap();
+    let previous = shout.prove(&table).unwrap();
+    let new_lookups = vec![LookupOp { index: 1, value: table.entries[1] }];
+    let proof = shout.prove_extends(&previous, &table, &new_lookups).unwrap();
+    
+    for proof in [&previous, &proof] {
+        let mut bytes = Vec::new();
+        proof.serialize_with_layout(ProofLayout::Recursive, &mut bytes).unwrap();
+        let decoded = ShoutProof::<KZGCommitment>::deserialize_with_layout(ProofLayout::Recursive, &bytes[..]).unwrap();
+        assert_eq!(decoded.compressed_size(), proof.compressed_size());
+        let mut reencoded = Vec::new();
+        decoded.serialize_with_layout(ProofLayout::Recursive, &mut reencoded).unwrap();
+        assert_eq!(bytes, reencoded);
+        assert!(shout.verify(&decoded, &verifier_params).unwrap());
+        
+        // Every group element sits after the scalar section
+        let mut groups = Vec::new();
+        proof.table_commitment.serialize_compressed(&mut groups).unwrap();
+        proof.index_commitment.serialize_compressed(&mut groups).unwrap();
+        proof.value_commitment.serialize_compressed(&mut groups).unwrap();
+        for commitment in &proof.lookup_opening.commitments {
+            commitment.serialize_compressed(&mut groups).unwrap();
+        }
+        for quotient in &proof.lookup_opening.proof.combined.quotients {
+            quotient.serialize_compressed(&mut groups).unwrap();
+        }
+        for opening in &proof.opening_proofs {
+            opening.serialize_compressed(&mut groups).unwrap();
+        }
+        if let Some(extension) = &proof.extension_proof {
+            extension.quotient_commitment.serialize_compressed(&mut groups).unwrap();
+            for opening in &extension.opening_proofs {
+                opening.serialize_compressed(&mut groups).unwrap();
+            }
+        }
+        assert!(bytes.ends_with(&groups));
+        
+        let mut vk_hash = Vec::new();
+        proof.params.vk_hash.serialize_compressed(&mut vk_hash).unwrap();
+        let header_len = bytes.len() - groups.len() - vk_hash.len()
+            - 32 * (proof.lookup_proof.round_polynomials.iter().map(Vec::len).sum::<usize>() + 1)
+            - 32 * proof.lookup_opening.evaluations.len()
+            - 32 * proof.final_evaluations.len()
+            - 32 * proof.extension_proof.as_ref().map_or(0, |extension| extension.evaluations.len());
+        assert_eq!(&bytes[header_len..header_len + 32], &vk_hash[..]);
+    }
+}
+
+#[test]
+fn test_shout_prove_extends() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let shout = Shout::new(&prover_params);
+    
+    let mut table = LookupTable::new((0..8u64).map(|i| FieldElement::from(3 * i + 1)).collect());
+    table.lookup(6).unwrap();
+    table.lookup(1).unwrap();
+    table.lookup(6).unwrap();
+    let previous = shout.prove(&table).unwrap();
+    
+    let mut continued = table.clone();
+    continued.lookup(2).unwrap();
+    continued.lookup(7).unwrap();
+    let new_lookups = continued.lookups[3..].to_vec();
+    
+    // An extension that keeps the prefix verifies
+    let extension = shout.prove_extends(&previous, &table, &new_lookups).unwrap();
+    assert_eq!(extension.extension_proof.as_ref().unwrap().prefix_length, 3);
+    assert!(shout.verify(&extension, &verifier_params).unwrap());
+    assert!(shout.verify_extends(&previous, &extension, &verifier_params).unwrap());
+    assert_eq!(extension.size_breakdown().total(), ark_serialize::CanonicalSerialize::compressed_size(&extension));
+    assert!(!shout.verify_extends(&previous, &shout.prove(&continued).unwrap(), &verifier_params).unwrap());
+    
+    // Altering a prefix lookup no longer matches the earlier proof
+    let mut altered = LookupTable::new(table.entries.clone());
+    altered.lookup(6).unwrap();
+    altered.lookup(5).unwrap();
+    altered.lookup(6).unwrap();
+    assert!(shout.prove_extends(&previous, &altered, &new_lookups).is_err());
+    
+    // Nor does an extension of the altered sequence verify against the original
+    let altered_previous = shout.prove(&altered).unwrap();
+    let altered_extension = shout.prove_extends(&altered_previous, &altered, &new_lookups).unwrap();
+    assert!(shout.verify_extends(&altered_previous, &altered_extension, &verifier_params).unwrap());
+    assert!(!shout.verify_extends(&previous, &altered_extension, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_shout_prove_table_update() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let shout = Shout::new(&prover_params);
+    
+    let entries: Vec<FieldElement> = (1..=8).map(|i| FieldElement::from(i as u64)).collect();
+    let mut old_table = LookupTable::new(entries.clone());
+    old_table.lookup(2).unwrap();
+    let mut updated = entries;
+    updated[5] = FieldElement::from(100u64);
+    let mut new_table = LookupTable::new(updated);
+    new_table.lookup(5).unwrap();
+    
+    let old_commitment = shout.prove(&old_table).unwrap().table_commitment;
+    let new_commitment = shout.prove(&new_table).unwrap().table_commitment;
+    let (proof, delta_commitment) = shout.prove_table_update(&old_commitment, &old_table, &new_table).unwrap();
+    
+    // The delta commitment moves the old commitment onto the recommitted table
+    assert_eq!(old_commitment.clone() + delta_commitment.clone(), new_commitment);
+    
+    let mut delta = vec![FieldElement::zero(); 8];
+    delta[5] = FieldElement::from(94u64);
+    assert!(shout.verify_table_update(&old_commitment, &new_commitment, &delta_commitment, &proof, &delta, &verifier_params).unwrap());
+    
+    // A different claimed change or an unrelated new commitment is rejected
+    let mut wrong_delta = delta.clone();
+    wrong_delta[4] = FieldElement::one();
+    assert!(!shout.verify_table_update(&old_commitment, &new_commitment, &delta_commitment, &proof, &wrong_delta, &verifier_params).unwrap());
+    assert!(!shout.verify_table_update(&old_commitment, &old_commitment, &delta_commitment, &proof, &delta, &verifier_params).unwrap());
+    
+    // The changes are absorbed before the challenge, so even a delta that
+    // interpolates to the same polynomial cannot restate the table size
+    assert!(!shout.verify_table_update(&old_commitment, &new_commitment, &delta_commitment, &proof, &delta[..6], &verifier_params).unwrap());
+}
@@ -24,6 +24,18 @@ fn test_memory_trace_basic_operations() {
     assert_eq!(trace.operations.len(), 7); // 3 writes + 4 reads
 }
 
+#[test]
+fn test_memory_trace_peek() {
+    let mut trace = MemoryTrace::new(8);
+    trace.write(4, FieldElement::from(17u64)).unwrap();
+    
+    assert_eq!(trace.peek(4).unwrap(), FieldElement::from(17u64));
+    assert_eq!(trace.peek(2).unwrap(), FieldElement::zero());
+    assert!(trace.peek(8).is_err());
+    assert_eq!(trace.operations.len(), 1);
+    assert_eq!(trace.timestamps.len(), 1);
+}
+
 #[test]
 fn test_memory_trace_write_then_read() {
     let mut trace = MemoryTrace::new(8);
@@ -232,4 +244,1342 @@ fn test_memory_operation_types() {
     };
     assert_eq!(read_op, read_op2);
     assert_ne!(read_op, write_op);
-}
\ No newline at end of file
+}
+
+/// Serialize every component of a Twist proof for byte-level comparisons
+fn proof_bytes(proof: &TwistProof) -> Vec<u8> {
+    use ark_serialize::CanonicalSerialize;
+    
+    let mut bytes = Vec::new();
+    proof.params.log_size.serialize_compressed(&mut bytes).unwrap();
+    proof.params.max_operations.serialize_compressed(&mut bytes).unwrap();
+    proof.params.vk_hash.serialize_compressed(&mut bytes).unwrap();
+    proof.num_operations.serialize_compressed(&mut bytes).unwrap();
+    proof.memory_size.serialize_compressed(&mut bytes).unwrap();
+    proof.constraints.serialize_compressed(&mut bytes).unwrap();
+    proof.address_commitment.serialize_compressed(&mut bytes).unwrap();
+    proof.value_commitment.serialize_compressed(&mut bytes).unwrap();
+    proof.op_commitment.serialize_compressed(&mut bytes).unwrap();
+    proof.timestamp_commitment.serialize_compressed(&mut bytes).unwrap();
+    proof.consistency_proof.round_polynomials.serialize_compressed(&mut bytes).unwrap();
+    proof.consistency_proof.final_evaluation.serialize_compressed(&mut bytes).unwrap();
+    proof.consistency_opening.serialize_compressed(&mut bytes).unwrap();
+    proof.range_proof.is_some().serialize_compressed(&mut bytes).unwrap();
+    if let Some(range) = &proof.range_proof {
+        range.value_bits.serialize_compressed(&mut bytes).unwrap();
+        range.bit_commitments.serialize_compressed(&mut bytes).unwrap();
+        range.quotient_commitment.serialize_compressed(&mut bytes).unwrap();
+        range.opening_proofs.serialize_compressed(&mut bytes).unwrap();
+        range.evaluations.serialize_compressed(&mut bytes).unwrap();
+    }
+    proof.opening_proofs.serialize_compressed(&mut bytes).unwrap();
+    proof.final_evaluations.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+#[test]
+fn test_twist_recursive_layout_roundtrip() {
+    use ark_serialize::CanonicalSerialize;
+    
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(9u64)).unwrap();
+    trace.read(1).unwrap();
+    trace.write(6, FieldElement::from(4u64)).unwrap();
+    let proof = twist.prove(&trace).unwrap();
+    
+    // The component layout is the canonical compressed encoding
+    let mut component = Vec::new();
+    proof.serialize_with_layout(ProofLayout::Component, &mut component).unwrap();
+    let mut canonical = Vec::new();
+    proof.serialize_compressed(&mut canonical).unwrap();
+    assert_eq!(component, canonical);
+    
+    let mut bytes = Vec::new();
+    proof.serialize_with_layout(ProofLayout::Recursive, &mut bytes).unwrap();
+    let decoded = TwistProof::<KZGCommitment>::deserialize_with_layout(ProofLayout::Recursive, &bytes[..]).unwrap();
+    assert_eq!(proof_bytes(&decoded), proof_bytes(&proof));
+    assert!(twist.verify(&decoded, &verifier_params).unwrap());
+    
+    // Header, then every scalar, then every group element
+    let rounds = |sumcheck: &twist_and_shout::sumcheck::SumCheckProof| 1 + sumcheck.round_polynomials.len();
+    let read_sets = &proof.constraints.read_sets;
+    let header_entries = 10 + 2 * read_sets.len() + rounds(&proof.consistency_proof) + 2;
+    let header_len = 8 + 8 * header_entries;
+    
+    let mut scalars = Vec::new();
+    proof.params.vk_hash.serialize_compressed(&mut scalars).unwrap();
+    for value in read_sets.values().flatten() {
+        value.serialize_compressed(&mut scalars).unwrap();
+    }
+    for coefficient in proof.consistency_proof.round_polynomials.iter().flatten() {
+        coefficient.serialize_compressed(&mut scalars).unwrap();
+    }
+    proof.consistency_proof.final_evaluation.serialize_compressed(&mut scalars).unwrap();
+    for evaluation in &proof.consistency_opening.evaluations {
+        evaluation.serialize_compressed(&mut scalars).unwrap();
+    }
+    for evaluation in &proof.final_evaluations {
+        evaluation.serialize_compressed(&mut scalars).unwrap();
+    }
+    
+    let mut groups = Vec::new();
+    for commitment in [&proof.address_commitment, &proof.value_commitment, &proof.op_commitment, &proof.timestamp_commitment] {
+        commitment.serialize_compressed(&mut groups).unwrap();
+    }
+    for commitment in &proof.consistency_opening.commitments {
+        commitment.serialize_compressed(&mut groups).unwrap();
+    }
+    for quotient in &proof.consistency_opening.proof.combined.quotients {
+        quotient.serialize_compressed(&mut groups).unwrap();
+    }
+    for opening in &proof.opening_proofs {
+        opening.serialize_compressed(&mut groups).unwrap();
+    }
+    
+    assert_eq!(bytes.len(), header_len + scalars.len() + groups.len());
+    assert_eq!(&bytes[header_len..header_len + scalars.len()], &scalars[..]);
+    assert_eq!(&bytes[header_len + scalars.len()..], &groups[..]);
+    
+    // A truncated encoding is rejected
+    assert!(TwistProof::<KZGCommitment>::deserialize_with_layout(ProofLayout::Recursive, &bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn test_twist_prove_deterministic() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(7u64)).unwrap();
+    trace.read(2).unwrap();
+    trace.write(5, FieldElement::from(11u64)).unwrap();
+    
+    let twist = Twist::new(&prover_params);
+    let statement_hash = [7u8; 32];
+    
+    let proof1 = twist.prove_deterministic(&trace, statement_hash).unwrap();
+    let proof2 = twist.prove_deterministic(&trace, statement_hash).unwrap();
+    assert_eq!(proof_bytes(&proof1), proof_bytes(&proof2), "Same statement should yield identical proofs");
+    
+    assert!(twist.verify_deterministic(&proof1, &verifier_params, statement_hash).unwrap());
+}
+
+#[test]
+fn test_twist_verify_from_verifier_view() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(5u64)).unwrap();
+    trace.write(3, FieldElement::from(9u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    
+    let view = proof.verifier_view();
+    assert_eq!(view.opening_proofs.len(), proof.opening_proofs.len());
+    assert!(twist.verify_view(view, &verifier_params).unwrap(), "Verifier view should be sufficient to verify");
+}
+
+#[test]
+fn test_twist_operation_count_is_public_input() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(0, FieldElement::from(3u64)).unwrap();
+    trace.write(1, FieldElement::from(4u64)).unwrap();
+    trace.read(0).unwrap();
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    assert_eq!(proof.num_operations, 3);
+    
+    assert!(twist.verify_with_operation_count(&proof, &verifier_params, 3).unwrap());
+    assert!(!twist.verify_with_operation_count(&proof, &verifier_params, 4).unwrap());
+    
+    // Claiming more operations than were proven changes the transcript and is rejected
+    let mut forged = proof.clone();
+    forged.num_operations = 4;
+    assert!(!twist.verify_with_operation_count(&forged, &verifier_params, 4).unwrap());
+    
+    // Sixteen operations need four sum-check rounds over the eight cells, not
+    // three, so the count is rejected before any challenge is drawn
+    forged.num_operations = 16;
+    let report = twist.verify_verbose(&forged, &verifier_params).unwrap();
+    assert!(!report.check("round_count").unwrap().passed);
+    assert!(!twist.verify(&forged, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_sorted_view_argument() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(3, FieldElement::from(30u64)).unwrap();
+    trace.write(1, FieldElement::from(10u64)).unwrap();
+    trace.read(3).unwrap();
+    trace.write(1, FieldElement::from(11u64)).unwrap();
+    trace.read(0).unwrap();
+    
+    let sorted = trace.sorted_view();
+    let addresses: Vec<usize> = sorted.iter().map(|op| op.address()).collect();
+    assert_eq!(addresses, vec![0, 1, 1, 3, 3]);
+    // Stable: operations on the same address keep trace order
+    assert_eq!(sorted[1], MemoryOp::Write { address: 1, value: FieldElement::from(10u64) });
+    assert_eq!(sorted[2], MemoryOp::Write { address: 1, value: FieldElement::from(11u64) });
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove_sorted(&trace, &sorted).unwrap();
+    assert!(twist.verify_sorted(&proof, &verifier_params).unwrap());
+    
+    // The proof is bound to its commitments and evaluations, and is no
+    // interleaved consistency proof
+    let mut swapped = proof.clone();
+    swapped.sorted_value_commitment = proof.value_commitment.clone();
+    assert!(!twist.verify_sorted(&swapped, &verifier_params).unwrap());
+    for i in 0..proof.final_evaluations.len() {
+        let mut tampered = proof.clone();
+        tampered.final_evaluations[i] += FieldElement::one();
+        assert!(!twist.verify_sorted(&tampered, &verifier_params).unwrap());
+    }
+    let mut tampered = proof.clone();
+    tampered.consistency_proof.final_evaluation += FieldElement::one();
+    assert!(!twist.verify_sorted(&tampered, &verifier_params).unwrap());
+    assert!(!twist.verify_interleaved(&proof, &verifier_params).unwrap());
+    
+    // Writes to one address must keep their trace order
+    let mut reordered = sorted.clone();
+    reordered.swap(1, 2);
+    assert!(twist.prove_sorted(&trace, &reordered).is_err());
+    
+    // A permutation that is not sorted fails the sortedness constraint
+    let mut unsorted = sorted.clone();
+    unsorted.swap(0, 4);
+    assert!(twist.prove_sorted(&trace, &unsorted).is_err());
+    
+    // A sorted view that is not a permutation fails the permutation constraint
+    let mut tampered = sorted.clone();
+    tampered[2] = MemoryOp::Write { address: 1, value: FieldElement::from(12u64) };
+    assert!(twist.prove_sorted(&trace, &tampered).is_err());
+    
+    // Addresses compare by value, not by their low bits first: 1 < 2
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(20u64)).unwrap();
+    trace.write(1, FieldElement::from(10u64)).unwrap();
+    let sorted = trace.sorted_view();
+    let proof = twist.prove_sorted(&trace, &sorted).unwrap();
+    assert!(twist.verify_sorted(&proof, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_estimate_prove_cost() {
+    let (prover_params, _) = setup_params(4);
+    let twist = Twist::new(&prover_params);
+    
+    let mut small = MemoryTrace::new(16);
+    for i in 0..3 {
+        small.write(i, FieldElement::from(i as u64)).unwrap();
+    }
+    
+    let mut large = MemoryTrace::new(16);
+    for i in 0..20 {
+        large.write(i % 16, FieldElement::from(i as u64)).unwrap();
+    }
+    
+    let small_cost = twist.estimate_prove_cost(&small);
+    let large_cost = twist.estimate_prove_cost(&large);
+    
+    assert_eq!(small_cost.padded_operations, 4);
+    assert_eq!(small_cost.sumcheck_rounds, 2);
+    assert_eq!(large_cost.padded_operations, 32);
+    assert_eq!(large_cost.sumcheck_rounds, 5);
+    
+    assert!(large_cost.field_multiplications > small_cost.field_multiplications);
+    assert!(large_cost.msm_size > small_cost.msm_size);
+}
+
+#[test]
+fn test_twist_prove_streaming_from_file() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    for i in 0..6 {
+        trace.write(i, FieldElement::from((i * 10) as u64)).unwrap();
+        trace.read((i + 7) % 8).unwrap();
+    }
+    
+    let path = std::env::temp_dir().join(format!("twist_streaming_{}.trace", std::process::id()));
+    trace.save(&path).unwrap();
+    
+    // The file replays the exact operation sequence
+    let replayed: Vec<MemoryOp> = StreamingTrace::open(&path)
+        .unwrap()
+        .collect::<Result<_>>()
+        .unwrap();
+    assert_eq!(replayed, trace.operations);
+    
+    let twist = Twist::new(&prover_params);
+    let streamed = twist.prove_streaming(&path).unwrap();
+    let in_memory = twist.prove(&trace).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    
+    assert_eq!(proof_bytes(&streamed), proof_bytes(&in_memory));
+    assert!(twist.verify(&streamed, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_prove_streaming_rejects_oversized_header() {
+    let (prover_params, _) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    
+    // A 2^40-cell header is refused before any memory state is allocated
+    let path = std::env::temp_dir().join(format!("twist_oversized_{}.trace", std::process::id()));
+    std::fs::write(&path, (1u64 << 40).to_le_bytes()).unwrap();
+    let streamed = twist.prove_streaming(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(streamed.is_err());
+    
+    assert!(twist.incremental(16).is_err());
+    assert!(twist.incremental(8).is_ok());
+}
+
+#[test]
+fn test_incremental_twist_matches_batch_proof() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    for i in 0..7 {
+        trace.write(i, FieldElement::from((i * 3 + 1) as u64)).unwrap();
+        trace.read((i + 5) % 8).unwrap();
+    }
+    
+    let twist = Twist::new(&prover_params);
+    let mut incremental = twist.incremental(8).unwrap();
+    for op in &trace.operations {
+        incremental.push_op(*op).unwrap();
+    }
+    assert_eq!(incremental.num_operations(), trace.operations.len());
+    
+    let pushed = incremental.finalize().unwrap();
+    let batch = twist.prove(&trace).unwrap();
+    assert_eq!(proof_bytes(&pushed), proof_bytes(&batch));
+    assert!(twist.verify(&pushed, &verifier_params).unwrap());
+    
+    // A read of a value never written is rejected as it arrives
+    let mut incremental = twist.incremental(8).unwrap();
+    incremental.push_op(MemoryOp::Write { address: 1, value: FieldElement::from(4u64) }).unwrap();
+    assert!(incremental.push_op(MemoryOp::Read { address: 1, value: FieldElement::from(5u64) }).is_err());
+    assert!(incremental.push_op(MemoryOp::Write { address: 8, value: FieldElement::from(5u64) }).is_err());
+}
+
+#[test]
+fn test_twist_word_memory_round_trip() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let word = |base: u64| [0, 1, 2, 3].map(|limb| FieldElement::from(base + limb));
+    let mut trace = WordMemoryTrace::<4>::new(8);
+    trace.write(2, word(100)).unwrap();
+    trace.write(5, word(200)).unwrap();
+    assert_eq!(trace.read(2).unwrap(), word(100));
+    assert_eq!(trace.read(5).unwrap(), word(200));
+    assert_eq!(trace.read(7).unwrap(), [FieldElement::zero(); 4]);
+    assert_eq!(trace.num_operations(), 5);
+    assert!(trace.write(8, word(1)).is_err());
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove_words(&trace).unwrap();
+    assert_eq!(proof.lane_commitments.len(), 4);
+    assert!(twist.verify_words(&proof, &verifier_params).unwrap());
+    
+    assert_eq!(proof.memory_proof.num_operations, 5);
+    assert!(twist.verify_words(&proof, &verifier_params).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.final_evaluations[3] += FieldElement::one();
+    assert!(!twist.verify_words(&tampered, &verifier_params).unwrap());
+    
+    // The combined column must open to the λ-combination of the lanes
+    let mut recombined = proof.clone();
+    recombined.final_evaluations[4] += FieldElement::one();
+    assert!(!twist.verify_words(&recombined, &verifier_params).unwrap());
+    
+    // Swapping two lanes changes the combination the memory check ran on
+    let mut swapped = proof.clone();
+    swapped.lane_commitments.swap(0, 1);
+    swapped.opening_proofs.swap(0, 1);
+    swapped.final_evaluations.swap(0, 1);
+    assert!(!twist.verify_words(&swapped, &verifier_params).unwrap());
+    
+    // A memory check over another trace does not verify against these lanes
+    let mut other_trace = trace.clone();
+    other_trace.write(1, word(300)).unwrap();
+    let other = twist.prove_words(&other_trace).unwrap();
+    let mut mixed = proof.clone();
+    mixed.memory_proof = other.memory_proof;
+    assert!(!twist.verify_words(&mixed, &verifier_params).unwrap());
+    
+    let mut dropped_lane = proof;
+    dropped_lane.lane_commitments.pop();
+    assert!(!twist.verify_words(&dropped_lane, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_single_operation_trace() {
+    let (prover_params, verifier_params) = setup_params(2);
+    
+    let mut trace = MemoryTrace::new(4);
+    trace.write(2, FieldElement::from(99u64)).unwrap();
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    
+    // One operation still leaves a row per memory cell to check
+    assert_eq!(proof.consistency_proof.round_polynomials.len(), 2);
+    assert_eq!(proof.opening_proofs.len(), 5, "Commitments must still be opened");
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    
+    // Dropping the openings is no longer accepted
+    let mut stripped = proof.clone();
+    stripped.opening_proofs.clear();
+    stripped.final_evaluations.clear();
+    assert!(!twist.verify(&stripped, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_constraint_debugging_accepts_valid_trace() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(3, FieldElement::from(11u64)).unwrap();
+    trace.read(3).unwrap();
+    trace.write(5, FieldElement::from(12u64)).unwrap();
+    
+    let twist = Twist::new(&prover_params).with_constraint_debugging(true);
+    let proof = twist.prove(&trace).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_timestamp_monotonicity() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(4u64)).unwrap();
+    trace.read(1).unwrap();
+    trace.write(2, FieldElement::from(9u64)).unwrap();
+    trace.read(2).unwrap();
+    assert_eq!(trace.timestamps, vec![0, 1, 2, 3]);
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    assert_eq!(proof.timestamp_commitment(), &proof.timestamp_commitment);
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    
+    // Gaps are fine as long as timestamps strictly increase
+    let mut sparse = trace.clone();
+    sparse.timestamps = vec![3, 10, 11, 40];
+    let sparse_proof = twist.prove(&sparse).unwrap();
+    assert!(twist.verify(&sparse_proof, &verifier_params).unwrap());
+    assert_ne!(sparse_proof.timestamp_commitment(), proof.timestamp_commitment());
+    
+    // A forged non-monotone sequence cannot be proven, and debugging names the row
+    let mut forged = trace.clone();
+    forged.timestamps = vec![0, 2, 2, 3];
+    assert!(twist.prove(&forged).is_err());
+    let err = twist.clone().with_constraint_debugging(true).prove(&forged).unwrap_err();
+    assert!(err.to_string().contains("row 2"), "unexpected error: {}", err);
+    
+    // The timestamp commitment's openings must back the monotonicity rows
+    for opening in [3, 4] {
+        let mut tampered = proof.clone();
+        tampered.final_evaluations[opening] += FieldElement::one();
+        assert!(!twist.verify(&tampered, &verifier_params).unwrap());
+    }
+}
+
+#[test]
+fn test_memory_trace_from_operations() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut recorded = MemoryTrace::new(8);
+    recorded.write(3, FieldElement::from(11u64)).unwrap();
+    recorded.read(3).unwrap();
+    recorded.read(5).unwrap();
+    recorded.write(3, FieldElement::from(12u64)).unwrap();
+    recorded.read(3).unwrap();
+    
+    let replayed = MemoryTrace::from_operations(8, recorded.operations.clone()).unwrap();
+    assert_eq!(replayed.operations, recorded.operations);
+    assert_eq!(replayed.timestamps, recorded.timestamps);
+    assert_eq!(replayed.peek(3).unwrap(), FieldElement::from(12u64));
+    assert_eq!(replayed.peek(5).unwrap(), FieldElement::zero());
+    
+    let twist = Twist::new(&prover_params);
+    assert!(twist.verify(&twist.prove(&replayed).unwrap(), &verifier_params).unwrap());
+    
+    // A read recording a stale value is rejected
+    let mut stale = recorded.operations.clone();
+    stale[4] = MemoryOp::Read { address: 3, value: FieldElement::from(11u64) };
+    let err = MemoryTrace::from_operations(8, stale).unwrap_err();
+    assert!(err.to_string().contains("operation 4"), "unexpected error: {}", err);
+    
+    // So are out-of-bounds addresses and non-power-of-two memories
+    let out_of_bounds = vec![MemoryOp::Write { address: 8, value: FieldElement::one() }];
+    assert!(MemoryTrace::from_operations(8, out_of_bounds).is_err());
+    assert!(MemoryTrace::from_operations(6, Vec::new()).is_err());
+}
+
+#[test]
+fn test_twist_reordered_operations_fail() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(4u64)).unwrap();
+    trace.read(1).unwrap();
+    trace.write(1, FieldElement::from(7u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    
+    // Swapping two operations along with their timestamps breaks monotonicity
+    let mut reordered = trace.clone();
+    reordered.operations.swap(1, 2);
+    reordered.timestamps.swap(1, 2);
+    assert!(twist.prove(&reordered).is_err());
+    
+    // Keeping the timestamps in place instead makes the read return a stale value
+    let mut restamped = trace.clone();
+    restamped.operations.swap(1, 2);
+    assert!(twist.prove(&restamped).is_err());
+    
+    // The timestamp column is bound into the transcript
+    let mut sparse = trace.clone();
+    sparse.timestamps = vec![0, 2, 3, 5];
+    let sparse_proof = twist.prove(&sparse).unwrap();
+    let mut spliced = proof.clone();
+    spliced.timestamp_commitment = sparse_proof.timestamp_commitment.clone();
+    assert!(!twist.verify(&spliced, &verifier_params).unwrap());
+    
+    // Appending after edited timestamps continues the counter
+    sparse.read(1).unwrap();
+    assert_eq!(sparse.timestamps, vec![0, 2, 3, 5, 6]);
+    let timestamped: Vec<u64> = sparse.timestamped_operations().map(|(timestamp, _)| timestamp).collect();
+    assert_eq!(timestamped, sparse.timestamps);
+    assert!(twist.verify(&twist.prove(&sparse).unwrap(), &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_prove_refinement() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut concrete = MemoryTrace::new(8);
+    concrete.write(1, FieldElement::from(10u64)).unwrap();
+    concrete.write(4, FieldElement::from(40u64)).unwrap();
+    concrete.read(1).unwrap();
+    
+    // The specification lists the same operations in a different order
+    let abstract_ops = vec![
+        MemoryOp::Write { address: 4, value: FieldElement::from(40u64) },
+        MemoryOp::Write { address: 1, value: FieldElement::from(10u64) },
+        MemoryOp::Read { address: 1, value: FieldElement::from(10u64) },
+    ];
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove_refinement(&concrete, &abstract_ops).unwrap();
+    assert!(twist.verify(&proof.trace_proof, &verifier_params).unwrap());
+    assert!(twist.verify_refinement(&proof, &abstract_ops, &verifier_params).unwrap());
+    
+    // A specification with different observable behavior cannot be proven ...
+    let mut mismatched = abstract_ops.clone();
+    mismatched[2] = MemoryOp::Read { address: 1, value: FieldElement::from(11u64) };
+    assert!(twist.prove_refinement(&concrete, &mismatched).is_err());
+    
+    // ... and an honest refinement proof does not verify against it
+    assert!(!twist.verify_refinement(&proof, &mismatched, &verifier_params).unwrap());
+    
+    // The argument is bound to the trace proof's commitments: another
+    // trace's proof cannot stand in
+    let mut other = MemoryTrace::new(8);
+    other.write(1, FieldElement::from(10u64)).unwrap();
+    other.write(4, FieldElement::from(41u64)).unwrap();
+    other.read(1).unwrap();
+    let mut swapped = proof.clone();
+    swapped.trace_proof = twist.prove(&other).unwrap();
+    assert!(!twist.verify_refinement(&swapped, &abstract_ops, &verifier_params).unwrap());
+    
+    // ... and every evaluation it rests on is checked
+    let mut tampered = proof.clone();
+    tampered.refinement_proof.final_evaluation += FieldElement::one();
+    assert!(!twist.verify_refinement(&tampered, &abstract_ops, &verifier_params).unwrap());
+    for i in 0..proof.final_evaluations.len() {
+        let mut tampered = proof.clone();
+        tampered.final_evaluations[i] += FieldElement::one();
+        assert!(!twist.verify_refinement(&tampered, &abstract_ops, &verifier_params).unwrap());
+    }
+    let mut tampered = proof.clone();
+    tampered.refinement_opening.evaluations[3] += FieldElement::one();
+    assert!(!twist.verify_refinement(&tampered, &abstract_ops, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_prove_with_range() {
+    let (prover_params, verifier_params) = setup_params(2);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(4);
+    trace.write(0, FieldElement::from(u32::MAX as u64)).unwrap();
+    trace.write(3, FieldElement::from(7u64)).unwrap();
+    trace.read(0).unwrap();
+    
+    let proof = twist.prove_with_range(&trace, 32).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    assert!(twist.verify_with_range(&proof, 32, &verifier_params).unwrap());
+    assert!(!twist.verify_with_range(&proof, 16, &verifier_params).unwrap());
+    
+    let mut bytes = Vec::new();
+    proof.serialize_with_layout(ProofLayout::Recursive, &mut bytes).unwrap();
+    let decoded = TwistProof::<KZGCommitment>::deserialize_with_layout(ProofLayout::Recursive, &bytes[..]).unwrap();
+    assert_eq!(proof_bytes(&decoded), proof_bytes(&proof));
+    assert_eq!(proof.size_breakdown().total(), proof_bytes(&proof).len());
+    
+    // The range width closes the header; one outside 1 to 64 bits is
+    // rejected before it sizes any read
+    let header_len = u64::from_le_bytes(bytes[..8].try_into().unwrap()) as usize;
+    let width = 8 * header_len..8 * header_len + 8;
+    assert_eq!(bytes[width.clone()], 32u64.to_le_bytes());
+    for value_bits in [0, 65, u64::MAX] {
+        let mut corrupted = bytes.clone();
+        corrupted[width.clone()].copy_from_slice(&value_bits.to_le_bytes());
+        assert!(TwistProof::<KZGCommitment>::deserialize_with_layout(ProofLayout::Recursive, &corrupted[..]).is_err());
+    }
+    
+    // 2^32 does not fit in 32 bits, so no range argument exists for it ...
+    let mut out_of_range = MemoryTrace::new(4);
+    out_of_range.write(1, FieldElement::from(1u64 << 32)).unwrap();
+    out_of_range.write(3, FieldElement::from(7u64)).unwrap();
+    out_of_range.read(0).unwrap();
+    assert!(twist.prove_with_range(&out_of_range, 32).is_err());
+    assert!(twist.prove_with_range(&out_of_range, 33).is_ok());
+    
+    // ... a plain proof of the trace carries none, and one borrowed from an
+    // in-range trace does not match its value commitment
+    let mut plain = twist.prove(&out_of_range).unwrap();
+    assert!(!twist.verify_with_range(&plain, 32, &verifier_params).unwrap());
+    plain.range_proof = proof.range_proof.clone();
+    assert!(twist.verify(&plain, &verifier_params).unwrap());
+    assert!(!twist.verify_with_range(&plain, 32, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_consistency_constraint_mle() {
+    let (prover_params, _) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.read(4).unwrap(); // Unwritten memory reads as zero
+    trace.write(4, FieldElement::from(9u64)).unwrap();
+    trace.read(4).unwrap();
+    trace.write(4, FieldElement::from(10u64)).unwrap();
+    trace.read(4).unwrap();
+    
+    let constraint = twist.consistency_constraint_mle(&trace).unwrap();
+    assert_eq!(constraint.num_vars, 3);
+    assert!(constraint.evaluations.iter().all(|e| e.is_zero()));
+    
+    // A read returning a stale value makes its row twice its error
+    let mut inconsistent = trace.clone();
+    inconsistent.operations[4] = MemoryOp::Read { address: 4, value: FieldElement::from(9u64) };
+    let constraint = twist.consistency_constraint_mle(&inconsistent).unwrap();
+    assert_eq!(twist_and_shout::polynomials::debug_find_violation(&constraint), Some(4));
+    assert_eq!(constraint.evaluations[4], -FieldElement::from(2u64));
+    assert!(twist.prove(&inconsistent).is_err());
+}
+
+#[test]
+fn test_twist_size_breakdown() {
+    let (prover_params, _) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    for i in 0..5 {
+        trace.write(i, FieldElement::from(i as u64 + 1)).unwrap();
+    }
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    let breakdown = proof.size_breakdown();
+    // Four univariate column commitments and 81 memory-checking column
+    // commitments, 64 of them timestamp step bits, the latter in a
+    // length-prefixed vector
+    assert_eq!(breakdown.commitments, (4 + 81) * 32 + 8);
+    assert_eq!(breakdown.total(), proof_bytes(&proof).len());
+}
+
+#[test]
+fn test_twist_verify_verbose_pinpoints_opening() {
+    let (prover_params, verifier_params) = setup_params(3);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(7u64)).unwrap();
+    trace.read(1).unwrap();
+    trace.write(2, FieldElement::from(9u64)).unwrap();
+    
+    let twist = Twist::new(&prover_params);
+    let proof = twist.prove(&trace).unwrap();
+    
+    let report = twist.verify_verbose(&proof, &verifier_params).unwrap();
+    assert!(report.is_valid(), "{}", report);
+    
+    let mut tampered = proof.clone();
+    tampered.final_evaluations[0] += FieldElement::from(1u64);
+    
+    // The openings are checked before the sum-check, whose claim the
+    // tampered evaluation also changes
+    let report = twist.verify_verbose(&tampered, &verifier_params).unwrap();
+    assert!(!report.is_valid());
+    assert!(!report.check("address_opening").unwrap().passed);
+    assert!(report.check("value_opening").unwrap().passed);
+    assert!(report.check("op_opening").unwrap().passed);
+    let failures: Vec<_> = report.failures().map(|check| check.name).collect();
+    assert_eq!(failures, vec!["address_opening", "consistency_sumcheck"]);
+    assert!(!twist.verify(&tampered, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_align_batch() {
+    let (prover_params, verifier_params) = setup_params(4);
+    
+    let mut traces = Vec::new();
+    for num_ops in [3usize, 5, 8] {
+        let mut trace = MemoryTrace::new(8);
+        // Address 0 holds a nonzero value, so a zero-valued read there would
+        // violate the constraint if padding rows were treated as reads
+        trace.write(0, FieldElement::from(99u64)).unwrap();
+        for i in 1..num_ops {
+            if i % 2 == 0 {
+                trace.read(0).unwrap();
+            } else {
+                trace.write(i % 8, FieldElement::from(i as u64)).unwrap();
+            }
+        }
+        traces.push(trace);
+    }
+    
+    MemoryTrace::align_batch(&mut traces);
+    
+    let twist = Twist::new(&prover_params);
+    for trace in &traces {
+        assert_eq!(trace.operations.len(), 8);
+        assert_eq!(trace.timestamps.len(), 8);
+        assert!(trace.timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+        
+        let constraint = twist.consistency_constraint_mle(trace).unwrap();
+        assert_eq!(twist_and_shout::polynomials::debug_find_violation(&constraint), None);
+        
+        let proof = twist.prove(trace).unwrap();
+        assert_eq!(proof.consistency_proof.round_polynomials.len(), 3);
+        assert!(twist.verify(&proof, &verifier_params).unwrap());
+    }
+    assert_eq!(traces[0].operations.iter().filter(|op| op.is_padding()).count(), 5);
+    assert_eq!(traces[2].operations.iter().filter(|op| op.is_padding()).count(), 0);
+}
+
+#[test]
+fn test_twist_prove_interleaved() {
+    let (prover_params, verifier_params) = setup_params(4);
+    
+    // Three addresses accessed in interleaved order
+    let mut trace = MemoryTrace::new(8);
+    trace.write(5, FieldElement::from(50u64)).unwrap();
+    trace.write(1, FieldElement::from(10u64)).unwrap();
+    trace.read(5).unwrap();
+    trace.write(3, FieldElement::from(30u64)).unwrap();
+    trace.read(1).unwrap();
+    trace.write(5, FieldElement::from(55u64)).unwrap();
+    trace.read(3).unwrap();
+    trace.read(5).unwrap();
+    
+    let twist = Twist::new(&prover_params).with_constraint_debugging(true);
+    let proof = twist.prove_interleaved(&trace).unwrap();
+    assert_eq!(proof.num_operations, 8);
+    assert!(twist.verify_interleaved(&proof, &verifier_params).unwrap());
+    
+    // The statement and every committed value are bound
+    let mut tampered = proof.clone();
+    tampered.num_operations = 7;
+    assert!(!twist.verify_interleaved(&tampered, &verifier_params).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.memory_size = 16;
+    assert!(!twist.verify_interleaved(&tampered, &verifier_params).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.sorted_value_commitment = proof.value_commitment.clone();
+    assert!(!twist.verify_interleaved(&tampered, &verifier_params).unwrap());
+    
+    for index in [0, 8] {
+        let mut tampered = proof.clone();
+        tampered.final_evaluations[index] += FieldElement::from(1u64);
+        assert!(!twist.verify_interleaved(&tampered, &verifier_params).unwrap());
+    }
+    
+    let mut tampered = proof.clone();
+    tampered.consistency_opening.evaluations[4] += FieldElement::from(1u64);
+    assert!(!twist.verify_interleaved(&tampered, &verifier_params).unwrap());
+    
+    let mut tampered = proof;
+    tampered.opening_proofs.pop();
+    assert!(!twist.verify_interleaved(&tampered, &verifier_params).unwrap());
+}
+
+#[test]
+fn test_twist_prove_interleaved_rejects_read_before_write() {
+    let (prover_params, _) = setup_params(4);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(20u64)).unwrap();
+    trace.read(6).unwrap();
+    trace.write(6, FieldElement::from(60u64)).unwrap();
+    trace.read(2).unwrap();
+    
+    // The first access to address 6 claims a value no write has stored yet
+    trace.operations[1] = MemoryOp::Read { address: 6, value: FieldElement::from(60u64) };
+    
+    let twist = Twist::new(&prover_params);
+    assert!(twist.prove_interleaved(&trace).is_err());
+}
+
+#[test]
+fn test_twist_rejects_corrupted_read() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(10u64)).unwrap();
+    trace.write(2, FieldElement::from(20u64)).unwrap();
+    trace.read(1).unwrap();
+    trace.read(2).unwrap();
+    assert!(twist.verify(&twist.prove(&trace).unwrap(), &verifier_params).unwrap());
+    
+    let rejects = |trace: &MemoryTrace| match twist.prove(trace) {
+        Ok(proof) => !twist.verify(&proof, &verifier_params).unwrap(),
+        Err(_) => true,
+    };
+    
+    // A single altered read value
+    let mut corrupted = trace.clone();
+    corrupted.operations[2] = MemoryOp::Read { address: 1, value: FieldElement::from(11u64) };
+    assert!(rejects(&corrupted));
+    
+    // Two altered reads whose errors cancel in a plain sum over the rows
+    let mut cancelling = trace.clone();
+    cancelling.operations[2] = MemoryOp::Read { address: 1, value: FieldElement::from(11u64) };
+    cancelling.operations[3] = MemoryOp::Read { address: 2, value: FieldElement::from(19u64) };
+    let constraint = twist.consistency_constraint_mle(&cancelling).unwrap();
+    assert!(constraint.evaluations.iter().sum::<FieldElement>().is_zero());
+    assert!(rejects(&cancelling));
+}
+
+#[test]
+fn test_twist_refuses_to_prove_bogus_read() {
+    let (prover_params, _) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    
+    // Built by hand, bypassing MemoryTrace::read
+    let mut trace = MemoryTrace::new(8);
+    trace.write(3, FieldElement::from(7u64)).unwrap();
+    trace.operations.push(MemoryOp::Read { address: 3, value: FieldElement::from(8u64) });
+    trace.timestamps.push(1);
+    
+    let err = trace.validate().unwrap_err();
+    assert!(matches!(err, TwistAndShoutError::ProofGeneration(_)));
+    assert!(err.to_string().contains("operation 1"), "unexpected error: {}", err);
+    assert!(matches!(twist.prove(&trace), Err(TwistAndShoutError::ProofGeneration(_))));
+    
+    // Every other way of proving the trace refuses it too
+    assert!(twist.prove_interleaved(&trace).is_err());
+    assert!(twist.prove_sorted(&trace, &trace.operations).is_err());
+    assert!(twist.prove_with_range(&trace, 8).is_err());
+    let path = std::env::temp_dir().join(format!("twist_bogus_read_{}.trace", std::process::id()));
+    trace.save(&path).unwrap();
+    let streamed = twist.prove_streaming(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert!(streamed.is_err());
+    
+    // Reads of never-written cells must record zero, and addresses must be in bounds
+    let mut unwritten = MemoryTrace::new(8);
+    unwritten.operations.push(MemoryOp::Read { address: 5, value: FieldElement::one() });
+    assert!(unwritten.validate().is_err());
+    let mut out_of_bounds = MemoryTrace::new(8);
+    out_of_bounds.operations.push(MemoryOp::Write { address: 8, value: FieldElement::one() });
+    assert!(out_of_bounds.validate().is_err());
+    
+    trace.operations[1] = MemoryOp::Read { address: 3, value: FieldElement::from(7u64) };
+    assert!(trace.validate().is_ok());
+    assert!(twist.prove(&trace).is_ok());
+}
+
+#[test]
+fn test_twist_read_in_set() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let twist = Twist::new(&prover_params);
+    let opcodes: Vec<FieldElement> = [1u64, 2, 3].iter().map(|&op| FieldElement::from(op)).collect();
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(0, FieldElement::from(2u64)).unwrap();
+    trace.write(1, FieldElement::from(7u64)).unwrap();
+    trace.read(0).unwrap();
+    trace.read(1).unwrap();
+    
+    // Only reads can be constrained
+    assert!(trace.constrain_read_in_set(0, &opcodes).is_err());
+    assert!(trace.constrain_read_in_set(4, &opcodes).is_err());
+    
+    // A read returning an allowed value passes
+    let mut allowed = trace.clone();
+    allowed.constrain_read_in_set(2, &opcodes).unwrap();
+    let proof = twist.prove(&allowed).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    assert!(twist.verify_with_constraints(&proof, &verifier_params, &allowed.constraints()).unwrap());
+    
+    // The verifier rejects a proof made under different constraints
+    assert!(!twist.verify_with_constraints(&proof, &verifier_params, &trace.constraints()).unwrap());
+    
+    // Dropping the read set from the proof breaks the transcript
+    let mut stripped = proof.clone();
+    stripped.constraints = trace.constraints();
+    assert!(!twist.verify(&stripped, &verifier_params).unwrap());
+    
+    // Narrowing the set to exclude the read value fails
+    let mut narrowed = proof.clone();
+    narrowed.constraints.read_sets.insert(2, vec![FieldElement::from(1u64), FieldElement::from(3u64)]);
+    assert!(!twist.verify(&narrowed, &verifier_params).unwrap());
+    
+    // A consistent read returning a disallowed value fails
+    let mut disallowed = trace.clone();
+    disallowed.constrain_read_in_set(3, &opcodes).unwrap();
+    assert!(twist.prove(&disallowed).is_err());
+    assert!(twist.clone().with_constraint_debugging(true).prove(&disallowed).is_err());
+}
+
+#[test]
+fn test_twist_public_digest() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(5u64)).unwrap();
+    trace.read(2).unwrap();
+    let proof = twist.prove(&trace).unwrap();
+    
+    let mut other_trace = trace.clone();
+    other_trace.write(3, FieldElement::from(6u64)).unwrap();
+    let other = twist.prove(&other_trace).unwrap();
+    
+    let digest = proof.public_digest();
+    assert_eq!(digest, twist.prove(&trace).unwrap().public_digest());
+    
+    // Changing any public input changes the digest
+    let mut changed = Vec::new();
+    let mut modified = proof.clone();
+    modified.num_operations += 1;
+    changed.push(modified);
+    let mut modified = proof.clone();
+    modified.params.log_size += 1;
+    changed.push(modified);
+    let mut modified = proof.clone();
+    modified.params.max_operations += 1;
+    changed.push(modified);
+    let mut modified = proof.clone();
+    modified.params.vk_hash += FieldElement::from(1u64);
+    changed.push(modified);
+    let mut modified = proof.clone();
+    modified.address_commitment = other.address_commitment.clone();
+    changed.push(modified);
+    let mut modified = proof.clone();
+    modified.value_commitment = other.value_commitment.clone();
+    changed.push(modified);
+    let mut modified = proof.clone();
+    modified.timestamp_commitment = other.timestamp_commitment.clone();
+    changed.push(modified);
+    for modified in &changed {
+        assert_ne!(modified.public_digest(), digest);
+    }
+    
+    assert!(twist.verify_with_public_digest(&proof, &verifier_params, Some(digest)).unwrap());
+    assert!(twist.verify_with_public_digest(&proof, &verifier_params, None).unwrap());
+    assert!(!twist.verify_with_public_digest(&proof, &verifier_params, Some(other.public_digest())).unwrap());
+}
+
+#[test]
+fn test_twist_proof_serialization_roundtrip() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    
+    let (prover_params, verifier_params) = setup_params(4);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(1, FieldElement::from(11u64)).unwrap();
+    trace.write(6, FieldElement::from(66u64)).unwrap();
+    trace.read(1).unwrap();
+    
+    for proof in [twist.prove(&trace).unwrap(), twist.prove_with_range(&trace, 8).unwrap()] {
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), proof.size_breakdown().total());
+        
+        let decoded = TwistProof::<KZGCommitment>::deserialize_compressed(&bytes[..]).unwrap();
+        let mut reencoded = Vec::new();
+        decoded.serialize_compressed(&mut reencoded).unwrap();
+        assert_eq!(bytes, reencoded);
+        assert_eq!(decoded.public_digest(), proof.public_digest());
+        assert_eq!(decoded.range_proof.is_some(), proof.range_proof.is_some());
+        assert!(twist.verify(&decoded, &verifier_params).unwrap());
+        
+        // Truncated input is rejected rather than misread
+        assert!(TwistProof::<KZGCommitment>::deserialize_compressed(&bytes[..bytes.len() - 1]).is_err());
+    }
+}
+
+#[test]
+fn test_twist_sized_values() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write_sized(0, FieldElement::from(0xabu64), 8).unwrap();
+    trace.write_sized(1, FieldElement::from(0xdead_beefu64), 32).unwrap();
+    trace.write_sized(2, FieldElement::from(0x7fu64), 8).unwrap();
+    trace.read(1).unwrap();
+    trace.read(0).unwrap();
+    trace.write(3, FieldElement::from(5u64)).unwrap();
+    
+    assert!(trace.write_sized(4, FieldElement::from(256u64), 8).is_err());
+    assert!(trace.write_sized(4, FieldElement::from(1u64), 65).is_err());
+    
+    // Reads inherit the width of their cell; plain writes are unsized
+    assert_eq!(trace.width(3), Some(32));
+    assert_eq!(trace.width(4), Some(8));
+    assert_eq!(trace.width(5), None);
+    
+    // 8 + 32 + 8 + 32 + 8 bits fit in a single field element, lowest first
+    let packed = trace.packed_values();
+    assert_eq!(packed.len(), 1);
+    let expected = 0xabu128 | 0xdead_beef << 8 | 0x7f << 40 | 0xdead_beef << 48 | 0xab << 80;
+    assert_eq!(packed[0], FieldElement::from(expected));
+    
+    let proof = twist.prove(&trace).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    assert_eq!(proof.constraints.widths.get(&3), Some(&32));
+    assert!(twist.verify_with_constraints(&proof, &verifier_params, &trace.constraints()).unwrap());
+    
+    // The widths are public: a proof claiming other widths fails
+    let mut widened = proof.clone();
+    widened.constraints.widths.insert(4, 32);
+    assert!(!twist.verify(&widened, &verifier_params).unwrap());
+    let mut unsized_proof = proof.clone();
+    unsized_proof.constraints.widths.clear();
+    assert!(!twist.verify(&unsized_proof, &verifier_params).unwrap());
+    assert!(!twist.verify_with_constraints(&proof, &verifier_params, &unsized_proof.constraints).unwrap());
+    
+    // A consistent write and read of a byte cell that overflow 8 bits
+    let mut overflowing = trace.clone();
+    overflowing.operations[0] = MemoryOp::Write { address: 0, value: FieldElement::from(0x1abu64) };
+    overflowing.operations[4] = MemoryOp::Read { address: 0, value: FieldElement::from(0x1abu64) };
+    assert!(twist.prove(&overflowing).is_err());
+    assert!(twist.clone().with_constraint_debugging(true).prove(&overflowing).is_err());
+}
+
+#[test]
+fn test_packed_values_start_new_element_at_slot_boundary() {
+    let mut trace = MemoryTrace::new(8);
+    for i in 0..8u64 {
+        trace.write_sized(i as usize, FieldElement::from(u64::MAX - i), 64).unwrap();
+    }
+    
+    // Three 64-bit values per 248-bit slot
+    let packed = trace.packed_values();
+    assert_eq!(packed.len(), 3);
+    let shift = FieldElement::from(2u64).pow([64u64]);
+    assert_eq!(packed[2], FieldElement::from(u64::MAX - 6) + shift * FieldElement::from(u64::MAX - 7));
+}
+
+#[test]
+fn test_twist_challenge_log() {
+    use ark_std::rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+    let (prover_params, verifier_params) = setup_params_with_rng(4, &mut ChaCha20Rng::from_seed([59u8; 32]));
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(3, FieldElement::from(7u64)).unwrap();
+    trace.read(3).unwrap();
+    trace.write(5, FieldElement::from(9u64)).unwrap();
+    trace.read(5).unwrap();
+    let proof = twist.prove(&trace).unwrap();
+    
+    // Fingerprint challenges (2), bridge point and combiner (2), zero-check
+    // weights over the 8 memory rows (3), constraint and term separators (2),
+    // consistency rounds (3) and the column batching challenge (1)
+    let log = twist.challenge_log(&proof, &verifier_params).unwrap();
+    assert_eq!(log.len(), 13);
+    assert_eq!(log, twist.challenge_log(&proof, &verifier_params).unwrap());
+    
+    // Pinned for the seeded setup, so a change to the transcript or to the
+    // order challenges are drawn in shows up here
+    let expected: Vec<FieldElement> = [
+        "3347387592499761656326159224726812909689757953111871599624667647484936374523",
+        "11531331068361309274645969550199834662690849365872394156771837809518969310151",
+        "16477005563849706095777815138977633310556215846320435376965070300954612352504",
+        "3886331066441311489243677187221874663560274969302009372452584259576002765970",
+        "19020302001967183787456774682399519570952813499282821890000380970711826070084",
+        "13426672972466856634644891006336813547587844438894296734692495379773139870107",
+        "18509271814055395185674574563779238005155966362643089230788565828727055128961",
+        "18322582645008399071793894832774198430438257205308813425513701662261269648323",
+        "13918317522097002115144451658615065613287842311041368873436922728222160743711",
+        "17961419256720286606103078996926072394261043585301523078207979088919126242325",
+        "8394374944458643895541651501406218966911878393231808562508433565750491299109",
+        "9442225551341221064232189379757956617442140288024361276632195249212200507765",
+        "20699165212804897015572310631389177900724240242154931518077576159833064463581",
+    ]
+    .iter()
+    .map(|value| twist_and_shout::utils::field_from_str(value).unwrap())
+    .collect();
+    assert_eq!(log, expected);
+    assert_eq!(log, twist.challenge_log(&twist.prove(&trace).unwrap(), &verifier_params).unwrap());
+    
+    // A proof that fails verification has no meaningful log
+    let mut tampered = proof.clone();
+    tampered.final_evaluations[0] += FieldElement::one();
+    assert!(twist.challenge_log(&tampered, &verifier_params).is_err());
+}
+
+fn core_trace(operations: Vec<MemoryOp>) -> MemoryTrace {
+    let mut trace = MemoryTrace::new(8);
+    trace.timestamps = (0..operations.len() as u64).collect();
+    trace.operations = operations;
+    trace
+}
+
+#[test]
+fn test_twist_sequential_consistency() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    let one = FieldElement::one();
+    let two = FieldElement::from(2u64);
+    
+    // Message passing: core 1 only observes the flag after the data is written
+    let cores = vec![
+        core_trace(vec![
+            MemoryOp::Write { address: 0, value: two },
+            MemoryOp::Write { address: 1, value: one },
+        ]),
+        core_trace(vec![
+            MemoryOp::Read { address: 1, value: one },
+            MemoryOp::Read { address: 0, value: two },
+        ]),
+    ];
+    let proof = twist.prove_sequential_consistency(&cores).unwrap();
+    assert_eq!(proof.trace_proof.num_operations, 4);
+    assert_eq!(proof.final_rows.len(), 2);
+    assert!(twist.verify_sequential_consistency(&proof, &cores, &verifier_params).unwrap());
+    
+    // Swapping core 0's writes keeps the same operations but not their
+    // program order, under which core 1 could read the flag before the data
+    let reordered = vec![core_trace(vec![
+        MemoryOp::Write { address: 1, value: one },
+        MemoryOp::Write { address: 0, value: two },
+    ]), cores[1].clone()];
+    assert!(!twist.verify_sequential_consistency(&proof, &reordered, &verifier_params).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.final_rows.swap(0, 1);
+    assert!(!twist.verify_sequential_consistency(&tampered, &cores, &verifier_params).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.order_opening.evaluations[4] += one;
+    assert!(!twist.verify_sequential_consistency(&tampered, &cores, &verifier_params).unwrap());
+    
+    // The proof does not transfer to a different set of core programs
+    let other = vec![cores[0].clone(), core_trace(vec![
+        MemoryOp::Read { address: 1, value: one },
+        MemoryOp::Read { address: 0, value: one },
+    ])];
+    assert!(!twist.verify_sequential_consistency(&proof, &other, &verifier_params).unwrap());
+    
+    // Store buffering: each core reads the other's flag as unset after setting
+    // its own, which no interleaving explains
+    let racy = vec![
+        core_trace(vec![
+            MemoryOp::Write { address: 0, value: one },
+            MemoryOp::Read { address: 1, value: FieldElement::zero() },
+        ]),
+        core_trace(vec![
+            MemoryOp::Write { address: 1, value: one },
+            MemoryOp::Read { address: 0, value: FieldElement::zero() },
+        ]),
+    ];
+    assert!(twist.prove_sequential_consistency(&racy).is_err());
+}
+
+#[test]
+fn test_twist_immutable_range() {
+    let (prover_params, verifier_params) = setup_params(4);
+    let twist = Twist::new(&prover_params);
+    
+    // Code occupies cells 0..4; the program only reads it and writes data above
+    let mut trace = MemoryTrace::new(8);
+    trace.read(1).unwrap();
+    trace.write(4, FieldElement::from(3u64)).unwrap();
+    trace.read(2).unwrap();
+    trace.write(7, FieldElement::from(9u64)).unwrap();
+    
+    assert!(trace.assert_immutable_range(2..2).is_err());
+    assert!(trace.assert_immutable_range(6..9).is_err());
+    
+    let mut immutable = trace.clone();
+    immutable.assert_immutable_range(0..4).unwrap();
+    let proof = twist.prove(&immutable).unwrap();
+    assert!(twist.verify(&proof, &verifier_params).unwrap());
+    assert!(twist.verify_with_constraints(&proof, &verifier_params, &immutable.constraints()).unwrap());
+    assert!(!twist.verify_with_constraints(&proof, &verifier_params, &trace.constraints()).unwrap());
+    
+    // The recursive layout carries the ranges
+    let mut bytes = Vec::new();
+    proof.serialize_with_layout(ProofLayout::Recursive, &mut bytes).unwrap();
+    let decoded = TwistProof::<KZGCommitment>::deserialize_with_layout(ProofLayout::Recursive, &bytes[..]).unwrap();
+    assert_eq!(decoded.constraints, proof.constraints);
+    
+    // Swapping in a range the trace writes to fails, as does dropping the range
+    let mut moved = proof.clone();
+    moved.constraints.immutable_ranges.clear();
+    moved.constraints.immutable_ranges.push(4..5);
+    assert!(!twist.verify(&moved, &verifier_params).unwrap());
+    let mut dropped = proof.clone();
+    dropped.constraints.immutable_ranges.clear();
+    assert!(!twist.verify(&dropped, &verifier_params).unwrap());
+    let mut outside = proof.clone();
+    outside.constraints.immutable_ranges.clear();
+    outside.constraints.immutable_ranges.push(4..9);
+    assert!(!twist.verify(&outside, &verifier_params).unwrap());
+    
+    // The range covers writes recorded before it was asserted
+    let mut violated = trace.clone();
+    violated.assert_immutable_range(4..5).unwrap();
+    assert!(twist.prove(&violated).is_err());
+    assert!(twist.clone().with_constraint_debugging(true).prove(&violated).is_err());
+}
+
+#[test]
+fn test_twist_verify_no_alloc() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(4u64)).unwrap();
+    trace.read(2).unwrap();
+    trace.write(6, FieldElement::from(8u64)).unwrap();
+    trace.read(6).unwrap();
+    let proof = twist.prove(&trace).unwrap();
+    
+    // 3 challenges plus 5 sample points for a degree-4 sum-check over the 8
+    // memory rows, then τ and the consistency point
+    let scratch_len = Twist::no_alloc_scratch_len(&proof);
+    assert_eq!(scratch_len, 8 + 2 * 3);
+    let mut scratch = vec![FieldElement::zero(); scratch_len];
+    assert!(twist.verify_no_alloc(&proof, &verifier_params, &mut scratch).unwrap());
+    
+    let mut tampered = proof.clone();
+    tampered.final_evaluations[0] += FieldElement::one();
+    assert!(!twist.verify_no_alloc(&tampered, &verifier_params, &mut scratch).unwrap());
+    assert!(!twist.verify(&tampered, &verifier_params).unwrap());
+    
+    let mut small = vec![FieldElement::zero(); scratch_len - 1];
+    assert!(twist.verify_no_alloc(&proof, &verifier_params, &mut small).is_err());
+}
+
+#[test]
+fn test_twist_prove_state_equals_table() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(4);
+    trace.write(0, FieldElement::from(10u64)).unwrap();
+    trace.write(2, FieldElement::from(30u64)).unwrap();
+    trace.read(0).unwrap();
+    trace.write(0, FieldElement::from(11u64)).unwrap();
+    
+    let final_state: Vec<FieldElement> = [11u64, 0, 30, 0].iter().map(|&v| FieldElement::from(v)).collect();
+    let table = LookupTable::new(final_state.clone());
+    let (twist_proof, shout_proof, equality) = twist.prove_state_equals_table(&trace, &table).unwrap();
+    assert_eq!(equality.offset, 4);
+    assert_eq!(twist_proof.num_operations, 8);
+    assert!(twist.verify_state_equals_table(&twist_proof, &shout_proof, &equality, &verifier_params).unwrap());
+    
+    // A table differing from the final memory in one cell is refused
+    let mut stale = final_state;
+    stale[0] = FieldElement::from(10u64);
+    let stale_table = LookupTable::new(stale);
+    assert!(twist.prove_state_equals_table(&trace, &stale_table).is_err());
+    
+    // The equality proof does not transfer to another table's Shout proof
+    let other_shout = Shout::new(&prover_params).prove(&stale_table).unwrap();
+    assert!(!twist.verify_state_equals_table(&twist_proof, &other_shout, &equality, &verifier_params).unwrap());
+    
+    // Nor does it survive a changed evaluation
+    for index in 0..5 {
+        let mut tampered = equality.clone();
+        tampered.evaluations[index] += FieldElement::one();
+        assert!(!twist.verify_state_equals_table(&twist_proof, &shout_proof, &tampered, &verifier_params).unwrap());
+    }
+    
+    assert!(twist.prove_state_equals_table(&trace, &LookupTable::new(vec![FieldElement::zero(); 2])).is_err());
+}
+
+#[test]
+fn test_twist_trimmed_params() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let trimmed = prover_params.trim(3).unwrap();
+    assert_eq!(trimmed.commitment_params.g1_powers.len(), 4);
+    assert!(prover_params.trim(prover_params.commitment_params.g1_powers.len()).is_err());
+    
+    // Four operations on four cells interpolate to degree-3 polynomials
+    let mut small = MemoryTrace::new(4);
+    small.write(3, FieldElement::from(8u64)).unwrap();
+    small.read(3).unwrap();
+    small.write(1, FieldElement::from(2u64)).unwrap();
+    small.read(1).unwrap();
+    let twist = Twist::new(&trimmed);
+    let proof = twist.prove(&small).unwrap();
+    assert!(Twist::new(&prover_params).verify(&proof, &verifier_params).unwrap());
+    
+    // A fifth operation pads the trace to eight rows, beyond the trimmed degree
+    let mut large = small.clone();
+    large.read(3).unwrap();
+    assert!(twist.prove(&large).is_err());
+    assert!(Twist::new(&prover_params).prove(&large).is_ok());
+}
+
+#[test]
+fn test_twist_proof_is_bound_to_its_commitments() {
+    let (prover_params, verifier_params) = setup_params(3);
+    let twist = Twist::new(&prover_params);
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(5, FieldElement::from(12u64)).unwrap();
+    trace.read(5).unwrap();
+    
+    // Proving is deterministic, so proofs of one trace are linkable
+    let proof = twist.prove(&trace).unwrap();
+    assert_eq!(proof_bytes(&proof), proof_bytes(&twist.prove(&trace).unwrap()));
+    
+    // Re-blinding a commitment changes the challenges it was absorbed into
+    let mut reblinded = proof.clone();
+    reblinded.value_commitment = reblinded.value_commitment.clone() + twist_and_shout::commitments::KZGCommitmentValue {
+        commitment: prover_params.commitment_params.g1_powers[prover_params.commitment_params.g1_powers.len() - 1],
+    };
+    assert!(!twist.verify(&reblinded, &verifier_params).unwrap());
+}
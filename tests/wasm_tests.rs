@@ -0,0 +1,117 @@
+//! Smoke tests for [`verify_proof_bytes`] and [`verify_lookup_proof_bytes`]
+//! against precomputed proofs.
+//!
+//! The same test runs natively and, under `wasm-bindgen-test`, in a
+//! single-threaded wasm build:
+//!
+//! ```bash
+//! cargo test --target wasm32-unknown-unknown --no-default-features --test wasm_tests
+//! ```
+//!
+//! After a proof format change, rewrite the fixtures with
+//! `cargo test --test wasm_tests -- --ignored`.
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use twist_and_shout::{verify_lookup_proof_bytes, verify_proof_bytes, KZGCommitment, TwistProof};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test as test;
+
+const VK_BYTES: &[u8] = include_bytes!("fixtures/twist_vk.bin");
+const PROOF_BYTES: &[u8] = include_bytes!("fixtures/twist_proof.bin");
+const LOOKUP_PROOF_BYTES: &[u8] = include_bytes!("fixtures/shout_proof.bin");
+
+#[test]
+fn test_verify_precomputed_proof_bytes() {
+    assert!(verify_proof_bytes(VK_BYTES, PROOF_BYTES));
+}
+
+#[test]
+fn test_rejects_corrupted_proof_bytes() {
+    let mut corrupted = PROOF_BYTES.to_vec();
+    let last = corrupted.len() - 1;
+    corrupted[last] ^= 1;
+    assert!(!verify_proof_bytes(VK_BYTES, &corrupted));
+    
+    assert!(!verify_proof_bytes(VK_BYTES, &PROOF_BYTES[..PROOF_BYTES.len() - 1]));
+    assert!(!verify_proof_bytes(&VK_BYTES[1..], PROOF_BYTES));
+    
+    let mut trailing = PROOF_BYTES.to_vec();
+    trailing.push(0);
+    assert!(!verify_proof_bytes(VK_BYTES, &trailing));
+}
+
+#[test]
+fn test_rejects_oversized_round_count() {
+    // 64 rounds over the largest power-of-two memory would shift out of
+    // range if the shape were computed unchecked; it must be a plain
+    // rejection, not a trap
+    let top_bit = 1usize << (usize::BITS - 1);
+    let mut proof = TwistProof::<KZGCommitment>::deserialize_compressed(PROOF_BYTES).unwrap();
+    let round = proof.consistency_proof.round_polynomials[0].clone();
+    proof.consistency_proof.round_polynomials = vec![round; 64];
+    for (num_operations, memory_size) in [(usize::MAX, top_bit), (top_bit, 8), (4, top_bit)] {
+        proof.num_operations = num_operations;
+        proof.memory_size = memory_size;
+        let mut crafted = Vec::new();
+        proof.serialize_compressed(&mut crafted).unwrap();
+        assert!(!verify_proof_bytes(VK_BYTES, &crafted));
+    }
+}
+
+#[test]
+fn test_verify_precomputed_lookup_proof_bytes() {
+    assert!(verify_lookup_proof_bytes(VK_BYTES, LOOKUP_PROOF_BYTES));
+    
+    // A Twist proof is no Shout proof, and vice versa
+    assert!(!verify_lookup_proof_bytes(VK_BYTES, PROOF_BYTES));
+    assert!(!verify_proof_bytes(VK_BYTES, LOOKUP_PROOF_BYTES));
+    
+    let mut corrupted = LOOKUP_PROOF_BYTES.to_vec();
+    let last = corrupted.len() - 1;
+    corrupted[last] ^= 1;
+    assert!(!verify_lookup_proof_bytes(VK_BYTES, &corrupted));
+    assert!(!verify_lookup_proof_bytes(VK_BYTES, &LOOKUP_PROOF_BYTES[..LOOKUP_PROOF_BYTES.len() - 1]));
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[test]
+#[ignore]
+fn regenerate_fixtures() {
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+    use twist_and_shout::{setup_params_with_rng, FieldElement, LookupTable, MemoryTrace, Shout, Twist};
+    
+    let (prover_params, verifier_params) =
+        setup_params_with_rng(3, &mut ChaCha20Rng::from_seed([11u8; 32]));
+    
+    let mut trace = MemoryTrace::new(8);
+    trace.write(2, FieldElement::from(7u64)).unwrap();
+    trace.write(5, FieldElement::from(9u64)).unwrap();
+    trace.read(2).unwrap();
+    trace.read(5).unwrap();
+    
+    let mut vk_bytes = Vec::new();
+    verifier_params.write(&mut vk_bytes).unwrap();
+    let mut proof_bytes = Vec::new();
+    Twist::new(&prover_params)
+        .prove(&trace)
+        .unwrap()
+        .serialize_compressed(&mut proof_bytes)
+        .unwrap();
+    
+    let mut table = LookupTable::new((0..8u64).map(|i| FieldElement::from(3 * i)).collect());
+    table.lookup(6).unwrap();
+    table.lookup(1).unwrap();
+    table.lookup(6).unwrap();
+    let mut lookup_proof_bytes = Vec::new();
+    Shout::new(&prover_params)
+        .prove(&table)
+        .unwrap()
+        .serialize_compressed(&mut lookup_proof_bytes)
+        .unwrap();
+    
+    let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    std::fs::write(fixtures.join("twist_vk.bin"), vk_bytes).unwrap();
+    std::fs::write(fixtures.join("twist_proof.bin"), proof_bytes).unwrap();
+    std::fs::write(fixtures.join("shout_proof.bin"), lookup_proof_bytes).unwrap();
+}